@@ -21,12 +21,18 @@ import {
   getAddressEncoder,
   getArrayDecoder,
   getArrayEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getI64Decoder,
+  getI64Encoder,
   getOptionDecoder,
   getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU32Decoder,
   getU32Encoder,
   getU64Decoder,
@@ -51,18 +57,30 @@ import {
   type ReadonlyUint8Array,
 } from '@solana/kit';
 import {
+  getBuyerLimitDecoder,
+  getBuyerLimitEncoder,
   getFeeAccountDecoder,
   getFeeAccountEncoder,
   getJellybeanStateDecoder,
   getJellybeanStateEncoder,
   getPrintFeeConfigDecoder,
   getPrintFeeConfigEncoder,
+  getProvenanceConfigDecoder,
+  getProvenanceConfigEncoder,
+  getRevealConfigDecoder,
+  getRevealConfigEncoder,
+  type BuyerLimit,
+  type BuyerLimitArgs,
   type FeeAccount,
   type FeeAccountArgs,
   type JellybeanState,
   type JellybeanStateArgs,
   type PrintFeeConfig,
   type PrintFeeConfigArgs,
+  type ProvenanceConfig,
+  type ProvenanceConfigArgs,
+  type RevealConfig,
+  type RevealConfigArgs,
 } from '../types';
 
 export const JELLYBEAN_MACHINE_DISCRIMINATOR = new Uint8Array([
@@ -87,7 +105,7 @@ export type JellybeanMachine = {
   feeAccounts: Array<FeeAccount>;
   /** Print fee config */
   printFeeConfig: Option<PrintFeeConfig>;
-  /** Total unique items loaded. Up to 255 items. */
+  /** Total unique items loaded. Up to 10,000 items. */
   itemsLoaded: number;
   /** Total supply_loaded of all items added. */
   supplyLoaded: bigint;
@@ -97,6 +115,54 @@ export type JellybeanMachine = {
   state: JellybeanState;
   /** Uri of off-chain metadata, max length 196 */
   uri: string;
+  /** Provenance attributes written to assets as they are claimed */
+  provenanceConfig: Option<ProvenanceConfig>;
+  /** Indices of items temporarily excluded from draws, up to 16 */
+  disabledItems: Array<number>;
+  /** Delayed reveal, claimed assets get a placeholder uri until revealed */
+  revealConfig: Option<RevealConfig>;
+  /** Maximum number of units of an item a single buyer can win, up to 16 items */
+  buyerLimits: Array<BuyerLimit>;
+  /**
+   * Maximum number of units of any item a single buyer can win, unless the item has its
+   * own buyer limit
+   */
+  maxWinsPerItem: Option<number>;
+  /** Unix timestamp at which draws open, the sale goes live on its own if not started */
+  goLiveAt: Option<bigint>;
+  /** Unix timestamp after which draws are closed and anyone can end the sale */
+  endAt: Option<bigint>;
+  /** Whether draws must satisfy the active phase of the guard phases account */
+  hasGuardPhases: boolean;
+  /** Maximum number of draws a single buyer can make over the lifetime of the machine */
+  maxDrawsPerWallet: Option<number>;
+  /**
+   * Lamports charged to the payer of a public draw that breaks a machine rule, instead of failing.
+   * The tax accrues on the machine account and is paid to the authority with its rent
+   */
+  botTax: Option<bigint>;
+  /**
+   * Draw prices paid for the prizes not claimed yet, escrowed by the authority when the sale
+   * is cancelled so each buyer gets back what they paid
+   */
+  unclaimedPrice: bigint;
+  /**
+   * Unix timestamp after which the authority can reclaim prizes that haven't been claimed.
+   * Must be after end_at when both are set, and no draw is accepted once it has passed
+   */
+  claimDeadline: Option<bigint>;
+  /**
+   * Whether buyers can draw without the mint authority co-signing, only through paid
+   * guard phases
+   */
+  publicDraw: boolean;
+  /** Number of bundle accounts holding escrowed components, must be zero to withdraw */
+  openBundles: number;
+  /**
+   * Number of authority pda token accounts opened by bundle deposits, must be zero to
+   * withdraw
+   */
+  openTokenAccounts: number;
   /** Padding for future use */
   padding: ReadonlyUint8Array;
 };
@@ -112,7 +178,7 @@ export type JellybeanMachineArgs = {
   feeAccounts: Array<FeeAccountArgs>;
   /** Print fee config */
   printFeeConfig: OptionOrNullable<PrintFeeConfigArgs>;
-  /** Total unique items loaded. Up to 255 items. */
+  /** Total unique items loaded. Up to 10,000 items. */
   itemsLoaded: number;
   /** Total supply_loaded of all items added. */
   supplyLoaded: number | bigint;
@@ -122,6 +188,54 @@ export type JellybeanMachineArgs = {
   state: JellybeanStateArgs;
   /** Uri of off-chain metadata, max length 196 */
   uri: string;
+  /** Provenance attributes written to assets as they are claimed */
+  provenanceConfig: OptionOrNullable<ProvenanceConfigArgs>;
+  /** Indices of items temporarily excluded from draws, up to 16 */
+  disabledItems: Array<number>;
+  /** Delayed reveal, claimed assets get a placeholder uri until revealed */
+  revealConfig: OptionOrNullable<RevealConfigArgs>;
+  /** Maximum number of units of an item a single buyer can win, up to 16 items */
+  buyerLimits: Array<BuyerLimitArgs>;
+  /**
+   * Maximum number of units of any item a single buyer can win, unless the item has its
+   * own buyer limit
+   */
+  maxWinsPerItem: OptionOrNullable<number>;
+  /** Unix timestamp at which draws open, the sale goes live on its own if not started */
+  goLiveAt: OptionOrNullable<number | bigint>;
+  /** Unix timestamp after which draws are closed and anyone can end the sale */
+  endAt: OptionOrNullable<number | bigint>;
+  /** Whether draws must satisfy the active phase of the guard phases account */
+  hasGuardPhases: boolean;
+  /** Maximum number of draws a single buyer can make over the lifetime of the machine */
+  maxDrawsPerWallet: OptionOrNullable<number>;
+  /**
+   * Lamports charged to the payer of a public draw that breaks a machine rule, instead of failing.
+   * The tax accrues on the machine account and is paid to the authority with its rent
+   */
+  botTax: OptionOrNullable<number | bigint>;
+  /**
+   * Draw prices paid for the prizes not claimed yet, escrowed by the authority when the sale
+   * is cancelled so each buyer gets back what they paid
+   */
+  unclaimedPrice: number | bigint;
+  /**
+   * Unix timestamp after which the authority can reclaim prizes that haven't been claimed.
+   * Must be after end_at when both are set, and no draw is accepted once it has passed
+   */
+  claimDeadline: OptionOrNullable<number | bigint>;
+  /**
+   * Whether buyers can draw without the mint authority co-signing, only through paid
+   * guard phases
+   */
+  publicDraw: boolean;
+  /** Number of bundle accounts holding escrowed components, must be zero to withdraw */
+  openBundles: number;
+  /**
+   * Number of authority pda token accounts opened by bundle deposits, must be zero to
+   * withdraw
+   */
+  openTokenAccounts: number;
   /** Padding for future use */
   padding: ReadonlyUint8Array;
 };
@@ -135,12 +249,27 @@ export function getJellybeanMachineEncoder(): Encoder<JellybeanMachineArgs> {
       ['mintAuthority', getAddressEncoder()],
       ['feeAccounts', getArrayEncoder(getFeeAccountEncoder())],
       ['printFeeConfig', getOptionEncoder(getPrintFeeConfigEncoder())],
-      ['itemsLoaded', getU8Encoder()],
+      ['itemsLoaded', getU16Encoder()],
       ['supplyLoaded', getU64Encoder()],
       ['supplyRedeemed', getU64Encoder()],
       ['state', getJellybeanStateEncoder()],
       ['uri', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
-      ['padding', fixEncoderSize(getBytesEncoder(), 320)],
+      ['provenanceConfig', getOptionEncoder(getProvenanceConfigEncoder())],
+      ['disabledItems', getArrayEncoder(getU16Encoder())],
+      ['revealConfig', getOptionEncoder(getRevealConfigEncoder())],
+      ['buyerLimits', getArrayEncoder(getBuyerLimitEncoder())],
+      ['maxWinsPerItem', getOptionEncoder(getU16Encoder())],
+      ['goLiveAt', getOptionEncoder(getI64Encoder())],
+      ['endAt', getOptionEncoder(getI64Encoder())],
+      ['hasGuardPhases', getBooleanEncoder()],
+      ['maxDrawsPerWallet', getOptionEncoder(getU16Encoder())],
+      ['botTax', getOptionEncoder(getU64Encoder())],
+      ['unclaimedPrice', getU64Encoder()],
+      ['claimDeadline', getOptionEncoder(getI64Encoder())],
+      ['publicDraw', getBooleanEncoder()],
+      ['openBundles', getU16Encoder()],
+      ['openTokenAccounts', getU16Encoder()],
+      ['padding', fixEncoderSize(getBytesEncoder(), 20)],
     ]),
     (value) => ({ ...value, discriminator: JELLYBEAN_MACHINE_DISCRIMINATOR })
   );
@@ -154,12 +283,27 @@ export function getJellybeanMachineDecoder(): Decoder<JellybeanMachine> {
     ['mintAuthority', getAddressDecoder()],
     ['feeAccounts', getArrayDecoder(getFeeAccountDecoder())],
     ['printFeeConfig', getOptionDecoder(getPrintFeeConfigDecoder())],
-    ['itemsLoaded', getU8Decoder()],
+    ['itemsLoaded', getU16Decoder()],
     ['supplyLoaded', getU64Decoder()],
     ['supplyRedeemed', getU64Decoder()],
     ['state', getJellybeanStateDecoder()],
     ['uri', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
-    ['padding', fixDecoderSize(getBytesDecoder(), 320)],
+    ['provenanceConfig', getOptionDecoder(getProvenanceConfigDecoder())],
+    ['disabledItems', getArrayDecoder(getU16Decoder())],
+    ['revealConfig', getOptionDecoder(getRevealConfigDecoder())],
+    ['buyerLimits', getArrayDecoder(getBuyerLimitDecoder())],
+    ['maxWinsPerItem', getOptionDecoder(getU16Decoder())],
+    ['goLiveAt', getOptionDecoder(getI64Decoder())],
+    ['endAt', getOptionDecoder(getI64Decoder())],
+    ['hasGuardPhases', getBooleanDecoder()],
+    ['maxDrawsPerWallet', getOptionDecoder(getU16Decoder())],
+    ['botTax', getOptionDecoder(getU64Decoder())],
+    ['unclaimedPrice', getU64Decoder()],
+    ['claimDeadline', getOptionDecoder(getI64Decoder())],
+    ['publicDraw', getBooleanDecoder()],
+    ['openBundles', getU16Decoder()],
+    ['openTokenAccounts', getU16Decoder()],
+    ['padding', fixDecoderSize(getBytesDecoder(), 20)],
   ]);
 }
 
@@ -40,8 +40,12 @@ import {
 } from '@solana/kit';
 import { UnclaimedPrizesSeeds, findUnclaimedPrizesPda } from '../pdas';
 import {
+  getItemWinsDecoder,
+  getItemWinsEncoder,
   getPrizeDecoder,
   getPrizeEncoder,
+  type ItemWins,
+  type ItemWinsArgs,
   type Prize,
   type PrizeArgs,
 } from '../types';
@@ -66,6 +70,11 @@ export type UnclaimedPrizes = {
   buyer: Address;
   /** Indices of prizes drawn by the buyer */
   prizes: Array<Prize>;
+  /**
+   * Units won of each item with a buyer limit, kept after the prizes are claimed until
+   * the sale is cancelled or the machine withdrawn
+   */
+  wins: Array<ItemWins>;
 };
 
 export type UnclaimedPrizesArgs = {
@@ -77,6 +86,11 @@ export type UnclaimedPrizesArgs = {
   buyer: Address;
   /** Indices of prizes drawn by the buyer */
   prizes: Array<PrizeArgs>;
+  /**
+   * Units won of each item with a buyer limit, kept after the prizes are claimed until
+   * the sale is cancelled or the machine withdrawn
+   */
+  wins: Array<ItemWinsArgs>;
 };
 
 export function getUnclaimedPrizesEncoder(): Encoder<UnclaimedPrizesArgs> {
@@ -87,6 +101,7 @@ export function getUnclaimedPrizesEncoder(): Encoder<UnclaimedPrizesArgs> {
       ['jellybeanMachine', getAddressEncoder()],
       ['buyer', getAddressEncoder()],
       ['prizes', getArrayEncoder(getPrizeEncoder())],
+      ['wins', getArrayEncoder(getItemWinsEncoder())],
     ]),
     (value) => ({ ...value, discriminator: UNCLAIMED_PRIZES_DISCRIMINATOR })
   );
@@ -99,6 +114,7 @@ export function getUnclaimedPrizesDecoder(): Decoder<UnclaimedPrizes> {
     ['jellybeanMachine', getAddressDecoder()],
     ['buyer', getAddressDecoder()],
     ['prizes', getArrayDecoder(getPrizeDecoder())],
+    ['wins', getArrayDecoder(getItemWinsDecoder())],
   ]);
 }
 
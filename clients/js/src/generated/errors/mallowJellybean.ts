@@ -66,66 +66,210 @@ export const MALLOW_JELLYBEAN_ERROR__TOO_MANY_FEE_ACCOUNTS = 0x1787; // 6023
 export const MALLOW_JELLYBEAN_ERROR__TOO_MANY_ITEMS = 0x1788; // 6024
 /** InvalidFeeAccountsLength: Invalid fee accounts length */
 export const MALLOW_JELLYBEAN_ERROR__INVALID_FEE_ACCOUNTS_LENGTH = 0x1789; // 6025
+/** InvalidVersion: Invalid account version */
+export const MALLOW_JELLYBEAN_ERROR__INVALID_VERSION = 0x178a; // 6026
+/** MissingPrintTemplate: Missing print template */
+export const MALLOW_JELLYBEAN_ERROR__MISSING_PRINT_TEMPLATE = 0x178b; // 6027
+/** PrintTemplateTooLong: Print template too long */
+export const MALLOW_JELLYBEAN_ERROR__PRINT_TEMPLATE_TOO_LONG = 0x178c; // 6028
+/** InsufficientPrintEscrow: Print escrow too small for the print name and uri */
+export const MALLOW_JELLYBEAN_ERROR__INSUFFICIENT_PRINT_ESCROW = 0x178d; // 6029
+/** MachineCannotUpdateAsset: The authority pda must be the update authority or an update delegate of the asset */
+export const MALLOW_JELLYBEAN_ERROR__MACHINE_CANNOT_UPDATE_ASSET = 0x178e; // 6030
+/** BundleLoaded: Bundle is loaded in the machine */
+export const MALLOW_JELLYBEAN_ERROR__BUNDLE_LOADED = 0x178f; // 6031
+/** TooManyBundleComponents: Too many bundle components */
+export const MALLOW_JELLYBEAN_ERROR__TOO_MANY_BUNDLE_COMPONENTS = 0x1790; // 6032
+/** InvalidBundleComponent: Invalid bundle component */
+export const MALLOW_JELLYBEAN_ERROR__INVALID_BUNDLE_COMPONENT = 0x1791; // 6033
+/** TooManyDisabledItems: Too many disabled items */
+export const MALLOW_JELLYBEAN_ERROR__TOO_MANY_DISABLED_ITEMS = 0x1792; // 6034
+/** RevealNotEnabled: Reveal is not enabled */
+export const MALLOW_JELLYBEAN_ERROR__REVEAL_NOT_ENABLED = 0x1793; // 6035
+/** AlreadyRevealed: Asset is already revealed */
+export const MALLOW_JELLYBEAN_ERROR__ALREADY_REVEALED = 0x1794; // 6036
+/** BuyerLimitReached: Buyer has reached the limit of every remaining item */
+export const MALLOW_JELLYBEAN_ERROR__BUYER_LIMIT_REACHED = 0x1795; // 6037
+/** TooManyBuyerLimits: Too many buyer limits */
+export const MALLOW_JELLYBEAN_ERROR__TOO_MANY_BUYER_LIMITS = 0x1796; // 6038
+/** InvalidSchedule: Sale end time must be after its go live time */
+export const MALLOW_JELLYBEAN_ERROR__INVALID_SCHEDULE = 0x1797; // 6039
+/** SaleNotStarted: Sale has not gone live yet */
+export const MALLOW_JELLYBEAN_ERROR__SALE_NOT_STARTED = 0x1798; // 6040
+/** SaleOver: Sale end time has passed */
+export const MALLOW_JELLYBEAN_ERROR__SALE_OVER = 0x1799; // 6041
+/** SalePaused: Sale is paused */
+export const MALLOW_JELLYBEAN_ERROR__SALE_PAUSED = 0x179a; // 6042
+/** TooManyGuardPhases: Too many guard phases */
+export const MALLOW_JELLYBEAN_ERROR__TOO_MANY_GUARD_PHASES = 0x179b; // 6043
+/** InvalidGuardPhases: Guard phases must be ordered by start time */
+export const MALLOW_JELLYBEAN_ERROR__INVALID_GUARD_PHASES = 0x179c; // 6044
+/** MissingGuardAccount: Missing guard account */
+export const MALLOW_JELLYBEAN_ERROR__MISSING_GUARD_ACCOUNT = 0x179d; // 6045
+/** NoActivePhase: No guard phase is active */
+export const MALLOW_JELLYBEAN_ERROR__NO_ACTIVE_PHASE = 0x179e; // 6046
+/** MissingBuyerSignature: Buyer must sign gated or limited draws */
+export const MALLOW_JELLYBEAN_ERROR__MISSING_BUYER_SIGNATURE = 0x179f; // 6047
+/** NotAllowlisted: Buyer is not in the allowlist */
+export const MALLOW_JELLYBEAN_ERROR__NOT_ALLOWLISTED = 0x17a0; // 6048
+/** MissingGateToken: Buyer does not hold the gate token */
+export const MALLOW_JELLYBEAN_ERROR__MISSING_GATE_TOKEN = 0x17a1; // 6049
+/** WalletLimitReached: Buyer has reached the draw limit */
+export const MALLOW_JELLYBEAN_ERROR__WALLET_LIMIT_REACHED = 0x17a2; // 6050
+/** GuardPhasesLocked: Guard phases can only be added after the existing ones once the sale has started */
+export const MALLOW_JELLYBEAN_ERROR__GUARD_PHASES_LOCKED = 0x17a3; // 6051
+/** GuardRecordInUse: Guard records can only be closed once the machine is withdrawn or cancelled */
+export const MALLOW_JELLYBEAN_ERROR__GUARD_RECORD_IN_USE = 0x17a4; // 6052
+/** InvalidGateAsset: Invalid gate asset */
+export const MALLOW_JELLYBEAN_ERROR__INVALID_GATE_ASSET = 0x17a5; // 6053
+/** GateAssetUsed: Gate asset has already been used */
+export const MALLOW_JELLYBEAN_ERROR__GATE_ASSET_USED = 0x17a6; // 6054
+/** BundlePartiallyDelivered: Bundle has already been partially delivered */
+export const MALLOW_JELLYBEAN_ERROR__BUNDLE_PARTIALLY_DELIVERED = 0x17a7; // 6055
+/** ClaimDeadlineNotReached: Claim deadline has not passed yet */
+export const MALLOW_JELLYBEAN_ERROR__CLAIM_DEADLINE_NOT_REACHED = 0x17a8; // 6056
+/** BundlesStillOpen: Bundles still hold escrowed components */
+export const MALLOW_JELLYBEAN_ERROR__BUNDLES_STILL_OPEN = 0x17a9; // 6057
+/** MissingTokenAccount: Missing token account */
+export const MALLOW_JELLYBEAN_ERROR__MISSING_TOKEN_ACCOUNT = 0x17aa; // 6058
+/** PublicDrawWithoutPrice: Public draws require guard phases with a price */
+export const MALLOW_JELLYBEAN_ERROR__PUBLIC_DRAW_WITHOUT_PRICE = 0x17ab; // 6059
+/** MissingPrintFeeAccount: Missing print fee account */
+export const MALLOW_JELLYBEAN_ERROR__MISSING_PRINT_FEE_ACCOUNT = 0x17ac; // 6060
+/** TokenAccountsStillOpen: Authority pda token accounts must be swept */
+export const MALLOW_JELLYBEAN_ERROR__TOKEN_ACCOUNTS_STILL_OPEN = 0x17ad; // 6061
 
 export type MallowJellybeanError =
+  | typeof MALLOW_JELLYBEAN_ERROR__ALREADY_REVEALED
+  | typeof MALLOW_JELLYBEAN_ERROR__BUNDLE_LOADED
+  | typeof MALLOW_JELLYBEAN_ERROR__BUNDLE_PARTIALLY_DELIVERED
+  | typeof MALLOW_JELLYBEAN_ERROR__BUNDLES_STILL_OPEN
+  | typeof MALLOW_JELLYBEAN_ERROR__BUYER_LIMIT_REACHED
+  | typeof MALLOW_JELLYBEAN_ERROR__CLAIM_DEADLINE_NOT_REACHED
+  | typeof MALLOW_JELLYBEAN_ERROR__GATE_ASSET_USED
+  | typeof MALLOW_JELLYBEAN_ERROR__GUARD_PHASES_LOCKED
+  | typeof MALLOW_JELLYBEAN_ERROR__GUARD_RECORD_IN_USE
   | typeof MALLOW_JELLYBEAN_ERROR__INDEX_GREATER_THAN_LENGTH
+  | typeof MALLOW_JELLYBEAN_ERROR__INSUFFICIENT_PRINT_ESCROW
   | typeof MALLOW_JELLYBEAN_ERROR__INVALID_ASSET
   | typeof MALLOW_JELLYBEAN_ERROR__INVALID_AUTHORITY
+  | typeof MALLOW_JELLYBEAN_ERROR__INVALID_BUNDLE_COMPONENT
   | typeof MALLOW_JELLYBEAN_ERROR__INVALID_BUYER
   | typeof MALLOW_JELLYBEAN_ERROR__INVALID_FEE_ACCOUNT_BASIS_POINTS
   | typeof MALLOW_JELLYBEAN_ERROR__INVALID_FEE_ACCOUNTS_LENGTH
+  | typeof MALLOW_JELLYBEAN_ERROR__INVALID_GATE_ASSET
+  | typeof MALLOW_JELLYBEAN_ERROR__INVALID_GUARD_PHASES
   | typeof MALLOW_JELLYBEAN_ERROR__INVALID_INPUT_LENGTH
   | typeof MALLOW_JELLYBEAN_ERROR__INVALID_ITEM_INDEX
   | typeof MALLOW_JELLYBEAN_ERROR__INVALID_JELLYBEAN_MACHINE
   | typeof MALLOW_JELLYBEAN_ERROR__INVALID_MASTER_EDITION_SUPPLY
   | typeof MALLOW_JELLYBEAN_ERROR__INVALID_MINT_AUTHORITY
   | typeof MALLOW_JELLYBEAN_ERROR__INVALID_OWNER
+  | typeof MALLOW_JELLYBEAN_ERROR__INVALID_SCHEDULE
   | typeof MALLOW_JELLYBEAN_ERROR__INVALID_STATE
+  | typeof MALLOW_JELLYBEAN_ERROR__INVALID_VERSION
   | typeof MALLOW_JELLYBEAN_ERROR__ITEM_NOT_FULLY_CLAIMED
   | typeof MALLOW_JELLYBEAN_ERROR__ITEMS_STILL_LOADED
   | typeof MALLOW_JELLYBEAN_ERROR__JELLYBEAN_MACHINE_EMPTY
+  | typeof MALLOW_JELLYBEAN_ERROR__MACHINE_CANNOT_UPDATE_ASSET
   | typeof MALLOW_JELLYBEAN_ERROR__MASTER_EDITION_NOT_EMPTY
+  | typeof MALLOW_JELLYBEAN_ERROR__MISSING_BUYER_SIGNATURE
+  | typeof MALLOW_JELLYBEAN_ERROR__MISSING_GATE_TOKEN
+  | typeof MALLOW_JELLYBEAN_ERROR__MISSING_GUARD_ACCOUNT
   | typeof MALLOW_JELLYBEAN_ERROR__MISSING_MASTER_EDITION
   | typeof MALLOW_JELLYBEAN_ERROR__MISSING_PRINT_ASSET
+  | typeof MALLOW_JELLYBEAN_ERROR__MISSING_PRINT_FEE_ACCOUNT
+  | typeof MALLOW_JELLYBEAN_ERROR__MISSING_PRINT_TEMPLATE
+  | typeof MALLOW_JELLYBEAN_ERROR__MISSING_TOKEN_ACCOUNT
+  | typeof MALLOW_JELLYBEAN_ERROR__NO_ACTIVE_PHASE
   | typeof MALLOW_JELLYBEAN_ERROR__NOT_ALL_SETTLED
+  | typeof MALLOW_JELLYBEAN_ERROR__NOT_ALLOWLISTED
   | typeof MALLOW_JELLYBEAN_ERROR__NUMERICAL_OVERFLOW_ERROR
+  | typeof MALLOW_JELLYBEAN_ERROR__PRINT_TEMPLATE_TOO_LONG
+  | typeof MALLOW_JELLYBEAN_ERROR__PUBLIC_DRAW_WITHOUT_PRICE
   | typeof MALLOW_JELLYBEAN_ERROR__PUBLIC_KEY_MISMATCH
+  | typeof MALLOW_JELLYBEAN_ERROR__REVEAL_NOT_ENABLED
+  | typeof MALLOW_JELLYBEAN_ERROR__SALE_NOT_STARTED
+  | typeof MALLOW_JELLYBEAN_ERROR__SALE_OVER
+  | typeof MALLOW_JELLYBEAN_ERROR__SALE_PAUSED
+  | typeof MALLOW_JELLYBEAN_ERROR__TOKEN_ACCOUNTS_STILL_OPEN
+  | typeof MALLOW_JELLYBEAN_ERROR__TOO_MANY_BUNDLE_COMPONENTS
+  | typeof MALLOW_JELLYBEAN_ERROR__TOO_MANY_BUYER_LIMITS
+  | typeof MALLOW_JELLYBEAN_ERROR__TOO_MANY_DISABLED_ITEMS
   | typeof MALLOW_JELLYBEAN_ERROR__TOO_MANY_FEE_ACCOUNTS
+  | typeof MALLOW_JELLYBEAN_ERROR__TOO_MANY_GUARD_PHASES
   | typeof MALLOW_JELLYBEAN_ERROR__TOO_MANY_ITEMS
   | typeof MALLOW_JELLYBEAN_ERROR__UNINITIALIZED_ACCOUNT
-  | typeof MALLOW_JELLYBEAN_ERROR__URI_TOO_LONG;
+  | typeof MALLOW_JELLYBEAN_ERROR__URI_TOO_LONG
+  | typeof MALLOW_JELLYBEAN_ERROR__WALLET_LIMIT_REACHED;
 
 let mallowJellybeanErrorMessages:
   | Record<MallowJellybeanError, string>
   | undefined;
 if (process.env.NODE_ENV !== 'production') {
   mallowJellybeanErrorMessages = {
+    [MALLOW_JELLYBEAN_ERROR__ALREADY_REVEALED]: `Asset is already revealed`,
+    [MALLOW_JELLYBEAN_ERROR__BUNDLE_LOADED]: `Bundle is loaded in the machine`,
+    [MALLOW_JELLYBEAN_ERROR__BUNDLE_PARTIALLY_DELIVERED]: `Bundle has already been partially delivered`,
+    [MALLOW_JELLYBEAN_ERROR__BUNDLES_STILL_OPEN]: `Bundles still hold escrowed components`,
+    [MALLOW_JELLYBEAN_ERROR__BUYER_LIMIT_REACHED]: `Buyer has reached the limit of every remaining item`,
+    [MALLOW_JELLYBEAN_ERROR__CLAIM_DEADLINE_NOT_REACHED]: `Claim deadline has not passed yet`,
+    [MALLOW_JELLYBEAN_ERROR__GATE_ASSET_USED]: `Gate asset has already been used`,
+    [MALLOW_JELLYBEAN_ERROR__GUARD_PHASES_LOCKED]: `Guard phases can only be added after the existing ones once the sale has started`,
+    [MALLOW_JELLYBEAN_ERROR__GUARD_RECORD_IN_USE]: `Guard records can only be closed once the machine is withdrawn or cancelled`,
     [MALLOW_JELLYBEAN_ERROR__INDEX_GREATER_THAN_LENGTH]: `Index greater than length`,
+    [MALLOW_JELLYBEAN_ERROR__INSUFFICIENT_PRINT_ESCROW]: `Print escrow too small for the print name and uri`,
     [MALLOW_JELLYBEAN_ERROR__INVALID_ASSET]: `Invalid asset`,
     [MALLOW_JELLYBEAN_ERROR__INVALID_AUTHORITY]: `Invalid authority`,
+    [MALLOW_JELLYBEAN_ERROR__INVALID_BUNDLE_COMPONENT]: `Invalid bundle component`,
     [MALLOW_JELLYBEAN_ERROR__INVALID_BUYER]: `Invalid buyer`,
     [MALLOW_JELLYBEAN_ERROR__INVALID_FEE_ACCOUNT_BASIS_POINTS]: `Fee account basis points must sum to 10000`,
     [MALLOW_JELLYBEAN_ERROR__INVALID_FEE_ACCOUNTS_LENGTH]: `Invalid fee accounts length`,
+    [MALLOW_JELLYBEAN_ERROR__INVALID_GATE_ASSET]: `Invalid gate asset`,
+    [MALLOW_JELLYBEAN_ERROR__INVALID_GUARD_PHASES]: `Guard phases must be ordered by start time`,
     [MALLOW_JELLYBEAN_ERROR__INVALID_INPUT_LENGTH]: `Invalid input length`,
     [MALLOW_JELLYBEAN_ERROR__INVALID_ITEM_INDEX]: `Invalid item index`,
     [MALLOW_JELLYBEAN_ERROR__INVALID_JELLYBEAN_MACHINE]: `Invalid jellybean machine`,
     [MALLOW_JELLYBEAN_ERROR__INVALID_MASTER_EDITION_SUPPLY]: `Invalid master edition supply`,
     [MALLOW_JELLYBEAN_ERROR__INVALID_MINT_AUTHORITY]: `Invalid mint authority`,
     [MALLOW_JELLYBEAN_ERROR__INVALID_OWNER]: `Invalid owner`,
+    [MALLOW_JELLYBEAN_ERROR__INVALID_SCHEDULE]: `Sale end time must be after its go live time`,
     [MALLOW_JELLYBEAN_ERROR__INVALID_STATE]: `Invalid state`,
+    [MALLOW_JELLYBEAN_ERROR__INVALID_VERSION]: `Invalid account version`,
     [MALLOW_JELLYBEAN_ERROR__ITEM_NOT_FULLY_CLAIMED]: `Item not fully claimed`,
     [MALLOW_JELLYBEAN_ERROR__ITEMS_STILL_LOADED]: `Items still loaded`,
     [MALLOW_JELLYBEAN_ERROR__JELLYBEAN_MACHINE_EMPTY]: `Jellybean machine is empty`,
+    [MALLOW_JELLYBEAN_ERROR__MACHINE_CANNOT_UPDATE_ASSET]: `The authority pda must be the update authority or an update delegate of the asset`,
     [MALLOW_JELLYBEAN_ERROR__MASTER_EDITION_NOT_EMPTY]: `Master edition not empty`,
+    [MALLOW_JELLYBEAN_ERROR__MISSING_BUYER_SIGNATURE]: `Buyer must sign gated or limited draws`,
+    [MALLOW_JELLYBEAN_ERROR__MISSING_GATE_TOKEN]: `Buyer does not hold the gate token`,
+    [MALLOW_JELLYBEAN_ERROR__MISSING_GUARD_ACCOUNT]: `Missing guard account`,
     [MALLOW_JELLYBEAN_ERROR__MISSING_MASTER_EDITION]: `Missing master edition`,
     [MALLOW_JELLYBEAN_ERROR__MISSING_PRINT_ASSET]: `Missing print asset`,
+    [MALLOW_JELLYBEAN_ERROR__MISSING_PRINT_FEE_ACCOUNT]: `Missing print fee account`,
+    [MALLOW_JELLYBEAN_ERROR__MISSING_PRINT_TEMPLATE]: `Missing print template`,
+    [MALLOW_JELLYBEAN_ERROR__MISSING_TOKEN_ACCOUNT]: `Missing token account`,
+    [MALLOW_JELLYBEAN_ERROR__NO_ACTIVE_PHASE]: `No guard phase is active`,
     [MALLOW_JELLYBEAN_ERROR__NOT_ALL_SETTLED]: `Not all items have been settled`,
+    [MALLOW_JELLYBEAN_ERROR__NOT_ALLOWLISTED]: `Buyer is not in the allowlist`,
     [MALLOW_JELLYBEAN_ERROR__NUMERICAL_OVERFLOW_ERROR]: `Numerical overflow error`,
+    [MALLOW_JELLYBEAN_ERROR__PRINT_TEMPLATE_TOO_LONG]: `Print template too long`,
+    [MALLOW_JELLYBEAN_ERROR__PUBLIC_DRAW_WITHOUT_PRICE]: `Public draws require guard phases with a price`,
     [MALLOW_JELLYBEAN_ERROR__PUBLIC_KEY_MISMATCH]: `Invalid public key`,
+    [MALLOW_JELLYBEAN_ERROR__REVEAL_NOT_ENABLED]: `Reveal is not enabled`,
+    [MALLOW_JELLYBEAN_ERROR__SALE_NOT_STARTED]: `Sale has not gone live yet`,
+    [MALLOW_JELLYBEAN_ERROR__SALE_OVER]: `Sale end time has passed`,
+    [MALLOW_JELLYBEAN_ERROR__SALE_PAUSED]: `Sale is paused`,
+    [MALLOW_JELLYBEAN_ERROR__TOKEN_ACCOUNTS_STILL_OPEN]: `Authority pda token accounts must be swept`,
+    [MALLOW_JELLYBEAN_ERROR__TOO_MANY_BUNDLE_COMPONENTS]: `Too many bundle components`,
+    [MALLOW_JELLYBEAN_ERROR__TOO_MANY_BUYER_LIMITS]: `Too many buyer limits`,
+    [MALLOW_JELLYBEAN_ERROR__TOO_MANY_DISABLED_ITEMS]: `Too many disabled items`,
     [MALLOW_JELLYBEAN_ERROR__TOO_MANY_FEE_ACCOUNTS]: `Too many fee accounts`,
+    [MALLOW_JELLYBEAN_ERROR__TOO_MANY_GUARD_PHASES]: `Too many guard phases`,
     [MALLOW_JELLYBEAN_ERROR__TOO_MANY_ITEMS]: `Too many items`,
     [MALLOW_JELLYBEAN_ERROR__UNINITIALIZED_ACCOUNT]: `Account not initialized`,
     [MALLOW_JELLYBEAN_ERROR__URI_TOO_LONG]: `URI too long`,
+    [MALLOW_JELLYBEAN_ERROR__WALLET_LIMIT_REACHED]: `Buyer has reached the draw limit`,
   };
 }
 
@@ -12,8 +12,13 @@ import {
   fixEncoderSize,
   getBytesDecoder,
   getBytesEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
+  none,
   transformEncoder,
   type Address,
   type Codec,
@@ -24,6 +29,7 @@ import {
   type IInstruction,
   type IInstructionWithAccounts,
   type IInstructionWithData,
+  type Option,
   type OptionOrNullable,
   type ReadonlyAccount,
   type ReadonlyUint8Array,
@@ -31,7 +37,11 @@ import {
   type WritableAccount,
   type WritableSignerAccount,
 } from '@solana/kit';
-import { resolveAuthorityPda } from '../../hooked';
+import {
+  resolveAuthorityPda,
+  resolveEventAuthorityPda,
+  resolveProgram,
+} from '../../hooked';
 import { MALLOW_JELLYBEAN_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
 
@@ -59,6 +69,8 @@ export type AddCoreItemInstruction<
   TAccountSystemProgram extends
     | string
     | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountEventAuthority extends string | IAccountMeta<string> = string,
+  TAccountProgram extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -90,24 +102,43 @@ export type AddCoreItemInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountEventAuthority extends string
+        ? ReadonlyAccount<TAccountEventAuthority>
+        : TAccountEventAuthority,
+      TAccountProgram extends string
+        ? ReadonlyAccount<TAccountProgram>
+        : TAccountProgram,
       ...TRemainingAccounts,
     ]
   >;
 
-export type AddCoreItemInstructionData = { discriminator: ReadonlyUint8Array };
+export type AddCoreItemInstructionData = {
+  discriminator: ReadonlyUint8Array;
+  supply: Option<number>;
+};
 
-export type AddCoreItemInstructionDataArgs = {};
+export type AddCoreItemInstructionDataArgs = {
+  supply?: OptionOrNullable<number>;
+};
 
 export function getAddCoreItemInstructionDataEncoder(): Encoder<AddCoreItemInstructionDataArgs> {
   return transformEncoder(
-    getStructEncoder([['discriminator', fixEncoderSize(getBytesEncoder(), 8)]]),
-    (value) => ({ ...value, discriminator: ADD_CORE_ITEM_DISCRIMINATOR })
+    getStructEncoder([
+      ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
+      ['supply', getOptionEncoder(getU32Encoder())],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: ADD_CORE_ITEM_DISCRIMINATOR,
+      supply: value.supply ?? none(),
+    })
   );
 }
 
 export function getAddCoreItemInstructionDataDecoder(): Decoder<AddCoreItemInstructionData> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
+    ['supply', getOptionDecoder(getU32Decoder())],
   ]);
 }
 
@@ -135,6 +166,8 @@ export type AddCoreItemInput<
   TAccountCollection extends string = string,
   TAccountMplCoreProgram extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountEventAuthority extends string = string,
+  TAccountProgram extends string = string,
 > = {
   /** Gumball Machine account. */
   jellybeanMachine: Address<TAccountJellybeanMachine>;
@@ -148,6 +181,9 @@ export type AddCoreItemInput<
   collection?: Address<TAccountCollection>;
   mplCoreProgram?: Address<TAccountMplCoreProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
+  eventAuthority?: Address<TAccountEventAuthority>;
+  program?: Address<TAccountProgram>;
+  supply?: AddCoreItemInstructionDataArgs['supply'];
   unused?: AddCoreItemInstructionExtraArgs['unused'];
 };
 
@@ -160,6 +196,8 @@ export function getAddCoreItemInstruction<
   TAccountCollection extends string,
   TAccountMplCoreProgram extends string,
   TAccountSystemProgram extends string,
+  TAccountEventAuthority extends string,
+  TAccountProgram extends string,
   TProgramAddress extends Address = typeof MALLOW_JELLYBEAN_PROGRAM_ADDRESS,
 >(
   input: AddCoreItemInput<
@@ -170,7 +208,9 @@ export function getAddCoreItemInstruction<
     TAccountAsset,
     TAccountCollection,
     TAccountMplCoreProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): AddCoreItemInstruction<
@@ -182,7 +222,9 @@ export function getAddCoreItemInstruction<
   TAccountAsset,
   TAccountCollection,
   TAccountMplCoreProgram,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountEventAuthority,
+  TAccountProgram
 > {
   // Program address.
   const programAddress =
@@ -201,6 +243,8 @@ export function getAddCoreItemInstruction<
     collection: { value: input.collection ?? null, isWritable: true },
     mplCoreProgram: { value: input.mplCoreProgram ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
+    program: { value: input.program ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -228,6 +272,21 @@ export function getAddCoreItemInstruction<
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
+  if (!accounts.eventAuthority.value) {
+    accounts.eventAuthority = {
+      ...accounts.eventAuthority,
+      ...resolveEventAuthorityPda(resolverScope),
+    };
+  }
+  if (!accounts.program.value) {
+    accounts.program = {
+      ...accounts.program,
+      ...resolveProgram(resolverScope),
+    };
+  }
+  if (!args.supply) {
+    args.supply = none();
+  }
   if (!args.unused) {
     args.unused = false;
   }
@@ -243,9 +302,13 @@ export function getAddCoreItemInstruction<
       getAccountMeta(accounts.collection),
       getAccountMeta(accounts.mplCoreProgram),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.eventAuthority),
+      getAccountMeta(accounts.program),
     ],
     programAddress,
-    data: getAddCoreItemInstructionDataEncoder().encode({}),
+    data: getAddCoreItemInstructionDataEncoder().encode(
+      args as AddCoreItemInstructionDataArgs
+    ),
   } as AddCoreItemInstruction<
     TProgramAddress,
     TAccountJellybeanMachine,
@@ -255,7 +318,9 @@ export function getAddCoreItemInstruction<
     TAccountAsset,
     TAccountCollection,
     TAccountMplCoreProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountEventAuthority,
+    TAccountProgram
   >;
 
   return instruction;
@@ -279,6 +344,8 @@ export type ParsedAddCoreItemInstruction<
     collection?: TAccountMetas[5] | undefined;
     mplCoreProgram: TAccountMetas[6];
     systemProgram: TAccountMetas[7];
+    eventAuthority: TAccountMetas[8];
+    program: TAccountMetas[9];
   };
   data: AddCoreItemInstructionData;
 };
@@ -291,7 +358,7 @@ export function parseAddCoreItemInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedAddCoreItemInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 8) {
+  if (instruction.accounts.length < 10) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -318,6 +385,8 @@ export function parseAddCoreItemInstruction<
       collection: getNextOptionalAccount(),
       mplCoreProgram: getNextAccount(),
       systemProgram: getNextAccount(),
+      eventAuthority: getNextAccount(),
+      program: getNextAccount(),
     },
     data: getAddCoreItemInstructionDataDecoder().decode(instruction.data),
   };
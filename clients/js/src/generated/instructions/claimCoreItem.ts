@@ -14,8 +14,8 @@ import {
   getBytesEncoder,
   getStructDecoder,
   getStructEncoder,
-  getU8Decoder,
-  getU8Encoder,
+  getU16Decoder,
+  getU16Encoder,
   transformEncoder,
   type Address,
   type Codec,
@@ -35,6 +35,7 @@ import {
 import {
   resolveAuthorityPda,
   resolveEventAuthorityPda,
+  resolvePrintTemplatePda,
   resolveProgram,
 } from '../../hooked';
 import { findUnclaimedPrizesPda } from '../pdas';
@@ -65,6 +66,7 @@ export type ClaimCoreItemInstruction<
   TAccountAsset extends string | IAccountMeta<string> = string,
   TAccountCollection extends string | IAccountMeta<string> = string,
   TAccountPrintAsset extends string | IAccountMeta<string> = string,
+  TAccountPrintTemplate extends string | IAccountMeta<string> = string,
   TAccountMplCoreProgram extends
     | string
     | IAccountMeta<string> = 'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d',
@@ -104,6 +106,9 @@ export type ClaimCoreItemInstruction<
         ? WritableSignerAccount<TAccountPrintAsset> &
             IAccountSignerMeta<TAccountPrintAsset>
         : TAccountPrintAsset,
+      TAccountPrintTemplate extends string
+        ? ReadonlyAccount<TAccountPrintTemplate>
+        : TAccountPrintTemplate,
       TAccountMplCoreProgram extends string
         ? ReadonlyAccount<TAccountMplCoreProgram>
         : TAccountMplCoreProgram,
@@ -131,7 +136,7 @@ export function getClaimCoreItemInstructionDataEncoder(): Encoder<ClaimCoreItemI
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
-      ['index', getU8Encoder()],
+      ['index', getU16Encoder()],
     ]),
     (value) => ({ ...value, discriminator: CLAIM_CORE_ITEM_DISCRIMINATOR })
   );
@@ -140,7 +145,7 @@ export function getClaimCoreItemInstructionDataEncoder(): Encoder<ClaimCoreItemI
 export function getClaimCoreItemInstructionDataDecoder(): Decoder<ClaimCoreItemInstructionData> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
-    ['index', getU8Decoder()],
+    ['index', getU16Decoder()],
   ]);
 }
 
@@ -163,6 +168,7 @@ export type ClaimCoreItemAsyncInput<
   TAccountAsset extends string = string,
   TAccountCollection extends string = string,
   TAccountPrintAsset extends string = string,
+  TAccountPrintTemplate extends string = string,
   TAccountMplCoreProgram extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountEventAuthority extends string = string,
@@ -180,6 +186,8 @@ export type ClaimCoreItemAsyncInput<
   asset?: Address<TAccountAsset>;
   collection?: Address<TAccountCollection>;
   printAsset?: TransactionSigner<TAccountPrintAsset>;
+  /** Print template of the master edition, required when claiming an edition. */
+  printTemplate?: Address<TAccountPrintTemplate>;
   mplCoreProgram?: Address<TAccountMplCoreProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
   eventAuthority?: Address<TAccountEventAuthority>;
@@ -196,6 +204,7 @@ export async function getClaimCoreItemInstructionAsync<
   TAccountAsset extends string,
   TAccountCollection extends string,
   TAccountPrintAsset extends string,
+  TAccountPrintTemplate extends string,
   TAccountMplCoreProgram extends string,
   TAccountSystemProgram extends string,
   TAccountEventAuthority extends string,
@@ -211,6 +220,7 @@ export async function getClaimCoreItemInstructionAsync<
     TAccountAsset,
     TAccountCollection,
     TAccountPrintAsset,
+    TAccountPrintTemplate,
     TAccountMplCoreProgram,
     TAccountSystemProgram,
     TAccountEventAuthority,
@@ -228,6 +238,7 @@ export async function getClaimCoreItemInstructionAsync<
     TAccountAsset,
     TAccountCollection,
     TAccountPrintAsset,
+    TAccountPrintTemplate,
     TAccountMplCoreProgram,
     TAccountSystemProgram,
     TAccountEventAuthority,
@@ -251,6 +262,7 @@ export async function getClaimCoreItemInstructionAsync<
     asset: { value: input.asset ?? null, isWritable: true },
     collection: { value: input.collection ?? null, isWritable: true },
     printAsset: { value: input.printAsset ?? null, isWritable: true },
+    printTemplate: { value: input.printTemplate ?? null, isWritable: false },
     mplCoreProgram: { value: input.mplCoreProgram ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
@@ -280,6 +292,12 @@ export async function getClaimCoreItemInstructionAsync<
       buyer: expectAddress(accounts.buyer.value),
     });
   }
+  if (!accounts.printTemplate.value) {
+    accounts.printTemplate = {
+      ...accounts.printTemplate,
+      ...resolvePrintTemplatePda(resolverScope),
+    };
+  }
   if (!accounts.mplCoreProgram.value) {
     accounts.mplCoreProgram.value =
       'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d' as Address<'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'>;
@@ -312,6 +330,7 @@ export async function getClaimCoreItemInstructionAsync<
       getAccountMeta(accounts.asset),
       getAccountMeta(accounts.collection),
       getAccountMeta(accounts.printAsset),
+      getAccountMeta(accounts.printTemplate),
       getAccountMeta(accounts.mplCoreProgram),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.eventAuthority),
@@ -331,6 +350,7 @@ export async function getClaimCoreItemInstructionAsync<
     TAccountAsset,
     TAccountCollection,
     TAccountPrintAsset,
+    TAccountPrintTemplate,
     TAccountMplCoreProgram,
     TAccountSystemProgram,
     TAccountEventAuthority,
@@ -349,6 +369,7 @@ export type ClaimCoreItemInput<
   TAccountAsset extends string = string,
   TAccountCollection extends string = string,
   TAccountPrintAsset extends string = string,
+  TAccountPrintTemplate extends string = string,
   TAccountMplCoreProgram extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountEventAuthority extends string = string,
@@ -366,6 +387,8 @@ export type ClaimCoreItemInput<
   asset?: Address<TAccountAsset>;
   collection?: Address<TAccountCollection>;
   printAsset?: TransactionSigner<TAccountPrintAsset>;
+  /** Print template of the master edition, required when claiming an edition. */
+  printTemplate?: Address<TAccountPrintTemplate>;
   mplCoreProgram?: Address<TAccountMplCoreProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
   eventAuthority?: Address<TAccountEventAuthority>;
@@ -382,6 +405,7 @@ export function getClaimCoreItemInstruction<
   TAccountAsset extends string,
   TAccountCollection extends string,
   TAccountPrintAsset extends string,
+  TAccountPrintTemplate extends string,
   TAccountMplCoreProgram extends string,
   TAccountSystemProgram extends string,
   TAccountEventAuthority extends string,
@@ -397,6 +421,7 @@ export function getClaimCoreItemInstruction<
     TAccountAsset,
     TAccountCollection,
     TAccountPrintAsset,
+    TAccountPrintTemplate,
     TAccountMplCoreProgram,
     TAccountSystemProgram,
     TAccountEventAuthority,
@@ -413,6 +438,7 @@ export function getClaimCoreItemInstruction<
   TAccountAsset,
   TAccountCollection,
   TAccountPrintAsset,
+  TAccountPrintTemplate,
   TAccountMplCoreProgram,
   TAccountSystemProgram,
   TAccountEventAuthority,
@@ -435,6 +461,7 @@ export function getClaimCoreItemInstruction<
     asset: { value: input.asset ?? null, isWritable: true },
     collection: { value: input.collection ?? null, isWritable: true },
     printAsset: { value: input.printAsset ?? null, isWritable: true },
+    printTemplate: { value: input.printTemplate ?? null, isWritable: false },
     mplCoreProgram: { value: input.mplCoreProgram ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     eventAuthority: { value: input.eventAuthority ?? null, isWritable: false },
@@ -458,6 +485,12 @@ export function getClaimCoreItemInstruction<
       ...resolveAuthorityPda(resolverScope),
     };
   }
+  if (!accounts.printTemplate.value) {
+    accounts.printTemplate = {
+      ...accounts.printTemplate,
+      ...resolvePrintTemplatePda(resolverScope),
+    };
+  }
   if (!accounts.mplCoreProgram.value) {
     accounts.mplCoreProgram.value =
       'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d' as Address<'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'>;
@@ -490,6 +523,7 @@ export function getClaimCoreItemInstruction<
       getAccountMeta(accounts.asset),
       getAccountMeta(accounts.collection),
       getAccountMeta(accounts.printAsset),
+      getAccountMeta(accounts.printTemplate),
       getAccountMeta(accounts.mplCoreProgram),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.eventAuthority),
@@ -509,6 +543,7 @@ export function getClaimCoreItemInstruction<
     TAccountAsset,
     TAccountCollection,
     TAccountPrintAsset,
+    TAccountPrintTemplate,
     TAccountMplCoreProgram,
     TAccountSystemProgram,
     TAccountEventAuthority,
@@ -536,10 +571,12 @@ export type ParsedClaimCoreItemInstruction<
     asset?: TAccountMetas[5] | undefined;
     collection?: TAccountMetas[6] | undefined;
     printAsset?: TAccountMetas[7] | undefined;
-    mplCoreProgram: TAccountMetas[8];
-    systemProgram: TAccountMetas[9];
-    eventAuthority: TAccountMetas[10];
-    program: TAccountMetas[11];
+    /** Print template of the master edition, required when claiming an edition. */
+    printTemplate?: TAccountMetas[8] | undefined;
+    mplCoreProgram: TAccountMetas[9];
+    systemProgram: TAccountMetas[10];
+    eventAuthority: TAccountMetas[11];
+    program: TAccountMetas[12];
   };
   data: ClaimCoreItemInstructionData;
 };
@@ -552,7 +589,7 @@ export function parseClaimCoreItemInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedClaimCoreItemInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 12) {
+  if (instruction.accounts.length < 13) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -579,6 +616,7 @@ export function parseClaimCoreItemInstruction<
       asset: getNextOptionalAccount(),
       collection: getNextOptionalAccount(),
       printAsset: getNextOptionalAccount(),
+      printTemplate: getNextOptionalAccount(),
       mplCoreProgram: getNextAccount(),
       systemProgram: getNextAccount(),
       eventAuthority: getNextAccount(),
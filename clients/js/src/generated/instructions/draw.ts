@@ -26,7 +26,6 @@ import {
   type IInstructionWithData,
   type OptionOrNullable,
   type ReadonlyAccount,
-  type ReadonlySignerAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
   type WritableAccount,
@@ -85,8 +84,7 @@ export type DrawInstruction<
         ? WritableAccount<TAccountAuthorityPda>
         : TAccountAuthorityPda,
       TAccountMintAuthority extends string
-        ? ReadonlySignerAccount<TAccountMintAuthority> &
-            IAccountSignerMeta<TAccountMintAuthority>
+        ? ReadonlyAccount<TAccountMintAuthority>
         : TAccountMintAuthority,
       TAccountPayer extends string
         ? WritableSignerAccount<TAccountPayer> &
@@ -169,8 +167,12 @@ export type DrawAsyncInput<
   /** Jellybean machine account. */
   jellybeanMachine: Address<TAccountJellybeanMachine>;
   authorityPda?: Address<TAccountAuthorityPda>;
-  /** Jellybean machine mint authority (mint only allowed for the mint_authority). */
-  mintAuthority: TransactionSigner<TAccountMintAuthority>;
+  /**
+   * Jellybean machine mint authority (mint only allowed for the mint_authority).
+   * Only required to sign if the machine doesn't allow public draws.
+   *
+   */
+  mintAuthority: Address<TAccountMintAuthority>;
   /** Payer for the transaction and account allocation (rent). */
   payer: TransactionSigner<TAccountPayer>;
   /**
@@ -374,8 +376,12 @@ export type DrawInput<
   /** Jellybean machine account. */
   jellybeanMachine: Address<TAccountJellybeanMachine>;
   authorityPda?: Address<TAccountAuthorityPda>;
-  /** Jellybean machine mint authority (mint only allowed for the mint_authority). */
-  mintAuthority: TransactionSigner<TAccountMintAuthority>;
+  /**
+   * Jellybean machine mint authority (mint only allowed for the mint_authority).
+   * Only required to sign if the machine doesn't allow public draws.
+   *
+   */
+  mintAuthority: Address<TAccountMintAuthority>;
   /** Payer for the transaction and account allocation (rent). */
   payer: TransactionSigner<TAccountPayer>;
   /**
@@ -563,7 +569,12 @@ export type ParsedDrawInstruction<
     /** Jellybean machine account. */
     jellybeanMachine: TAccountMetas[0];
     authorityPda: TAccountMetas[1];
-    /** Jellybean machine mint authority (mint only allowed for the mint_authority). */
+    /**
+     * Jellybean machine mint authority (mint only allowed for the mint_authority).
+     * Only required to sign if the machine doesn't allow public draws.
+     *
+     */
+
     mintAuthority: TAccountMetas[2];
     /** Payer for the transaction and account allocation (rent). */
     payer: TAccountMetas[3];
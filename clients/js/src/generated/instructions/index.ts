@@ -6,13 +6,39 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './addBundleComponent';
+export * from './addBundleItem';
 export * from './addCoreItem';
+export * from './addCoreItems';
+export * from './addPrints';
+export * from './cancelSale';
+export * from './claimBundleComponent';
 export * from './claimCoreItem';
+export * from './closeGuardRecord';
+export * from './closeUnclaimedPrizes';
 export * from './draw';
 export * from './endSale';
+export * from './endScheduledSale';
 export * from './initialize';
+export * from './migrateJellybeanMachine';
+export * from './migrateUnclaimedPrizes';
+export * from './pauseSale';
+export * from './proveAllowlist';
+export * from './reclaimPrize';
+export * from './refundPrize';
+export * from './removeBundleComponent';
+export * from './removeBundleItem';
 export * from './removeCoreItem';
+export * from './removeCoreItems';
+export * from './replaceCoreItem';
+export * from './resumeSale';
+export * from './revealItems';
+export * from './setGuardPhases';
+export * from './setItemBuyerLimit';
+export * from './setItemEnabled';
 export * from './setMintAuthority';
+export * from './setPrintTemplate';
 export * from './startSale';
+export * from './unloadAll';
 export * from './updateSettings';
 export * from './withdraw';
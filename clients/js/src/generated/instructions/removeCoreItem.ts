@@ -14,8 +14,8 @@ import {
   getBytesEncoder,
   getStructDecoder,
   getStructEncoder,
-  getU8Decoder,
-  getU8Encoder,
+  getU16Decoder,
+  getU16Encoder,
   transformEncoder,
   type Address,
   type Codec,
@@ -32,7 +32,7 @@ import {
   type WritableAccount,
   type WritableSignerAccount,
 } from '@solana/kit';
-import { resolveAuthorityPda } from '../../hooked';
+import { resolveAuthorityPda, resolvePrintTemplatePda } from '../../hooked';
 import { MALLOW_JELLYBEAN_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
 
@@ -53,6 +53,7 @@ export type RemoveCoreItemInstruction<
   TAccountAuthority extends string | IAccountMeta<string> = string,
   TAccountAsset extends string | IAccountMeta<string> = string,
   TAccountCollection extends string | IAccountMeta<string> = string,
+  TAccountPrintTemplate extends string | IAccountMeta<string> = string,
   TAccountMplCoreProgram extends
     | string
     | IAccountMeta<string> = 'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d',
@@ -80,6 +81,9 @@ export type RemoveCoreItemInstruction<
       TAccountCollection extends string
         ? WritableAccount<TAccountCollection>
         : TAccountCollection,
+      TAccountPrintTemplate extends string
+        ? WritableAccount<TAccountPrintTemplate>
+        : TAccountPrintTemplate,
       TAccountMplCoreProgram extends string
         ? ReadonlyAccount<TAccountMplCoreProgram>
         : TAccountMplCoreProgram,
@@ -101,7 +105,7 @@ export function getRemoveCoreItemInstructionDataEncoder(): Encoder<RemoveCoreIte
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
-      ['index', getU8Encoder()],
+      ['index', getU16Encoder()],
     ]),
     (value) => ({ ...value, discriminator: REMOVE_CORE_ITEM_DISCRIMINATOR })
   );
@@ -110,7 +114,7 @@ export function getRemoveCoreItemInstructionDataEncoder(): Encoder<RemoveCoreIte
 export function getRemoveCoreItemInstructionDataDecoder(): Decoder<RemoveCoreItemInstructionData> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
-    ['index', getU8Decoder()],
+    ['index', getU16Decoder()],
   ]);
 }
 
@@ -130,6 +134,7 @@ export type RemoveCoreItemInput<
   TAccountAuthority extends string = string,
   TAccountAsset extends string = string,
   TAccountCollection extends string = string,
+  TAccountPrintTemplate extends string = string,
   TAccountMplCoreProgram extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
@@ -141,6 +146,8 @@ export type RemoveCoreItemInput<
   asset?: Address<TAccountAsset>;
   /** Core asset's collection if it's part of one. */
   collection?: Address<TAccountCollection>;
+  /** Print template of the master edition, required when removing one. */
+  printTemplate?: Address<TAccountPrintTemplate>;
   mplCoreProgram?: Address<TAccountMplCoreProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
   index: RemoveCoreItemInstructionDataArgs['index'];
@@ -152,6 +159,7 @@ export function getRemoveCoreItemInstruction<
   TAccountAuthority extends string,
   TAccountAsset extends string,
   TAccountCollection extends string,
+  TAccountPrintTemplate extends string,
   TAccountMplCoreProgram extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof MALLOW_JELLYBEAN_PROGRAM_ADDRESS,
@@ -162,6 +170,7 @@ export function getRemoveCoreItemInstruction<
     TAccountAuthority,
     TAccountAsset,
     TAccountCollection,
+    TAccountPrintTemplate,
     TAccountMplCoreProgram,
     TAccountSystemProgram
   >,
@@ -173,6 +182,7 @@ export function getRemoveCoreItemInstruction<
   TAccountAuthority,
   TAccountAsset,
   TAccountCollection,
+  TAccountPrintTemplate,
   TAccountMplCoreProgram,
   TAccountSystemProgram
 > {
@@ -190,6 +200,7 @@ export function getRemoveCoreItemInstruction<
     authority: { value: input.authority ?? null, isWritable: true },
     asset: { value: input.asset ?? null, isWritable: true },
     collection: { value: input.collection ?? null, isWritable: true },
+    printTemplate: { value: input.printTemplate ?? null, isWritable: true },
     mplCoreProgram: { value: input.mplCoreProgram ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
@@ -211,6 +222,12 @@ export function getRemoveCoreItemInstruction<
       ...resolveAuthorityPda(resolverScope),
     };
   }
+  if (!accounts.printTemplate.value) {
+    accounts.printTemplate = {
+      ...accounts.printTemplate,
+      ...resolvePrintTemplatePda(resolverScope),
+    };
+  }
   if (!accounts.mplCoreProgram.value) {
     accounts.mplCoreProgram.value =
       'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d' as Address<'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'>;
@@ -228,6 +245,7 @@ export function getRemoveCoreItemInstruction<
       getAccountMeta(accounts.authority),
       getAccountMeta(accounts.asset),
       getAccountMeta(accounts.collection),
+      getAccountMeta(accounts.printTemplate),
       getAccountMeta(accounts.mplCoreProgram),
       getAccountMeta(accounts.systemProgram),
     ],
@@ -242,6 +260,7 @@ export function getRemoveCoreItemInstruction<
     TAccountAuthority,
     TAccountAsset,
     TAccountCollection,
+    TAccountPrintTemplate,
     TAccountMplCoreProgram,
     TAccountSystemProgram
   >;
@@ -263,8 +282,10 @@ export type ParsedRemoveCoreItemInstruction<
     asset?: TAccountMetas[3] | undefined;
     /** Core asset's collection if it's part of one. */
     collection?: TAccountMetas[4] | undefined;
-    mplCoreProgram: TAccountMetas[5];
-    systemProgram: TAccountMetas[6];
+    /** Print template of the master edition, required when removing one. */
+    printTemplate?: TAccountMetas[5] | undefined;
+    mplCoreProgram: TAccountMetas[6];
+    systemProgram: TAccountMetas[7];
   };
   data: RemoveCoreItemInstructionData;
 };
@@ -277,7 +298,7 @@ export function parseRemoveCoreItemInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedRemoveCoreItemInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 7) {
+  if (instruction.accounts.length < 8) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -301,6 +322,7 @@ export function parseRemoveCoreItemInstruction<
       authority: getNextAccount(),
       asset: getNextOptionalAccount(),
       collection: getNextOptionalAccount(),
+      printTemplate: getNextOptionalAccount(),
       mplCoreProgram: getNextAccount(),
       systemProgram: getNextAccount(),
     },
@@ -24,6 +24,7 @@ import {
   type IInstruction,
   type IInstructionWithAccounts,
   type IInstructionWithData,
+  type ReadonlyAccount,
   type ReadonlySignerAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
@@ -44,6 +45,7 @@ export type StartSaleInstruction<
   TProgram extends string = typeof MALLOW_JELLYBEAN_PROGRAM_ADDRESS,
   TAccountJellybeanMachine extends string | IAccountMeta<string> = string,
   TAccountAuthority extends string | IAccountMeta<string> = string,
+  TAccountGuardPhases extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -56,6 +58,9 @@ export type StartSaleInstruction<
         ? ReadonlySignerAccount<TAccountAuthority> &
             IAccountSignerMeta<TAccountAuthority>
         : TAccountAuthority,
+      TAccountGuardPhases extends string
+        ? ReadonlyAccount<TAccountGuardPhases>
+        : TAccountGuardPhases,
       ...TRemainingAccounts,
     ]
   >;
@@ -90,24 +95,33 @@ export function getStartSaleInstructionDataCodec(): Codec<
 export type StartSaleInput<
   TAccountJellybeanMachine extends string = string,
   TAccountAuthority extends string = string,
+  TAccountGuardPhases extends string = string,
 > = {
   /** Jellybean machine account. */
   jellybeanMachine: Address<TAccountJellybeanMachine>;
   /** Jellybean Machine authority. This can be the mint authority or the authority. */
   authority: TransactionSigner<TAccountAuthority>;
+  /** Guard phases account, required for machines with public draws. */
+  guardPhases?: Address<TAccountGuardPhases>;
 };
 
 export function getStartSaleInstruction<
   TAccountJellybeanMachine extends string,
   TAccountAuthority extends string,
+  TAccountGuardPhases extends string,
   TProgramAddress extends Address = typeof MALLOW_JELLYBEAN_PROGRAM_ADDRESS,
 >(
-  input: StartSaleInput<TAccountJellybeanMachine, TAccountAuthority>,
+  input: StartSaleInput<
+    TAccountJellybeanMachine,
+    TAccountAuthority,
+    TAccountGuardPhases
+  >,
   config?: { programAddress?: TProgramAddress }
 ): StartSaleInstruction<
   TProgramAddress,
   TAccountJellybeanMachine,
-  TAccountAuthority
+  TAccountAuthority,
+  TAccountGuardPhases
 > {
   // Program address.
   const programAddress =
@@ -120,6 +134,7 @@ export function getStartSaleInstruction<
       isWritable: true,
     },
     authority: { value: input.authority ?? null, isWritable: false },
+    guardPhases: { value: input.guardPhases ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -131,13 +146,15 @@ export function getStartSaleInstruction<
     accounts: [
       getAccountMeta(accounts.jellybeanMachine),
       getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.guardPhases),
     ],
     programAddress,
     data: getStartSaleInstructionDataEncoder().encode({}),
   } as StartSaleInstruction<
     TProgramAddress,
     TAccountJellybeanMachine,
-    TAccountAuthority
+    TAccountAuthority,
+    TAccountGuardPhases
   >;
 
   return instruction;
@@ -153,6 +170,8 @@ export type ParsedStartSaleInstruction<
     jellybeanMachine: TAccountMetas[0];
     /** Jellybean Machine authority. This can be the mint authority or the authority. */
     authority: TAccountMetas[1];
+    /** Guard phases account, required for machines with public draws. */
+    guardPhases?: TAccountMetas[2] | undefined;
   };
   data: StartSaleInstructionData;
 };
@@ -165,7 +184,7 @@ export function parseStartSaleInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedStartSaleInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 2) {
+  if (instruction.accounts.length < 3) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -175,11 +194,18 @@ export function parseStartSaleInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === MALLOW_JELLYBEAN_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       jellybeanMachine: getNextAccount(),
       authority: getNextAccount(),
+      guardPhases: getNextOptionalAccount(),
     },
     data: getStartSaleInstructionDataDecoder().decode(instruction.data),
   };
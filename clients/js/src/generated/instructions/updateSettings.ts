@@ -24,6 +24,7 @@ import {
   type IInstruction,
   type IInstructionWithAccounts,
   type IInstructionWithData,
+  type ReadonlyAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
   type WritableAccount,
@@ -52,6 +53,7 @@ export type UpdateSettingsInstruction<
   TProgram extends string = typeof MALLOW_JELLYBEAN_PROGRAM_ADDRESS,
   TAccountJellybeanMachine extends string | IAccountMeta<string> = string,
   TAccountAuthority extends string | IAccountMeta<string> = string,
+  TAccountGuardPhases extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -64,6 +66,9 @@ export type UpdateSettingsInstruction<
         ? WritableSignerAccount<TAccountAuthority> &
             IAccountSignerMeta<TAccountAuthority>
         : TAccountAuthority,
+      TAccountGuardPhases extends string
+        ? ReadonlyAccount<TAccountGuardPhases>
+        : TAccountGuardPhases,
       ...TRemainingAccounts,
     ]
   >;
@@ -105,25 +110,34 @@ export function getUpdateSettingsInstructionDataCodec(): Codec<
 export type UpdateSettingsInput<
   TAccountJellybeanMachine extends string = string,
   TAccountAuthority extends string = string,
+  TAccountGuardPhases extends string = string,
 > = {
   /** Gumball machine account. */
   jellybeanMachine: Address<TAccountJellybeanMachine>;
   /** Gumball Machine authority. This is the address that controls the upate of the jellybean machine. */
   authority: TransactionSigner<TAccountAuthority>;
+  /** Guard phases account, required for machines with public draws. */
+  guardPhases?: Address<TAccountGuardPhases>;
   args: UpdateSettingsInstructionDataArgs['args'];
 };
 
 export function getUpdateSettingsInstruction<
   TAccountJellybeanMachine extends string,
   TAccountAuthority extends string,
+  TAccountGuardPhases extends string,
   TProgramAddress extends Address = typeof MALLOW_JELLYBEAN_PROGRAM_ADDRESS,
 >(
-  input: UpdateSettingsInput<TAccountJellybeanMachine, TAccountAuthority>,
+  input: UpdateSettingsInput<
+    TAccountJellybeanMachine,
+    TAccountAuthority,
+    TAccountGuardPhases
+  >,
   config?: { programAddress?: TProgramAddress }
 ): UpdateSettingsInstruction<
   TProgramAddress,
   TAccountJellybeanMachine,
-  TAccountAuthority
+  TAccountAuthority,
+  TAccountGuardPhases
 > {
   // Program address.
   const programAddress =
@@ -136,6 +150,7 @@ export function getUpdateSettingsInstruction<
       isWritable: true,
     },
     authority: { value: input.authority ?? null, isWritable: true },
+    guardPhases: { value: input.guardPhases ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -150,6 +165,7 @@ export function getUpdateSettingsInstruction<
     accounts: [
       getAccountMeta(accounts.jellybeanMachine),
       getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.guardPhases),
     ],
     programAddress,
     data: getUpdateSettingsInstructionDataEncoder().encode(
@@ -158,7 +174,8 @@ export function getUpdateSettingsInstruction<
   } as UpdateSettingsInstruction<
     TProgramAddress,
     TAccountJellybeanMachine,
-    TAccountAuthority
+    TAccountAuthority,
+    TAccountGuardPhases
   >;
 
   return instruction;
@@ -174,6 +191,8 @@ export type ParsedUpdateSettingsInstruction<
     jellybeanMachine: TAccountMetas[0];
     /** Gumball Machine authority. This is the address that controls the upate of the jellybean machine. */
     authority: TAccountMetas[1];
+    /** Guard phases account, required for machines with public draws. */
+    guardPhases?: TAccountMetas[2] | undefined;
   };
   data: UpdateSettingsInstructionData;
 };
@@ -186,7 +205,7 @@ export function parseUpdateSettingsInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedUpdateSettingsInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 2) {
+  if (instruction.accounts.length < 3) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -196,11 +215,18 @@ export function parseUpdateSettingsInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === MALLOW_JELLYBEAN_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       jellybeanMachine: getNextAccount(),
       authority: getNextAccount(),
+      guardPhases: getNextOptionalAccount(),
     },
     data: getUpdateSettingsInstructionDataDecoder().decode(instruction.data),
   };
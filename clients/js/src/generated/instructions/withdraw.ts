@@ -24,13 +24,21 @@ import {
   type IInstruction,
   type IInstructionWithAccounts,
   type IInstructionWithData,
+  type OptionOrNullable,
+  type ReadonlyAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
   type WritableAccount,
   type WritableSignerAccount,
 } from '@solana/kit';
+import { resolveAuthorityPda } from '../../hooked';
+import { findGuardPhasesPda } from '../pdas';
 import { MALLOW_JELLYBEAN_PROGRAM_ADDRESS } from '../programs';
-import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
 
 export const WITHDRAW_DISCRIMINATOR = new Uint8Array([
   183, 18, 70, 156, 148, 109, 161, 34,
@@ -45,6 +53,13 @@ export type WithdrawInstruction<
   TAccountJellybeanMachine extends string | IAccountMeta<string> = string,
   TAccountAuthority extends string | IAccountMeta<string> = string,
   TAccountMintAuthority extends string | IAccountMeta<string> = string,
+  TAccountGuardPhases extends string | IAccountMeta<string> = string,
+  TAccountAuthorityPda extends string | IAccountMeta<string> = string,
+  TAccountTokenProgram extends string | IAccountMeta<string> = string,
+  TAccountAssociatedTokenProgram extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -61,6 +76,21 @@ export type WithdrawInstruction<
         ? WritableSignerAccount<TAccountMintAuthority> &
             IAccountSignerMeta<TAccountMintAuthority>
         : TAccountMintAuthority,
+      TAccountGuardPhases extends string
+        ? WritableAccount<TAccountGuardPhases>
+        : TAccountGuardPhases,
+      TAccountAuthorityPda extends string
+        ? WritableAccount<TAccountAuthorityPda>
+        : TAccountAuthorityPda,
+      TAccountTokenProgram extends string
+        ? ReadonlyAccount<TAccountTokenProgram>
+        : TAccountTokenProgram,
+      TAccountAssociatedTokenProgram extends string
+        ? ReadonlyAccount<TAccountAssociatedTokenProgram>
+        : TAccountAssociatedTokenProgram,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -92,10 +122,161 @@ export function getWithdrawInstructionDataCodec(): Codec<
   );
 }
 
+export type WithdrawInstructionExtraArgs = {
+  /** Forcing WithdrawInstructionExtraArgs to be rendered to fix a bug where resolvedArgs is using an undefined type */
+  unused?: OptionOrNullable<boolean>;
+};
+
+export type WithdrawAsyncInput<
+  TAccountJellybeanMachine extends string = string,
+  TAccountAuthority extends string = string,
+  TAccountMintAuthority extends string = string,
+  TAccountGuardPhases extends string = string,
+  TAccountAuthorityPda extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountAssociatedTokenProgram extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  /** Gumball Machine acccount. */
+  jellybeanMachine: Address<TAccountJellybeanMachine>;
+  /** Authority of the jellybean machine. */
+  authority: TransactionSigner<TAccountAuthority>;
+  /** Mint authority of the jellybean machine. */
+  mintAuthority: TransactionSigner<TAccountMintAuthority>;
+  /** Guard phases account, closed with the machine if it exists. */
+  guardPhases?: Address<TAccountGuardPhases>;
+  authorityPda?: Address<TAccountAuthorityPda>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  unused?: WithdrawInstructionExtraArgs['unused'];
+};
+
+export async function getWithdrawInstructionAsync<
+  TAccountJellybeanMachine extends string,
+  TAccountAuthority extends string,
+  TAccountMintAuthority extends string,
+  TAccountGuardPhases extends string,
+  TAccountAuthorityPda extends string,
+  TAccountTokenProgram extends string,
+  TAccountAssociatedTokenProgram extends string,
+  TAccountSystemProgram extends string,
+  TProgramAddress extends Address = typeof MALLOW_JELLYBEAN_PROGRAM_ADDRESS,
+>(
+  input: WithdrawAsyncInput<
+    TAccountJellybeanMachine,
+    TAccountAuthority,
+    TAccountMintAuthority,
+    TAccountGuardPhases,
+    TAccountAuthorityPda,
+    TAccountTokenProgram,
+    TAccountAssociatedTokenProgram,
+    TAccountSystemProgram
+  >,
+  config?: { programAddress?: TProgramAddress }
+): Promise<
+  WithdrawInstruction<
+    TProgramAddress,
+    TAccountJellybeanMachine,
+    TAccountAuthority,
+    TAccountMintAuthority,
+    TAccountGuardPhases,
+    TAccountAuthorityPda,
+    TAccountTokenProgram,
+    TAccountAssociatedTokenProgram,
+    TAccountSystemProgram
+  >
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? MALLOW_JELLYBEAN_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    jellybeanMachine: {
+      value: input.jellybeanMachine ?? null,
+      isWritable: true,
+    },
+    authority: { value: input.authority ?? null, isWritable: true },
+    mintAuthority: { value: input.mintAuthority ?? null, isWritable: true },
+    guardPhases: { value: input.guardPhases ?? null, isWritable: true },
+    authorityPda: { value: input.authorityPda ?? null, isWritable: true },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    associatedTokenProgram: {
+      value: input.associatedTokenProgram ?? null,
+      isWritable: false,
+    },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolver scope.
+  const resolverScope = { programAddress, accounts, args };
+
+  // Resolve default values.
+  if (!accounts.guardPhases.value) {
+    accounts.guardPhases.value = await findGuardPhasesPda({
+      jellybeanMachine: expectAddress(accounts.jellybeanMachine.value),
+    });
+  }
+  if (!accounts.authorityPda.value) {
+    accounts.authorityPda = {
+      ...accounts.authorityPda,
+      ...resolveAuthorityPda(resolverScope),
+    };
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+  if (!args.unused) {
+    args.unused = false;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.jellybeanMachine),
+      getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.mintAuthority),
+      getAccountMeta(accounts.guardPhases),
+      getAccountMeta(accounts.authorityPda),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.associatedTokenProgram),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getWithdrawInstructionDataEncoder().encode({}),
+  } as WithdrawInstruction<
+    TProgramAddress,
+    TAccountJellybeanMachine,
+    TAccountAuthority,
+    TAccountMintAuthority,
+    TAccountGuardPhases,
+    TAccountAuthorityPda,
+    TAccountTokenProgram,
+    TAccountAssociatedTokenProgram,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
 export type WithdrawInput<
   TAccountJellybeanMachine extends string = string,
   TAccountAuthority extends string = string,
   TAccountMintAuthority extends string = string,
+  TAccountGuardPhases extends string = string,
+  TAccountAuthorityPda extends string = string,
+  TAccountTokenProgram extends string = string,
+  TAccountAssociatedTokenProgram extends string = string,
+  TAccountSystemProgram extends string = string,
 > = {
   /** Gumball Machine acccount. */
   jellybeanMachine: Address<TAccountJellybeanMachine>;
@@ -103,25 +284,47 @@ export type WithdrawInput<
   authority: TransactionSigner<TAccountAuthority>;
   /** Mint authority of the jellybean machine. */
   mintAuthority: TransactionSigner<TAccountMintAuthority>;
+  /** Guard phases account, closed with the machine if it exists. */
+  guardPhases: Address<TAccountGuardPhases>;
+  authorityPda?: Address<TAccountAuthorityPda>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  unused?: WithdrawInstructionExtraArgs['unused'];
 };
 
 export function getWithdrawInstruction<
   TAccountJellybeanMachine extends string,
   TAccountAuthority extends string,
   TAccountMintAuthority extends string,
+  TAccountGuardPhases extends string,
+  TAccountAuthorityPda extends string,
+  TAccountTokenProgram extends string,
+  TAccountAssociatedTokenProgram extends string,
+  TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof MALLOW_JELLYBEAN_PROGRAM_ADDRESS,
 >(
   input: WithdrawInput<
     TAccountJellybeanMachine,
     TAccountAuthority,
-    TAccountMintAuthority
+    TAccountMintAuthority,
+    TAccountGuardPhases,
+    TAccountAuthorityPda,
+    TAccountTokenProgram,
+    TAccountAssociatedTokenProgram,
+    TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): WithdrawInstruction<
   TProgramAddress,
   TAccountJellybeanMachine,
   TAccountAuthority,
-  TAccountMintAuthority
+  TAccountMintAuthority,
+  TAccountGuardPhases,
+  TAccountAuthorityPda,
+  TAccountTokenProgram,
+  TAccountAssociatedTokenProgram,
+  TAccountSystemProgram
 > {
   // Program address.
   const programAddress =
@@ -135,18 +338,52 @@ export function getWithdrawInstruction<
     },
     authority: { value: input.authority ?? null, isWritable: true },
     mintAuthority: { value: input.mintAuthority ?? null, isWritable: true },
+    guardPhases: { value: input.guardPhases ?? null, isWritable: true },
+    authorityPda: { value: input.authorityPda ?? null, isWritable: true },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+    associatedTokenProgram: {
+      value: input.associatedTokenProgram ?? null,
+      isWritable: false,
+    },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
     ResolvedAccount
   >;
 
+  // Original args.
+  const args = { ...input };
+
+  // Resolver scope.
+  const resolverScope = { programAddress, accounts, args };
+
+  // Resolve default values.
+  if (!accounts.authorityPda.value) {
+    accounts.authorityPda = {
+      ...accounts.authorityPda,
+      ...resolveAuthorityPda(resolverScope),
+    };
+  }
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+  if (!args.unused) {
+    args.unused = false;
+  }
+
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   const instruction = {
     accounts: [
       getAccountMeta(accounts.jellybeanMachine),
       getAccountMeta(accounts.authority),
       getAccountMeta(accounts.mintAuthority),
+      getAccountMeta(accounts.guardPhases),
+      getAccountMeta(accounts.authorityPda),
+      getAccountMeta(accounts.tokenProgram),
+      getAccountMeta(accounts.associatedTokenProgram),
+      getAccountMeta(accounts.systemProgram),
     ],
     programAddress,
     data: getWithdrawInstructionDataEncoder().encode({}),
@@ -154,7 +391,12 @@ export function getWithdrawInstruction<
     TProgramAddress,
     TAccountJellybeanMachine,
     TAccountAuthority,
-    TAccountMintAuthority
+    TAccountMintAuthority,
+    TAccountGuardPhases,
+    TAccountAuthorityPda,
+    TAccountTokenProgram,
+    TAccountAssociatedTokenProgram,
+    TAccountSystemProgram
   >;
 
   return instruction;
@@ -172,6 +414,12 @@ export type ParsedWithdrawInstruction<
     authority: TAccountMetas[1];
     /** Mint authority of the jellybean machine. */
     mintAuthority: TAccountMetas[2];
+    /** Guard phases account, closed with the machine if it exists. */
+    guardPhases: TAccountMetas[3];
+    authorityPda: TAccountMetas[4];
+    tokenProgram?: TAccountMetas[5] | undefined;
+    associatedTokenProgram?: TAccountMetas[6] | undefined;
+    systemProgram: TAccountMetas[7];
   };
   data: WithdrawInstructionData;
 };
@@ -184,7 +432,7 @@ export function parseWithdrawInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedWithdrawInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 3) {
+  if (instruction.accounts.length < 8) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -194,12 +442,23 @@ export function parseWithdrawInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === MALLOW_JELLYBEAN_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       jellybeanMachine: getNextAccount(),
       authority: getNextAccount(),
       mintAuthority: getNextAccount(),
+      guardPhases: getNextAccount(),
+      authorityPda: getNextAccount(),
+      tokenProgram: getNextOptionalAccount(),
+      associatedTokenProgram: getNextOptionalAccount(),
+      systemProgram: getNextAccount(),
     },
     data: getWithdrawInstructionDataDecoder().decode(instruction.data),
   };
@@ -6,4 +6,10 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './allowlistProof';
+export * from './bundle';
+export * from './buyerRecord';
+export * from './guardPhases';
+export * from './printTemplate';
 export * from './unclaimedPrizes';
+export * from './usedGateAsset';
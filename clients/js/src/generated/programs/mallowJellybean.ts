@@ -14,14 +14,40 @@ import {
   type ReadonlyUint8Array,
 } from '@solana/kit';
 import {
+  type ParsedAddBundleComponentInstruction,
+  type ParsedAddBundleItemInstruction,
   type ParsedAddCoreItemInstruction,
+  type ParsedAddCoreItemsInstruction,
+  type ParsedAddPrintsInstruction,
+  type ParsedCancelSaleInstruction,
+  type ParsedClaimBundleComponentInstruction,
   type ParsedClaimCoreItemInstruction,
+  type ParsedCloseGuardRecordInstruction,
+  type ParsedCloseUnclaimedPrizesInstruction,
   type ParsedDrawInstruction,
   type ParsedEndSaleInstruction,
+  type ParsedEndScheduledSaleInstruction,
   type ParsedInitializeInstruction,
+  type ParsedMigrateJellybeanMachineInstruction,
+  type ParsedMigrateUnclaimedPrizesInstruction,
+  type ParsedPauseSaleInstruction,
+  type ParsedProveAllowlistInstruction,
+  type ParsedReclaimPrizeInstruction,
+  type ParsedRefundPrizeInstruction,
+  type ParsedRemoveBundleComponentInstruction,
+  type ParsedRemoveBundleItemInstruction,
   type ParsedRemoveCoreItemInstruction,
+  type ParsedRemoveCoreItemsInstruction,
+  type ParsedReplaceCoreItemInstruction,
+  type ParsedResumeSaleInstruction,
+  type ParsedRevealItemsInstruction,
+  type ParsedSetGuardPhasesInstruction,
+  type ParsedSetItemBuyerLimitInstruction,
+  type ParsedSetItemEnabledInstruction,
   type ParsedSetMintAuthorityInstruction,
+  type ParsedSetPrintTemplateInstruction,
   type ParsedStartSaleInstruction,
+  type ParsedUnloadAllInstruction,
   type ParsedUpdateSettingsInstruction,
   type ParsedWithdrawInstruction,
 } from '../instructions';
@@ -30,14 +56,53 @@ export const MALLOW_JELLYBEAN_PROGRAM_ADDRESS =
   'J3LLYcm8V5hJRzCKENRPW3yGdQ6xU8Nie8jr3mU88eqq' as Address<'J3LLYcm8V5hJRzCKENRPW3yGdQ6xU8Nie8jr3mU88eqq'>;
 
 export enum MallowJellybeanAccount {
+  Bundle,
+  AllowlistProof,
+  GuardPhases,
   JellybeanMachine,
+  PrintTemplate,
   UnclaimedPrizes,
+  BuyerRecord,
+  UsedGateAsset,
 }
 
 export function identifyMallowJellybeanAccount(
   account: { data: ReadonlyUint8Array } | ReadonlyUint8Array
 ): MallowJellybeanAccount {
   const data = 'data' in account ? account.data : account;
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([15, 82, 167, 230, 37, 214, 82, 80])
+      ),
+      0
+    )
+  ) {
+    return MallowJellybeanAccount.Bundle;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([216, 131, 22, 210, 201, 113, 60, 233])
+      ),
+      0
+    )
+  ) {
+    return MallowJellybeanAccount.AllowlistProof;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([73, 71, 170, 54, 187, 119, 2, 178])
+      ),
+      0
+    )
+  ) {
+    return MallowJellybeanAccount.GuardPhases;
+  }
   if (
     containsBytes(
       data,
@@ -49,6 +114,17 @@ export function identifyMallowJellybeanAccount(
   ) {
     return MallowJellybeanAccount.JellybeanMachine;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([79, 69, 168, 137, 236, 86, 133, 108])
+      ),
+      0
+    )
+  ) {
+    return MallowJellybeanAccount.PrintTemplate;
+  }
   if (
     containsBytes(
       data,
@@ -60,6 +136,28 @@ export function identifyMallowJellybeanAccount(
   ) {
     return MallowJellybeanAccount.UnclaimedPrizes;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([107, 122, 54, 31, 4, 54, 209, 38])
+      ),
+      0
+    )
+  ) {
+    return MallowJellybeanAccount.BuyerRecord;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([170, 62, 56, 157, 118, 245, 31, 197])
+      ),
+      0
+    )
+  ) {
+    return MallowJellybeanAccount.UsedGateAsset;
+  }
   throw new Error(
     'The provided account could not be identified as a mallowJellybean account.'
   );
@@ -69,13 +167,39 @@ export enum MallowJellybeanInstruction {
   Initialize,
   UpdateSettings,
   AddCoreItem,
+  AddCoreItems,
+  AddPrints,
+  SetPrintTemplate,
   RemoveCoreItem,
+  RemoveCoreItems,
+  UnloadAll,
+  ReplaceCoreItem,
+  AddBundleComponent,
+  RemoveBundleComponent,
+  AddBundleItem,
+  RemoveBundleItem,
   StartSale,
+  PauseSale,
+  ResumeSale,
   EndSale,
+  EndScheduledSale,
+  CancelSale,
+  RefundPrize,
+  ReclaimPrize,
   Draw,
   ClaimCoreItem,
+  ClaimBundleComponent,
+  RevealItems,
+  SetGuardPhases,
+  ProveAllowlist,
+  CloseGuardRecord,
+  CloseUnclaimedPrizes,
+  SetItemEnabled,
+  SetItemBuyerLimit,
   SetMintAuthority,
   Withdraw,
+  MigrateJellybeanMachine,
+  MigrateUnclaimedPrizes,
 }
 
 export function identifyMallowJellybeanInstruction(
@@ -115,6 +239,39 @@ export function identifyMallowJellybeanInstruction(
   ) {
     return MallowJellybeanInstruction.AddCoreItem;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([181, 117, 190, 30, 165, 202, 233, 196])
+      ),
+      0
+    )
+  ) {
+    return MallowJellybeanInstruction.AddCoreItems;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([27, 94, 69, 147, 29, 197, 80, 119])
+      ),
+      0
+    )
+  ) {
+    return MallowJellybeanInstruction.AddPrints;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([164, 92, 14, 41, 166, 16, 151, 125])
+      ),
+      0
+    )
+  ) {
+    return MallowJellybeanInstruction.SetPrintTemplate;
+  }
   if (
     containsBytes(
       data,
@@ -126,6 +283,83 @@ export function identifyMallowJellybeanInstruction(
   ) {
     return MallowJellybeanInstruction.RemoveCoreItem;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([126, 133, 24, 173, 204, 97, 223, 196])
+      ),
+      0
+    )
+  ) {
+    return MallowJellybeanInstruction.RemoveCoreItems;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([72, 135, 52, 231, 175, 132, 154, 83])
+      ),
+      0
+    )
+  ) {
+    return MallowJellybeanInstruction.UnloadAll;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([195, 48, 13, 234, 254, 235, 205, 99])
+      ),
+      0
+    )
+  ) {
+    return MallowJellybeanInstruction.ReplaceCoreItem;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([144, 39, 45, 196, 67, 163, 42, 239])
+      ),
+      0
+    )
+  ) {
+    return MallowJellybeanInstruction.AddBundleComponent;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([128, 95, 16, 93, 223, 220, 138, 156])
+      ),
+      0
+    )
+  ) {
+    return MallowJellybeanInstruction.RemoveBundleComponent;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([134, 206, 210, 166, 87, 228, 132, 120])
+      ),
+      0
+    )
+  ) {
+    return MallowJellybeanInstruction.AddBundleItem;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([165, 239, 118, 61, 150, 244, 36, 17])
+      ),
+      0
+    )
+  ) {
+    return MallowJellybeanInstruction.RemoveBundleItem;
+  }
   if (
     containsBytes(
       data,
@@ -137,6 +371,28 @@ export function identifyMallowJellybeanInstruction(
   ) {
     return MallowJellybeanInstruction.StartSale;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([120, 107, 163, 108, 19, 201, 121, 223])
+      ),
+      0
+    )
+  ) {
+    return MallowJellybeanInstruction.PauseSale;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([222, 242, 38, 239, 148, 224, 167, 188])
+      ),
+      0
+    )
+  ) {
+    return MallowJellybeanInstruction.ResumeSale;
+  }
   if (
     containsBytes(
       data,
@@ -148,6 +404,50 @@ export function identifyMallowJellybeanInstruction(
   ) {
     return MallowJellybeanInstruction.EndSale;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([191, 128, 174, 37, 221, 71, 1, 214])
+      ),
+      0
+    )
+  ) {
+    return MallowJellybeanInstruction.EndScheduledSale;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([82, 137, 56, 136, 94, 9, 205, 10])
+      ),
+      0
+    )
+  ) {
+    return MallowJellybeanInstruction.CancelSale;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([222, 186, 4, 38, 66, 17, 1, 164])
+      ),
+      0
+    )
+  ) {
+    return MallowJellybeanInstruction.RefundPrize;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([74, 240, 215, 247, 126, 227, 246, 135])
+      ),
+      0
+    )
+  ) {
+    return MallowJellybeanInstruction.ReclaimPrize;
+  }
   if (
     containsBytes(
       data,
@@ -170,6 +470,94 @@ export function identifyMallowJellybeanInstruction(
   ) {
     return MallowJellybeanInstruction.ClaimCoreItem;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([72, 30, 50, 46, 39, 198, 187, 187])
+      ),
+      0
+    )
+  ) {
+    return MallowJellybeanInstruction.ClaimBundleComponent;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([250, 215, 127, 148, 25, 159, 153, 108])
+      ),
+      0
+    )
+  ) {
+    return MallowJellybeanInstruction.RevealItems;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([166, 69, 111, 93, 185, 248, 72, 185])
+      ),
+      0
+    )
+  ) {
+    return MallowJellybeanInstruction.SetGuardPhases;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([70, 228, 63, 26, 46, 10, 250, 6])
+      ),
+      0
+    )
+  ) {
+    return MallowJellybeanInstruction.ProveAllowlist;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([150, 39, 184, 170, 224, 164, 87, 215])
+      ),
+      0
+    )
+  ) {
+    return MallowJellybeanInstruction.CloseGuardRecord;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([153, 87, 102, 227, 220, 252, 170, 166])
+      ),
+      0
+    )
+  ) {
+    return MallowJellybeanInstruction.CloseUnclaimedPrizes;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([208, 241, 67, 147, 79, 253, 166, 232])
+      ),
+      0
+    )
+  ) {
+    return MallowJellybeanInstruction.SetItemEnabled;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([133, 49, 205, 207, 106, 47, 216, 138])
+      ),
+      0
+    )
+  ) {
+    return MallowJellybeanInstruction.SetItemBuyerLimit;
+  }
   if (
     containsBytes(
       data,
@@ -192,6 +580,28 @@ export function identifyMallowJellybeanInstruction(
   ) {
     return MallowJellybeanInstruction.Withdraw;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([169, 152, 202, 127, 147, 186, 56, 213])
+      ),
+      0
+    )
+  ) {
+    return MallowJellybeanInstruction.MigrateJellybeanMachine;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([189, 98, 199, 173, 51, 155, 246, 228])
+      ),
+      0
+    )
+  ) {
+    return MallowJellybeanInstruction.MigrateUnclaimedPrizes;
+  }
   throw new Error(
     'The provided instruction could not be identified as a mallowJellybean instruction.'
   );
@@ -209,24 +619,102 @@ export type ParsedMallowJellybeanInstruction<
   | ({
       instructionType: MallowJellybeanInstruction.AddCoreItem;
     } & ParsedAddCoreItemInstruction<TProgram>)
+  | ({
+      instructionType: MallowJellybeanInstruction.AddCoreItems;
+    } & ParsedAddCoreItemsInstruction<TProgram>)
+  | ({
+      instructionType: MallowJellybeanInstruction.AddPrints;
+    } & ParsedAddPrintsInstruction<TProgram>)
+  | ({
+      instructionType: MallowJellybeanInstruction.SetPrintTemplate;
+    } & ParsedSetPrintTemplateInstruction<TProgram>)
   | ({
       instructionType: MallowJellybeanInstruction.RemoveCoreItem;
     } & ParsedRemoveCoreItemInstruction<TProgram>)
+  | ({
+      instructionType: MallowJellybeanInstruction.RemoveCoreItems;
+    } & ParsedRemoveCoreItemsInstruction<TProgram>)
+  | ({
+      instructionType: MallowJellybeanInstruction.UnloadAll;
+    } & ParsedUnloadAllInstruction<TProgram>)
+  | ({
+      instructionType: MallowJellybeanInstruction.ReplaceCoreItem;
+    } & ParsedReplaceCoreItemInstruction<TProgram>)
+  | ({
+      instructionType: MallowJellybeanInstruction.AddBundleComponent;
+    } & ParsedAddBundleComponentInstruction<TProgram>)
+  | ({
+      instructionType: MallowJellybeanInstruction.RemoveBundleComponent;
+    } & ParsedRemoveBundleComponentInstruction<TProgram>)
+  | ({
+      instructionType: MallowJellybeanInstruction.AddBundleItem;
+    } & ParsedAddBundleItemInstruction<TProgram>)
+  | ({
+      instructionType: MallowJellybeanInstruction.RemoveBundleItem;
+    } & ParsedRemoveBundleItemInstruction<TProgram>)
   | ({
       instructionType: MallowJellybeanInstruction.StartSale;
     } & ParsedStartSaleInstruction<TProgram>)
+  | ({
+      instructionType: MallowJellybeanInstruction.PauseSale;
+    } & ParsedPauseSaleInstruction<TProgram>)
+  | ({
+      instructionType: MallowJellybeanInstruction.ResumeSale;
+    } & ParsedResumeSaleInstruction<TProgram>)
   | ({
       instructionType: MallowJellybeanInstruction.EndSale;
     } & ParsedEndSaleInstruction<TProgram>)
+  | ({
+      instructionType: MallowJellybeanInstruction.EndScheduledSale;
+    } & ParsedEndScheduledSaleInstruction<TProgram>)
+  | ({
+      instructionType: MallowJellybeanInstruction.CancelSale;
+    } & ParsedCancelSaleInstruction<TProgram>)
+  | ({
+      instructionType: MallowJellybeanInstruction.RefundPrize;
+    } & ParsedRefundPrizeInstruction<TProgram>)
+  | ({
+      instructionType: MallowJellybeanInstruction.ReclaimPrize;
+    } & ParsedReclaimPrizeInstruction<TProgram>)
   | ({
       instructionType: MallowJellybeanInstruction.Draw;
     } & ParsedDrawInstruction<TProgram>)
   | ({
       instructionType: MallowJellybeanInstruction.ClaimCoreItem;
     } & ParsedClaimCoreItemInstruction<TProgram>)
+  | ({
+      instructionType: MallowJellybeanInstruction.ClaimBundleComponent;
+    } & ParsedClaimBundleComponentInstruction<TProgram>)
+  | ({
+      instructionType: MallowJellybeanInstruction.RevealItems;
+    } & ParsedRevealItemsInstruction<TProgram>)
+  | ({
+      instructionType: MallowJellybeanInstruction.SetGuardPhases;
+    } & ParsedSetGuardPhasesInstruction<TProgram>)
+  | ({
+      instructionType: MallowJellybeanInstruction.ProveAllowlist;
+    } & ParsedProveAllowlistInstruction<TProgram>)
+  | ({
+      instructionType: MallowJellybeanInstruction.CloseGuardRecord;
+    } & ParsedCloseGuardRecordInstruction<TProgram>)
+  | ({
+      instructionType: MallowJellybeanInstruction.CloseUnclaimedPrizes;
+    } & ParsedCloseUnclaimedPrizesInstruction<TProgram>)
+  | ({
+      instructionType: MallowJellybeanInstruction.SetItemEnabled;
+    } & ParsedSetItemEnabledInstruction<TProgram>)
+  | ({
+      instructionType: MallowJellybeanInstruction.SetItemBuyerLimit;
+    } & ParsedSetItemBuyerLimitInstruction<TProgram>)
   | ({
       instructionType: MallowJellybeanInstruction.SetMintAuthority;
     } & ParsedSetMintAuthorityInstruction<TProgram>)
   | ({
       instructionType: MallowJellybeanInstruction.Withdraw;
-    } & ParsedWithdrawInstruction<TProgram>);
+    } & ParsedWithdrawInstruction<TProgram>)
+  | ({
+      instructionType: MallowJellybeanInstruction.MigrateJellybeanMachine;
+    } & ParsedMigrateJellybeanMachineInstruction<TProgram>)
+  | ({
+      instructionType: MallowJellybeanInstruction.MigrateUnclaimedPrizes;
+    } & ParsedMigrateUnclaimedPrizesInstruction<TProgram>);
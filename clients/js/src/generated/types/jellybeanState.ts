@@ -19,6 +19,8 @@ export enum JellybeanState {
   None,
   SaleLive,
   SaleEnded,
+  Paused,
+  Cancelled,
 }
 
 export type JellybeanStateArgs = JellybeanState;
@@ -10,30 +10,48 @@ import {
   combineCodec,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU32Decoder,
   getU32Encoder,
-  getU8Decoder,
-  getU8Encoder,
+  getU64Decoder,
+  getU64Encoder,
   type Codec,
   type Decoder,
   type Encoder,
 } from '@solana/kit';
 
-export type Prize = { itemIndex: number; editionNumber: number };
+export type Prize = {
+  itemIndex: number;
+  editionNumber: number;
+  drawSlot: bigint;
+  /** Price paid through the machine's guard phases, zero when a wrapping program charged */
+  price: bigint;
+};
 
-export type PrizeArgs = Prize;
+export type PrizeArgs = {
+  itemIndex: number;
+  editionNumber: number;
+  drawSlot: number | bigint;
+  /** Price paid through the machine's guard phases, zero when a wrapping program charged */
+  price: number | bigint;
+};
 
 export function getPrizeEncoder(): Encoder<PrizeArgs> {
   return getStructEncoder([
-    ['itemIndex', getU8Encoder()],
+    ['itemIndex', getU16Encoder()],
     ['editionNumber', getU32Encoder()],
+    ['drawSlot', getU64Encoder()],
+    ['price', getU64Encoder()],
   ]);
 }
 
 export function getPrizeDecoder(): Decoder<Prize> {
   return getStructDecoder([
-    ['itemIndex', getU8Decoder()],
+    ['itemIndex', getU16Decoder()],
     ['editionNumber', getU32Decoder()],
+    ['drawSlot', getU64Decoder()],
+    ['price', getU64Decoder()],
   ]);
 }
 
@@ -12,12 +12,20 @@ import {
   combineCodec,
   getArrayDecoder,
   getArrayEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
+  getI64Decoder,
+  getI64Encoder,
   getOptionDecoder,
   getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU32Decoder,
   getU32Encoder,
+  getU64Decoder,
+  getU64Encoder,
   getUtf8Decoder,
   getUtf8Encoder,
   none,
@@ -33,10 +41,18 @@ import {
   getFeeAccountEncoder,
   getPrintFeeConfigDecoder,
   getPrintFeeConfigEncoder,
+  getProvenanceConfigDecoder,
+  getProvenanceConfigEncoder,
+  getRevealConfigDecoder,
+  getRevealConfigEncoder,
   type FeeAccount,
   type FeeAccountArgs,
   type PrintFeeConfig,
   type PrintFeeConfigArgs,
+  type ProvenanceConfig,
+  type ProvenanceConfigArgs,
+  type RevealConfig,
+  type RevealConfigArgs,
 } from '.';
 
 /** Common arguments for settings-related operations (initialize and update_settings) */
@@ -44,12 +60,30 @@ export type SettingsArgs = {
   uri: string;
   feeAccounts: Array<FeeAccount>;
   printFeeConfig: Option<PrintFeeConfig>;
+  provenanceConfig: Option<ProvenanceConfig>;
+  revealConfig: Option<RevealConfig>;
+  maxWinsPerItem: Option<number>;
+  goLiveAt: Option<bigint>;
+  endAt: Option<bigint>;
+  maxDrawsPerWallet: Option<number>;
+  botTax: Option<bigint>;
+  claimDeadline: Option<bigint>;
+  publicDraw: boolean;
 };
 
 export type SettingsArgsArgs = {
   uri: string;
   feeAccounts: Array<FeeAccountArgs>;
   printFeeConfig?: OptionOrNullable<PrintFeeConfigArgs>;
+  provenanceConfig?: OptionOrNullable<ProvenanceConfigArgs>;
+  revealConfig?: OptionOrNullable<RevealConfigArgs>;
+  maxWinsPerItem?: OptionOrNullable<number>;
+  goLiveAt?: OptionOrNullable<number | bigint>;
+  endAt?: OptionOrNullable<number | bigint>;
+  maxDrawsPerWallet?: OptionOrNullable<number>;
+  botTax?: OptionOrNullable<number | bigint>;
+  claimDeadline?: OptionOrNullable<number | bigint>;
+  publicDraw?: boolean;
 };
 
 export function getSettingsArgsEncoder(): Encoder<SettingsArgsArgs> {
@@ -58,8 +92,29 @@ export function getSettingsArgsEncoder(): Encoder<SettingsArgsArgs> {
       ['uri', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
       ['feeAccounts', getArrayEncoder(getFeeAccountEncoder())],
       ['printFeeConfig', getOptionEncoder(getPrintFeeConfigEncoder())],
+      ['provenanceConfig', getOptionEncoder(getProvenanceConfigEncoder())],
+      ['revealConfig', getOptionEncoder(getRevealConfigEncoder())],
+      ['maxWinsPerItem', getOptionEncoder(getU16Encoder())],
+      ['goLiveAt', getOptionEncoder(getI64Encoder())],
+      ['endAt', getOptionEncoder(getI64Encoder())],
+      ['maxDrawsPerWallet', getOptionEncoder(getU16Encoder())],
+      ['botTax', getOptionEncoder(getU64Encoder())],
+      ['claimDeadline', getOptionEncoder(getI64Encoder())],
+      ['publicDraw', getBooleanEncoder()],
     ]),
-    (value) => ({ ...value, printFeeConfig: value.printFeeConfig ?? none() })
+    (value) => ({
+      ...value,
+      printFeeConfig: value.printFeeConfig ?? none(),
+      provenanceConfig: value.provenanceConfig ?? none(),
+      revealConfig: value.revealConfig ?? none(),
+      maxWinsPerItem: value.maxWinsPerItem ?? none(),
+      goLiveAt: value.goLiveAt ?? none(),
+      endAt: value.endAt ?? none(),
+      maxDrawsPerWallet: value.maxDrawsPerWallet ?? none(),
+      botTax: value.botTax ?? none(),
+      claimDeadline: value.claimDeadline ?? none(),
+      publicDraw: value.publicDraw ?? false,
+    })
   );
 }
 
@@ -68,6 +123,15 @@ export function getSettingsArgsDecoder(): Decoder<SettingsArgs> {
     ['uri', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
     ['feeAccounts', getArrayDecoder(getFeeAccountDecoder())],
     ['printFeeConfig', getOptionDecoder(getPrintFeeConfigDecoder())],
+    ['provenanceConfig', getOptionDecoder(getProvenanceConfigDecoder())],
+    ['revealConfig', getOptionDecoder(getRevealConfigDecoder())],
+    ['maxWinsPerItem', getOptionDecoder(getU16Decoder())],
+    ['goLiveAt', getOptionDecoder(getI64Decoder())],
+    ['endAt', getOptionDecoder(getI64Decoder())],
+    ['maxDrawsPerWallet', getOptionDecoder(getU16Decoder())],
+    ['botTax', getOptionDecoder(getU64Decoder())],
+    ['claimDeadline', getOptionDecoder(getI64Decoder())],
+    ['publicDraw', getBooleanDecoder()],
   ]);
 }
 
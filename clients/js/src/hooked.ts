@@ -5,6 +5,7 @@ import {
   getUtf8Encoder,
   ProgramDerivedAddress,
 } from '@solana/kit';
+import { findPrintTemplatePda } from './generated/pdas';
 import { expectAddress, ResolvedAccount } from './generated/shared';
 
 export type AuthoritySeeds = {
@@ -42,6 +43,25 @@ export const resolveAuthorityPda = async ({
   };
 };
 
+/** Master editions always pass their print template, even when none has been set. */
+export const resolvePrintTemplatePda = async ({
+  accounts,
+}: {
+  programAddress: Address;
+  accounts: Record<string, ResolvedAccount>;
+}): Promise<{ value: Address | null }> => {
+  return {
+    value: accounts.collection.value
+      ? (
+          await findPrintTemplatePda({
+            jellybeanMachine: expectAddress(accounts.jellybeanMachine.value),
+            collection: expectAddress(accounts.collection.value),
+          })
+        )[0]
+      : null,
+  };
+};
+
 export const resolveEventAuthorityPda = async ({
   programAddress,
 }: {
@@ -5,9 +5,12 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+use crate::generated::types::BuyerLimit;
 use crate::generated::types::FeeAccount;
 use crate::generated::types::JellybeanState;
 use crate::generated::types::PrintFeeConfig;
+use crate::generated::types::ProvenanceConfig;
+use crate::generated::types::RevealConfig;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use solana_program::pubkey::Pubkey;
@@ -36,8 +39,8 @@ pub struct JellybeanMachine {
     pub fee_accounts: Vec<FeeAccount>,
     /// Print fee config
     pub print_fee_config: Option<PrintFeeConfig>,
-    /// Total unique items loaded. Up to 255 items.
-    pub items_loaded: u8,
+    /// Total unique items loaded. Up to 10,000 items.
+    pub items_loaded: u16,
     /// Total supply_loaded of all items added.
     pub supply_loaded: u64,
     /// Number of times items have been redeemed.
@@ -46,9 +49,44 @@ pub struct JellybeanMachine {
     pub state: JellybeanState,
     /// Uri of off-chain metadata, max length 196
     pub uri: String,
+    /// Provenance attributes written to assets as they are claimed
+    pub provenance_config: Option<ProvenanceConfig>,
+    /// Indices of items temporarily excluded from draws, up to 16
+    pub disabled_items: Vec<u16>,
+    /// Delayed reveal, claimed assets get a placeholder uri until revealed
+    pub reveal_config: Option<RevealConfig>,
+    /// Maximum number of units of an item a single buyer can win, up to 16 items
+    pub buyer_limits: Vec<BuyerLimit>,
+    /// Maximum number of units of any item a single buyer can win, unless the item has its
+    /// own buyer limit
+    pub max_wins_per_item: Option<u16>,
+    /// Unix timestamp at which draws open, the sale goes live on its own if not started
+    pub go_live_at: Option<i64>,
+    /// Unix timestamp after which draws are closed and anyone can end the sale
+    pub end_at: Option<i64>,
+    /// Whether draws must satisfy the active phase of the guard phases account
+    pub has_guard_phases: bool,
+    /// Maximum number of draws a single buyer can make over the lifetime of the machine
+    pub max_draws_per_wallet: Option<u16>,
+    /// Lamports charged to the payer of a public draw that breaks a machine rule, instead of failing.
+    /// The tax accrues on the machine account and is paid to the authority with its rent
+    pub bot_tax: Option<u64>,
+    /// Draw prices paid for the prizes not claimed yet, escrowed by the authority when the sale
+    /// is cancelled so each buyer gets back what they paid
+    pub unclaimed_price: u64,
+    /// Unix timestamp after which the authority can reclaim prizes that haven't been claimed.
+    /// Must be after end_at when both are set, and no draw is accepted once it has passed
+    pub claim_deadline: Option<i64>,
+    /// Whether buyers can draw without the mint authority co-signing, only through paid
+    /// guard phases
+    pub public_draw: bool,
+    /// Number of bundle accounts holding escrowed components, must be zero to withdraw
+    pub open_bundles: u16,
+    /// Number of authority pda token accounts opened by bundle deposits, must be zero to
+    /// withdraw
+    pub open_token_accounts: u16,
     /// Padding for future use
-    #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
-    pub padding: [u8; 320],
+    pub padding: [u8; 20],
 }
 
 impl JellybeanMachine {
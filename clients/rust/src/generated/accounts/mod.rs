@@ -5,8 +5,20 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+pub(crate) mod r#allowlist_proof;
+pub(crate) mod r#bundle;
+pub(crate) mod r#buyer_record;
+pub(crate) mod r#guard_phases;
 pub(crate) mod r#jellybean_machine;
+pub(crate) mod r#print_template;
 pub(crate) mod r#unclaimed_prizes;
+pub(crate) mod r#used_gate_asset;
 
+pub use self::r#allowlist_proof::*;
+pub use self::r#bundle::*;
+pub use self::r#buyer_record::*;
+pub use self::r#guard_phases::*;
 pub use self::r#jellybean_machine::*;
+pub use self::r#print_template::*;
 pub use self::r#unclaimed_prizes::*;
+pub use self::r#used_gate_asset::*;
@@ -5,6 +5,7 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+use crate::generated::types::ItemWins;
 use crate::generated::types::Prize;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
@@ -32,6 +33,9 @@ pub struct UnclaimedPrizes {
     pub buyer: Pubkey,
     /// Indices of prizes drawn by the buyer
     pub prizes: Vec<Prize>,
+    /// Units won of each item with a buyer limit, kept after the prizes are claimed until
+    /// the sale is cancelled or the machine withdrawn
+    pub wins: Vec<ItemWins>,
 }
 
 impl UnclaimedPrizes {
@@ -88,6 +88,114 @@ pub enum MallowJellybeanError {
     /// 6025 - Invalid fee accounts length
     #[error("Invalid fee accounts length")]
     InvalidFeeAccountsLength = 0x1789,
+    /// 6026 - Invalid account version
+    #[error("Invalid account version")]
+    InvalidVersion = 0x178A,
+    /// 6027 - Missing print template
+    #[error("Missing print template")]
+    MissingPrintTemplate = 0x178B,
+    /// 6028 - Print template too long
+    #[error("Print template too long")]
+    PrintTemplateTooLong = 0x178C,
+    /// 6029 - Print escrow too small for the print name and uri
+    #[error("Print escrow too small for the print name and uri")]
+    InsufficientPrintEscrow = 0x178D,
+    /// 6030 - The authority pda must be the update authority or an update delegate of the asset
+    #[error("The authority pda must be the update authority or an update delegate of the asset")]
+    MachineCannotUpdateAsset = 0x178E,
+    /// 6031 - Bundle is loaded in the machine
+    #[error("Bundle is loaded in the machine")]
+    BundleLoaded = 0x178F,
+    /// 6032 - Too many bundle components
+    #[error("Too many bundle components")]
+    TooManyBundleComponents = 0x1790,
+    /// 6033 - Invalid bundle component
+    #[error("Invalid bundle component")]
+    InvalidBundleComponent = 0x1791,
+    /// 6034 - Too many disabled items
+    #[error("Too many disabled items")]
+    TooManyDisabledItems = 0x1792,
+    /// 6035 - Reveal is not enabled
+    #[error("Reveal is not enabled")]
+    RevealNotEnabled = 0x1793,
+    /// 6036 - Asset is already revealed
+    #[error("Asset is already revealed")]
+    AlreadyRevealed = 0x1794,
+    /// 6037 - Buyer has reached the limit of every remaining item
+    #[error("Buyer has reached the limit of every remaining item")]
+    BuyerLimitReached = 0x1795,
+    /// 6038 - Too many buyer limits
+    #[error("Too many buyer limits")]
+    TooManyBuyerLimits = 0x1796,
+    /// 6039 - Sale end time must be after its go live time
+    #[error("Sale end time must be after its go live time")]
+    InvalidSchedule = 0x1797,
+    /// 6040 - Sale has not gone live yet
+    #[error("Sale has not gone live yet")]
+    SaleNotStarted = 0x1798,
+    /// 6041 - Sale end time has passed
+    #[error("Sale end time has passed")]
+    SaleOver = 0x1799,
+    /// 6042 - Sale is paused
+    #[error("Sale is paused")]
+    SalePaused = 0x179A,
+    /// 6043 - Too many guard phases
+    #[error("Too many guard phases")]
+    TooManyGuardPhases = 0x179B,
+    /// 6044 - Guard phases must be ordered by start time
+    #[error("Guard phases must be ordered by start time")]
+    InvalidGuardPhases = 0x179C,
+    /// 6045 - Missing guard account
+    #[error("Missing guard account")]
+    MissingGuardAccount = 0x179D,
+    /// 6046 - No guard phase is active
+    #[error("No guard phase is active")]
+    NoActivePhase = 0x179E,
+    /// 6047 - Buyer must sign gated or limited draws
+    #[error("Buyer must sign gated or limited draws")]
+    MissingBuyerSignature = 0x179F,
+    /// 6048 - Buyer is not in the allowlist
+    #[error("Buyer is not in the allowlist")]
+    NotAllowlisted = 0x17A0,
+    /// 6049 - Buyer does not hold the gate token
+    #[error("Buyer does not hold the gate token")]
+    MissingGateToken = 0x17A1,
+    /// 6050 - Buyer has reached the draw limit
+    #[error("Buyer has reached the draw limit")]
+    WalletLimitReached = 0x17A2,
+    /// 6051 - Guard phases can only be added after the existing ones once the sale has started
+    #[error("Guard phases can only be added after the existing ones once the sale has started")]
+    GuardPhasesLocked = 0x17A3,
+    /// 6052 - Guard records can only be closed once the machine is withdrawn or cancelled
+    #[error("Guard records can only be closed once the machine is withdrawn or cancelled")]
+    GuardRecordInUse = 0x17A4,
+    /// 6053 - Invalid gate asset
+    #[error("Invalid gate asset")]
+    InvalidGateAsset = 0x17A5,
+    /// 6054 - Gate asset has already been used
+    #[error("Gate asset has already been used")]
+    GateAssetUsed = 0x17A6,
+    /// 6055 - Bundle has already been partially delivered
+    #[error("Bundle has already been partially delivered")]
+    BundlePartiallyDelivered = 0x17A7,
+    /// 6056 - Claim deadline has not passed yet
+    #[error("Claim deadline has not passed yet")]
+    ClaimDeadlineNotReached = 0x17A8,
+    /// 6057 - Bundles still hold escrowed components
+    #[error("Bundles still hold escrowed components")]
+    BundlesStillOpen = 0x17A9,
+    /// 6058 - Missing token account
+    #[error("Missing token account")]
+    MissingTokenAccount = 0x17AA,
+    /// 6059 - Public draws require guard phases with a price
+    #[error("Public draws require guard phases with a price")]
+    PublicDrawWithoutPrice = 0x17AB,
+    /// 6060 - Missing print fee account
+    #[error("Missing print fee account")]
+    MissingPrintFeeAccount = 0x17AC,
+    /// 6061 - Authority pda token accounts must be swept
+    #[error("Authority pda token accounts must be swept")]
+    TokenAccountsStillOpen = 0x17AD,
 }
 
 impl solana_program::program_error::PrintProgramError for MallowJellybeanError {
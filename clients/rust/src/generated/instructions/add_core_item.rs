@@ -27,19 +27,27 @@ pub struct AddCoreItem {
     pub mpl_core_program: solana_program::pubkey::Pubkey,
 
     pub system_program: solana_program::pubkey::Pubkey,
+
+    pub event_authority: solana_program::pubkey::Pubkey,
+
+    pub program: solana_program::pubkey::Pubkey,
 }
 
 impl AddCoreItem {
-    pub fn instruction(&self) -> solana_program::instruction::Instruction {
-        self.instruction_with_remaining_accounts(&[])
+    pub fn instruction(
+        &self,
+        args: AddCoreItemInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
     }
     #[allow(clippy::arithmetic_side_effects)]
     #[allow(clippy::vec_init_then_push)]
     pub fn instruction_with_remaining_accounts(
         &self,
+        args: AddCoreItemInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.jellybean_machine,
             false,
@@ -81,8 +89,18 @@ impl AddCoreItem {
             self.system_program,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.event_authority,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
-        let data = borsh::to_vec(&AddCoreItemInstructionData::new()).unwrap();
+        let mut data = borsh::to_vec(&AddCoreItemInstructionData::new()).unwrap();
+        let mut args = borsh::to_vec(&args).unwrap();
+        data.append(&mut args);
 
         solana_program::instruction::Instruction {
             program_id: crate::MALLOW_JELLYBEAN_ID,
@@ -112,6 +130,12 @@ impl Default for AddCoreItemInstructionData {
     }
 }
 
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AddCoreItemInstructionArgs {
+    pub supply: Option<u32>,
+}
+
 /// Instruction builder for `AddCoreItem`.
 ///
 /// ### Accounts:
@@ -124,6 +148,8 @@ impl Default for AddCoreItemInstructionData {
 ///   5. `[writable, optional]` collection
 ///   6. `[optional]` mpl_core_program (default to `CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d`)
 ///   7. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   8. `[]` event_authority
+///   9. `[]` program
 #[derive(Clone, Debug, Default)]
 pub struct AddCoreItemBuilder {
     jellybean_machine: Option<solana_program::pubkey::Pubkey>,
@@ -134,6 +160,9 @@ pub struct AddCoreItemBuilder {
     collection: Option<solana_program::pubkey::Pubkey>,
     mpl_core_program: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    event_authority: Option<solana_program::pubkey::Pubkey>,
+    program: Option<solana_program::pubkey::Pubkey>,
+    supply: Option<u32>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -195,6 +224,25 @@ impl AddCoreItemBuilder {
         self.system_program = Some(system_program);
         self
     }
+    #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn supply(&mut self, supply: u32) -> &mut Self {
+        self.supply = Some(supply);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -230,9 +278,14 @@ impl AddCoreItemBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            event_authority: self.event_authority.expect("event_authority is not set"),
+            program: self.program.expect("program is not set"),
+        };
+        let args = AddCoreItemInstructionArgs {
+            supply: self.supply.clone(),
         };
 
-        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
     }
 }
 
@@ -254,6 +307,10 @@ pub struct AddCoreItemCpiAccounts<'a, 'b> {
     pub mpl_core_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 /// `add_core_item` CPI instruction.
@@ -276,12 +333,19 @@ pub struct AddCoreItemCpi<'a, 'b> {
     pub mpl_core_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: AddCoreItemInstructionArgs,
 }
 
 impl<'a, 'b> AddCoreItemCpi<'a, 'b> {
     pub fn new(
         program: &'b solana_program::account_info::AccountInfo<'a>,
         accounts: AddCoreItemCpiAccounts<'a, 'b>,
+        args: AddCoreItemInstructionArgs,
     ) -> Self {
         Self {
             __program: program,
@@ -293,6 +357,9 @@ impl<'a, 'b> AddCoreItemCpi<'a, 'b> {
             collection: accounts.collection,
             mpl_core_program: accounts.mpl_core_program,
             system_program: accounts.system_program,
+            event_authority: accounts.event_authority,
+            program: accounts.program,
+            __args: args,
         }
     }
     #[inline(always)]
@@ -329,7 +396,7 @@ impl<'a, 'b> AddCoreItemCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.jellybean_machine.key,
             false,
@@ -375,6 +442,14 @@ impl<'a, 'b> AddCoreItemCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.event_authority.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -382,14 +457,16 @@ impl<'a, 'b> AddCoreItemCpi<'a, 'b> {
                 is_writable: remaining_account.2,
             })
         });
-        let data = borsh::to_vec(&AddCoreItemInstructionData::new()).unwrap();
+        let mut data = borsh::to_vec(&AddCoreItemInstructionData::new()).unwrap();
+        let mut args = borsh::to_vec(&self.__args).unwrap();
+        data.append(&mut args);
 
         let instruction = solana_program::instruction::Instruction {
             program_id: crate::MALLOW_JELLYBEAN_ID,
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(9 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(11 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.jellybean_machine.clone());
         account_infos.push(self.authority_pda.clone());
@@ -403,6 +480,8 @@ impl<'a, 'b> AddCoreItemCpi<'a, 'b> {
         }
         account_infos.push(self.mpl_core_program.clone());
         account_infos.push(self.system_program.clone());
+        account_infos.push(self.event_authority.clone());
+        account_infos.push(self.program.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -427,6 +506,8 @@ impl<'a, 'b> AddCoreItemCpi<'a, 'b> {
 ///   5. `[writable, optional]` collection
 ///   6. `[]` mpl_core_program
 ///   7. `[]` system_program
+///   8. `[]` event_authority
+///   9. `[]` program
 #[derive(Clone, Debug)]
 pub struct AddCoreItemCpiBuilder<'a, 'b> {
     instruction: Box<AddCoreItemCpiBuilderInstruction<'a, 'b>>,
@@ -444,6 +525,9 @@ impl<'a, 'b> AddCoreItemCpiBuilder<'a, 'b> {
             collection: None,
             mpl_core_program: None,
             system_program: None,
+            event_authority: None,
+            program: None,
+            supply: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -515,6 +599,28 @@ impl<'a, 'b> AddCoreItemCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(
+        &mut self,
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn supply(&mut self, supply: u32) -> &mut Self {
+        self.instruction.supply = Some(supply);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -556,6 +662,9 @@ impl<'a, 'b> AddCoreItemCpiBuilder<'a, 'b> {
         &self,
         signers_seeds: &[&[&[u8]]],
     ) -> solana_program::entrypoint::ProgramResult {
+        let args = AddCoreItemInstructionArgs {
+            supply: self.instruction.supply.clone(),
+        };
         let instruction = AddCoreItemCpi {
             __program: self.instruction.__program,
 
@@ -586,6 +695,14 @@ impl<'a, 'b> AddCoreItemCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            event_authority: self
+                .instruction
+                .event_authority
+                .expect("event_authority is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
+            __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -605,6 +722,9 @@ struct AddCoreItemCpiBuilderInstruction<'a, 'b> {
     collection: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     mpl_core_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    event_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    supply: Option<u32>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
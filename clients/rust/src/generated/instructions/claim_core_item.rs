@@ -27,6 +27,8 @@ pub struct ClaimCoreItem {
     pub collection: Option<solana_program::pubkey::Pubkey>,
 
     pub print_asset: Option<solana_program::pubkey::Pubkey>,
+    /// Print template of the master edition, required when claiming an edition.
+    pub print_template: Option<solana_program::pubkey::Pubkey>,
 
     pub mpl_core_program: solana_program::pubkey::Pubkey,
 
@@ -51,7 +53,7 @@ impl ClaimCoreItem {
         args: ClaimCoreItemInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(13 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.payer, true,
         ));
@@ -99,6 +101,17 @@ impl ClaimCoreItem {
                 false,
             ));
         }
+        if let Some(print_template) = self.print_template {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                print_template,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MALLOW_JELLYBEAN_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.mpl_core_program,
             false,
@@ -151,7 +164,7 @@ impl Default for ClaimCoreItemInstructionData {
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ClaimCoreItemInstructionArgs {
-    pub index: u8,
+    pub index: u16,
 }
 
 /// Instruction builder for `ClaimCoreItem`.
@@ -166,10 +179,11 @@ pub struct ClaimCoreItemInstructionArgs {
 ///   5. `[writable, optional]` asset
 ///   6. `[writable, optional]` collection
 ///   7. `[writable, signer, optional]` print_asset
-///   8. `[optional]` mpl_core_program (default to `CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d`)
-///   9. `[optional]` system_program (default to `11111111111111111111111111111111`)
-///   10. `[]` event_authority
-///   11. `[]` program
+///   8. `[optional]` print_template
+///   9. `[optional]` mpl_core_program (default to `CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d`)
+///   10. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   11. `[]` event_authority
+///   12. `[]` program
 #[derive(Clone, Debug, Default)]
 pub struct ClaimCoreItemBuilder {
     payer: Option<solana_program::pubkey::Pubkey>,
@@ -180,11 +194,12 @@ pub struct ClaimCoreItemBuilder {
     asset: Option<solana_program::pubkey::Pubkey>,
     collection: Option<solana_program::pubkey::Pubkey>,
     print_asset: Option<solana_program::pubkey::Pubkey>,
+    print_template: Option<solana_program::pubkey::Pubkey>,
     mpl_core_program: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
     event_authority: Option<solana_program::pubkey::Pubkey>,
     program: Option<solana_program::pubkey::Pubkey>,
-    index: Option<u8>,
+    index: Option<u16>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -248,6 +263,16 @@ impl ClaimCoreItemBuilder {
         self.print_asset = print_asset;
         self
     }
+    /// `[optional account]`
+    /// Print template of the master edition, required when claiming an edition.
+    #[inline(always)]
+    pub fn print_template(
+        &mut self,
+        print_template: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.print_template = print_template;
+        self
+    }
     /// `[optional account, default to 'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d']`
     #[inline(always)]
     pub fn mpl_core_program(
@@ -277,7 +302,7 @@ impl ClaimCoreItemBuilder {
         self
     }
     #[inline(always)]
-    pub fn index(&mut self, index: u8) -> &mut Self {
+    pub fn index(&mut self, index: u16) -> &mut Self {
         self.index = Some(index);
         self
     }
@@ -312,6 +337,7 @@ impl ClaimCoreItemBuilder {
             asset: self.asset,
             collection: self.collection,
             print_asset: self.print_asset,
+            print_template: self.print_template,
             mpl_core_program: self.mpl_core_program.unwrap_or(solana_program::pubkey!(
                 "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
             )),
@@ -347,6 +373,8 @@ pub struct ClaimCoreItemCpiAccounts<'a, 'b> {
     pub collection: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 
     pub print_asset: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Print template of the master edition, required when claiming an edition.
+    pub print_template: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 
     pub mpl_core_program: &'b solana_program::account_info::AccountInfo<'a>,
 
@@ -377,6 +405,8 @@ pub struct ClaimCoreItemCpi<'a, 'b> {
     pub collection: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 
     pub print_asset: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Print template of the master edition, required when claiming an edition.
+    pub print_template: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 
     pub mpl_core_program: &'b solana_program::account_info::AccountInfo<'a>,
 
@@ -405,6 +435,7 @@ impl<'a, 'b> ClaimCoreItemCpi<'a, 'b> {
             asset: accounts.asset,
             collection: accounts.collection,
             print_asset: accounts.print_asset,
+            print_template: accounts.print_template,
             mpl_core_program: accounts.mpl_core_program,
             system_program: accounts.system_program,
             event_authority: accounts.event_authority,
@@ -446,7 +477,7 @@ impl<'a, 'b> ClaimCoreItemCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(13 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.payer.key,
             true,
@@ -499,6 +530,17 @@ impl<'a, 'b> ClaimCoreItemCpi<'a, 'b> {
                 false,
             ));
         }
+        if let Some(print_template) = self.print_template {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *print_template.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MALLOW_JELLYBEAN_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.mpl_core_program.key,
             false,
@@ -531,7 +573,7 @@ impl<'a, 'b> ClaimCoreItemCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(13 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(14 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.payer.clone());
         account_infos.push(self.jellybean_machine.clone());
@@ -547,6 +589,9 @@ impl<'a, 'b> ClaimCoreItemCpi<'a, 'b> {
         if let Some(print_asset) = self.print_asset {
             account_infos.push(print_asset.clone());
         }
+        if let Some(print_template) = self.print_template {
+            account_infos.push(print_template.clone());
+        }
         account_infos.push(self.mpl_core_program.clone());
         account_infos.push(self.system_program.clone());
         account_infos.push(self.event_authority.clone());
@@ -575,10 +620,11 @@ impl<'a, 'b> ClaimCoreItemCpi<'a, 'b> {
 ///   5. `[writable, optional]` asset
 ///   6. `[writable, optional]` collection
 ///   7. `[writable, signer, optional]` print_asset
-///   8. `[]` mpl_core_program
-///   9. `[]` system_program
-///   10. `[]` event_authority
-///   11. `[]` program
+///   8. `[optional]` print_template
+///   9. `[]` mpl_core_program
+///   10. `[]` system_program
+///   11. `[]` event_authority
+///   12. `[]` program
 #[derive(Clone, Debug)]
 pub struct ClaimCoreItemCpiBuilder<'a, 'b> {
     instruction: Box<ClaimCoreItemCpiBuilderInstruction<'a, 'b>>,
@@ -596,6 +642,7 @@ impl<'a, 'b> ClaimCoreItemCpiBuilder<'a, 'b> {
             asset: None,
             collection: None,
             print_asset: None,
+            print_template: None,
             mpl_core_program: None,
             system_program: None,
             event_authority: None,
@@ -670,6 +717,16 @@ impl<'a, 'b> ClaimCoreItemCpiBuilder<'a, 'b> {
         self.instruction.print_asset = print_asset;
         self
     }
+    /// `[optional account]`
+    /// Print template of the master edition, required when claiming an edition.
+    #[inline(always)]
+    pub fn print_template(
+        &mut self,
+        print_template: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.print_template = print_template;
+        self
+    }
     #[inline(always)]
     pub fn mpl_core_program(
         &mut self,
@@ -703,7 +760,7 @@ impl<'a, 'b> ClaimCoreItemCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
-    pub fn index(&mut self, index: u8) -> &mut Self {
+    pub fn index(&mut self, index: u16) -> &mut Self {
         self.instruction.index = Some(index);
         self
     }
@@ -779,6 +836,8 @@ impl<'a, 'b> ClaimCoreItemCpiBuilder<'a, 'b> {
 
             print_asset: self.instruction.print_asset,
 
+            print_template: self.instruction.print_template,
+
             mpl_core_program: self
                 .instruction
                 .mpl_core_program
@@ -815,11 +874,12 @@ struct ClaimCoreItemCpiBuilderInstruction<'a, 'b> {
     asset: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     collection: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     print_asset: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    print_template: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     mpl_core_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     event_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
-    index: Option<u8>,
+    index: Option<u16>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -16,6 +16,8 @@ pub struct Draw {
 
     pub authority_pda: solana_program::pubkey::Pubkey,
     /// Jellybean machine mint authority (mint only allowed for the mint_authority).
+    /// Only required to sign if the machine doesn't allow public draws.
+    ///
     pub mint_authority: solana_program::pubkey::Pubkey,
     /// Payer for the transaction and account allocation (rent).
     pub payer: solana_program::pubkey::Pubkey,
@@ -60,7 +62,7 @@ impl Draw {
         ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.mint_authority,
-            true,
+            false,
         ));
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.payer, true,
@@ -139,7 +141,7 @@ impl Default for DrawInstructionData {
 ///
 ///   0. `[writable]` jellybean_machine
 ///   1. `[writable]` authority_pda
-///   2. `[signer]` mint_authority
+///   2. `[]` mint_authority
 ///   3. `[writable, signer]` payer
 ///   4. `[]` buyer
 ///   5. `[writable]` unclaimed_prizes
@@ -185,6 +187,8 @@ impl DrawBuilder {
         self
     }
     /// Jellybean machine mint authority (mint only allowed for the mint_authority).
+    /// Only required to sign if the machine doesn't allow public draws.
+    ///
     #[inline(always)]
     pub fn mint_authority(&mut self, mint_authority: solana_program::pubkey::Pubkey) -> &mut Self {
         self.mint_authority = Some(mint_authority);
@@ -314,6 +318,8 @@ pub struct DrawCpiAccounts<'a, 'b> {
 
     pub authority_pda: &'b solana_program::account_info::AccountInfo<'a>,
     /// Jellybean machine mint authority (mint only allowed for the mint_authority).
+    /// Only required to sign if the machine doesn't allow public draws.
+    ///
     pub mint_authority: &'b solana_program::account_info::AccountInfo<'a>,
     /// Payer for the transaction and account allocation (rent).
     pub payer: &'b solana_program::account_info::AccountInfo<'a>,
@@ -346,6 +352,8 @@ pub struct DrawCpi<'a, 'b> {
 
     pub authority_pda: &'b solana_program::account_info::AccountInfo<'a>,
     /// Jellybean machine mint authority (mint only allowed for the mint_authority).
+    /// Only required to sign if the machine doesn't allow public draws.
+    ///
     pub mint_authority: &'b solana_program::account_info::AccountInfo<'a>,
     /// Payer for the transaction and account allocation (rent).
     pub payer: &'b solana_program::account_info::AccountInfo<'a>,
@@ -435,7 +443,7 @@ impl<'a, 'b> DrawCpi<'a, 'b> {
         ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.mint_authority.key,
-            true,
+            false,
         ));
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.payer.key,
@@ -528,7 +536,7 @@ impl<'a, 'b> DrawCpi<'a, 'b> {
 ///
 ///   0. `[writable]` jellybean_machine
 ///   1. `[writable]` authority_pda
-///   2. `[signer]` mint_authority
+///   2. `[]` mint_authority
 ///   3. `[writable, signer]` payer
 ///   4. `[]` buyer
 ///   5. `[writable]` unclaimed_prizes
@@ -581,6 +589,8 @@ impl<'a, 'b> DrawCpiBuilder<'a, 'b> {
         self
     }
     /// Jellybean machine mint authority (mint only allowed for the mint_authority).
+    /// Only required to sign if the machine doesn't allow public draws.
+    ///
     #[inline(always)]
     pub fn mint_authority(
         &mut self,
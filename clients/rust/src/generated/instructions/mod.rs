@@ -5,24 +5,76 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+pub(crate) mod r#add_bundle_component;
+pub(crate) mod r#add_bundle_item;
 pub(crate) mod r#add_core_item;
+pub(crate) mod r#add_core_items;
+pub(crate) mod r#add_prints;
+pub(crate) mod r#cancel_sale;
+pub(crate) mod r#claim_bundle_component;
 pub(crate) mod r#claim_core_item;
+pub(crate) mod r#close_guard_record;
+pub(crate) mod r#close_unclaimed_prizes;
 pub(crate) mod r#draw;
 pub(crate) mod r#end_sale;
+pub(crate) mod r#end_scheduled_sale;
 pub(crate) mod r#initialize;
+pub(crate) mod r#migrate_jellybean_machine;
+pub(crate) mod r#migrate_unclaimed_prizes;
+pub(crate) mod r#pause_sale;
+pub(crate) mod r#prove_allowlist;
+pub(crate) mod r#reclaim_prize;
+pub(crate) mod r#refund_prize;
+pub(crate) mod r#remove_bundle_component;
+pub(crate) mod r#remove_bundle_item;
 pub(crate) mod r#remove_core_item;
+pub(crate) mod r#remove_core_items;
+pub(crate) mod r#replace_core_item;
+pub(crate) mod r#resume_sale;
+pub(crate) mod r#reveal_items;
+pub(crate) mod r#set_guard_phases;
+pub(crate) mod r#set_item_buyer_limit;
+pub(crate) mod r#set_item_enabled;
 pub(crate) mod r#set_mint_authority;
+pub(crate) mod r#set_print_template;
 pub(crate) mod r#start_sale;
+pub(crate) mod r#unload_all;
 pub(crate) mod r#update_settings;
 pub(crate) mod r#withdraw;
 
+pub use self::r#add_bundle_component::*;
+pub use self::r#add_bundle_item::*;
 pub use self::r#add_core_item::*;
+pub use self::r#add_core_items::*;
+pub use self::r#add_prints::*;
+pub use self::r#cancel_sale::*;
+pub use self::r#claim_bundle_component::*;
 pub use self::r#claim_core_item::*;
+pub use self::r#close_guard_record::*;
+pub use self::r#close_unclaimed_prizes::*;
 pub use self::r#draw::*;
 pub use self::r#end_sale::*;
+pub use self::r#end_scheduled_sale::*;
 pub use self::r#initialize::*;
+pub use self::r#migrate_jellybean_machine::*;
+pub use self::r#migrate_unclaimed_prizes::*;
+pub use self::r#pause_sale::*;
+pub use self::r#prove_allowlist::*;
+pub use self::r#reclaim_prize::*;
+pub use self::r#refund_prize::*;
+pub use self::r#remove_bundle_component::*;
+pub use self::r#remove_bundle_item::*;
 pub use self::r#remove_core_item::*;
+pub use self::r#remove_core_items::*;
+pub use self::r#replace_core_item::*;
+pub use self::r#resume_sale::*;
+pub use self::r#reveal_items::*;
+pub use self::r#set_guard_phases::*;
+pub use self::r#set_item_buyer_limit::*;
+pub use self::r#set_item_enabled::*;
 pub use self::r#set_mint_authority::*;
+pub use self::r#set_print_template::*;
 pub use self::r#start_sale::*;
+pub use self::r#unload_all::*;
 pub use self::r#update_settings::*;
 pub use self::r#withdraw::*;
@@ -21,6 +21,8 @@ pub struct RemoveCoreItem {
     pub asset: Option<solana_program::pubkey::Pubkey>,
     /// Core asset's collection if it's part of one.
     pub collection: Option<solana_program::pubkey::Pubkey>,
+    /// Print template of the master edition, required when removing one.
+    pub print_template: Option<solana_program::pubkey::Pubkey>,
 
     pub mpl_core_program: solana_program::pubkey::Pubkey,
 
@@ -41,7 +43,7 @@ impl RemoveCoreItem {
         args: RemoveCoreItemInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.jellybean_machine,
             false,
@@ -72,6 +74,17 @@ impl RemoveCoreItem {
                 false,
             ));
         }
+        if let Some(print_template) = self.print_template {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                print_template,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MALLOW_JELLYBEAN_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.mpl_core_program,
             false,
@@ -116,7 +129,7 @@ impl Default for RemoveCoreItemInstructionData {
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RemoveCoreItemInstructionArgs {
-    pub index: u8,
+    pub index: u16,
 }
 
 /// Instruction builder for `RemoveCoreItem`.
@@ -128,8 +141,9 @@ pub struct RemoveCoreItemInstructionArgs {
 ///   2. `[writable, signer]` authority
 ///   3. `[writable, optional]` asset
 ///   4. `[writable, optional]` collection
-///   5. `[optional]` mpl_core_program (default to `CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d`)
-///   6. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   5. `[writable, optional]` print_template
+///   6. `[optional]` mpl_core_program (default to `CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d`)
+///   7. `[optional]` system_program (default to `11111111111111111111111111111111`)
 #[derive(Clone, Debug, Default)]
 pub struct RemoveCoreItemBuilder {
     jellybean_machine: Option<solana_program::pubkey::Pubkey>,
@@ -137,9 +151,10 @@ pub struct RemoveCoreItemBuilder {
     authority: Option<solana_program::pubkey::Pubkey>,
     asset: Option<solana_program::pubkey::Pubkey>,
     collection: Option<solana_program::pubkey::Pubkey>,
+    print_template: Option<solana_program::pubkey::Pubkey>,
     mpl_core_program: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
-    index: Option<u8>,
+    index: Option<u16>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -180,6 +195,16 @@ impl RemoveCoreItemBuilder {
         self.collection = collection;
         self
     }
+    /// `[optional account]`
+    /// Print template of the master edition, required when removing one.
+    #[inline(always)]
+    pub fn print_template(
+        &mut self,
+        print_template: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.print_template = print_template;
+        self
+    }
     /// `[optional account, default to 'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d']`
     #[inline(always)]
     pub fn mpl_core_program(
@@ -196,7 +221,7 @@ impl RemoveCoreItemBuilder {
         self
     }
     #[inline(always)]
-    pub fn index(&mut self, index: u8) -> &mut Self {
+    pub fn index(&mut self, index: u16) -> &mut Self {
         self.index = Some(index);
         self
     }
@@ -228,6 +253,7 @@ impl RemoveCoreItemBuilder {
             authority: self.authority.expect("authority is not set"),
             asset: self.asset,
             collection: self.collection,
+            print_template: self.print_template,
             mpl_core_program: self.mpl_core_program.unwrap_or(solana_program::pubkey!(
                 "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
             )),
@@ -255,6 +281,8 @@ pub struct RemoveCoreItemCpiAccounts<'a, 'b> {
     pub asset: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Core asset's collection if it's part of one.
     pub collection: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Print template of the master edition, required when removing one.
+    pub print_template: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 
     pub mpl_core_program: &'b solana_program::account_info::AccountInfo<'a>,
 
@@ -275,6 +303,8 @@ pub struct RemoveCoreItemCpi<'a, 'b> {
     pub asset: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Core asset's collection if it's part of one.
     pub collection: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Print template of the master edition, required when removing one.
+    pub print_template: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 
     pub mpl_core_program: &'b solana_program::account_info::AccountInfo<'a>,
 
@@ -296,6 +326,7 @@ impl<'a, 'b> RemoveCoreItemCpi<'a, 'b> {
             authority: accounts.authority,
             asset: accounts.asset,
             collection: accounts.collection,
+            print_template: accounts.print_template,
             mpl_core_program: accounts.mpl_core_program,
             system_program: accounts.system_program,
             __args: args,
@@ -335,7 +366,7 @@ impl<'a, 'b> RemoveCoreItemCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.jellybean_machine.key,
             false,
@@ -369,6 +400,17 @@ impl<'a, 'b> RemoveCoreItemCpi<'a, 'b> {
                 false,
             ));
         }
+        if let Some(print_template) = self.print_template {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *print_template.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MALLOW_JELLYBEAN_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.mpl_core_program.key,
             false,
@@ -393,7 +435,7 @@ impl<'a, 'b> RemoveCoreItemCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(8 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(9 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.jellybean_machine.clone());
         account_infos.push(self.authority_pda.clone());
@@ -404,6 +446,9 @@ impl<'a, 'b> RemoveCoreItemCpi<'a, 'b> {
         if let Some(collection) = self.collection {
             account_infos.push(collection.clone());
         }
+        if let Some(print_template) = self.print_template {
+            account_infos.push(print_template.clone());
+        }
         account_infos.push(self.mpl_core_program.clone());
         account_infos.push(self.system_program.clone());
         remaining_accounts
@@ -427,8 +472,9 @@ impl<'a, 'b> RemoveCoreItemCpi<'a, 'b> {
 ///   2. `[writable, signer]` authority
 ///   3. `[writable, optional]` asset
 ///   4. `[writable, optional]` collection
-///   5. `[]` mpl_core_program
-///   6. `[]` system_program
+///   5. `[writable, optional]` print_template
+///   6. `[]` mpl_core_program
+///   7. `[]` system_program
 #[derive(Clone, Debug)]
 pub struct RemoveCoreItemCpiBuilder<'a, 'b> {
     instruction: Box<RemoveCoreItemCpiBuilderInstruction<'a, 'b>>,
@@ -443,6 +489,7 @@ impl<'a, 'b> RemoveCoreItemCpiBuilder<'a, 'b> {
             authority: None,
             asset: None,
             collection: None,
+            print_template: None,
             mpl_core_program: None,
             system_program: None,
             index: None,
@@ -495,6 +542,16 @@ impl<'a, 'b> RemoveCoreItemCpiBuilder<'a, 'b> {
         self.instruction.collection = collection;
         self
     }
+    /// `[optional account]`
+    /// Print template of the master edition, required when removing one.
+    #[inline(always)]
+    pub fn print_template(
+        &mut self,
+        print_template: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.print_template = print_template;
+        self
+    }
     #[inline(always)]
     pub fn mpl_core_program(
         &mut self,
@@ -512,7 +569,7 @@ impl<'a, 'b> RemoveCoreItemCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
-    pub fn index(&mut self, index: u8) -> &mut Self {
+    pub fn index(&mut self, index: u16) -> &mut Self {
         self.instruction.index = Some(index);
         self
     }
@@ -579,6 +636,8 @@ impl<'a, 'b> RemoveCoreItemCpiBuilder<'a, 'b> {
 
             collection: self.instruction.collection,
 
+            print_template: self.instruction.print_template,
+
             mpl_core_program: self
                 .instruction
                 .mpl_core_program
@@ -605,9 +664,10 @@ struct RemoveCoreItemCpiBuilderInstruction<'a, 'b> {
     authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     asset: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     collection: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    print_template: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     mpl_core_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
-    index: Option<u8>,
+    index: Option<u16>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -15,6 +15,8 @@ pub struct StartSale {
     pub jellybean_machine: solana_program::pubkey::Pubkey,
     /// Jellybean Machine authority. This can be the mint authority or the authority.
     pub authority: solana_program::pubkey::Pubkey,
+    /// Guard phases account, required for machines with public draws.
+    pub guard_phases: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl StartSale {
@@ -27,7 +29,7 @@ impl StartSale {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(2 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.jellybean_machine,
             false,
@@ -36,6 +38,17 @@ impl StartSale {
             self.authority,
             true,
         ));
+        if let Some(guard_phases) = self.guard_phases {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                guard_phases,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MALLOW_JELLYBEAN_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let data = borsh::to_vec(&StartSaleInstructionData::new()).unwrap();
 
@@ -73,10 +86,12 @@ impl Default for StartSaleInstructionData {
 ///
 ///   0. `[writable]` jellybean_machine
 ///   1. `[signer]` authority
+///   2. `[optional]` guard_phases
 #[derive(Clone, Debug, Default)]
 pub struct StartSaleBuilder {
     jellybean_machine: Option<solana_program::pubkey::Pubkey>,
     authority: Option<solana_program::pubkey::Pubkey>,
+    guard_phases: Option<solana_program::pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -99,6 +114,16 @@ impl StartSaleBuilder {
         self.authority = Some(authority);
         self
     }
+    /// `[optional account]`
+    /// Guard phases account, required for machines with public draws.
+    #[inline(always)]
+    pub fn guard_phases(
+        &mut self,
+        guard_phases: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.guard_phases = guard_phases;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -124,6 +149,7 @@ impl StartSaleBuilder {
                 .jellybean_machine
                 .expect("jellybean_machine is not set"),
             authority: self.authority.expect("authority is not set"),
+            guard_phases: self.guard_phases,
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -136,6 +162,8 @@ pub struct StartSaleCpiAccounts<'a, 'b> {
     pub jellybean_machine: &'b solana_program::account_info::AccountInfo<'a>,
     /// Jellybean Machine authority. This can be the mint authority or the authority.
     pub authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Guard phases account, required for machines with public draws.
+    pub guard_phases: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `start_sale` CPI instruction.
@@ -146,6 +174,8 @@ pub struct StartSaleCpi<'a, 'b> {
     pub jellybean_machine: &'b solana_program::account_info::AccountInfo<'a>,
     /// Jellybean Machine authority. This can be the mint authority or the authority.
     pub authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Guard phases account, required for machines with public draws.
+    pub guard_phases: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 impl<'a, 'b> StartSaleCpi<'a, 'b> {
@@ -157,6 +187,7 @@ impl<'a, 'b> StartSaleCpi<'a, 'b> {
             __program: program,
             jellybean_machine: accounts.jellybean_machine,
             authority: accounts.authority,
+            guard_phases: accounts.guard_phases,
         }
     }
     #[inline(always)]
@@ -193,7 +224,7 @@ impl<'a, 'b> StartSaleCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(2 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.jellybean_machine.key,
             false,
@@ -202,6 +233,17 @@ impl<'a, 'b> StartSaleCpi<'a, 'b> {
             *self.authority.key,
             true,
         ));
+        if let Some(guard_phases) = self.guard_phases {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *guard_phases.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MALLOW_JELLYBEAN_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -216,10 +258,13 @@ impl<'a, 'b> StartSaleCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(4 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.jellybean_machine.clone());
         account_infos.push(self.authority.clone());
+        if let Some(guard_phases) = self.guard_phases {
+            account_infos.push(guard_phases.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -238,6 +283,7 @@ impl<'a, 'b> StartSaleCpi<'a, 'b> {
 ///
 ///   0. `[writable]` jellybean_machine
 ///   1. `[signer]` authority
+///   2. `[optional]` guard_phases
 #[derive(Clone, Debug)]
 pub struct StartSaleCpiBuilder<'a, 'b> {
     instruction: Box<StartSaleCpiBuilderInstruction<'a, 'b>>,
@@ -249,6 +295,7 @@ impl<'a, 'b> StartSaleCpiBuilder<'a, 'b> {
             __program: program,
             jellybean_machine: None,
             authority: None,
+            guard_phases: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -271,6 +318,16 @@ impl<'a, 'b> StartSaleCpiBuilder<'a, 'b> {
         self.instruction.authority = Some(authority);
         self
     }
+    /// `[optional account]`
+    /// Guard phases account, required for machines with public draws.
+    #[inline(always)]
+    pub fn guard_phases(
+        &mut self,
+        guard_phases: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.guard_phases = guard_phases;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -321,6 +378,8 @@ impl<'a, 'b> StartSaleCpiBuilder<'a, 'b> {
                 .expect("jellybean_machine is not set"),
 
             authority: self.instruction.authority.expect("authority is not set"),
+
+            guard_phases: self.instruction.guard_phases,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -334,6 +393,7 @@ struct StartSaleCpiBuilderInstruction<'a, 'b> {
     __program: &'b solana_program::account_info::AccountInfo<'a>,
     jellybean_machine: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    guard_phases: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -16,6 +16,8 @@ pub struct UpdateSettings {
     pub jellybean_machine: solana_program::pubkey::Pubkey,
     /// Gumball Machine authority. This is the address that controls the upate of the jellybean machine.
     pub authority: solana_program::pubkey::Pubkey,
+    /// Guard phases account, required for machines with public draws.
+    pub guard_phases: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl UpdateSettings {
@@ -32,7 +34,7 @@ impl UpdateSettings {
         args: UpdateSettingsInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(2 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.jellybean_machine,
             false,
@@ -41,6 +43,17 @@ impl UpdateSettings {
             self.authority,
             true,
         ));
+        if let Some(guard_phases) = self.guard_phases {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                guard_phases,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MALLOW_JELLYBEAN_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let mut data = borsh::to_vec(&UpdateSettingsInstructionData::new()).unwrap();
         let mut args = borsh::to_vec(&args).unwrap();
@@ -86,10 +99,12 @@ pub struct UpdateSettingsInstructionArgs {
 ///
 ///   0. `[writable]` jellybean_machine
 ///   1. `[writable, signer]` authority
+///   2. `[optional]` guard_phases
 #[derive(Clone, Debug, Default)]
 pub struct UpdateSettingsBuilder {
     jellybean_machine: Option<solana_program::pubkey::Pubkey>,
     authority: Option<solana_program::pubkey::Pubkey>,
+    guard_phases: Option<solana_program::pubkey::Pubkey>,
     args: Option<SettingsArgs>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -113,6 +128,16 @@ impl UpdateSettingsBuilder {
         self.authority = Some(authority);
         self
     }
+    /// `[optional account]`
+    /// Guard phases account, required for machines with public draws.
+    #[inline(always)]
+    pub fn guard_phases(
+        &mut self,
+        guard_phases: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.guard_phases = guard_phases;
+        self
+    }
     #[inline(always)]
     pub fn args(&mut self, args: SettingsArgs) -> &mut Self {
         self.args = Some(args);
@@ -143,6 +168,7 @@ impl UpdateSettingsBuilder {
                 .jellybean_machine
                 .expect("jellybean_machine is not set"),
             authority: self.authority.expect("authority is not set"),
+            guard_phases: self.guard_phases,
         };
         let args = UpdateSettingsInstructionArgs {
             args: self.args.clone().expect("args is not set"),
@@ -158,6 +184,8 @@ pub struct UpdateSettingsCpiAccounts<'a, 'b> {
     pub jellybean_machine: &'b solana_program::account_info::AccountInfo<'a>,
     /// Gumball Machine authority. This is the address that controls the upate of the jellybean machine.
     pub authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Guard phases account, required for machines with public draws.
+    pub guard_phases: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `update_settings` CPI instruction.
@@ -168,6 +196,8 @@ pub struct UpdateSettingsCpi<'a, 'b> {
     pub jellybean_machine: &'b solana_program::account_info::AccountInfo<'a>,
     /// Gumball Machine authority. This is the address that controls the upate of the jellybean machine.
     pub authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Guard phases account, required for machines with public draws.
+    pub guard_phases: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The arguments for the instruction.
     pub __args: UpdateSettingsInstructionArgs,
 }
@@ -182,6 +212,7 @@ impl<'a, 'b> UpdateSettingsCpi<'a, 'b> {
             __program: program,
             jellybean_machine: accounts.jellybean_machine,
             authority: accounts.authority,
+            guard_phases: accounts.guard_phases,
             __args: args,
         }
     }
@@ -219,7 +250,7 @@ impl<'a, 'b> UpdateSettingsCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(2 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.jellybean_machine.key,
             false,
@@ -228,6 +259,17 @@ impl<'a, 'b> UpdateSettingsCpi<'a, 'b> {
             *self.authority.key,
             true,
         ));
+        if let Some(guard_phases) = self.guard_phases {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *guard_phases.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MALLOW_JELLYBEAN_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -244,10 +286,13 @@ impl<'a, 'b> UpdateSettingsCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(4 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.jellybean_machine.clone());
         account_infos.push(self.authority.clone());
+        if let Some(guard_phases) = self.guard_phases {
+            account_infos.push(guard_phases.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -266,6 +311,7 @@ impl<'a, 'b> UpdateSettingsCpi<'a, 'b> {
 ///
 ///   0. `[writable]` jellybean_machine
 ///   1. `[writable, signer]` authority
+///   2. `[optional]` guard_phases
 #[derive(Clone, Debug)]
 pub struct UpdateSettingsCpiBuilder<'a, 'b> {
     instruction: Box<UpdateSettingsCpiBuilderInstruction<'a, 'b>>,
@@ -277,6 +323,7 @@ impl<'a, 'b> UpdateSettingsCpiBuilder<'a, 'b> {
             __program: program,
             jellybean_machine: None,
             authority: None,
+            guard_phases: None,
             args: None,
             __remaining_accounts: Vec::new(),
         });
@@ -300,6 +347,16 @@ impl<'a, 'b> UpdateSettingsCpiBuilder<'a, 'b> {
         self.instruction.authority = Some(authority);
         self
     }
+    /// `[optional account]`
+    /// Guard phases account, required for machines with public draws.
+    #[inline(always)]
+    pub fn guard_phases(
+        &mut self,
+        guard_phases: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.guard_phases = guard_phases;
+        self
+    }
     #[inline(always)]
     pub fn args(&mut self, args: SettingsArgs) -> &mut Self {
         self.instruction.args = Some(args);
@@ -358,6 +415,8 @@ impl<'a, 'b> UpdateSettingsCpiBuilder<'a, 'b> {
                 .expect("jellybean_machine is not set"),
 
             authority: self.instruction.authority.expect("authority is not set"),
+
+            guard_phases: self.instruction.guard_phases,
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -372,6 +431,7 @@ struct UpdateSettingsCpiBuilderInstruction<'a, 'b> {
     __program: &'b solana_program::account_info::AccountInfo<'a>,
     jellybean_machine: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    guard_phases: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     args: Option<SettingsArgs>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
@@ -17,6 +17,16 @@ pub struct Withdraw {
     pub authority: solana_program::pubkey::Pubkey,
     /// Mint authority of the jellybean machine.
     pub mint_authority: solana_program::pubkey::Pubkey,
+    /// Guard phases account, closed with the machine if it exists.
+    pub guard_phases: solana_program::pubkey::Pubkey,
+
+    pub authority_pda: solana_program::pubkey::Pubkey,
+
+    pub token_program: Option<solana_program::pubkey::Pubkey>,
+
+    pub associated_token_program: Option<solana_program::pubkey::Pubkey>,
+
+    pub system_program: solana_program::pubkey::Pubkey,
 }
 
 impl Withdraw {
@@ -29,7 +39,7 @@ impl Withdraw {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.jellybean_machine,
             false,
@@ -42,6 +52,40 @@ impl Withdraw {
             self.mint_authority,
             true,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.guard_phases,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.authority_pda,
+            false,
+        ));
+        if let Some(token_program) = self.token_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                token_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MALLOW_JELLYBEAN_ID,
+                false,
+            ));
+        }
+        if let Some(associated_token_program) = self.associated_token_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                associated_token_program,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MALLOW_JELLYBEAN_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let data = borsh::to_vec(&WithdrawInstructionData::new()).unwrap();
 
@@ -80,11 +124,21 @@ impl Default for WithdrawInstructionData {
 ///   0. `[writable]` jellybean_machine
 ///   1. `[writable, signer]` authority
 ///   2. `[writable, signer]` mint_authority
+///   3. `[writable]` guard_phases
+///   4. `[writable]` authority_pda
+///   5. `[optional]` token_program
+///   6. `[optional]` associated_token_program
+///   7. `[optional]` system_program (default to `11111111111111111111111111111111`)
 #[derive(Clone, Debug, Default)]
 pub struct WithdrawBuilder {
     jellybean_machine: Option<solana_program::pubkey::Pubkey>,
     authority: Option<solana_program::pubkey::Pubkey>,
     mint_authority: Option<solana_program::pubkey::Pubkey>,
+    guard_phases: Option<solana_program::pubkey::Pubkey>,
+    authority_pda: Option<solana_program::pubkey::Pubkey>,
+    token_program: Option<solana_program::pubkey::Pubkey>,
+    associated_token_program: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -113,6 +167,41 @@ impl WithdrawBuilder {
         self.mint_authority = Some(mint_authority);
         self
     }
+    /// Guard phases account, closed with the machine if it exists.
+    #[inline(always)]
+    pub fn guard_phases(&mut self, guard_phases: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.guard_phases = Some(guard_phases);
+        self
+    }
+    #[inline(always)]
+    pub fn authority_pda(&mut self, authority_pda: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.authority_pda = Some(authority_pda);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.token_program = token_program;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn associated_token_program(
+        &mut self,
+        associated_token_program: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.associated_token_program = associated_token_program;
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -139,6 +228,13 @@ impl WithdrawBuilder {
                 .expect("jellybean_machine is not set"),
             authority: self.authority.expect("authority is not set"),
             mint_authority: self.mint_authority.expect("mint_authority is not set"),
+            guard_phases: self.guard_phases.expect("guard_phases is not set"),
+            authority_pda: self.authority_pda.expect("authority_pda is not set"),
+            token_program: self.token_program,
+            associated_token_program: self.associated_token_program,
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -153,6 +249,16 @@ pub struct WithdrawCpiAccounts<'a, 'b> {
     pub authority: &'b solana_program::account_info::AccountInfo<'a>,
     /// Mint authority of the jellybean machine.
     pub mint_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Guard phases account, closed with the machine if it exists.
+    pub guard_phases: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub authority_pda: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub associated_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 /// `withdraw` CPI instruction.
@@ -165,6 +271,16 @@ pub struct WithdrawCpi<'a, 'b> {
     pub authority: &'b solana_program::account_info::AccountInfo<'a>,
     /// Mint authority of the jellybean machine.
     pub mint_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Guard phases account, closed with the machine if it exists.
+    pub guard_phases: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub authority_pda: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub associated_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 impl<'a, 'b> WithdrawCpi<'a, 'b> {
@@ -177,6 +293,11 @@ impl<'a, 'b> WithdrawCpi<'a, 'b> {
             jellybean_machine: accounts.jellybean_machine,
             authority: accounts.authority,
             mint_authority: accounts.mint_authority,
+            guard_phases: accounts.guard_phases,
+            authority_pda: accounts.authority_pda,
+            token_program: accounts.token_program,
+            associated_token_program: accounts.associated_token_program,
+            system_program: accounts.system_program,
         }
     }
     #[inline(always)]
@@ -213,7 +334,7 @@ impl<'a, 'b> WithdrawCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.jellybean_machine.key,
             false,
@@ -226,6 +347,40 @@ impl<'a, 'b> WithdrawCpi<'a, 'b> {
             *self.mint_authority.key,
             true,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.guard_phases.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.authority_pda.key,
+            false,
+        ));
+        if let Some(token_program) = self.token_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *token_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MALLOW_JELLYBEAN_ID,
+                false,
+            ));
+        }
+        if let Some(associated_token_program) = self.associated_token_program {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *associated_token_program.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MALLOW_JELLYBEAN_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -240,11 +395,20 @@ impl<'a, 'b> WithdrawCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(9 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.jellybean_machine.clone());
         account_infos.push(self.authority.clone());
         account_infos.push(self.mint_authority.clone());
+        account_infos.push(self.guard_phases.clone());
+        account_infos.push(self.authority_pda.clone());
+        if let Some(token_program) = self.token_program {
+            account_infos.push(token_program.clone());
+        }
+        if let Some(associated_token_program) = self.associated_token_program {
+            account_infos.push(associated_token_program.clone());
+        }
+        account_infos.push(self.system_program.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -264,6 +428,11 @@ impl<'a, 'b> WithdrawCpi<'a, 'b> {
 ///   0. `[writable]` jellybean_machine
 ///   1. `[writable, signer]` authority
 ///   2. `[writable, signer]` mint_authority
+///   3. `[writable]` guard_phases
+///   4. `[writable]` authority_pda
+///   5. `[optional]` token_program
+///   6. `[optional]` associated_token_program
+///   7. `[]` system_program
 #[derive(Clone, Debug)]
 pub struct WithdrawCpiBuilder<'a, 'b> {
     instruction: Box<WithdrawCpiBuilderInstruction<'a, 'b>>,
@@ -276,6 +445,11 @@ impl<'a, 'b> WithdrawCpiBuilder<'a, 'b> {
             jellybean_machine: None,
             authority: None,
             mint_authority: None,
+            guard_phases: None,
+            authority_pda: None,
+            token_program: None,
+            associated_token_program: None,
+            system_program: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -307,6 +481,49 @@ impl<'a, 'b> WithdrawCpiBuilder<'a, 'b> {
         self.instruction.mint_authority = Some(mint_authority);
         self
     }
+    /// Guard phases account, closed with the machine if it exists.
+    #[inline(always)]
+    pub fn guard_phases(
+        &mut self,
+        guard_phases: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.guard_phases = Some(guard_phases);
+        self
+    }
+    #[inline(always)]
+    pub fn authority_pda(
+        &mut self,
+        authority_pda: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.authority_pda = Some(authority_pda);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.token_program = token_program;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn associated_token_program(
+        &mut self,
+        associated_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.associated_token_program = associated_token_program;
+        self
+    }
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -362,6 +579,25 @@ impl<'a, 'b> WithdrawCpiBuilder<'a, 'b> {
                 .instruction
                 .mint_authority
                 .expect("mint_authority is not set"),
+
+            guard_phases: self
+                .instruction
+                .guard_phases
+                .expect("guard_phases is not set"),
+
+            authority_pda: self
+                .instruction
+                .authority_pda
+                .expect("authority_pda is not set"),
+
+            token_program: self.instruction.token_program,
+
+            associated_token_program: self.instruction.associated_token_program,
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -376,6 +612,11 @@ struct WithdrawCpiBuilderInstruction<'a, 'b> {
     jellybean_machine: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     mint_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    guard_phases: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    authority_pda: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    associated_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -26,4 +26,6 @@ pub enum JellybeanState {
     None,
     SaleLive,
     SaleEnded,
+    Paused,
+    Cancelled,
 }
@@ -5,14 +5,36 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+pub(crate) mod r#bundle_component;
+pub(crate) mod r#bundle_component_kind;
+pub(crate) mod r#buyer_limit;
+pub(crate) mod r#draw_gate;
+pub(crate) mod r#draw_gate_action;
+pub(crate) mod r#draw_gate_kind;
 pub(crate) mod r#fee_account;
+pub(crate) mod r#guard_phase;
+pub(crate) mod r#item_wins;
 pub(crate) mod r#jellybean_state;
 pub(crate) mod r#print_fee_config;
 pub(crate) mod r#prize;
+pub(crate) mod r#provenance_authority;
+pub(crate) mod r#provenance_config;
+pub(crate) mod r#reveal_config;
 pub(crate) mod r#settings_args;
 
+pub use self::r#bundle_component::*;
+pub use self::r#bundle_component_kind::*;
+pub use self::r#buyer_limit::*;
+pub use self::r#draw_gate::*;
+pub use self::r#draw_gate_action::*;
+pub use self::r#draw_gate_kind::*;
 pub use self::r#fee_account::*;
+pub use self::r#guard_phase::*;
+pub use self::r#item_wins::*;
 pub use self::r#jellybean_state::*;
 pub use self::r#print_fee_config::*;
 pub use self::r#prize::*;
+pub use self::r#provenance_authority::*;
+pub use self::r#provenance_config::*;
+pub use self::r#reveal_config::*;
 pub use self::r#settings_args::*;
@@ -11,6 +11,9 @@ use borsh::BorshSerialize;
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Prize {
-    pub item_index: u8,
+    pub item_index: u16,
     pub edition_number: u32,
+    pub draw_slot: u64,
+    /// Price paid through the machine's guard phases, zero when a wrapping program charged
+    pub price: u64,
 }
@@ -7,6 +7,8 @@
 
 use crate::generated::types::FeeAccount;
 use crate::generated::types::PrintFeeConfig;
+use crate::generated::types::ProvenanceConfig;
+use crate::generated::types::RevealConfig;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 
@@ -17,4 +19,13 @@ pub struct SettingsArgs {
     pub uri: String,
     pub fee_accounts: Vec<FeeAccount>,
     pub print_fee_config: Option<PrintFeeConfig>,
+    pub provenance_config: Option<ProvenanceConfig>,
+    pub reveal_config: Option<RevealConfig>,
+    pub max_wins_per_item: Option<u16>,
+    pub go_live_at: Option<i64>,
+    pub end_at: Option<i64>,
+    pub max_draws_per_wallet: Option<u16>,
+    pub bot_tax: Option<u64>,
+    pub claim_deadline: Option<i64>,
+    pub public_draw: bool,
 }
@@ -3,6 +3,8 @@ import { setComputeUnitLimit } from '@metaplex-foundation/mpl-toolbox';
 import {
   chunk,
   generateSigner,
+  PublicKey,
+  sol,
   some,
   transactionBuilder,
 } from '@metaplex-foundation/umi';
@@ -10,19 +12,16 @@ import { generateSignerWithSol } from '@metaplex-foundation/umi-bundle-tests';
 import test from 'ava';
 import {
   addCoreItem,
+  addCoreItems,
   claimCoreItem,
   fetchJellybeanMachineWithItems,
   fetchUnclaimedPrizesFromSeeds,
   JellybeanMachineAccountWithItemsData,
   JellybeanState,
+  MALLOW_JELLYBEAN_PROGRAM_ID,
   startSale,
 } from '../src';
-import {
-  create,
-  createMasterEdition,
-  createUmi,
-  DEFAULT_MAX_SUPPLY,
-} from '../test/_setup';
+import { create, createMasterEdition, createUmi } from '../test/_setup';
 
 test('it draws items with reasonable randomness distribution', async (t) => {
   const sellerUmi = await createUmi();
@@ -142,23 +141,53 @@ test('it draws items with reasonable randomness distribution', async (t) => {
   t.is(totalItemsRedeemed, drawCount);
 });
 
-test('it can draw from 255 master editions', async (t) => {
-  t.timeout(60_000);
+test('it can draw the last of 10,000 items within the compute budget', async (t) => {
+  t.timeout(1_800_000);
 
-  const count = 255;
-  const drawCount = 100;
+  const count = 10_000;
   const sellerUmi = await createUmi();
-  const promises = Array.from({ length: count }, () =>
-    createMasterEdition(sellerUmi)
-  );
-  const collectionSigners = await Promise.all(promises);
-  const jellybeanMachine = await create(sellerUmi, {
-    items: collectionSigners.map((signer) => ({
-      collection: signer.publicKey,
-    })),
-  });
+  await sellerUmi.rpc.airdrop(sellerUmi.identity.publicKey, sol(100));
+  const jellybeanMachine = await create(sellerUmi);
+
+  // Almost all of the supply is in the last item, so the draw scans every item
+  const collections: PublicKey[] = [];
+  for (const batch of chunk(
+    Array.from({ length: count }, (_, index) => index),
+    100
+  )) {
+    const signers = await Promise.all(
+      batch.map((index) =>
+        createMasterEdition(sellerUmi, {
+          maxSupply: index === count - 1 ? 100_000_000 : 1,
+        })
+      )
+    );
+    collections.push(...signers.map((signer) => signer.publicKey));
+  }
 
-  // Should fail to add the 256th item
+  for (const batch of chunk(chunk(collections, 12), 20)) {
+    await Promise.all(
+      batch.map((items) =>
+        addCoreItems(sellerUmi, {
+          jellybeanMachine,
+          supplies: items.map(() => null),
+        })
+          .addRemainingAccounts(
+            items.flatMap((collection) => [
+              {
+                pubkey: MALLOW_JELLYBEAN_PROGRAM_ID,
+                isSigner: false,
+                isWritable: false,
+              },
+              { pubkey: collection, isSigner: false, isWritable: true },
+            ])
+          )
+          .sendAndConfirm(sellerUmi)
+      )
+    );
+  }
+
+  // Should fail to add the 10,001st item
   await t.throwsAsync(
     addCoreItem(sellerUmi, {
       jellybeanMachine,
@@ -176,30 +205,26 @@ test('it can draw from 255 master editions', async (t) => {
   const buyer = await generateSignerWithSol(sellerUmi);
   const buyerUmi = await createUmi(buyer);
 
-  const batches = chunk(
-    Array.from({ length: drawCount }, () => null),
-    8
-  );
-  for (const batch of batches) {
-    let builder = transactionBuilder().add(
-      setComputeUnitLimit(buyerUmi, {
-        units: 1_400_000,
+  const { signature } = await transactionBuilder()
+    .add(setComputeUnitLimit(buyerUmi, { units: 1_400_000 }))
+    .add(
+      drawJellybean(buyerUmi, {
+        jellybeanMachine,
+        mintArgs: {
+          solPayment: some({
+            feeAccounts: [sellerUmi.identity.publicKey],
+          }),
+        },
       })
-    );
-    batch.forEach(() => {
-      builder = builder.add(
-        drawJellybean(buyerUmi, {
-          jellybeanMachine,
-          mintArgs: {
-            solPayment: some({
-              feeAccounts: [sellerUmi.identity.publicKey],
-            }),
-          },
-        })
-      );
-    });
-    await builder.sendAndConfirm(buyerUmi);
-  }
+    )
+    .sendAndConfirm(buyerUmi);
+
+  const transaction = await buyerUmi.rpc.getTransaction(signature);
+  const consumed = transaction!.meta.logs
+    .map((log) => log.match(/^Program \S+ consumed (\d+) of/))
+    .filter((match) => match !== null)
+    .map((match) => Number(match![1]));
+  t.log(`Draw consumed ${Math.max(...consumed)} compute units`);
 
   const jellybeanMachineAccount = await fetchJellybeanMachineWithItems(
     sellerUmi,
@@ -207,23 +232,23 @@ test('it can draw from 255 master editions', async (t) => {
   );
   t.like(jellybeanMachineAccount, <JellybeanMachineAccountWithItemsData>{
     itemsLoaded: count,
-    supplyLoaded: BigInt(DEFAULT_MAX_SUPPLY * count),
-    supplyRedeemed: BigInt(drawCount),
+    supplyLoaded: BigInt(count - 1 + 100_000_000),
+    supplyRedeemed: 1n,
     state: JellybeanState.SaleLive,
   });
 
-  // Can claim final index
   const unclaimedPrizes = await fetchUnclaimedPrizesFromSeeds(sellerUmi, {
     jellybeanMachine,
     buyer: buyer.publicKey,
   });
-  const index =
-    unclaimedPrizes.prizes[unclaimedPrizes.prizes.length - 1].itemIndex;
+  t.is(unclaimedPrizes.prizes[0].itemIndex, count - 1);
+
+  // Can claim the final index
   const printAsset = generateSigner(buyerUmi);
   await claimCoreItem(buyerUmi, {
     jellybeanMachine,
-    index,
-    collection: collectionSigners[index].publicKey,
+    index: count - 1,
+    collection: collections[count - 1],
     printAsset,
   }).sendAndConfirm(buyerUmi);
 });
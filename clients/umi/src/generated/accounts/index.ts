@@ -6,5 +6,11 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './allowlistProof';
+export * from './bundle';
+export * from './buyerRecord';
+export * from './guardPhases';
 export * from './jellybeanMachine';
+export * from './printTemplate';
 export * from './unclaimedPrizes';
+export * from './usedGateAsset';
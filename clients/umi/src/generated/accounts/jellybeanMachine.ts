@@ -24,25 +24,37 @@ import {
 import {
   Serializer,
   array,
+  bool,
   bytes,
+  i64,
   mapSerializer,
   option,
   publicKey as publicKeySerializer,
   string,
   struct,
+  u16,
   u64,
   u8,
 } from '@metaplex-foundation/umi/serializers';
 import {
+  BuyerLimit,
+  BuyerLimitArgs,
   FeeAccount,
   FeeAccountArgs,
   JellybeanState,
   JellybeanStateArgs,
   PrintFeeConfig,
   PrintFeeConfigArgs,
+  ProvenanceConfig,
+  ProvenanceConfigArgs,
+  RevealConfig,
+  RevealConfigArgs,
+  getBuyerLimitSerializer,
   getFeeAccountSerializer,
   getJellybeanStateSerializer,
   getPrintFeeConfigSerializer,
+  getProvenanceConfigSerializer,
+  getRevealConfigSerializer,
 } from '../types';
 
 /** Jellybean machine state and config data. */
@@ -60,7 +72,7 @@ export type JellybeanMachineAccountData = {
   feeAccounts: Array<FeeAccount>;
   /** Print fee config */
   printFeeConfig: Option<PrintFeeConfig>;
-  /** Total unique items loaded. Up to 255 items. */
+  /** Total unique items loaded. Up to 10,000 items. */
   itemsLoaded: number;
   /** Total supply_loaded of all items added. */
   supplyLoaded: bigint;
@@ -70,6 +82,54 @@ export type JellybeanMachineAccountData = {
   state: JellybeanState;
   /** Uri of off-chain metadata, max length 196 */
   uri: string;
+  /** Provenance attributes written to assets as they are claimed */
+  provenanceConfig: Option<ProvenanceConfig>;
+  /** Indices of items temporarily excluded from draws, up to 16 */
+  disabledItems: Array<number>;
+  /** Delayed reveal, claimed assets get a placeholder uri until revealed */
+  revealConfig: Option<RevealConfig>;
+  /** Maximum number of units of an item a single buyer can win, up to 16 items */
+  buyerLimits: Array<BuyerLimit>;
+  /**
+   * Maximum number of units of any item a single buyer can win, unless the item has its
+   * own buyer limit
+   */
+  maxWinsPerItem: Option<number>;
+  /** Unix timestamp at which draws open, the sale goes live on its own if not started */
+  goLiveAt: Option<bigint>;
+  /** Unix timestamp after which draws are closed and anyone can end the sale */
+  endAt: Option<bigint>;
+  /** Whether draws must satisfy the active phase of the guard phases account */
+  hasGuardPhases: boolean;
+  /** Maximum number of draws a single buyer can make over the lifetime of the machine */
+  maxDrawsPerWallet: Option<number>;
+  /**
+   * Lamports charged to the payer of a public draw that breaks a machine rule, instead of failing.
+   * The tax accrues on the machine account and is paid to the authority with its rent
+   */
+  botTax: Option<bigint>;
+  /**
+   * Draw prices paid for the prizes not claimed yet, escrowed by the authority when the sale
+   * is cancelled so each buyer gets back what they paid
+   */
+  unclaimedPrice: bigint;
+  /**
+   * Unix timestamp after which the authority can reclaim prizes that haven't been claimed.
+   * Must be after end_at when both are set, and no draw is accepted once it has passed
+   */
+  claimDeadline: Option<bigint>;
+  /**
+   * Whether buyers can draw without the mint authority co-signing, only through paid
+   * guard phases
+   */
+  publicDraw: boolean;
+  /** Number of bundle accounts holding escrowed components, must be zero to withdraw */
+  openBundles: number;
+  /**
+   * Number of authority pda token accounts opened by bundle deposits, must be zero to
+   * withdraw
+   */
+  openTokenAccounts: number;
   /** Padding for future use */
   padding: Uint8Array;
 };
@@ -85,7 +145,7 @@ export type JellybeanMachineAccountDataArgs = {
   feeAccounts: Array<FeeAccountArgs>;
   /** Print fee config */
   printFeeConfig: OptionOrNullable<PrintFeeConfigArgs>;
-  /** Total unique items loaded. Up to 255 items. */
+  /** Total unique items loaded. Up to 10,000 items. */
   itemsLoaded: number;
   /** Total supply_loaded of all items added. */
   supplyLoaded: number | bigint;
@@ -95,6 +155,54 @@ export type JellybeanMachineAccountDataArgs = {
   state: JellybeanStateArgs;
   /** Uri of off-chain metadata, max length 196 */
   uri: string;
+  /** Provenance attributes written to assets as they are claimed */
+  provenanceConfig: OptionOrNullable<ProvenanceConfigArgs>;
+  /** Indices of items temporarily excluded from draws, up to 16 */
+  disabledItems: Array<number>;
+  /** Delayed reveal, claimed assets get a placeholder uri until revealed */
+  revealConfig: OptionOrNullable<RevealConfigArgs>;
+  /** Maximum number of units of an item a single buyer can win, up to 16 items */
+  buyerLimits: Array<BuyerLimitArgs>;
+  /**
+   * Maximum number of units of any item a single buyer can win, unless the item has its
+   * own buyer limit
+   */
+  maxWinsPerItem: OptionOrNullable<number>;
+  /** Unix timestamp at which draws open, the sale goes live on its own if not started */
+  goLiveAt: OptionOrNullable<number | bigint>;
+  /** Unix timestamp after which draws are closed and anyone can end the sale */
+  endAt: OptionOrNullable<number | bigint>;
+  /** Whether draws must satisfy the active phase of the guard phases account */
+  hasGuardPhases: boolean;
+  /** Maximum number of draws a single buyer can make over the lifetime of the machine */
+  maxDrawsPerWallet: OptionOrNullable<number>;
+  /**
+   * Lamports charged to the payer of a public draw that breaks a machine rule, instead of failing.
+   * The tax accrues on the machine account and is paid to the authority with its rent
+   */
+  botTax: OptionOrNullable<number | bigint>;
+  /**
+   * Draw prices paid for the prizes not claimed yet, escrowed by the authority when the sale
+   * is cancelled so each buyer gets back what they paid
+   */
+  unclaimedPrice: number | bigint;
+  /**
+   * Unix timestamp after which the authority can reclaim prizes that haven't been claimed.
+   * Must be after end_at when both are set, and no draw is accepted once it has passed
+   */
+  claimDeadline: OptionOrNullable<number | bigint>;
+  /**
+   * Whether buyers can draw without the mint authority co-signing, only through paid
+   * guard phases
+   */
+  publicDraw: boolean;
+  /** Number of bundle accounts holding escrowed components, must be zero to withdraw */
+  openBundles: number;
+  /**
+   * Number of authority pda token accounts opened by bundle deposits, must be zero to
+   * withdraw
+   */
+  openTokenAccounts: number;
   /** Padding for future use */
   padding: Uint8Array;
 };
@@ -116,12 +224,27 @@ export function getJellybeanMachineAccountDataSerializer(): Serializer<
         ['mintAuthority', publicKeySerializer()],
         ['feeAccounts', array(getFeeAccountSerializer())],
         ['printFeeConfig', option(getPrintFeeConfigSerializer())],
-        ['itemsLoaded', u8()],
+        ['itemsLoaded', u16()],
         ['supplyLoaded', u64()],
         ['supplyRedeemed', u64()],
         ['state', getJellybeanStateSerializer()],
         ['uri', string()],
-        ['padding', bytes({ size: 320 })],
+        ['provenanceConfig', option(getProvenanceConfigSerializer())],
+        ['disabledItems', array(u16())],
+        ['revealConfig', option(getRevealConfigSerializer())],
+        ['buyerLimits', array(getBuyerLimitSerializer())],
+        ['maxWinsPerItem', option(u16())],
+        ['goLiveAt', option(i64())],
+        ['endAt', option(i64())],
+        ['hasGuardPhases', bool()],
+        ['maxDrawsPerWallet', option(u16())],
+        ['botTax', option(u64())],
+        ['unclaimedPrice', u64()],
+        ['claimDeadline', option(i64())],
+        ['publicDraw', bool()],
+        ['openBundles', u16()],
+        ['openTokenAccounts', u16()],
+        ['padding', bytes({ size: 20 })],
       ],
       { description: 'JellybeanMachineAccountData' }
     ),
@@ -217,6 +340,21 @@ export function getJellybeanMachineGpaBuilder(
       supplyRedeemed: number | bigint;
       state: JellybeanStateArgs;
       uri: string;
+      provenanceConfig: OptionOrNullable<ProvenanceConfigArgs>;
+      disabledItems: Array<number>;
+      revealConfig: OptionOrNullable<RevealConfigArgs>;
+      buyerLimits: Array<BuyerLimitArgs>;
+      maxWinsPerItem: OptionOrNullable<number>;
+      goLiveAt: OptionOrNullable<number | bigint>;
+      endAt: OptionOrNullable<number | bigint>;
+      hasGuardPhases: boolean;
+      maxDrawsPerWallet: OptionOrNullable<number>;
+      botTax: OptionOrNullable<number | bigint>;
+      unclaimedPrice: number | bigint;
+      claimDeadline: OptionOrNullable<number | bigint>;
+      publicDraw: boolean;
+      openBundles: number;
+      openTokenAccounts: number;
       padding: Uint8Array;
     }>({
       discriminator: [0, bytes({ size: 8 })],
@@ -225,12 +363,27 @@ export function getJellybeanMachineGpaBuilder(
       mintAuthority: [41, publicKeySerializer()],
       feeAccounts: [73, array(getFeeAccountSerializer())],
       printFeeConfig: [null, option(getPrintFeeConfigSerializer())],
-      itemsLoaded: [null, u8()],
+      itemsLoaded: [null, u16()],
       supplyLoaded: [null, u64()],
       supplyRedeemed: [null, u64()],
       state: [null, getJellybeanStateSerializer()],
       uri: [null, string()],
-      padding: [null, bytes({ size: 320 })],
+      provenanceConfig: [null, option(getProvenanceConfigSerializer())],
+      disabledItems: [null, array(u16())],
+      revealConfig: [null, option(getRevealConfigSerializer())],
+      buyerLimits: [null, array(getBuyerLimitSerializer())],
+      maxWinsPerItem: [null, option(u16())],
+      goLiveAt: [null, option(i64())],
+      endAt: [null, option(i64())],
+      hasGuardPhases: [null, bool()],
+      maxDrawsPerWallet: [null, option(u16())],
+      botTax: [null, option(u64())],
+      unclaimedPrice: [null, u64()],
+      claimDeadline: [null, option(i64())],
+      publicDraw: [null, bool()],
+      openBundles: [null, u16()],
+      openTokenAccounts: [null, u16()],
+      padding: [null, bytes({ size: 20 })],
     })
     .deserializeUsing<JellybeanMachine>((account) =>
       deserializeJellybeanMachine(account)
@@ -29,7 +29,14 @@ import {
   struct,
   u8,
 } from '@metaplex-foundation/umi/serializers';
-import { Prize, PrizeArgs, getPrizeSerializer } from '../types';
+import {
+  ItemWins,
+  ItemWinsArgs,
+  Prize,
+  PrizeArgs,
+  getItemWinsSerializer,
+  getPrizeSerializer,
+} from '../types';
 
 /** Jellybean machine state and config data. */
 export type UnclaimedPrizes = Account<UnclaimedPrizesAccountData>;
@@ -44,6 +51,11 @@ export type UnclaimedPrizesAccountData = {
   buyer: PublicKey;
   /** Indices of prizes drawn by the buyer */
   prizes: Array<Prize>;
+  /**
+   * Units won of each item with a buyer limit, kept after the prizes are claimed until
+   * the sale is cancelled or the machine withdrawn
+   */
+  wins: Array<ItemWins>;
 };
 
 export type UnclaimedPrizesAccountDataArgs = {
@@ -55,6 +67,11 @@ export type UnclaimedPrizesAccountDataArgs = {
   buyer: PublicKey;
   /** Indices of prizes drawn by the buyer */
   prizes: Array<PrizeArgs>;
+  /**
+   * Units won of each item with a buyer limit, kept after the prizes are claimed until
+   * the sale is cancelled or the machine withdrawn
+   */
+  wins: Array<ItemWinsArgs>;
 };
 
 export function getUnclaimedPrizesAccountDataSerializer(): Serializer<
@@ -73,6 +90,7 @@ export function getUnclaimedPrizesAccountDataSerializer(): Serializer<
         ['jellybeanMachine', publicKeySerializer()],
         ['buyer', publicKeySerializer()],
         ['prizes', array(getPrizeSerializer())],
+        ['wins', array(getItemWinsSerializer())],
       ],
       { description: 'UnclaimedPrizesAccountData' }
     ),
@@ -162,12 +180,14 @@ export function getUnclaimedPrizesGpaBuilder(
       jellybeanMachine: PublicKey;
       buyer: PublicKey;
       prizes: Array<PrizeArgs>;
+      wins: Array<ItemWinsArgs>;
     }>({
       discriminator: [0, bytes({ size: 8 })],
       version: [8, u8()],
       jellybeanMachine: [9, publicKeySerializer()],
       buyer: [41, publicKeySerializer()],
       prizes: [73, array(getPrizeSerializer())],
+      wins: [null, array(getItemWinsSerializer())],
     })
     .deserializeUsing<UnclaimedPrizes>((account) =>
       deserializeUnclaimedPrizes(account)
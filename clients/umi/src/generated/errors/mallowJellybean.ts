@@ -359,6 +359,490 @@ export class InvalidFeeAccountsLengthError extends ProgramError {
 codeToErrorMap.set(0x1789, InvalidFeeAccountsLengthError);
 nameToErrorMap.set('InvalidFeeAccountsLength', InvalidFeeAccountsLengthError);
 
+/** InvalidVersion: Invalid account version */
+export class InvalidVersionError extends ProgramError {
+  override readonly name: string = 'InvalidVersion';
+
+  readonly code: number = 0x178a; // 6026
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid account version', program, cause);
+  }
+}
+codeToErrorMap.set(0x178a, InvalidVersionError);
+nameToErrorMap.set('InvalidVersion', InvalidVersionError);
+
+/** MissingPrintTemplate: Missing print template */
+export class MissingPrintTemplateError extends ProgramError {
+  override readonly name: string = 'MissingPrintTemplate';
+
+  readonly code: number = 0x178b; // 6027
+
+  constructor(program: Program, cause?: Error) {
+    super('Missing print template', program, cause);
+  }
+}
+codeToErrorMap.set(0x178b, MissingPrintTemplateError);
+nameToErrorMap.set('MissingPrintTemplate', MissingPrintTemplateError);
+
+/** PrintTemplateTooLong: Print template too long */
+export class PrintTemplateTooLongError extends ProgramError {
+  override readonly name: string = 'PrintTemplateTooLong';
+
+  readonly code: number = 0x178c; // 6028
+
+  constructor(program: Program, cause?: Error) {
+    super('Print template too long', program, cause);
+  }
+}
+codeToErrorMap.set(0x178c, PrintTemplateTooLongError);
+nameToErrorMap.set('PrintTemplateTooLong', PrintTemplateTooLongError);
+
+/** InsufficientPrintEscrow: Print escrow too small for the print name and uri */
+export class InsufficientPrintEscrowError extends ProgramError {
+  override readonly name: string = 'InsufficientPrintEscrow';
+
+  readonly code: number = 0x178d; // 6029
+
+  constructor(program: Program, cause?: Error) {
+    super('Print escrow too small for the print name and uri', program, cause);
+  }
+}
+codeToErrorMap.set(0x178d, InsufficientPrintEscrowError);
+nameToErrorMap.set('InsufficientPrintEscrow', InsufficientPrintEscrowError);
+
+/** MachineCannotUpdateAsset: The authority pda must be the update authority or an update delegate of the asset */
+export class MachineCannotUpdateAssetError extends ProgramError {
+  override readonly name: string = 'MachineCannotUpdateAsset';
+
+  readonly code: number = 0x178e; // 6030
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The authority pda must be the update authority or an update delegate of the asset',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x178e, MachineCannotUpdateAssetError);
+nameToErrorMap.set('MachineCannotUpdateAsset', MachineCannotUpdateAssetError);
+
+/** BundleLoaded: Bundle is loaded in the machine */
+export class BundleLoadedError extends ProgramError {
+  override readonly name: string = 'BundleLoaded';
+
+  readonly code: number = 0x178f; // 6031
+
+  constructor(program: Program, cause?: Error) {
+    super('Bundle is loaded in the machine', program, cause);
+  }
+}
+codeToErrorMap.set(0x178f, BundleLoadedError);
+nameToErrorMap.set('BundleLoaded', BundleLoadedError);
+
+/** TooManyBundleComponents: Too many bundle components */
+export class TooManyBundleComponentsError extends ProgramError {
+  override readonly name: string = 'TooManyBundleComponents';
+
+  readonly code: number = 0x1790; // 6032
+
+  constructor(program: Program, cause?: Error) {
+    super('Too many bundle components', program, cause);
+  }
+}
+codeToErrorMap.set(0x1790, TooManyBundleComponentsError);
+nameToErrorMap.set('TooManyBundleComponents', TooManyBundleComponentsError);
+
+/** InvalidBundleComponent: Invalid bundle component */
+export class InvalidBundleComponentError extends ProgramError {
+  override readonly name: string = 'InvalidBundleComponent';
+
+  readonly code: number = 0x1791; // 6033
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid bundle component', program, cause);
+  }
+}
+codeToErrorMap.set(0x1791, InvalidBundleComponentError);
+nameToErrorMap.set('InvalidBundleComponent', InvalidBundleComponentError);
+
+/** TooManyDisabledItems: Too many disabled items */
+export class TooManyDisabledItemsError extends ProgramError {
+  override readonly name: string = 'TooManyDisabledItems';
+
+  readonly code: number = 0x1792; // 6034
+
+  constructor(program: Program, cause?: Error) {
+    super('Too many disabled items', program, cause);
+  }
+}
+codeToErrorMap.set(0x1792, TooManyDisabledItemsError);
+nameToErrorMap.set('TooManyDisabledItems', TooManyDisabledItemsError);
+
+/** RevealNotEnabled: Reveal is not enabled */
+export class RevealNotEnabledError extends ProgramError {
+  override readonly name: string = 'RevealNotEnabled';
+
+  readonly code: number = 0x1793; // 6035
+
+  constructor(program: Program, cause?: Error) {
+    super('Reveal is not enabled', program, cause);
+  }
+}
+codeToErrorMap.set(0x1793, RevealNotEnabledError);
+nameToErrorMap.set('RevealNotEnabled', RevealNotEnabledError);
+
+/** AlreadyRevealed: Asset is already revealed */
+export class AlreadyRevealedError extends ProgramError {
+  override readonly name: string = 'AlreadyRevealed';
+
+  readonly code: number = 0x1794; // 6036
+
+  constructor(program: Program, cause?: Error) {
+    super('Asset is already revealed', program, cause);
+  }
+}
+codeToErrorMap.set(0x1794, AlreadyRevealedError);
+nameToErrorMap.set('AlreadyRevealed', AlreadyRevealedError);
+
+/** BuyerLimitReached: Buyer has reached the limit of every remaining item */
+export class BuyerLimitReachedError extends ProgramError {
+  override readonly name: string = 'BuyerLimitReached';
+
+  readonly code: number = 0x1795; // 6037
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Buyer has reached the limit of every remaining item',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x1795, BuyerLimitReachedError);
+nameToErrorMap.set('BuyerLimitReached', BuyerLimitReachedError);
+
+/** TooManyBuyerLimits: Too many buyer limits */
+export class TooManyBuyerLimitsError extends ProgramError {
+  override readonly name: string = 'TooManyBuyerLimits';
+
+  readonly code: number = 0x1796; // 6038
+
+  constructor(program: Program, cause?: Error) {
+    super('Too many buyer limits', program, cause);
+  }
+}
+codeToErrorMap.set(0x1796, TooManyBuyerLimitsError);
+nameToErrorMap.set('TooManyBuyerLimits', TooManyBuyerLimitsError);
+
+/** InvalidSchedule: Sale end time must be after its go live time */
+export class InvalidScheduleError extends ProgramError {
+  override readonly name: string = 'InvalidSchedule';
+
+  readonly code: number = 0x1797; // 6039
+
+  constructor(program: Program, cause?: Error) {
+    super('Sale end time must be after its go live time', program, cause);
+  }
+}
+codeToErrorMap.set(0x1797, InvalidScheduleError);
+nameToErrorMap.set('InvalidSchedule', InvalidScheduleError);
+
+/** SaleNotStarted: Sale has not gone live yet */
+export class SaleNotStartedError extends ProgramError {
+  override readonly name: string = 'SaleNotStarted';
+
+  readonly code: number = 0x1798; // 6040
+
+  constructor(program: Program, cause?: Error) {
+    super('Sale has not gone live yet', program, cause);
+  }
+}
+codeToErrorMap.set(0x1798, SaleNotStartedError);
+nameToErrorMap.set('SaleNotStarted', SaleNotStartedError);
+
+/** SaleOver: Sale end time has passed */
+export class SaleOverError extends ProgramError {
+  override readonly name: string = 'SaleOver';
+
+  readonly code: number = 0x1799; // 6041
+
+  constructor(program: Program, cause?: Error) {
+    super('Sale end time has passed', program, cause);
+  }
+}
+codeToErrorMap.set(0x1799, SaleOverError);
+nameToErrorMap.set('SaleOver', SaleOverError);
+
+/** SalePaused: Sale is paused */
+export class SalePausedError extends ProgramError {
+  override readonly name: string = 'SalePaused';
+
+  readonly code: number = 0x179a; // 6042
+
+  constructor(program: Program, cause?: Error) {
+    super('Sale is paused', program, cause);
+  }
+}
+codeToErrorMap.set(0x179a, SalePausedError);
+nameToErrorMap.set('SalePaused', SalePausedError);
+
+/** TooManyGuardPhases: Too many guard phases */
+export class TooManyGuardPhasesError extends ProgramError {
+  override readonly name: string = 'TooManyGuardPhases';
+
+  readonly code: number = 0x179b; // 6043
+
+  constructor(program: Program, cause?: Error) {
+    super('Too many guard phases', program, cause);
+  }
+}
+codeToErrorMap.set(0x179b, TooManyGuardPhasesError);
+nameToErrorMap.set('TooManyGuardPhases', TooManyGuardPhasesError);
+
+/** InvalidGuardPhases: Guard phases must be ordered by start time */
+export class InvalidGuardPhasesError extends ProgramError {
+  override readonly name: string = 'InvalidGuardPhases';
+
+  readonly code: number = 0x179c; // 6044
+
+  constructor(program: Program, cause?: Error) {
+    super('Guard phases must be ordered by start time', program, cause);
+  }
+}
+codeToErrorMap.set(0x179c, InvalidGuardPhasesError);
+nameToErrorMap.set('InvalidGuardPhases', InvalidGuardPhasesError);
+
+/** MissingGuardAccount: Missing guard account */
+export class MissingGuardAccountError extends ProgramError {
+  override readonly name: string = 'MissingGuardAccount';
+
+  readonly code: number = 0x179d; // 6045
+
+  constructor(program: Program, cause?: Error) {
+    super('Missing guard account', program, cause);
+  }
+}
+codeToErrorMap.set(0x179d, MissingGuardAccountError);
+nameToErrorMap.set('MissingGuardAccount', MissingGuardAccountError);
+
+/** NoActivePhase: No guard phase is active */
+export class NoActivePhaseError extends ProgramError {
+  override readonly name: string = 'NoActivePhase';
+
+  readonly code: number = 0x179e; // 6046
+
+  constructor(program: Program, cause?: Error) {
+    super('No guard phase is active', program, cause);
+  }
+}
+codeToErrorMap.set(0x179e, NoActivePhaseError);
+nameToErrorMap.set('NoActivePhase', NoActivePhaseError);
+
+/** MissingBuyerSignature: Buyer must sign gated or limited draws */
+export class MissingBuyerSignatureError extends ProgramError {
+  override readonly name: string = 'MissingBuyerSignature';
+
+  readonly code: number = 0x179f; // 6047
+
+  constructor(program: Program, cause?: Error) {
+    super('Buyer must sign gated or limited draws', program, cause);
+  }
+}
+codeToErrorMap.set(0x179f, MissingBuyerSignatureError);
+nameToErrorMap.set('MissingBuyerSignature', MissingBuyerSignatureError);
+
+/** NotAllowlisted: Buyer is not in the allowlist */
+export class NotAllowlistedError extends ProgramError {
+  override readonly name: string = 'NotAllowlisted';
+
+  readonly code: number = 0x17a0; // 6048
+
+  constructor(program: Program, cause?: Error) {
+    super('Buyer is not in the allowlist', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a0, NotAllowlistedError);
+nameToErrorMap.set('NotAllowlisted', NotAllowlistedError);
+
+/** MissingGateToken: Buyer does not hold the gate token */
+export class MissingGateTokenError extends ProgramError {
+  override readonly name: string = 'MissingGateToken';
+
+  readonly code: number = 0x17a1; // 6049
+
+  constructor(program: Program, cause?: Error) {
+    super('Buyer does not hold the gate token', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a1, MissingGateTokenError);
+nameToErrorMap.set('MissingGateToken', MissingGateTokenError);
+
+/** WalletLimitReached: Buyer has reached the draw limit */
+export class WalletLimitReachedError extends ProgramError {
+  override readonly name: string = 'WalletLimitReached';
+
+  readonly code: number = 0x17a2; // 6050
+
+  constructor(program: Program, cause?: Error) {
+    super('Buyer has reached the draw limit', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a2, WalletLimitReachedError);
+nameToErrorMap.set('WalletLimitReached', WalletLimitReachedError);
+
+/** GuardPhasesLocked: Guard phases can only be added after the existing ones once the sale has started */
+export class GuardPhasesLockedError extends ProgramError {
+  override readonly name: string = 'GuardPhasesLocked';
+
+  readonly code: number = 0x17a3; // 6051
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Guard phases can only be added after the existing ones once the sale has started',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17a3, GuardPhasesLockedError);
+nameToErrorMap.set('GuardPhasesLocked', GuardPhasesLockedError);
+
+/** GuardRecordInUse: Guard records can only be closed once the machine is withdrawn or cancelled */
+export class GuardRecordInUseError extends ProgramError {
+  override readonly name: string = 'GuardRecordInUse';
+
+  readonly code: number = 0x17a4; // 6052
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Guard records can only be closed once the machine is withdrawn or cancelled',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17a4, GuardRecordInUseError);
+nameToErrorMap.set('GuardRecordInUse', GuardRecordInUseError);
+
+/** InvalidGateAsset: Invalid gate asset */
+export class InvalidGateAssetError extends ProgramError {
+  override readonly name: string = 'InvalidGateAsset';
+
+  readonly code: number = 0x17a5; // 6053
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid gate asset', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a5, InvalidGateAssetError);
+nameToErrorMap.set('InvalidGateAsset', InvalidGateAssetError);
+
+/** GateAssetUsed: Gate asset has already been used */
+export class GateAssetUsedError extends ProgramError {
+  override readonly name: string = 'GateAssetUsed';
+
+  readonly code: number = 0x17a6; // 6054
+
+  constructor(program: Program, cause?: Error) {
+    super('Gate asset has already been used', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a6, GateAssetUsedError);
+nameToErrorMap.set('GateAssetUsed', GateAssetUsedError);
+
+/** BundlePartiallyDelivered: Bundle has already been partially delivered */
+export class BundlePartiallyDeliveredError extends ProgramError {
+  override readonly name: string = 'BundlePartiallyDelivered';
+
+  readonly code: number = 0x17a7; // 6055
+
+  constructor(program: Program, cause?: Error) {
+    super('Bundle has already been partially delivered', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a7, BundlePartiallyDeliveredError);
+nameToErrorMap.set('BundlePartiallyDelivered', BundlePartiallyDeliveredError);
+
+/** ClaimDeadlineNotReached: Claim deadline has not passed yet */
+export class ClaimDeadlineNotReachedError extends ProgramError {
+  override readonly name: string = 'ClaimDeadlineNotReached';
+
+  readonly code: number = 0x17a8; // 6056
+
+  constructor(program: Program, cause?: Error) {
+    super('Claim deadline has not passed yet', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a8, ClaimDeadlineNotReachedError);
+nameToErrorMap.set('ClaimDeadlineNotReached', ClaimDeadlineNotReachedError);
+
+/** BundlesStillOpen: Bundles still hold escrowed components */
+export class BundlesStillOpenError extends ProgramError {
+  override readonly name: string = 'BundlesStillOpen';
+
+  readonly code: number = 0x17a9; // 6057
+
+  constructor(program: Program, cause?: Error) {
+    super('Bundles still hold escrowed components', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a9, BundlesStillOpenError);
+nameToErrorMap.set('BundlesStillOpen', BundlesStillOpenError);
+
+/** MissingTokenAccount: Missing token account */
+export class MissingTokenAccountError extends ProgramError {
+  override readonly name: string = 'MissingTokenAccount';
+
+  readonly code: number = 0x17aa; // 6058
+
+  constructor(program: Program, cause?: Error) {
+    super('Missing token account', program, cause);
+  }
+}
+codeToErrorMap.set(0x17aa, MissingTokenAccountError);
+nameToErrorMap.set('MissingTokenAccount', MissingTokenAccountError);
+
+/** PublicDrawWithoutPrice: Public draws require guard phases with a price */
+export class PublicDrawWithoutPriceError extends ProgramError {
+  override readonly name: string = 'PublicDrawWithoutPrice';
+
+  readonly code: number = 0x17ab; // 6059
+
+  constructor(program: Program, cause?: Error) {
+    super('Public draws require guard phases with a price', program, cause);
+  }
+}
+codeToErrorMap.set(0x17ab, PublicDrawWithoutPriceError);
+nameToErrorMap.set('PublicDrawWithoutPrice', PublicDrawWithoutPriceError);
+
+/** MissingPrintFeeAccount: Missing print fee account */
+export class MissingPrintFeeAccountError extends ProgramError {
+  override readonly name: string = 'MissingPrintFeeAccount';
+
+  readonly code: number = 0x17ac; // 6060
+
+  constructor(program: Program, cause?: Error) {
+    super('Missing print fee account', program, cause);
+  }
+}
+codeToErrorMap.set(0x17ac, MissingPrintFeeAccountError);
+nameToErrorMap.set('MissingPrintFeeAccount', MissingPrintFeeAccountError);
+
+/** TokenAccountsStillOpen: Authority pda token accounts must be swept */
+export class TokenAccountsStillOpenError extends ProgramError {
+  override readonly name: string = 'TokenAccountsStillOpen';
+
+  readonly code: number = 0x17ad; // 6061
+
+  constructor(program: Program, cause?: Error) {
+    super('Authority pda token accounts must be swept', program, cause);
+  }
+}
+codeToErrorMap.set(0x17ad, TokenAccountsStillOpenError);
+nameToErrorMap.set('TokenAccountsStillOpen', TokenAccountsStillOpenError);
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
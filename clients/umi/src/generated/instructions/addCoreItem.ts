@@ -8,20 +8,28 @@
 
 import {
   Context,
+  Option,
   OptionOrNullable,
   Pda,
   PublicKey,
   Signer,
   TransactionBuilder,
+  none,
   transactionBuilder,
 } from '@metaplex-foundation/umi';
 import {
   Serializer,
   bytes,
   mapSerializer,
+  option,
   struct,
+  u32,
 } from '@metaplex-foundation/umi/serializers';
-import { resolveAuthorityPda } from '../../hooked';
+import {
+  resolveAuthorityPda,
+  resolveEventAuthorityPda,
+  resolveProgram,
+} from '../../hooked';
 import {
   PickPartial,
   ResolvedAccount,
@@ -43,12 +51,19 @@ export type AddCoreItemInstructionAccounts = {
   collection?: PublicKey | Pda;
   mplCoreProgram?: PublicKey | Pda;
   systemProgram?: PublicKey | Pda;
+  eventAuthority?: PublicKey | Pda;
+  program?: PublicKey | Pda;
 };
 
 // Data.
-export type AddCoreItemInstructionData = { discriminator: Uint8Array };
+export type AddCoreItemInstructionData = {
+  discriminator: Uint8Array;
+  supply: Option<number>;
+};
 
-export type AddCoreItemInstructionDataArgs = {};
+export type AddCoreItemInstructionDataArgs = {
+  supply?: OptionOrNullable<number>;
+};
 
 export function getAddCoreItemInstructionDataSerializer(): Serializer<
   AddCoreItemInstructionDataArgs,
@@ -60,12 +75,16 @@ export function getAddCoreItemInstructionDataSerializer(): Serializer<
     AddCoreItemInstructionData
   >(
     struct<AddCoreItemInstructionData>(
-      [['discriminator', bytes({ size: 8 })]],
+      [
+        ['discriminator', bytes({ size: 8 })],
+        ['supply', option(u32())],
+      ],
       { description: 'AddCoreItemInstructionData' }
     ),
     (value) => ({
       ...value,
       discriminator: new Uint8Array([138, 200, 152, 167, 252, 149, 240, 114]),
+      supply: value.supply ?? none(),
     })
   ) as Serializer<AddCoreItemInstructionDataArgs, AddCoreItemInstructionData>;
 }
@@ -78,8 +97,8 @@ export type AddCoreItemInstructionExtraArgs = {
 
 // Args.
 export type AddCoreItemInstructionArgs = PickPartial<
-  AddCoreItemInstructionExtraArgs,
-  'unused'
+  AddCoreItemInstructionDataArgs & AddCoreItemInstructionExtraArgs,
+  'supply' | 'unused'
 >;
 
 // Instruction.
@@ -135,6 +154,16 @@ export function addCoreItem(
       isWritable: false as boolean,
       value: input.systemProgram ?? null,
     },
+    eventAuthority: {
+      index: 8,
+      isWritable: false as boolean,
+      value: input.eventAuthority ?? null,
+    },
+    program: {
+      index: 9,
+      isWritable: false as boolean,
+      value: input.program ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
   // Arguments.
@@ -173,6 +202,33 @@ export function addCoreItem(
     );
     resolvedAccounts.systemProgram.isWritable = false;
   }
+  if (!resolvedAccounts.eventAuthority.value) {
+    resolvedAccounts.eventAuthority = {
+      ...resolvedAccounts.eventAuthority,
+      ...resolveEventAuthorityPda(
+        context,
+        resolvedAccounts,
+        resolvedArgs,
+        programId,
+        false
+      ),
+    };
+  }
+  if (!resolvedAccounts.program.value) {
+    resolvedAccounts.program = {
+      ...resolvedAccounts.program,
+      ...resolveProgram(
+        context,
+        resolvedAccounts,
+        resolvedArgs,
+        programId,
+        false
+      ),
+    };
+  }
+  if (!resolvedArgs.supply) {
+    resolvedArgs.supply = none();
+  }
   if (!resolvedArgs.unused) {
     resolvedArgs.unused = false;
   }
@@ -190,7 +246,9 @@ export function addCoreItem(
   );
 
   // Data.
-  const data = getAddCoreItemInstructionDataSerializer().serialize({});
+  const data = getAddCoreItemInstructionDataSerializer().serialize(
+    resolvedArgs as AddCoreItemInstructionDataArgs
+  );
 
   // Bytes Created On Chain.
   const bytesCreatedOnChain = 0;
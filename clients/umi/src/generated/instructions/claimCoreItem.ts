@@ -19,11 +19,12 @@ import {
   bytes,
   mapSerializer,
   struct,
-  u8,
+  u16,
 } from '@metaplex-foundation/umi/serializers';
 import {
   resolveAuthorityPda,
   resolveEventAuthorityPda,
+  resolvePrintTemplatePda,
   resolveProgram,
 } from '../../hooked';
 import { findUnclaimedPrizesPda } from '../accounts';
@@ -48,6 +49,8 @@ export type ClaimCoreItemInstructionAccounts = {
   asset?: PublicKey | Pda;
   collection?: PublicKey | Pda;
   printAsset?: Signer;
+  /** Print template of the master edition, required when claiming an edition. */
+  printTemplate?: PublicKey | Pda;
   mplCoreProgram?: PublicKey | Pda;
   systemProgram?: PublicKey | Pda;
   eventAuthority?: PublicKey | Pda;
@@ -74,7 +77,7 @@ export function getClaimCoreItemInstructionDataSerializer(): Serializer<
     struct<ClaimCoreItemInstructionData>(
       [
         ['discriminator', bytes({ size: 8 })],
-        ['index', u8()],
+        ['index', u16()],
       ],
       { description: 'ClaimCoreItemInstructionData' }
     ),
@@ -144,23 +147,28 @@ export function claimCoreItem(
       isWritable: true as boolean,
       value: input.printAsset ?? null,
     },
-    mplCoreProgram: {
+    printTemplate: {
       index: 8,
       isWritable: false as boolean,
+      value: input.printTemplate ?? null,
+    },
+    mplCoreProgram: {
+      index: 9,
+      isWritable: false as boolean,
       value: input.mplCoreProgram ?? null,
     },
     systemProgram: {
-      index: 9,
+      index: 10,
       isWritable: false as boolean,
       value: input.systemProgram ?? null,
     },
     eventAuthority: {
-      index: 10,
+      index: 11,
       isWritable: false as boolean,
       value: input.eventAuthority ?? null,
     },
     program: {
-      index: 11,
+      index: 12,
       isWritable: false as boolean,
       value: input.program ?? null,
     },
@@ -196,6 +204,18 @@ export function claimCoreItem(
       buyer: expectPublicKey(resolvedAccounts.buyer.value),
     });
   }
+  if (!resolvedAccounts.printTemplate.value) {
+    resolvedAccounts.printTemplate = {
+      ...resolvedAccounts.printTemplate,
+      ...resolvePrintTemplatePda(
+        context,
+        resolvedAccounts,
+        resolvedArgs,
+        programId,
+        false
+      ),
+    };
+  }
   if (!resolvedAccounts.mplCoreProgram.value) {
     resolvedAccounts.mplCoreProgram.value = context.programs.getPublicKey(
       'mplCore',
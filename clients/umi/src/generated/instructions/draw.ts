@@ -41,8 +41,13 @@ export type DrawInstructionAccounts = {
   /** Jellybean machine account. */
   jellybeanMachine: PublicKey | Pda;
   authorityPda?: PublicKey | Pda;
-  /** Jellybean machine mint authority (mint only allowed for the mint_authority). */
-  mintAuthority?: Signer;
+  /**
+   * Jellybean machine mint authority (mint only allowed for the mint_authority).
+   * Only required to sign if the machine doesn't allow public draws.
+   *
+   */
+
+  mintAuthority?: PublicKey | Pda;
   /** Payer for the transaction and account allocation (rent). */
   payer?: Signer;
   /**
@@ -189,7 +194,7 @@ export function draw(
     };
   }
   if (!resolvedAccounts.mintAuthority.value) {
-    resolvedAccounts.mintAuthority.value = context.identity;
+    resolvedAccounts.mintAuthority.value = context.identity.publicKey;
   }
   if (!resolvedAccounts.payer.value) {
     resolvedAccounts.payer.value = context.payer;
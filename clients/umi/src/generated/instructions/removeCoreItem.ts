@@ -19,9 +19,9 @@ import {
   bytes,
   mapSerializer,
   struct,
-  u8,
+  u16,
 } from '@metaplex-foundation/umi/serializers';
-import { resolveAuthorityPda } from '../../hooked';
+import { resolveAuthorityPda, resolvePrintTemplatePda } from '../../hooked';
 import {
   ResolvedAccount,
   ResolvedAccountsWithIndices,
@@ -38,6 +38,8 @@ export type RemoveCoreItemInstructionAccounts = {
   asset?: PublicKey | Pda;
   /** Core asset's collection if it's part of one. */
   collection?: PublicKey | Pda;
+  /** Print template of the master edition, required when removing one. */
+  printTemplate?: PublicKey | Pda;
   mplCoreProgram?: PublicKey | Pda;
   systemProgram?: PublicKey | Pda;
 };
@@ -62,7 +64,7 @@ export function getRemoveCoreItemInstructionDataSerializer(): Serializer<
     struct<RemoveCoreItemInstructionData>(
       [
         ['discriminator', bytes({ size: 8 })],
-        ['index', u8()],
+        ['index', u16()],
       ],
       { description: 'RemoveCoreItemInstructionData' }
     ),
@@ -117,13 +119,18 @@ export function removeCoreItem(
       isWritable: true as boolean,
       value: input.collection ?? null,
     },
-    mplCoreProgram: {
+    printTemplate: {
       index: 5,
+      isWritable: true as boolean,
+      value: input.printTemplate ?? null,
+    },
+    mplCoreProgram: {
+      index: 6,
       isWritable: false as boolean,
       value: input.mplCoreProgram ?? null,
     },
     systemProgram: {
-      index: 6,
+      index: 7,
       isWritable: false as boolean,
       value: input.systemProgram ?? null,
     },
@@ -148,6 +155,18 @@ export function removeCoreItem(
   if (!resolvedAccounts.authority.value) {
     resolvedAccounts.authority.value = context.identity;
   }
+  if (!resolvedAccounts.printTemplate.value) {
+    resolvedAccounts.printTemplate = {
+      ...resolvedAccounts.printTemplate,
+      ...resolvePrintTemplatePda(
+        context,
+        resolvedAccounts,
+        resolvedArgs,
+        programId,
+        true
+      ),
+    };
+  }
   if (!resolvedAccounts.mplCoreProgram.value) {
     resolvedAccounts.mplCoreProgram.value = context.programs.getPublicKey(
       'mplCore',
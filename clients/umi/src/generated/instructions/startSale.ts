@@ -32,6 +32,8 @@ export type StartSaleInstructionAccounts = {
   jellybeanMachine: PublicKey | Pda;
   /** Jellybean Machine authority. This can be the mint authority or the authority. */
   authority?: Signer;
+  /** Guard phases account, required for machines with public draws. */
+  guardPhases?: PublicKey | Pda;
 };
 
 // Data.
@@ -81,6 +83,11 @@ export function startSale(
       isWritable: false as boolean,
       value: input.authority ?? null,
     },
+    guardPhases: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.guardPhases ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
   // Default values.
@@ -37,6 +37,8 @@ export type UpdateSettingsInstructionAccounts = {
   jellybeanMachine: PublicKey | Pda;
   /** Gumball Machine authority. This is the address that controls the upate of the jellybean machine. */
   authority?: Signer;
+  /** Guard phases account, required for machines with public draws. */
+  guardPhases?: PublicKey | Pda;
 };
 
 // Data.
@@ -99,6 +101,11 @@ export function updateSettings(
       isWritable: true as boolean,
       value: input.authority ?? null,
     },
+    guardPhases: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.guardPhases ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
   // Arguments.
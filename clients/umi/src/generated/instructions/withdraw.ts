@@ -8,6 +8,7 @@
 
 import {
   Context,
+  OptionOrNullable,
   Pda,
   PublicKey,
   Signer,
@@ -20,9 +21,13 @@ import {
   mapSerializer,
   struct,
 } from '@metaplex-foundation/umi/serializers';
+import { resolveAuthorityPda } from '../../hooked';
+import { findGuardPhasesPda } from '../accounts';
 import {
+  PickPartial,
   ResolvedAccount,
   ResolvedAccountsWithIndices,
+  expectPublicKey,
   getAccountMetasAndSigners,
 } from '../shared';
 
@@ -34,6 +39,12 @@ export type WithdrawInstructionAccounts = {
   authority?: Signer;
   /** Mint authority of the jellybean machine. */
   mintAuthority?: Signer;
+  /** Guard phases account, closed with the machine if it exists. */
+  guardPhases?: PublicKey | Pda;
+  authorityPda?: PublicKey | Pda;
+  tokenProgram?: PublicKey | Pda;
+  associatedTokenProgram?: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
 };
 
 // Data.
@@ -60,10 +71,22 @@ export function getWithdrawInstructionDataSerializer(): Serializer<
   ) as Serializer<WithdrawInstructionDataArgs, WithdrawInstructionData>;
 }
 
+// Extra Args.
+export type WithdrawInstructionExtraArgs = {
+  /** Forcing WithdrawInstructionExtraArgs to be rendered to fix a bug where resolvedArgs is using an undefined type */
+  unused?: OptionOrNullable<boolean>;
+};
+
+// Args.
+export type WithdrawInstructionArgs = PickPartial<
+  WithdrawInstructionExtraArgs,
+  'unused'
+>;
+
 // Instruction.
 export function withdraw(
-  context: Pick<Context, 'identity' | 'programs'>,
-  input: WithdrawInstructionAccounts
+  context: Pick<Context, 'eddsa' | 'identity' | 'payer' | 'programs'>,
+  input: WithdrawInstructionAccounts & WithdrawInstructionArgs
 ): TransactionBuilder {
   // Program ID.
   const programId = context.programs.getPublicKey(
@@ -88,8 +111,36 @@ export function withdraw(
       isWritable: true as boolean,
       value: input.mintAuthority ?? null,
     },
+    guardPhases: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.guardPhases ?? null,
+    },
+    authorityPda: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.authorityPda ?? null,
+    },
+    tokenProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+    associatedTokenProgram: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.associatedTokenProgram ?? null,
+    },
+    systemProgram: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
+  // Arguments.
+  const resolvedArgs: WithdrawInstructionArgs = { ...input };
+
   // Default values.
   if (!resolvedAccounts.authority.value) {
     resolvedAccounts.authority.value = context.identity;
@@ -97,6 +148,35 @@ export function withdraw(
   if (!resolvedAccounts.mintAuthority.value) {
     resolvedAccounts.mintAuthority.value = context.identity;
   }
+  if (!resolvedAccounts.guardPhases.value) {
+    resolvedAccounts.guardPhases.value = findGuardPhasesPda(context, {
+      jellybeanMachine: expectPublicKey(
+        resolvedAccounts.jellybeanMachine.value
+      ),
+    });
+  }
+  if (!resolvedAccounts.authorityPda.value) {
+    resolvedAccounts.authorityPda = {
+      ...resolvedAccounts.authorityPda,
+      ...resolveAuthorityPda(
+        context,
+        resolvedAccounts,
+        resolvedArgs,
+        programId,
+        true
+      ),
+    };
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedArgs.unused) {
+    resolvedArgs.unused = false;
+  }
 
   // Accounts in order.
   const orderedAccounts: ResolvedAccount[] = Object.values(
@@ -6,8 +6,19 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './bundleComponent';
+export * from './bundleComponentKind';
+export * from './buyerLimit';
+export * from './drawGate';
+export * from './drawGateAction';
+export * from './drawGateKind';
 export * from './feeAccount';
+export * from './guardPhase';
+export * from './itemWins';
 export * from './jellybeanState';
 export * from './printFeeConfig';
 export * from './prize';
+export * from './provenanceAuthority';
+export * from './provenanceConfig';
+export * from './revealConfig';
 export * from './settingsArgs';
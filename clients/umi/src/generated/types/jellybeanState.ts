@@ -12,6 +12,8 @@ export enum JellybeanState {
   None,
   SaleLive,
   SaleEnded,
+  Paused,
+  Cancelled,
 }
 
 export type JellybeanStateArgs = JellybeanState;
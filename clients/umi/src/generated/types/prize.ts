@@ -9,19 +9,34 @@
 import {
   Serializer,
   struct,
+  u16,
   u32,
-  u8,
+  u64,
 } from '@metaplex-foundation/umi/serializers';
 
-export type Prize = { itemIndex: number; editionNumber: number };
+export type Prize = {
+  itemIndex: number;
+  editionNumber: number;
+  drawSlot: bigint;
+  /** Price paid through the machine's guard phases, zero when a wrapping program charged */
+  price: bigint;
+};
 
-export type PrizeArgs = Prize;
+export type PrizeArgs = {
+  itemIndex: number;
+  editionNumber: number;
+  drawSlot: number | bigint;
+  /** Price paid through the machine's guard phases, zero when a wrapping program charged */
+  price: number | bigint;
+};
 
 export function getPrizeSerializer(): Serializer<PrizeArgs, Prize> {
   return struct<Prize>(
     [
-      ['itemIndex', u8()],
+      ['itemIndex', u16()],
       ['editionNumber', u32()],
+      ['drawSlot', u64()],
+      ['price', u64()],
     ],
     { description: 'Prize' }
   ) as Serializer<PrizeArgs, Prize>;
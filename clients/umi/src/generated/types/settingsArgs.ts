@@ -10,18 +10,28 @@ import { Option, OptionOrNullable, none } from '@metaplex-foundation/umi';
 import {
   Serializer,
   array,
+  bool,
+  i64,
   mapSerializer,
   option,
   string,
   struct,
+  u16,
+  u64,
 } from '@metaplex-foundation/umi/serializers';
 import {
   FeeAccount,
   FeeAccountArgs,
   PrintFeeConfig,
   PrintFeeConfigArgs,
+  ProvenanceConfig,
+  ProvenanceConfigArgs,
+  RevealConfig,
+  RevealConfigArgs,
   getFeeAccountSerializer,
   getPrintFeeConfigSerializer,
+  getProvenanceConfigSerializer,
+  getRevealConfigSerializer,
 } from '.';
 
 /** Common arguments for settings-related operations (initialize and update_settings) */
@@ -29,12 +39,30 @@ export type SettingsArgs = {
   uri: string;
   feeAccounts: Array<FeeAccount>;
   printFeeConfig: Option<PrintFeeConfig>;
+  provenanceConfig: Option<ProvenanceConfig>;
+  revealConfig: Option<RevealConfig>;
+  maxWinsPerItem: Option<number>;
+  goLiveAt: Option<bigint>;
+  endAt: Option<bigint>;
+  maxDrawsPerWallet: Option<number>;
+  botTax: Option<bigint>;
+  claimDeadline: Option<bigint>;
+  publicDraw: boolean;
 };
 
 export type SettingsArgsArgs = {
   uri: string;
   feeAccounts: Array<FeeAccountArgs>;
   printFeeConfig?: OptionOrNullable<PrintFeeConfigArgs>;
+  provenanceConfig?: OptionOrNullable<ProvenanceConfigArgs>;
+  revealConfig?: OptionOrNullable<RevealConfigArgs>;
+  maxWinsPerItem?: OptionOrNullable<number>;
+  goLiveAt?: OptionOrNullable<number | bigint>;
+  endAt?: OptionOrNullable<number | bigint>;
+  maxDrawsPerWallet?: OptionOrNullable<number>;
+  botTax?: OptionOrNullable<number | bigint>;
+  claimDeadline?: OptionOrNullable<number | bigint>;
+  publicDraw?: boolean;
 };
 
 export function getSettingsArgsSerializer(): Serializer<
@@ -47,9 +75,30 @@ export function getSettingsArgsSerializer(): Serializer<
         ['uri', string()],
         ['feeAccounts', array(getFeeAccountSerializer())],
         ['printFeeConfig', option(getPrintFeeConfigSerializer())],
+        ['provenanceConfig', option(getProvenanceConfigSerializer())],
+        ['revealConfig', option(getRevealConfigSerializer())],
+        ['maxWinsPerItem', option(u16())],
+        ['goLiveAt', option(i64())],
+        ['endAt', option(i64())],
+        ['maxDrawsPerWallet', option(u16())],
+        ['botTax', option(u64())],
+        ['claimDeadline', option(i64())],
+        ['publicDraw', bool()],
       ],
       { description: 'SettingsArgs' }
     ),
-    (value) => ({ ...value, printFeeConfig: value.printFeeConfig ?? none() })
+    (value) => ({
+      ...value,
+      printFeeConfig: value.printFeeConfig ?? none(),
+      provenanceConfig: value.provenanceConfig ?? none(),
+      revealConfig: value.revealConfig ?? none(),
+      maxWinsPerItem: value.maxWinsPerItem ?? none(),
+      goLiveAt: value.goLiveAt ?? none(),
+      endAt: value.endAt ?? none(),
+      maxDrawsPerWallet: value.maxDrawsPerWallet ?? none(),
+      botTax: value.botTax ?? none(),
+      claimDeadline: value.claimDeadline ?? none(),
+      publicDraw: value.publicDraw ?? false,
+    })
   ) as Serializer<SettingsArgsArgs, SettingsArgs>;
 }
@@ -10,7 +10,11 @@ import { initialize } from '../generated';
 export const MAX_FEE_ACCOUNTS = 6;
 export const FEE_ACCOUNT_SIZE = 32 + 2; // address + basis points
 export const MAX_URI_LENGTH = 196;
-export const PADDING_SIZE = 320;
+export const MAX_DISABLED_ITEMS = 16;
+export const MAX_PLACEHOLDER_URI_LENGTH = 100;
+export const MAX_BUYER_LIMITS = 16;
+export const BUYER_LIMIT_SIZE = 2 + 2; // item index + max per buyer
+export const PADDING_SIZE = 20;
 
 export const JELLYBEAN_MACHINE_BASE_SIZE =
   8 + // discriminator
@@ -19,12 +23,31 @@ export const JELLYBEAN_MACHINE_BASE_SIZE =
   32 + // mint authority
   4 + // fee account vec size
   41 + // print fee config
-  1 + // items loaded
+  2 + // items loaded
   8 + // supply loaded
   8 + // supply redeemed
   8 + // supply settled
   1 + // state
   MAX_URI_LENGTH + // uri
+  34 + // provenance config
+  4 +
+  2 * MAX_DISABLED_ITEMS + // disabled items
+  1 +
+  4 +
+  MAX_PLACEHOLDER_URI_LENGTH + // reveal config
+  4 +
+  BUYER_LIMIT_SIZE * MAX_BUYER_LIMITS + // buyer limits
+  3 + // max wins per item
+  9 + // go live at
+  9 + // end at
+  1 + // has guard phases
+  3 + // max draws per wallet
+  9 + // bot tax
+  8 + // unclaimed price
+  9 + // claim deadline
+  1 + // public draw
+  2 + // open bundles
+  2 + // open token accounts
   PADDING_SIZE; // padding
 
 export function getJellybeanMachineBaseSize(feeAccounts: number) {
@@ -4,4 +4,5 @@ export * from './create';
 export * from './createJellybeanMachine';
 export * from './eventAuthorityPda';
 export * from './jellybeanMachineWithItems';
+export * from './printTemplatePda';
 export * from './program';
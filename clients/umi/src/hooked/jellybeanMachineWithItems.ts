@@ -13,6 +13,7 @@ import {
   array,
   mapSerializer,
   publicKey,
+  scalarEnum,
   Serializer,
   struct,
   u32,
@@ -43,7 +44,11 @@ export type JellybeanMachineItem = {
   /** The index of the item in all items. */
   readonly index: number;
 
-  /** The asset or master edition collection of the NFT. */
+  /**
+   * The asset or master edition collection of the NFT.
+   * This is the default public key for a removed item whose slot is kept
+   * because later items still have unclaimed prizes.
+   */
   readonly mint: PublicKey;
 
   /** The initial supply of the item that can be redeemed. */
@@ -57,9 +62,24 @@ export type JellybeanMachineItem = {
 
   /** The escrow amount for the item. */
   readonly escrowAmount: bigint;
+
+  /** The prints of a master edition minted before it was loaded. */
+  readonly existingPrints: number;
+
+  /** What was loaded, a Core asset, a master edition or a bundle. */
+  readonly kind: ItemKind;
 };
 
-export const JELLYBEAN_MACHINE_ITEM_SIZE = 32 + 4 + 4 + 4 + 8;
+/**
+ * The kind of a loaded item.
+ */
+export enum ItemKind {
+  CoreAsset,
+  MasterEdition,
+  Bundle,
+}
+
+export const JELLYBEAN_MACHINE_ITEM_SIZE = 32 + 4 + 4 + 4 + 8 + 4 + 1;
 
 type JellybeanMachineHiddenSection = {
   items: Omit<JellybeanMachineItem, 'index'>[];
@@ -81,12 +101,19 @@ function getHiddenSection(
             supplyRedeemed: number;
             supplyClaimed: number;
             escrowAmount: bigint;
+            existingPrints: number;
+            kind: ItemKind;
           }>([
             ['mint', publicKey()],
             ['supplyLoaded', u32()],
             ['supplyRedeemed', u32()],
             ['supplyClaimed', u32()],
             ['escrowAmount', u64()],
+            ['existingPrints', u32()],
+            [
+              'kind',
+              scalarEnum<ItemKind>(ItemKind, { description: 'ItemKind' }),
+            ],
           ]),
           { size: itemsLoaded }
         ),
@@ -0,0 +1,22 @@
+import { Context, Pda, PublicKey } from '@metaplex-foundation/umi';
+import {
+  expectPublicKey,
+  findPrintTemplatePda,
+  ResolvedAccount,
+} from '../generated';
+
+/** Master editions always pass their print template, even when none has been set. */
+export const resolvePrintTemplatePda = (
+  context: Pick<Context, 'eddsa' | 'programs' | 'identity' | 'payer'>,
+  accounts: Record<string, ResolvedAccount>,
+  _args?: Record<string, unknown>,
+  _programId?: PublicKey,
+  _isWritable?: boolean
+): { value: Pda | null } => ({
+  value: accounts.collection.value
+    ? findPrintTemplatePda(context, {
+        jellybeanMachine: expectPublicKey(accounts.jellybeanMachine.value),
+        collection: expectPublicKey(accounts.collection.value),
+      })
+    : null,
+});
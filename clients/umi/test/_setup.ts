@@ -22,8 +22,10 @@ import {
   setComputeUnitLimit,
 } from '@metaplex-foundation/mpl-toolbox';
 import {
+  AccountMeta,
   DateTime,
   generateSigner,
+  none,
   now,
   percentAmount,
   PublicKey,
@@ -44,10 +46,20 @@ import {
   createJellybeanMachine,
   FeeAccount,
   fetchUnclaimedPrizesFromSeeds,
+  findAllowlistProofPda,
+  findAuthorityPda,
+  findBuyerRecordPda,
+  findGuardPhasesPda,
+  findPrintTemplatePda,
+  GuardPhaseArgs,
   initialize,
+  MALLOW_JELLYBEAN_PROGRAM_ID,
   mallowJellybean,
   Prize,
+  setGuardPhases,
+  SettingsArgsArgs,
   startSale,
+  updateSettings,
 } from '../src';
 
 export const DEFAULT_MAX_SUPPLY = 100;
@@ -201,27 +213,54 @@ export const create = async (
     items?: {
       asset?: PublicKey;
       collection?: PublicKey;
+      supply?: number;
     }[];
     startSale?: boolean;
+    phases?: GuardPhaseArgs[];
   } & Partial<GumballGuardDataArgs<DefaultGuardSetArgs>> = {}
 ) => {
   const jellybeanMachineSigner = input.jellybeanMachine ?? generateSigner(umi);
   const jellybeanMachine = jellybeanMachineSigner.publicKey;
-
+  const settings: SettingsArgsArgs = {
+    feeAccounts:
+      input.args?.feeAccounts ?? getDefaultFeeAccounts(umi.identity.publicKey),
+    uri: input.args?.uri ?? 'https://example.com/jellybean-machine.json',
+    printFeeConfig: input.args?.printFeeConfig,
+    provenanceConfig: input.args?.provenanceConfig,
+    revealConfig: input.args?.revealConfig,
+    goLiveAt: input.args?.goLiveAt,
+    endAt: input.args?.endAt,
+    maxWinsPerItem: input.args?.maxWinsPerItem,
+    maxDrawsPerWallet: input.args?.maxDrawsPerWallet,
+    botTax: input.args?.botTax,
+    claimDeadline: input.args?.claimDeadline,
+    publicDraw: input.args?.publicDraw,
+  };
+
+  // Public draws can only be enabled once the machine has guard phases
   let builder = transactionBuilder().add(
     await createJellybeanMachine(umi, {
       ...input,
       jellybeanMachine: jellybeanMachineSigner,
-      args: {
-        feeAccounts:
-          input.args?.feeAccounts ??
-          getDefaultFeeAccounts(umi.identity.publicKey),
-        uri: input.args?.uri ?? 'https://example.com/jellybean-machine.json',
-        printFeeConfig: input.args?.printFeeConfig,
-      },
+      args: input.phases ? { ...settings, publicDraw: false } : settings,
     })
   );
 
+  if (input.phases) {
+    builder = builder.add(
+      setGuardPhases(umi, { jellybeanMachine, phases: input.phases })
+    );
+    if (settings.publicDraw) {
+      builder = builder.add(
+        updateSettings(umi, {
+          jellybeanMachine,
+          args: settings,
+          guardPhases: findGuardPhasesPda(umi, { jellybeanMachine }),
+        })
+      );
+    }
+  }
+
   const guards = input.groups
     ? {}
     : (input.guards ?? {
@@ -265,10 +304,15 @@ export const create = async (
     });
   }
 
+  const guardPhases = input.phases
+    ? findGuardPhasesPda(umi, { jellybeanMachine })
+    : undefined;
+
   if (itemBatches.length === 0 && input.startSale) {
     builder = builder.add(
       startSale(umi, {
         jellybeanMachine,
+        guardPhases,
       })
     );
   }
@@ -290,6 +334,7 @@ export const create = async (
       const builder = transactionBuilder().add(
         startSale(umi, {
           jellybeanMachine,
+          guardPhases,
         })
       );
       await builder.sendAndConfirm(umi);
@@ -305,6 +350,123 @@ export const defaultAssetData = () => ({
   uri: 'https://example.com/my-asset.json',
 });
 
+/**
+ * Remaining accounts of remove_core_items and unload_all, three per item: the
+ * asset, its collection and the print template of a master edition. Accounts
+ * an item doesn't use are set to the program id.
+ */
+export const getRemoveItemAccounts = (
+  umi: Umi,
+  jellybeanMachine: PublicKey,
+  items: { asset?: PublicKey; collection?: PublicKey }[]
+): AccountMeta[] =>
+  items.flatMap(({ asset, collection }) => {
+    const printTemplate =
+      asset === undefined && collection !== undefined
+        ? findPrintTemplatePda(umi, { jellybeanMachine, collection })[0]
+        : undefined;
+
+    return [asset, collection, printTemplate].map((pubkey) => ({
+      pubkey: pubkey ?? MALLOW_JELLYBEAN_PROGRAM_ID,
+      isSigner: false,
+      isWritable: pubkey !== undefined,
+    }));
+  });
+
+/**
+ * Remaining accounts of a withdraw sweeping the authority pda token account of each mint
+ * to the authority's associated token account.
+ */
+export const getWithdrawTokenAccounts = (
+  umi: Umi,
+  jellybeanMachine: PublicKey,
+  mints: PublicKey[]
+): AccountMeta[] => {
+  const [authorityPda] = findAuthorityPda(umi, { jellybeanMachine });
+
+  return mints.flatMap((mint) => [
+    { pubkey: mint, isSigner: false, isWritable: false },
+    {
+      pubkey: findAssociatedTokenPda(umi, { mint, owner: authorityPda })[0],
+      isSigner: false,
+      isWritable: true,
+    },
+    {
+      pubkey: findAssociatedTokenPda(umi, {
+        mint,
+        owner: umi.identity.publicKey,
+      })[0],
+      isSigner: false,
+      isWritable: true,
+    },
+  ]);
+};
+
+/** A guard phase that started yesterday and charges the default price. */
+export const guardPhase = (
+  input: Partial<GuardPhaseArgs> = {}
+): GuardPhaseArgs => ({
+  startAt: yesterday(),
+  price: DEFAULT_SOL_PAYMENT_LAMPORTS.basisPoints,
+  allowlistRoot: none(),
+  gate: none(),
+  maxDrawsPerWallet: none(),
+  ...input,
+});
+
+/**
+ * Remaining accounts of a draw checked by guards, in the order the program reads them:
+ * the guard phases, the allowlist proof of the buyer, the gate accounts, the buyer
+ * record and the fee accounts paid by a priced phase.
+ */
+export const getGuardAccounts = (
+  umi: Umi,
+  jellybeanMachine: PublicKey,
+  input: {
+    buyer: PublicKey;
+    guardPhases?: boolean;
+    allowlistRoot?: Uint8Array;
+    gateAccounts?: AccountMeta[];
+    buyerRecord?: boolean;
+    feeAccounts?: PublicKey[];
+  }
+): AccountMeta[] => {
+  const accounts: AccountMeta[] = [];
+  const readonly = (pubkey: PublicKey) => ({
+    pubkey,
+    isSigner: false,
+    isWritable: false,
+  });
+  const writable = (pubkey: PublicKey) => ({
+    pubkey,
+    isSigner: false,
+    isWritable: true,
+  });
+
+  if (input.guardPhases ?? true) {
+    accounts.push(readonly(findGuardPhasesPda(umi, { jellybeanMachine })[0]));
+  }
+  if (input.allowlistRoot) {
+    const [allowlistProof] = findAllowlistProofPda(umi, {
+      jellybeanMachine,
+      merkleRoot: input.allowlistRoot,
+      buyer: input.buyer,
+    });
+    accounts.push(readonly(allowlistProof));
+  }
+  accounts.push(...(input.gateAccounts ?? []));
+  if (input.buyerRecord) {
+    const [buyerRecord] = findBuyerRecordPda(umi, {
+      jellybeanMachine,
+      buyer: input.buyer,
+    });
+    accounts.push(writable(buyerRecord));
+  }
+  accounts.push(...(input.feeAccounts ?? []).map(writable));
+
+  return accounts;
+};
+
 export const createGumballGuard = async <
   DA extends GuardSetArgs = DefaultGuardSetArgs,
 >(
@@ -43,7 +43,7 @@ test('it can add a one-of-one asset to a jellybean machine', async (t) => {
 
   t.like(jellybeanMachineAccount, <JellybeanMachineWithItems>{
     publicKey: jellybeanMachine.publicKey,
-    version: 0,
+    version: 1,
     authority: umi.identity.publicKey,
     mintAuthority: umi.identity.publicKey,
     itemsLoaded: 1,
@@ -95,7 +95,7 @@ test('it can add a master edition asset to a jellybean machine', async (t) => {
 
   t.like(jellybeanMachineAccount, <JellybeanMachineWithItems>{
     publicKey: jellybeanMachine.publicKey,
-    version: 0,
+    version: 1,
     authority: umi.identity.publicKey,
     mintAuthority: umi.identity.publicKey,
     itemsLoaded: 1,
@@ -123,7 +123,7 @@ test('it can add a master edition asset to a jellybean machine', async (t) => {
   t.true(jellybeanMachineAccount.items[0].escrowAmount > 0);
 });
 
-test('it cannot add an item if the machine is started', async (t) => {
+test('it can add an item while the sale is live', async (t) => {
   const umi = await createUmi();
   const jellybeanMachine = generateSigner(umi);
   const uri = 'https://example.com/metadata.json';
@@ -147,13 +147,33 @@ test('it cannot add an item if the machine is started', async (t) => {
   }).sendAndConfirm(umi);
 
   const asset2 = await createCoreAsset(umi);
-  const promise = addCoreItem(umi, {
+  await addCoreItem(umi, {
     jellybeanMachine: jellybeanMachine.publicKey,
     asset: asset2.publicKey,
   }).sendAndConfirm(umi);
 
-  await t.throwsAsync(promise, {
-    message: /InvalidState/,
+  const jellybeanMachineAccount = await fetchJellybeanMachineWithItems(
+    umi,
+    jellybeanMachine.publicKey
+  );
+
+  t.like(jellybeanMachineAccount, <JellybeanMachineWithItems>{
+    itemsLoaded: 2,
+    supplyLoaded: 2n,
+    supplyRedeemed: 0n,
+    state: JellybeanState.SaleLive,
+    items: [
+      {
+        index: 0,
+        mint: asset.publicKey,
+        supplyLoaded: 1,
+      },
+      {
+        index: 1,
+        mint: asset2.publicKey,
+        supplyLoaded: 1,
+      },
+    ],
   });
 });
 
@@ -183,7 +203,31 @@ test('it cannot add a collection that is not a master edition', async (t) => {
   });
 });
 
-test('it cannot add a master edition with infinite supply', async (t) => {
+test('it cannot add an open edition without a supply', async (t) => {
+  const umi = await createUmi();
+  const jellybeanMachine = generateSigner(umi);
+  const uri = 'https://example.com/metadata.json';
+  const feeAccounts = getDefaultFeeAccounts(umi.identity.publicKey);
+
+  await (
+    await createJellybeanMachine(umi, {
+      jellybeanMachine,
+      args: { feeAccounts, uri },
+    })
+  ).sendAndConfirm(umi);
+
+  const collection = await createMasterEdition(umi, { maxSupply: undefined });
+  const promise = addCoreItem(umi, {
+    jellybeanMachine: jellybeanMachine.publicKey,
+    collection: collection.publicKey,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(promise, {
+    message: /InvalidMasterEditionSupply/,
+  });
+});
+
+test('it can add an open edition with an explicit supply', async (t) => {
   const umi = await createUmi();
   const jellybeanMachine = generateSigner(umi);
   const uri = 'https://example.com/metadata.json';
@@ -197,9 +241,81 @@ test('it cannot add a master edition with infinite supply', async (t) => {
   ).sendAndConfirm(umi);
 
   const collection = await createMasterEdition(umi, { maxSupply: undefined });
+  await addCoreItem(umi, {
+    jellybeanMachine: jellybeanMachine.publicKey,
+    collection: collection.publicKey,
+    supply: 5,
+  }).sendAndConfirm(umi);
+
+  const jellybeanMachineAccount = await fetchJellybeanMachineWithItems(
+    umi,
+    jellybeanMachine.publicKey
+  );
+
+  t.like(jellybeanMachineAccount, <JellybeanMachineWithItems>{
+    itemsLoaded: 1,
+    supplyLoaded: 5n,
+    items: [
+      {
+        index: 0,
+        mint: collection.publicKey,
+        supplyLoaded: 5,
+        supplyRedeemed: 0,
+        supplyClaimed: 0,
+      },
+    ],
+  });
+});
+
+test('it can offer fewer prints than the max supply', async (t) => {
+  const umi = await createUmi();
+  const jellybeanMachine = generateSigner(umi);
+  const uri = 'https://example.com/metadata.json';
+  const feeAccounts = getDefaultFeeAccounts(umi.identity.publicKey);
+
+  await (
+    await createJellybeanMachine(umi, {
+      jellybeanMachine,
+      args: { feeAccounts, uri },
+    })
+  ).sendAndConfirm(umi);
+
+  const collection = await createMasterEdition(umi);
+  await addCoreItem(umi, {
+    jellybeanMachine: jellybeanMachine.publicKey,
+    collection: collection.publicKey,
+    supply: DEFAULT_MAX_SUPPLY - 1,
+  }).sendAndConfirm(umi);
+
+  const jellybeanMachineAccount = await fetchJellybeanMachineWithItems(
+    umi,
+    jellybeanMachine.publicKey
+  );
+
+  t.like(jellybeanMachineAccount, <JellybeanMachineWithItems>{
+    supplyLoaded: BigInt(DEFAULT_MAX_SUPPLY - 1),
+    items: [{ index: 0, supplyLoaded: DEFAULT_MAX_SUPPLY - 1 }],
+  });
+});
+
+test('it cannot offer more prints than the max supply', async (t) => {
+  const umi = await createUmi();
+  const jellybeanMachine = generateSigner(umi);
+  const uri = 'https://example.com/metadata.json';
+  const feeAccounts = getDefaultFeeAccounts(umi.identity.publicKey);
+
+  await (
+    await createJellybeanMachine(umi, {
+      jellybeanMachine,
+      args: { feeAccounts, uri },
+    })
+  ).sendAndConfirm(umi);
+
+  const collection = await createMasterEdition(umi);
   const promise = addCoreItem(umi, {
     jellybeanMachine: jellybeanMachine.publicKey,
     collection: collection.publicKey,
+    supply: DEFAULT_MAX_SUPPLY + 1,
   }).sendAndConfirm(umi);
 
   await t.throwsAsync(promise, {
@@ -207,7 +323,7 @@ test('it cannot add a master edition with infinite supply', async (t) => {
   });
 });
 
-test('it cannot add a master edition with existing prints', async (t) => {
+test('it can add a master edition with existing prints', async (t) => {
   const umi = await createUmi();
   const jellybeanMachine = generateSigner(umi);
   const uri = 'https://example.com/metadata.json';
@@ -230,12 +346,29 @@ test('it cannot add a master edition with existing prints', async (t) => {
     uri: 'https://example.com/my-asset.json',
   }).sendAndConfirm(umi);
 
-  const promise = addCoreItem(umi, {
+  await addCoreItem(umi, {
     jellybeanMachine: jellybeanMachine.publicKey,
     collection: collection.publicKey,
   }).sendAndConfirm(umi);
 
-  await t.throwsAsync(promise, {
-    message: /MasterEditionNotEmpty/,
+  const jellybeanMachineAccount = await fetchJellybeanMachineWithItems(
+    umi,
+    jellybeanMachine.publicKey
+  );
+
+  // The existing print counts as redeemed and claimed so it is never drawn
+  t.like(jellybeanMachineAccount, <JellybeanMachineWithItems>{
+    itemsLoaded: 1,
+    supplyLoaded: BigInt(DEFAULT_MAX_SUPPLY),
+    supplyRedeemed: 1n,
+    items: [
+      {
+        index: 0,
+        mint: collection.publicKey,
+        supplyLoaded: DEFAULT_MAX_SUPPLY,
+        supplyRedeemed: 1,
+        supplyClaimed: 1,
+      },
+    ],
   });
 });
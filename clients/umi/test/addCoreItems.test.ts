@@ -0,0 +1,130 @@
+import {
+  AccountMeta,
+  generateSigner,
+  PublicKey,
+} from '@metaplex-foundation/umi';
+import test from 'ava';
+import {
+  addCoreItems,
+  createJellybeanMachine,
+  fetchJellybeanMachineWithItems,
+  getJellybeanMachineBaseSize,
+  JELLYBEAN_MACHINE_ITEM_SIZE,
+  JellybeanMachineWithItems,
+  MALLOW_JELLYBEAN_PROGRAM_ID,
+} from '../src';
+import {
+  createCoreAsset,
+  createMasterEdition,
+  createUmi,
+  getDefaultFeeAccounts,
+} from './_setup';
+
+// One asset and collection pair per item, the program id stands in for a
+// missing asset or collection.
+const itemAccounts = (
+  items: { asset?: PublicKey; collection?: PublicKey }[]
+): AccountMeta[] =>
+  items.flatMap(({ asset, collection }) => [
+    {
+      pubkey: asset ?? MALLOW_JELLYBEAN_PROGRAM_ID,
+      isSigner: false,
+      isWritable: asset !== undefined,
+    },
+    {
+      pubkey: collection ?? MALLOW_JELLYBEAN_PROGRAM_ID,
+      isSigner: false,
+      isWritable: collection !== undefined,
+    },
+  ]);
+
+test('it can add several items in one instruction', async (t) => {
+  const umi = await createUmi();
+  const jellybeanMachine = generateSigner(umi);
+  const feeAccounts = getDefaultFeeAccounts(umi.identity.publicKey);
+
+  await (
+    await createJellybeanMachine(umi, {
+      jellybeanMachine,
+      args: { feeAccounts, uri: 'https://example.com/metadata.json' },
+    })
+  ).sendAndConfirm(umi);
+
+  const asset1 = await createCoreAsset(umi);
+  const asset2 = await createCoreAsset(umi);
+  const collection = await createMasterEdition(umi, { maxSupply: 10 });
+
+  await addCoreItems(umi, {
+    jellybeanMachine: jellybeanMachine.publicKey,
+    supplies: [null, null, 5],
+  })
+    .addRemainingAccounts(
+      itemAccounts([
+        { asset: asset1.publicKey },
+        { asset: asset2.publicKey },
+        { collection: collection.publicKey },
+      ])
+    )
+    .sendAndConfirm(umi);
+
+  const jellybeanMachineAccount = await fetchJellybeanMachineWithItems(
+    umi,
+    jellybeanMachine.publicKey
+  );
+
+  t.like(jellybeanMachineAccount, <JellybeanMachineWithItems>{
+    itemsLoaded: 3,
+    supplyLoaded: 7n,
+    items: [
+      {
+        index: 0,
+        mint: asset1.publicKey,
+        supplyLoaded: 1,
+      },
+      {
+        index: 1,
+        mint: asset2.publicKey,
+        supplyLoaded: 1,
+      },
+      {
+        index: 2,
+        mint: collection.publicKey,
+        supplyLoaded: 5,
+      },
+    ],
+  });
+
+  const account = await umi.rpc.getAccount(jellybeanMachine.publicKey);
+  t.true(account.exists);
+  t.is(
+    account.exists ? account.data.length : 0,
+    getJellybeanMachineBaseSize(feeAccounts.length) +
+      3 * JELLYBEAN_MACHINE_ITEM_SIZE
+  );
+});
+
+test('it cannot add a one-of-one asset with a supply', async (t) => {
+  const umi = await createUmi();
+  const jellybeanMachine = generateSigner(umi);
+
+  await (
+    await createJellybeanMachine(umi, {
+      jellybeanMachine,
+      args: {
+        feeAccounts: getDefaultFeeAccounts(umi.identity.publicKey),
+        uri: 'https://example.com/metadata.json',
+      },
+    })
+  ).sendAndConfirm(umi);
+
+  const asset = await createCoreAsset(umi);
+
+  const promise = addCoreItems(umi, {
+    jellybeanMachine: jellybeanMachine.publicKey,
+    supplies: [5],
+  })
+    .addRemainingAccounts(itemAccounts([{ asset: asset.publicKey }]))
+    .sendAndConfirm(umi);
+
+  await t.throwsAsync(promise, { message: /InvalidMasterEditionSupply/ });
+});
@@ -0,0 +1,104 @@
+import test from 'ava';
+import {
+  addPrints,
+  fetchJellybeanMachineWithItems,
+  JellybeanMachineWithItems,
+} from '../src';
+import {
+  create,
+  createMasterEdition,
+  createUmi,
+  DEFAULT_MAX_SUPPLY,
+} from './_setup';
+
+test('it can offer more prints up to the max supply', async (t) => {
+  const umi = await createUmi();
+  const collection = await createMasterEdition(umi);
+
+  const jellybeanMachine = await create(umi, {
+    items: [
+      {
+        collection: collection.publicKey,
+        supply: DEFAULT_MAX_SUPPLY / 2,
+      },
+    ],
+    startSale: true,
+  });
+
+  await addPrints(umi, {
+    jellybeanMachine,
+    collection: collection.publicKey,
+    index: 0,
+    amount: DEFAULT_MAX_SUPPLY / 2,
+  }).sendAndConfirm(umi);
+
+  const jellybeanMachineAccount = await fetchJellybeanMachineWithItems(
+    umi,
+    jellybeanMachine
+  );
+  t.like(jellybeanMachineAccount, <JellybeanMachineWithItems>{
+    supplyLoaded: BigInt(DEFAULT_MAX_SUPPLY),
+    items: [{ index: 0, supplyLoaded: DEFAULT_MAX_SUPPLY }],
+  });
+});
+
+test('it cannot offer more prints than the max supply', async (t) => {
+  const umi = await createUmi();
+  const collection = await createMasterEdition(umi);
+
+  const jellybeanMachine = await create(umi, {
+    items: [
+      {
+        collection: collection.publicKey,
+        supply: DEFAULT_MAX_SUPPLY / 2,
+      },
+    ],
+  });
+
+  const promise = addPrints(umi, {
+    jellybeanMachine,
+    collection: collection.publicKey,
+    index: 0,
+    amount: DEFAULT_MAX_SUPPLY / 2 + 1,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(promise, {
+    message: /InvalidMasterEditionSupply/,
+  });
+
+  const jellybeanMachineAccount = await fetchJellybeanMachineWithItems(
+    umi,
+    jellybeanMachine
+  );
+  t.is(jellybeanMachineAccount.supplyLoaded, BigInt(DEFAULT_MAX_SUPPLY / 2));
+});
+
+test('it can offer more prints of an open edition', async (t) => {
+  const umi = await createUmi();
+  const collection = await createMasterEdition(umi, { maxSupply: undefined });
+
+  const jellybeanMachine = await create(umi, {
+    items: [
+      {
+        collection: collection.publicKey,
+        supply: 10,
+      },
+    ],
+  });
+
+  await addPrints(umi, {
+    jellybeanMachine,
+    collection: collection.publicKey,
+    index: 0,
+    amount: 1000,
+  }).sendAndConfirm(umi);
+
+  const jellybeanMachineAccount = await fetchJellybeanMachineWithItems(
+    umi,
+    jellybeanMachine
+  );
+  t.like(jellybeanMachineAccount, <JellybeanMachineWithItems>{
+    supplyLoaded: 1010n,
+    items: [{ index: 0, supplyLoaded: 1010 }],
+  });
+});
@@ -0,0 +1,276 @@
+import { drawJellybean } from '@mallow-labs/mallow-gumball';
+import { fetchAsset } from '@metaplex-foundation/mpl-core';
+import { some } from '@metaplex-foundation/umi';
+import { generateSignerWithSol } from '@metaplex-foundation/umi-bundle-tests';
+import test from 'ava';
+import {
+  addBundleComponent,
+  addBundleItem,
+  Bundle,
+  BundleComponentKind,
+  claimBundleComponent,
+  fetchBundleFromSeeds,
+  fetchJellybeanMachineWithItems,
+  findAuthorityPda,
+  findBundlePda,
+  JellybeanMachineAccountWithItemsData,
+  removeBundleComponent,
+  removeBundleItem,
+  safeFetchBundleFromSeeds,
+  safeFetchUnclaimedPrizesFromSeeds,
+  startSale,
+} from '../src';
+import { create, createCoreAsset, createUmi } from './_setup';
+
+test('it can load a bundle and deliver it to the buyer', async (t) => {
+  const sellerUmi = await createUmi();
+  const asset1Signer = await createCoreAsset(sellerUmi);
+  const asset2Signer = await createCoreAsset(sellerUmi);
+  const jellybeanMachine = await create(sellerUmi);
+  const [authorityPda] = findAuthorityPda(sellerUmi, { jellybeanMachine });
+  const [bundle] = findBundlePda(sellerUmi, { jellybeanMachine, id: 0 });
+
+  for (const signer of [asset1Signer, asset2Signer]) {
+    await addBundleComponent(sellerUmi, {
+      jellybeanMachine,
+      asset: signer.publicKey,
+      id: 0,
+      amount: 1,
+    }).sendAndConfirm(sellerUmi);
+  }
+
+  t.like(
+    await fetchBundleFromSeeds(sellerUmi, { jellybeanMachine, id: 0 }),
+    <Bundle>{
+      jellybeanMachine,
+      id: 0,
+      loaded: false,
+      components: [
+        {
+          kind: BundleComponentKind.CoreAsset,
+          address: asset1Signer.publicKey,
+          amount: 1n,
+          delivered: false,
+        },
+        {
+          kind: BundleComponentKind.CoreAsset,
+          address: asset2Signer.publicKey,
+          amount: 1n,
+          delivered: false,
+        },
+      ],
+    }
+  );
+  for (const signer of [asset1Signer, asset2Signer]) {
+    const asset = await fetchAsset(sellerUmi, signer.publicKey);
+    t.is(asset.owner, authorityPda);
+  }
+
+  await addBundleItem(sellerUmi, { jellybeanMachine, bundle })
+    .add(startSale(sellerUmi, { jellybeanMachine }))
+    .sendAndConfirm(sellerUmi);
+
+  const buyer = await generateSignerWithSol(sellerUmi);
+  const buyerUmi = await createUmi(buyer);
+
+  await drawJellybean(buyerUmi, {
+    jellybeanMachine,
+    mintArgs: {
+      solPayment: some({
+        feeAccounts: [sellerUmi.identity.publicKey],
+      }),
+    },
+  }).sendAndConfirm(buyerUmi);
+
+  await claimBundleComponent(buyerUmi, {
+    jellybeanMachine,
+    authority: sellerUmi.identity.publicKey,
+    buyer: buyer.publicKey,
+    bundle,
+    asset: asset1Signer.publicKey,
+    index: 0,
+    component: 0,
+  }).sendAndConfirm(buyerUmi);
+
+  // The prize is kept until every component has been delivered
+  t.like(
+    await fetchBundleFromSeeds(sellerUmi, { jellybeanMachine, id: 0 }),
+    <Bundle>{
+      components: [{ delivered: true }, { delivered: false }],
+    }
+  );
+  t.truthy(
+    await safeFetchUnclaimedPrizesFromSeeds(sellerUmi, {
+      jellybeanMachine,
+      buyer: buyer.publicKey,
+    })
+  );
+
+  await claimBundleComponent(buyerUmi, {
+    jellybeanMachine,
+    authority: sellerUmi.identity.publicKey,
+    buyer: buyer.publicKey,
+    bundle,
+    asset: asset2Signer.publicKey,
+    index: 0,
+    component: 1,
+  }).sendAndConfirm(buyerUmi);
+
+  for (const signer of [asset1Signer, asset2Signer]) {
+    const asset = await fetchAsset(sellerUmi, signer.publicKey);
+    t.is(asset.owner, buyer.publicKey);
+  }
+  t.falsy(
+    await safeFetchBundleFromSeeds(sellerUmi, { jellybeanMachine, id: 0 })
+  );
+  t.falsy(
+    await safeFetchUnclaimedPrizesFromSeeds(sellerUmi, {
+      jellybeanMachine,
+      buyer: buyer.publicKey,
+    })
+  );
+
+  const jellybeanMachineAccount = await fetchJellybeanMachineWithItems(
+    sellerUmi,
+    jellybeanMachine
+  );
+  t.like(jellybeanMachineAccount, <JellybeanMachineAccountWithItemsData>{
+    openBundles: 0,
+    items: [
+      {
+        index: 0,
+        mint: bundle,
+        supplyRedeemed: 1,
+        supplyClaimed: 1,
+      },
+    ],
+  });
+
+  // The delivered bundle can be removed once the sale is over
+  await removeBundleItem(sellerUmi, {
+    jellybeanMachine,
+    bundle,
+    index: 0,
+  }).sendAndConfirm(sellerUmi);
+
+  t.is(
+    (await fetchJellybeanMachineWithItems(sellerUmi, jellybeanMachine))
+      .itemsLoaded,
+    0
+  );
+});
+
+test('it can return the components of a bundle that is not loaded', async (t) => {
+  const umi = await createUmi();
+  const assetSigner = await createCoreAsset(umi);
+  const jellybeanMachine = await create(umi);
+  const [bundle] = findBundlePda(umi, { jellybeanMachine, id: 3 });
+
+  await addBundleComponent(umi, {
+    jellybeanMachine,
+    asset: assetSigner.publicKey,
+    id: 3,
+    amount: 1,
+  }).sendAndConfirm(umi);
+
+  t.is(
+    (await fetchJellybeanMachineWithItems(umi, jellybeanMachine)).openBundles,
+    1
+  );
+
+  await removeBundleComponent(umi, {
+    jellybeanMachine,
+    bundle,
+    asset: assetSigner.publicKey,
+    component: 0,
+  }).sendAndConfirm(umi);
+
+  const asset = await fetchAsset(umi, assetSigner.publicKey);
+  t.is(asset.owner, umi.identity.publicKey);
+
+  // The bundle is closed with its last component
+  t.falsy(await safeFetchBundleFromSeeds(umi, { jellybeanMachine, id: 3 }));
+  t.is(
+    (await fetchJellybeanMachineWithItems(umi, jellybeanMachine)).openBundles,
+    0
+  );
+});
+
+test('it cannot change the components of a loaded bundle', async (t) => {
+  const umi = await createUmi();
+  const asset1Signer = await createCoreAsset(umi);
+  const asset2Signer = await createCoreAsset(umi);
+  const jellybeanMachine = await create(umi);
+  const [bundle] = findBundlePda(umi, { jellybeanMachine, id: 0 });
+
+  await addBundleComponent(umi, {
+    jellybeanMachine,
+    asset: asset1Signer.publicKey,
+    id: 0,
+    amount: 1,
+  })
+    .add(addBundleItem(umi, { jellybeanMachine, bundle }))
+    .sendAndConfirm(umi);
+
+  const addPromise = addBundleComponent(umi, {
+    jellybeanMachine,
+    asset: asset2Signer.publicKey,
+    id: 0,
+    amount: 1,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(addPromise, { message: /BundleLoaded/ });
+
+  const removePromise = removeBundleComponent(umi, {
+    jellybeanMachine,
+    bundle,
+    asset: asset1Signer.publicKey,
+    component: 0,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(removePromise, { message: /BundleLoaded/ });
+});
+
+test('it unlocks a bundle removed before it is drawn', async (t) => {
+  const umi = await createUmi();
+  const assetSigner = await createCoreAsset(umi);
+  const jellybeanMachine = await create(umi);
+  const [bundle] = findBundlePda(umi, { jellybeanMachine, id: 0 });
+
+  await addBundleComponent(umi, {
+    jellybeanMachine,
+    asset: assetSigner.publicKey,
+    id: 0,
+    amount: 1,
+  })
+    .add(addBundleItem(umi, { jellybeanMachine, bundle }))
+    .sendAndConfirm(umi);
+
+  t.like(
+    await fetchJellybeanMachineWithItems(umi, jellybeanMachine),
+    <JellybeanMachineAccountWithItemsData>{
+      itemsLoaded: 1,
+      supplyLoaded: 1n,
+      items: [{ index: 0, mint: bundle, supplyLoaded: 1 }],
+    }
+  );
+
+  await removeBundleItem(umi, {
+    jellybeanMachine,
+    bundle,
+    index: 0,
+  }).sendAndConfirm(umi);
+
+  t.is(
+    (await fetchJellybeanMachineWithItems(umi, jellybeanMachine)).itemsLoaded,
+    0
+  );
+  const bundleAccount = await fetchBundleFromSeeds(umi, {
+    jellybeanMachine,
+    id: 0,
+  });
+  t.like(bundleAccount, <Bundle>{
+    loaded: false,
+    components: [{ address: assetSigner.publicKey }],
+  });
+});
@@ -0,0 +1,259 @@
+import { drawJellybean } from '@mallow-labs/mallow-gumball';
+import { addAmounts, isEqualToAmount, some } from '@metaplex-foundation/umi';
+import { generateSignerWithSol } from '@metaplex-foundation/umi-bundle-tests';
+import test from 'ava';
+import {
+  cancelSale,
+  claimCoreItem,
+  draw,
+  fetchJellybeanMachineWithItems,
+  fetchUnclaimedPrizes,
+  findAuthorityPda,
+  findUnclaimedPrizesPda,
+  JellybeanMachineAccountWithItemsData,
+  JellybeanState,
+  refundPrize,
+  safeFetchUnclaimedPrizes,
+} from '../src';
+import {
+  create,
+  createCoreAsset,
+  createUmi,
+  DEFAULT_SOL_PAYMENT_LAMPORTS,
+  getGuardAccounts,
+  guardPhase,
+} from './_setup';
+
+test('it refunds the price paid for the unclaimed prizes of a cancelled sale', async (t) => {
+  const sellerUmi = await createUmi();
+  const firstAsset = await createCoreAsset(sellerUmi);
+  const secondAsset = await createCoreAsset(sellerUmi);
+  const assets = [firstAsset.publicKey, secondAsset.publicKey];
+
+  const jellybeanMachine = await create(sellerUmi, {
+    args: {
+      publicDraw: true,
+    },
+    items: assets.map((asset) => ({ asset })),
+    guards: {},
+    phases: [guardPhase()],
+    startSale: true,
+  });
+
+  const buyer = await generateSignerWithSol(sellerUmi);
+  const buyerUmi = await createUmi(buyer);
+  const drawItem = () =>
+    draw(buyerUmi, {
+      jellybeanMachine,
+      mintAuthority: sellerUmi.identity.publicKey,
+    })
+      .addRemainingAccounts(
+        getGuardAccounts(buyerUmi, jellybeanMachine, {
+          buyer: buyer.publicKey,
+          feeAccounts: [sellerUmi.identity.publicKey],
+        })
+      )
+      .sendAndConfirm(buyerUmi);
+
+  await drawItem();
+  await drawItem();
+
+  // Each prize records the price of the phase it was drawn in
+  const [unclaimedPrizes] = findUnclaimedPrizesPda(sellerUmi, {
+    jellybeanMachine,
+    buyer: buyer.publicKey,
+  });
+  const { prizes } = await fetchUnclaimedPrizes(sellerUmi, unclaimedPrizes);
+  t.deepEqual(
+    prizes.map((prize) => prize.price),
+    [
+      DEFAULT_SOL_PAYMENT_LAMPORTS.basisPoints,
+      DEFAULT_SOL_PAYMENT_LAMPORTS.basisPoints,
+    ]
+  );
+  t.like(
+    await fetchJellybeanMachineWithItems(sellerUmi, jellybeanMachine),
+    <JellybeanMachineAccountWithItemsData>{
+      unclaimedPrice: DEFAULT_SOL_PAYMENT_LAMPORTS.basisPoints * 2n,
+    }
+  );
+
+  // A claimed prize is no longer refunded
+  const [claimedPrize, refundedPrize] = prizes;
+  await claimCoreItem(buyerUmi, {
+    jellybeanMachine,
+    buyer: buyer.publicKey,
+    index: claimedPrize.itemIndex,
+    asset: assets[claimedPrize.itemIndex],
+  }).sendAndConfirm(buyerUmi);
+
+  const [authorityPda] = findAuthorityPda(sellerUmi, { jellybeanMachine });
+  const authorityPdaBalance = await sellerUmi.rpc.getBalance(authorityPda);
+
+  await cancelSale(sellerUmi, { jellybeanMachine }).sendAndConfirm(sellerUmi);
+
+  // The price of the only unclaimed prize is escrowed in the authority pda
+  t.true(
+    isEqualToAmount(
+      await sellerUmi.rpc.getBalance(authorityPda),
+      addAmounts(authorityPdaBalance, DEFAULT_SOL_PAYMENT_LAMPORTS)
+    )
+  );
+
+  let jellybeanMachineAccount = await fetchJellybeanMachineWithItems(
+    sellerUmi,
+    jellybeanMachine
+  );
+  t.like(jellybeanMachineAccount, <JellybeanMachineAccountWithItemsData>{
+    state: JellybeanState.Cancelled,
+    unclaimedPrice: DEFAULT_SOL_PAYMENT_LAMPORTS.basisPoints,
+    supplyRedeemed: 2n,
+  });
+
+  const unclaimedPrizesRent = await sellerUmi.rpc.getBalance(unclaimedPrizes);
+  const buyerBalance = await sellerUmi.rpc.getBalance(buyer.publicKey);
+
+  // Anyone can send the refund, it always goes to the buyer
+  await refundPrize(sellerUmi, {
+    jellybeanMachine,
+    buyer: buyer.publicKey,
+    item: assets[refundedPrize.itemIndex],
+    index: refundedPrize.itemIndex,
+  }).sendAndConfirm(sellerUmi);
+
+  t.true(
+    isEqualToAmount(
+      await sellerUmi.rpc.getBalance(buyer.publicKey),
+      addAmounts(
+        addAmounts(buyerBalance, DEFAULT_SOL_PAYMENT_LAMPORTS),
+        unclaimedPrizesRent
+      )
+    )
+  );
+  t.true(
+    isEqualToAmount(
+      await sellerUmi.rpc.getBalance(authorityPda),
+      authorityPdaBalance
+    )
+  );
+  t.falsy(await safeFetchUnclaimedPrizes(sellerUmi, unclaimedPrizes));
+
+  jellybeanMachineAccount = await fetchJellybeanMachineWithItems(
+    sellerUmi,
+    jellybeanMachine
+  );
+  t.like(jellybeanMachineAccount, <JellybeanMachineAccountWithItemsData>{
+    unclaimedPrice: 0n,
+    supplyRedeemed: 1n,
+  });
+
+  // The prize can only be refunded once
+  const promise = refundPrize(sellerUmi, {
+    jellybeanMachine,
+    buyer: buyer.publicKey,
+    item: assets[refundedPrize.itemIndex],
+    index: refundedPrize.itemIndex,
+  }).sendAndConfirm(sellerUmi);
+
+  await t.throwsAsync(promise, { message: /AccountNotInitialized/ });
+});
+
+test('it does not refund a price charged by a wrapping program', async (t) => {
+  const sellerUmi = await createUmi();
+  const assetSigner = await createCoreAsset(sellerUmi);
+
+  const jellybeanMachine = await create(sellerUmi, {
+    items: [
+      {
+        asset: assetSigner.publicKey,
+      },
+    ],
+    startSale: true,
+  });
+
+  const buyer = await generateSignerWithSol(sellerUmi);
+  const buyerUmi = await createUmi(buyer);
+
+  await drawJellybean(buyerUmi, {
+    jellybeanMachine,
+    mintArgs: {
+      solPayment: some({
+        feeAccounts: [sellerUmi.identity.publicKey],
+      }),
+    },
+  }).sendAndConfirm(buyerUmi);
+
+  const [unclaimedPrizes] = findUnclaimedPrizesPda(sellerUmi, {
+    jellybeanMachine,
+    buyer: buyer.publicKey,
+  });
+  t.is(
+    (await fetchUnclaimedPrizes(sellerUmi, unclaimedPrizes)).prizes[0].price,
+    0n
+  );
+
+  const [authorityPda] = findAuthorityPda(sellerUmi, { jellybeanMachine });
+  const authorityPdaBalance = await sellerUmi.rpc.getBalance(authorityPda);
+
+  await cancelSale(sellerUmi, { jellybeanMachine }).sendAndConfirm(sellerUmi);
+
+  // The gumball guard paid the fee accounts, the machine holds nothing to refund
+  t.true(
+    isEqualToAmount(
+      await sellerUmi.rpc.getBalance(authorityPda),
+      authorityPdaBalance
+    )
+  );
+
+  const unclaimedPrizesRent = await sellerUmi.rpc.getBalance(unclaimedPrizes);
+  const buyerBalance = await sellerUmi.rpc.getBalance(buyer.publicKey);
+
+  await refundPrize(sellerUmi, {
+    jellybeanMachine,
+    buyer: buyer.publicKey,
+    item: assetSigner.publicKey,
+    index: 0,
+  }).sendAndConfirm(sellerUmi);
+
+  t.true(
+    isEqualToAmount(
+      await sellerUmi.rpc.getBalance(buyer.publicKey),
+      addAmounts(buyerBalance, unclaimedPrizesRent)
+    )
+  );
+});
+
+test('it cannot refund a prize before the sale is cancelled', async (t) => {
+  const sellerUmi = await createUmi();
+  const assetSigner = await createCoreAsset(sellerUmi);
+
+  const jellybeanMachine = await create(sellerUmi, {
+    items: [
+      {
+        asset: assetSigner.publicKey,
+      },
+    ],
+    startSale: true,
+  });
+
+  const buyer = await generateSignerWithSol(sellerUmi);
+  const buyerUmi = await createUmi(buyer);
+
+  await drawJellybean(buyerUmi, {
+    jellybeanMachine,
+    mintArgs: {
+      solPayment: some({
+        feeAccounts: [sellerUmi.identity.publicKey],
+      }),
+    },
+  }).sendAndConfirm(buyerUmi);
+
+  const promise = refundPrize(buyerUmi, {
+    jellybeanMachine,
+    buyer: buyer.publicKey,
+    item: assetSigner.publicKey,
+    index: 0,
+  }).sendAndConfirm(buyerUmi);
+
+  await t.throwsAsync(promise, { message: /InvalidState/ });
+});
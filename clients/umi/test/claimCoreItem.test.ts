@@ -1,5 +1,10 @@
 import { drawJellybean } from '@mallow-labs/mallow-gumball';
-import { AssetV1, fetchAsset } from '@metaplex-foundation/mpl-core';
+import {
+  AssetV1,
+  createV1,
+  fetchAsset,
+  fetchCollection,
+} from '@metaplex-foundation/mpl-core';
 import { transferSol } from '@metaplex-foundation/mpl-toolbox';
 import {
   generateSigner,
@@ -13,10 +18,15 @@ import { generateSignerWithSol } from '@metaplex-foundation/umi-bundle-tests';
 import test from 'ava';
 import {
   claimCoreItem,
+  endSale,
   fetchJellybeanMachineWithItems,
   fetchUnclaimedPrizesFromSeeds,
+  findAuthorityPda,
   JellybeanMachineAccountWithItemsData,
   JellybeanState,
+  provenanceAuthority,
+  removeCoreItem,
+  revealItems,
   safeFetchUnclaimedPrizesFromSeeds,
   UnclaimedPrizes,
 } from '../src';
@@ -27,6 +37,7 @@ import {
   createUmi,
   DEFAULT_MAX_SUPPLY,
   defaultAssetData,
+  getDefaultFeeAccounts,
 } from './_setup';
 
 test('it can claim an edition', async (t) => {
@@ -114,6 +125,408 @@ test('it can claim an edition', async (t) => {
   });
 });
 
+test('it records provenance attributes on a printed edition', async (t) => {
+  const sellerUmi = await createUmi();
+  const collectionSigner = await createMasterEdition(sellerUmi);
+
+  const jellybeanMachine = await create(sellerUmi, {
+    args: {
+      uri: 'https://example.com',
+      feeAccounts: [
+        {
+          address: sellerUmi.identity.publicKey,
+          basisPoints: 10000,
+        },
+      ],
+      provenanceConfig: {
+        authority: provenanceAuthority('None'),
+      },
+    },
+    items: [
+      {
+        collection: collectionSigner.publicKey,
+      },
+    ],
+    startSale: true,
+  });
+
+  const buyer = await generateSignerWithSol(sellerUmi);
+  const buyerUmi = await createUmi(buyer);
+
+  await drawJellybean(buyerUmi, {
+    jellybeanMachine,
+    mintArgs: {
+      solPayment: some({
+        feeAccounts: [sellerUmi.identity.publicKey],
+      }),
+    },
+  }).sendAndConfirm(buyerUmi);
+
+  const unclaimedPrizes = await fetchUnclaimedPrizesFromSeeds(sellerUmi, {
+    jellybeanMachine,
+    buyer: buyer.publicKey,
+  });
+  const prize = unclaimedPrizes.prizes[0];
+  t.true(prize.drawSlot > 0n);
+
+  const printAsset = generateSigner(buyerUmi);
+  await claimCoreItem(buyerUmi, {
+    jellybeanMachine,
+    buyer: buyer.publicKey,
+    collection: collectionSigner.publicKey,
+    index: 0,
+    printAsset,
+  }).sendAndConfirm(buyerUmi);
+
+  const printedAsset = await fetchAsset(sellerUmi, printAsset.publicKey);
+  t.like(printedAsset, <AssetV1>{
+    attributes: {
+      authority: { type: 'None' },
+      attributeList: [
+        { key: 'jellybean_machine', value: jellybeanMachine.toString() },
+        { key: 'draw_slot', value: prize.drawSlot.toString() },
+        { key: 'edition', value: prize.editionNumber.toString() },
+        { key: 'tier', value: 'edition' },
+      ],
+    },
+  });
+});
+
+test('it records provenance attributes on a one of one asset', async (t) => {
+  const sellerUmi = await createUmi();
+  const jellybeanMachineSigner = generateSigner(sellerUmi);
+  const [authorityPda] = findAuthorityPda(sellerUmi, {
+    jellybeanMachine: jellybeanMachineSigner.publicKey,
+  });
+
+  // The authority pda must be able to update the asset to write its provenance
+  const assetSigner = await createCoreAsset(sellerUmi, {
+    plugins: [
+      {
+        type: 'UpdateDelegate',
+        additionalDelegates: [],
+        authority: { type: 'Address', address: authorityPda },
+      },
+    ],
+  });
+
+  const jellybeanMachine = await create(sellerUmi, {
+    jellybeanMachine: jellybeanMachineSigner,
+    args: {
+      uri: 'https://example.com',
+      feeAccounts: [
+        {
+          address: sellerUmi.identity.publicKey,
+          basisPoints: 10000,
+        },
+      ],
+      provenanceConfig: {
+        authority: provenanceAuthority('UpdateAuthority'),
+      },
+    },
+    items: [
+      {
+        asset: assetSigner.publicKey,
+      },
+    ],
+    startSale: true,
+  });
+
+  const buyer = await generateSignerWithSol(sellerUmi);
+  const buyerUmi = await createUmi(buyer);
+
+  await drawJellybean(buyerUmi, {
+    jellybeanMachine,
+    mintArgs: {
+      solPayment: some({
+        feeAccounts: [sellerUmi.identity.publicKey],
+      }),
+    },
+  }).sendAndConfirm(buyerUmi);
+
+  const unclaimedPrizes = await fetchUnclaimedPrizesFromSeeds(sellerUmi, {
+    jellybeanMachine,
+    buyer: buyer.publicKey,
+  });
+  const prize = unclaimedPrizes.prizes[0];
+
+  await claimCoreItem(buyerUmi, {
+    jellybeanMachine,
+    buyer: buyer.publicKey,
+    asset: assetSigner.publicKey,
+    index: 0,
+  }).sendAndConfirm(buyerUmi);
+
+  const asset = await fetchAsset(sellerUmi, assetSigner.publicKey);
+  t.like(asset, <AssetV1>{
+    owner: buyer.publicKey,
+    attributes: {
+      authority: { type: 'UpdateAuthority' },
+      attributeList: [
+        { key: 'jellybean_machine', value: jellybeanMachine.toString() },
+        { key: 'draw_slot', value: prize.drawSlot.toString() },
+        { key: 'tier', value: 'one_of_one' },
+      ],
+    },
+  });
+});
+
+test('it cannot load an asset the authority pda cannot update when provenance is enabled', async (t) => {
+  const sellerUmi = await createUmi();
+  const assetSigner = await createCoreAsset(sellerUmi);
+
+  const promise = create(sellerUmi, {
+    args: {
+      uri: 'https://example.com',
+      feeAccounts: getDefaultFeeAccounts(sellerUmi.identity.publicKey),
+      provenanceConfig: {
+        authority: provenanceAuthority('None'),
+      },
+    },
+    items: [
+      {
+        asset: assetSigner.publicKey,
+      },
+    ],
+  });
+
+  await t.throwsAsync(promise, {
+    message: /MachineCannotUpdateAsset/,
+  });
+});
+
+test('it prints an edition with the placeholder uri in reveal mode', async (t) => {
+  const sellerUmi = await createUmi();
+  const collectionSigner = await createMasterEdition(sellerUmi);
+
+  const jellybeanMachine = await create(sellerUmi, {
+    args: {
+      uri: 'https://example.com',
+      feeAccounts: [
+        {
+          address: sellerUmi.identity.publicKey,
+          basisPoints: 10000,
+        },
+      ],
+      revealConfig: {
+        placeholderUri: 'https://example.com/mystery-box.json',
+      },
+    },
+    items: [
+      {
+        collection: collectionSigner.publicKey,
+      },
+    ],
+    startSale: true,
+  });
+
+  const buyer = await generateSignerWithSol(sellerUmi);
+  const buyerUmi = await createUmi(buyer);
+
+  await drawJellybean(buyerUmi, {
+    jellybeanMachine,
+    mintArgs: {
+      solPayment: some({
+        feeAccounts: [sellerUmi.identity.publicKey],
+      }),
+    },
+  }).sendAndConfirm(buyerUmi);
+
+  const printAsset = generateSigner(buyerUmi);
+  await claimCoreItem(buyerUmi, {
+    jellybeanMachine,
+    buyer: buyer.publicKey,
+    collection: collectionSigner.publicKey,
+    index: 0,
+    printAsset,
+  }).sendAndConfirm(buyerUmi);
+
+  const printedAsset = await fetchAsset(sellerUmi, printAsset.publicKey);
+  t.like(printedAsset, <AssetV1>{
+    owner: buyer.publicKey,
+    uri: 'https://example.com/mystery-box.json',
+  });
+});
+
+test('it can reveal prints after their master edition is removed', async (t) => {
+  const sellerUmi = await createUmi();
+  const collectionSigner = await createMasterEdition(sellerUmi);
+
+  const jellybeanMachine = await create(sellerUmi, {
+    args: {
+      uri: 'https://example.com',
+      feeAccounts: getDefaultFeeAccounts(sellerUmi.identity.publicKey),
+      revealConfig: {
+        placeholderUri: 'https://example.com/mystery-box.json',
+      },
+    },
+    items: [
+      {
+        collection: collectionSigner.publicKey,
+      },
+    ],
+    startSale: true,
+  });
+
+  const buyer = await generateSignerWithSol(sellerUmi);
+  const buyerUmi = await createUmi(buyer);
+
+  await drawJellybean(buyerUmi, {
+    jellybeanMachine,
+    mintArgs: {
+      solPayment: some({
+        feeAccounts: [sellerUmi.identity.publicKey],
+      }),
+    },
+  }).sendAndConfirm(buyerUmi);
+
+  const printAsset = generateSigner(buyerUmi);
+  await claimCoreItem(buyerUmi, {
+    jellybeanMachine,
+    buyer: buyer.publicKey,
+    collection: collectionSigner.publicKey,
+    index: 0,
+    printAsset,
+  }).sendAndConfirm(buyerUmi);
+
+  await endSale(sellerUmi, { jellybeanMachine })
+    .add(
+      removeCoreItem(sellerUmi, {
+        jellybeanMachine,
+        collection: collectionSigner.publicKey,
+        index: 0,
+      })
+    )
+    .sendAndConfirm(sellerUmi);
+
+  // The master edition is returned to the seller
+  const collection = await fetchCollection(
+    sellerUmi,
+    collectionSigner.publicKey
+  );
+  t.is(collection.updateAuthority, sellerUmi.identity.publicKey);
+
+  await revealItems(sellerUmi, {
+    jellybeanMachine,
+    uris: ['https://example.com/revealed.json'],
+  })
+    .addRemainingAccounts([
+      { pubkey: printAsset.publicKey, isSigner: false, isWritable: true },
+      {
+        pubkey: collectionSigner.publicKey,
+        isSigner: false,
+        isWritable: true,
+      },
+    ])
+    .sendAndConfirm(sellerUmi);
+
+  const printedAsset = await fetchAsset(sellerUmi, printAsset.publicKey);
+  t.like(printedAsset, <AssetV1>{
+    owner: buyer.publicKey,
+    uri: 'https://example.com/revealed.json',
+  });
+});
+
+test('it cannot load an asset the authority pda cannot update in reveal mode', async (t) => {
+  const sellerUmi = await createUmi();
+  const assetSigner = await createCoreAsset(sellerUmi);
+
+  const promise = create(sellerUmi, {
+    args: {
+      uri: 'https://example.com',
+      feeAccounts: getDefaultFeeAccounts(sellerUmi.identity.publicKey),
+      revealConfig: {
+        placeholderUri: 'https://example.com/mystery-box.json',
+      },
+    },
+    items: [
+      {
+        asset: assetSigner.publicKey,
+      },
+    ],
+  });
+
+  await t.throwsAsync(promise, {
+    message: /MachineCannotUpdateAsset/,
+  });
+});
+
+test('it continues edition numbers after existing prints', async (t) => {
+  const sellerUmi = await createUmi();
+  const collectionSigner = await createMasterEdition(sellerUmi);
+
+  // Airdrop the first two editions before loading the master edition
+  for (let i = 0; i < 2; i += 1) {
+    await createV1(sellerUmi, {
+      asset: generateSigner(sellerUmi),
+      collection: collectionSigner.publicKey,
+      name: 'My Asset',
+      uri: 'https://example.com/my-asset.json',
+    }).sendAndConfirm(sellerUmi);
+  }
+
+  const jellybeanMachine = await create(sellerUmi, {
+    items: [
+      {
+        collection: collectionSigner.publicKey,
+      },
+    ],
+    startSale: true,
+  });
+
+  const buyer = await generateSignerWithSol(sellerUmi);
+  const buyerUmi = await createUmi(buyer);
+
+  await drawJellybean(buyerUmi, {
+    jellybeanMachine,
+    mintArgs: {
+      solPayment: some({
+        feeAccounts: [sellerUmi.identity.publicKey],
+      }),
+    },
+  }).sendAndConfirm(buyerUmi);
+
+  const unclaimedPrizes = await fetchUnclaimedPrizesFromSeeds(sellerUmi, {
+    jellybeanMachine,
+    buyer: buyer.publicKey,
+  });
+  t.is(unclaimedPrizes.prizes[0].editionNumber, 3);
+
+  const printAsset = generateSigner(buyerUmi);
+  await claimCoreItem(buyerUmi, {
+    jellybeanMachine,
+    buyer: buyer.publicKey,
+    collection: collectionSigner.publicKey,
+    index: 0,
+    printAsset,
+  }).sendAndConfirm(buyerUmi);
+
+  const jellybeanMachineAccount = await fetchJellybeanMachineWithItems(
+    sellerUmi,
+    jellybeanMachine
+  );
+  t.like(jellybeanMachineAccount, <JellybeanMachineAccountWithItemsData>{
+    supplyLoaded: BigInt(DEFAULT_MAX_SUPPLY),
+    supplyRedeemed: 3n,
+    items: [
+      {
+        index: 0,
+        supplyLoaded: DEFAULT_MAX_SUPPLY,
+        supplyRedeemed: 3,
+        supplyClaimed: 3,
+      },
+    ],
+  });
+
+  const printedAsset = await fetchAsset(sellerUmi, printAsset.publicKey);
+  t.like(printedAsset, <AssetV1>{
+    owner: buyer.publicKey,
+    edition: {
+      number: 3,
+    },
+  });
+});
+
 test('it can claim a one of one asset', async (t) => {
   const sellerUmi = await createUmi();
   const assetSigner = await createCoreAsset(sellerUmi);
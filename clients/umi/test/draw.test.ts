@@ -1,19 +1,30 @@
 import { drawJellybean } from '@mallow-labs/mallow-gumball';
 import {
+  addAmounts,
   generateSigner,
   isEqualToAmount,
   lamports,
+  sol,
   some,
+  subtractAmounts,
 } from '@metaplex-foundation/umi';
 import { generateSignerWithSol } from '@metaplex-foundation/umi-bundle-tests';
 import test from 'ava';
 import {
+  draw,
   endSale,
   fetchJellybeanMachineWithItems,
   fetchUnclaimedPrizesFromSeeds,
+  findAuthorityPda,
+  findGuardPhasesPda,
+  ItemKind,
   JellybeanMachineAccountWithItemsData,
   JellybeanState,
+  removeCoreItem,
+  safeFetchUnclaimedPrizesFromSeeds,
+  setGuardPhases,
   UnclaimedPrizes,
+  withdraw,
 } from '../src';
 import {
   create,
@@ -21,6 +32,10 @@ import {
   createMasterEdition,
   createUmi,
   DEFAULT_MAX_SUPPLY,
+  getGuardAccounts,
+  guardPhase,
+  tomorrow,
+  yesterday,
 } from './_setup';
 
 test('it can draw an item from a master edition', async (t) => {
@@ -383,7 +398,7 @@ test('it initializes unclaimed prizes account on first draw', async (t) => {
   });
 
   t.like(unclaimedPrizes, <UnclaimedPrizes>{
-    version: 0,
+    version: 1,
     jellybeanMachine,
     buyer: buyer.publicKey,
   });
@@ -612,3 +627,412 @@ test('it fails when providing the wrong print fee account', async (t) => {
     { message: /Invalid print fee account/ }
   );
 });
+
+test('it transfers print fee for a master edition with a single print', async (t) => {
+  const sellerUmi = await createUmi();
+  const collectionSigner = await createMasterEdition(sellerUmi, {
+    maxSupply: 1,
+  });
+  const feeAccount = generateSigner(sellerUmi);
+
+  const jellybeanMachine = await create(sellerUmi, {
+    args: {
+      printFeeConfig: {
+        address: feeAccount.publicKey,
+        amount: 11_000_000,
+      },
+    },
+    items: [
+      {
+        collection: collectionSigner.publicKey,
+      },
+    ],
+    startSale: true,
+  });
+
+  t.like(
+    await fetchJellybeanMachineWithItems(sellerUmi, jellybeanMachine),
+    <JellybeanMachineAccountWithItemsData>{
+      items: [{ supplyLoaded: 1, kind: ItemKind.MasterEdition }],
+    }
+  );
+
+  const buyer = await generateSignerWithSol(sellerUmi);
+  const buyerUmi = await createUmi(buyer);
+
+  await drawJellybean(buyerUmi, {
+    jellybeanMachine,
+    mintArgs: {
+      solPayment: some({
+        feeAccounts: [sellerUmi.identity.publicKey],
+      }),
+    },
+    printFeeAccount: feeAccount.publicKey,
+  }).sendAndConfirm(buyerUmi);
+
+  const feeAccountBalance = await sellerUmi.rpc.getBalance(
+    feeAccount.publicKey
+  );
+  t.true(isEqualToAmount(feeAccountBalance, lamports(11_000_000)));
+});
+
+test('it fails when the print fee account is missing', async (t) => {
+  const sellerUmi = await createUmi();
+  const collectionSigner = await createMasterEdition(sellerUmi);
+
+  const jellybeanMachine = await create(sellerUmi, {
+    args: {
+      printFeeConfig: {
+        address: generateSigner(sellerUmi).publicKey,
+        amount: 11_000_000,
+      },
+    },
+    items: [
+      {
+        collection: collectionSigner.publicKey,
+      },
+    ],
+    startSale: true,
+  });
+
+  const buyer = await generateSignerWithSol(sellerUmi);
+  const buyerUmi = await createUmi(buyer);
+
+  await t.throwsAsync(
+    () =>
+      drawJellybean(buyerUmi, {
+        jellybeanMachine,
+        mintArgs: {
+          solPayment: some({
+            feeAccounts: [sellerUmi.identity.publicKey],
+          }),
+        },
+      }).sendAndConfirm(buyerUmi),
+    { message: /MissingPrintFeeAccount/ }
+  );
+});
+
+test('it goes live on the first draw after go_live_at', async (t) => {
+  const sellerUmi = await createUmi();
+  const collectionSigner = await createMasterEdition(sellerUmi);
+
+  const jellybeanMachine = await create(sellerUmi, {
+    args: {
+      goLiveAt: yesterday(),
+    },
+    items: [
+      {
+        collection: collectionSigner.publicKey,
+      },
+    ],
+    startSale: false, // Goes live on its own
+  });
+
+  const buyer = await generateSignerWithSol(sellerUmi);
+  const buyerUmi = await createUmi(buyer);
+
+  await drawJellybean(buyerUmi, {
+    jellybeanMachine,
+    mintArgs: {
+      solPayment: some({
+        feeAccounts: [sellerUmi.identity.publicKey],
+      }),
+    },
+  }).sendAndConfirm(buyerUmi);
+
+  const jellybeanMachineAccount = await fetchJellybeanMachineWithItems(
+    sellerUmi,
+    jellybeanMachine
+  );
+  t.like(jellybeanMachineAccount, <JellybeanMachineAccountWithItemsData>{
+    supplyRedeemed: 1n,
+    state: JellybeanState.SaleLive,
+  });
+});
+
+test('it fails to draw before go_live_at', async (t) => {
+  const sellerUmi = await createUmi();
+  const collectionSigner = await createMasterEdition(sellerUmi);
+
+  const jellybeanMachine = await create(sellerUmi, {
+    args: {
+      goLiveAt: tomorrow(),
+    },
+    items: [
+      {
+        collection: collectionSigner.publicKey,
+      },
+    ],
+    startSale: true,
+  });
+
+  const buyer = await generateSignerWithSol(sellerUmi);
+  const buyerUmi = await createUmi(buyer);
+
+  await t.throwsAsync(
+    () =>
+      drawJellybean(buyerUmi, {
+        jellybeanMachine,
+        mintArgs: {
+          solPayment: some({
+            feeAccounts: [sellerUmi.identity.publicKey],
+          }),
+        },
+      }).sendAndConfirm(buyerUmi),
+    { message: /SaleNotStarted/ }
+  );
+});
+
+test('it fails to draw after end_at', async (t) => {
+  const sellerUmi = await createUmi();
+  const collectionSigner = await createMasterEdition(sellerUmi);
+
+  const jellybeanMachine = await create(sellerUmi, {
+    args: {
+      endAt: yesterday(),
+    },
+    items: [
+      {
+        collection: collectionSigner.publicKey,
+      },
+    ],
+    startSale: true,
+  });
+
+  const buyer = await generateSignerWithSol(sellerUmi);
+  const buyerUmi = await createUmi(buyer);
+
+  await t.throwsAsync(
+    () =>
+      drawJellybean(buyerUmi, {
+        jellybeanMachine,
+        mintArgs: {
+          solPayment: some({
+            feeAccounts: [sellerUmi.identity.publicKey],
+          }),
+        },
+      }).sendAndConfirm(buyerUmi),
+    { message: /SaleOver/ }
+  );
+});
+
+test('it charges the bot tax instead of failing a draw before go_live_at', async (t) => {
+  const sellerUmi = await createUmi();
+  const collectionSigner = await createMasterEdition(sellerUmi);
+  const feeAccount = generateSigner(sellerUmi).publicKey;
+
+  const jellybeanMachine = await create(sellerUmi, {
+    args: {
+      feeAccounts: [{ address: feeAccount, basisPoints: 10000 }],
+      goLiveAt: tomorrow(),
+      botTax: some(sol(0.01).basisPoints),
+      publicDraw: true,
+    },
+    items: [
+      {
+        collection: collectionSigner.publicKey,
+      },
+    ],
+    guards: {},
+    phases: [guardPhase()],
+    startSale: true,
+  });
+
+  const buyer = await generateSignerWithSol(sellerUmi);
+  const buyerUmi = await createUmi(buyer);
+  const machineBalance = await sellerUmi.rpc.getBalance(jellybeanMachine);
+
+  const { signature } = await draw(buyerUmi, {
+    jellybeanMachine,
+    mintAuthority: sellerUmi.identity.publicKey,
+  })
+    .addRemainingAccounts(
+      getGuardAccounts(buyerUmi, jellybeanMachine, {
+        buyer: buyer.publicKey,
+        feeAccounts: [feeAccount],
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+
+  const transaction = await buyerUmi.rpc.getTransaction(signature);
+  t.regex(
+    transaction!.meta.logs.join(''),
+    /Jellybean Machine Botting is taxed.*SaleNotStarted/
+  );
+
+  // The tax goes to the machine and the price isn't paid
+  t.true(
+    isEqualToAmount(
+      await sellerUmi.rpc.getBalance(jellybeanMachine),
+      addAmounts(machineBalance, sol(0.01))
+    )
+  );
+  t.true(
+    isEqualToAmount(await sellerUmi.rpc.getBalance(feeAccount), lamports(0))
+  );
+
+  // The buyer account created by the draw is closed again
+  t.falsy(
+    await safeFetchUnclaimedPrizesFromSeeds(sellerUmi, {
+      jellybeanMachine,
+      buyer: buyer.publicKey,
+    })
+  );
+
+  const jellybeanMachineAccount = await fetchJellybeanMachineWithItems(
+    sellerUmi,
+    jellybeanMachine
+  );
+  t.like(jellybeanMachineAccount, <JellybeanMachineAccountWithItemsData>{
+    supplyRedeemed: 0n,
+    state: JellybeanState.SaleLive,
+  });
+
+  // The authority receives the tax with the rent of the accounts it withdraws
+  const sweptBalance = [
+    await sellerUmi.rpc.getBalance(jellybeanMachine),
+    await sellerUmi.rpc.getBalance(
+      findGuardPhasesPda(sellerUmi, { jellybeanMachine })[0]
+    ),
+    await sellerUmi.rpc.getBalance(
+      findAuthorityPda(sellerUmi, { jellybeanMachine })[0]
+    ),
+  ].reduce(addAmounts);
+  const authorityBalance = await sellerUmi.rpc.getBalance(
+    sellerUmi.identity.publicKey
+  );
+
+  const { signature: withdrawSignature } = await endSale(sellerUmi, {
+    jellybeanMachine,
+  })
+    .add(
+      removeCoreItem(sellerUmi, {
+        jellybeanMachine,
+        collection: collectionSigner.publicKey,
+        index: 0,
+      })
+    )
+    .add(withdraw(sellerUmi, { jellybeanMachine }))
+    .sendAndConfirm(sellerUmi);
+  const withdrawTransaction =
+    await sellerUmi.rpc.getTransaction(withdrawSignature);
+
+  t.true(
+    isEqualToAmount(
+      await sellerUmi.rpc.getBalance(sellerUmi.identity.publicKey),
+      subtractAmounts(
+        addAmounts(authorityBalance, sweptBalance),
+        withdrawTransaction!.meta.fee
+      )
+    )
+  );
+});
+
+test('it can draw from a public machine without the mint authority signing', async (t) => {
+  const sellerUmi = await createUmi();
+  const assetSigner = await createCoreAsset(sellerUmi);
+
+  const jellybeanMachine = await create(sellerUmi, {
+    args: {
+      publicDraw: true,
+    },
+    items: [
+      {
+        asset: assetSigner.publicKey,
+      },
+    ],
+    guards: {},
+    phases: [guardPhase()],
+    startSale: true,
+  });
+
+  const buyer = await generateSignerWithSol(sellerUmi);
+  const buyerUmi = await createUmi(buyer);
+
+  await draw(buyerUmi, {
+    jellybeanMachine,
+    mintAuthority: sellerUmi.identity.publicKey,
+  })
+    .addRemainingAccounts(
+      getGuardAccounts(buyerUmi, jellybeanMachine, {
+        buyer: buyer.publicKey,
+        feeAccounts: [sellerUmi.identity.publicKey],
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+
+  const jellybeanMachineAccount = await fetchJellybeanMachineWithItems(
+    sellerUmi,
+    jellybeanMachine
+  );
+  t.like(jellybeanMachineAccount, <JellybeanMachineAccountWithItemsData>{
+    publicDraw: true,
+    supplyRedeemed: 1n,
+  });
+});
+
+test('it cannot enable public draws without paid guard phases', async (t) => {
+  const umi = await createUmi();
+  const assetSigner = await createCoreAsset(umi);
+
+  await t.throwsAsync(
+    create(umi, {
+      args: {
+        publicDraw: true,
+      },
+      items: [
+        {
+          asset: assetSigner.publicKey,
+        },
+      ],
+      guards: {},
+    }),
+    { message: /PublicDrawWithoutPrice/ }
+  );
+
+  const jellybeanMachine = await create(umi, {
+    args: {
+      publicDraw: true,
+    },
+    items: [
+      {
+        asset: assetSigner.publicKey,
+      },
+    ],
+    guards: {},
+    phases: [guardPhase()],
+  });
+
+  // Every phase of a public machine must charge a price
+  const promise = setGuardPhases(umi, {
+    jellybeanMachine,
+    phases: [guardPhase(), guardPhase({ startAt: tomorrow(), price: 0 })],
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(promise, { message: /PublicDrawWithoutPrice/ });
+});
+
+test('it fails to draw without the mint authority signing when draws are not public', async (t) => {
+  const sellerUmi = await createUmi();
+  const assetSigner = await createCoreAsset(sellerUmi);
+
+  const jellybeanMachine = await create(sellerUmi, {
+    items: [
+      {
+        asset: assetSigner.publicKey,
+      },
+    ],
+    guards: {},
+    startSale: true,
+  });
+
+  const buyer = await generateSignerWithSol(sellerUmi);
+  const buyerUmi = await createUmi(buyer);
+
+  const promise = draw(buyerUmi, {
+    jellybeanMachine,
+    mintAuthority: sellerUmi.identity.publicKey,
+  }).sendAndConfirm(buyerUmi);
+
+  await t.throwsAsync(promise, { message: /InvalidMintAuthority/ });
+});
@@ -0,0 +1,238 @@
+import { createCollection, createV1 } from '@metaplex-foundation/mpl-core';
+import { fetchToken } from '@metaplex-foundation/mpl-toolbox';
+import {
+  AccountMeta,
+  generateSigner,
+  PublicKey,
+  some,
+  Umi,
+} from '@metaplex-foundation/umi';
+import { generateSignerWithSol } from '@metaplex-foundation/umi-bundle-tests';
+import test from 'ava';
+import {
+  draw,
+  DrawGateAction,
+  drawGateKind,
+  fetchUnclaimedPrizesFromSeeds,
+  fetchUsedGateAsset,
+  findUsedGateAssetPda,
+  UsedGateAsset,
+} from '../src';
+import {
+  create,
+  createMasterEdition,
+  createMintWithHolders,
+  createUmi,
+  getGuardAccounts,
+  guardPhase,
+} from './_setup';
+
+const drawWithGate = (
+  buyerUmi: Umi,
+  sellerUmi: Umi,
+  jellybeanMachine: PublicKey,
+  gateAccounts: AccountMeta[]
+) =>
+  draw(buyerUmi, {
+    jellybeanMachine,
+    mintAuthority: sellerUmi.identity.publicKey,
+  })
+    .addRemainingAccounts(
+      getGuardAccounts(buyerUmi, jellybeanMachine, {
+        buyer: buyerUmi.identity.publicKey,
+        gateAccounts,
+        feeAccounts: [sellerUmi.identity.publicKey],
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+
+test('it only lets buyers holding the gate tokens draw', async (t) => {
+  const sellerUmi = await createUmi();
+  const collectionSigner = await createMasterEdition(sellerUmi);
+  const buyer = await generateSignerWithSol(sellerUmi);
+  const buyerUmi = await createUmi(buyer);
+  const otherBuyer = await generateSignerWithSol(sellerUmi);
+  const otherBuyerUmi = await createUmi(otherBuyer);
+
+  const [mint, buyerAta, otherBuyerAta] = await createMintWithHolders(
+    sellerUmi,
+    {
+      holders: [
+        { owner: buyer.publicKey, amount: 1 },
+        { owner: otherBuyer.publicKey, amount: 0 },
+      ],
+    }
+  );
+
+  const jellybeanMachine = await create(sellerUmi, {
+    args: {
+      publicDraw: true,
+    },
+    items: [
+      {
+        collection: collectionSigner.publicKey,
+      },
+    ],
+    guards: {},
+    phases: [
+      guardPhase({
+        gate: some({
+          kind: drawGateKind('Token', { mint: mint.publicKey, amount: 1 }),
+          action: DrawGateAction.Hold,
+        }),
+      }),
+    ],
+    startSale: true,
+  });
+
+  await drawWithGate(buyerUmi, sellerUmi, jellybeanMachine, [
+    { pubkey: buyerAta, isSigner: false, isWritable: false },
+  ]);
+
+  const unclaimedPrizes = await fetchUnclaimedPrizesFromSeeds(sellerUmi, {
+    jellybeanMachine,
+    buyer: buyer.publicKey,
+  });
+  t.is(unclaimedPrizes.prizes.length, 1);
+
+  // Held tokens are only checked
+  t.is((await fetchToken(sellerUmi, buyerAta)).amount, 1n);
+
+  const promise = drawWithGate(otherBuyerUmi, sellerUmi, jellybeanMachine, [
+    { pubkey: otherBuyerAta, isSigner: false, isWritable: false },
+  ]);
+
+  await t.throwsAsync(promise, { message: /MissingGateToken/ });
+});
+
+test('it burns the gate tokens of a draw', async (t) => {
+  const sellerUmi = await createUmi();
+  const collectionSigner = await createMasterEdition(sellerUmi);
+  const buyer = await generateSignerWithSol(sellerUmi);
+  const buyerUmi = await createUmi(buyer);
+
+  const [mint, buyerAta] = await createMintWithHolders(sellerUmi, {
+    holders: [{ owner: buyer.publicKey, amount: 3 }],
+  });
+
+  const jellybeanMachine = await create(sellerUmi, {
+    args: {
+      publicDraw: true,
+    },
+    items: [
+      {
+        collection: collectionSigner.publicKey,
+      },
+    ],
+    guards: {},
+    phases: [
+      guardPhase({
+        gate: some({
+          kind: drawGateKind('Token', { mint: mint.publicKey, amount: 2 }),
+          action: DrawGateAction.Burn,
+        }),
+      }),
+    ],
+    startSale: true,
+  });
+
+  const gateAccounts = [
+    { pubkey: buyerAta, isSigner: false, isWritable: true },
+    { pubkey: mint.publicKey, isSigner: false, isWritable: true },
+    {
+      pubkey: sellerUmi.programs.getPublicKey('splToken'),
+      isSigner: false,
+      isWritable: false,
+    },
+  ];
+
+  await drawWithGate(buyerUmi, sellerUmi, jellybeanMachine, gateAccounts);
+
+  t.is((await fetchToken(sellerUmi, buyerAta)).amount, 1n);
+
+  // The remaining token isn't enough for another draw
+  const promise = drawWithGate(
+    buyerUmi,
+    sellerUmi,
+    jellybeanMachine,
+    gateAccounts
+  );
+
+  await t.throwsAsync(promise, { message: /MissingGateToken/ });
+});
+
+test('it only lets a gate asset be used for one draw', async (t) => {
+  const sellerUmi = await createUmi();
+  const collectionSigner = await createMasterEdition(sellerUmi);
+  const buyer = await generateSignerWithSol(sellerUmi);
+  const buyerUmi = await createUmi(buyer);
+  const otherBuyer = await generateSignerWithSol(sellerUmi);
+  const otherBuyerUmi = await createUmi(otherBuyer);
+
+  const gateCollection = generateSigner(sellerUmi);
+  await createCollection(sellerUmi, {
+    collection: gateCollection,
+    name: 'Gate Collection',
+    uri: 'https://example.com/gate-collection.json',
+  }).sendAndConfirm(sellerUmi);
+
+  const gateAsset = generateSigner(sellerUmi);
+  await createV1(sellerUmi, {
+    asset: gateAsset,
+    collection: gateCollection.publicKey,
+    owner: buyer.publicKey,
+    name: 'Gate Asset',
+    uri: 'https://example.com/gate-asset.json',
+  }).sendAndConfirm(sellerUmi);
+
+  const jellybeanMachine = await create(sellerUmi, {
+    args: {
+      publicDraw: true,
+    },
+    items: [
+      {
+        collection: collectionSigner.publicKey,
+      },
+    ],
+    guards: {},
+    phases: [
+      guardPhase({
+        gate: some({
+          kind: drawGateKind('CoreCollection', {
+            collection: gateCollection.publicKey,
+          }),
+          action: DrawGateAction.MarkUsed,
+        }),
+      }),
+    ],
+    startSale: true,
+  });
+
+  const [usedGateAsset] = findUsedGateAssetPda(sellerUmi, {
+    jellybeanMachine,
+    asset: gateAsset.publicKey,
+  });
+  const gateAccounts = [
+    { pubkey: gateAsset.publicKey, isSigner: false, isWritable: false },
+    { pubkey: usedGateAsset, isSigner: false, isWritable: true },
+  ];
+
+  // Only the owner of the asset can use it
+  await t.throwsAsync(
+    drawWithGate(otherBuyerUmi, sellerUmi, jellybeanMachine, gateAccounts),
+    { message: /InvalidGateAsset/ }
+  );
+
+  await drawWithGate(buyerUmi, sellerUmi, jellybeanMachine, gateAccounts);
+
+  t.like(await fetchUsedGateAsset(sellerUmi, usedGateAsset), <UsedGateAsset>{
+    jellybeanMachine,
+    asset: gateAsset.publicKey,
+    buyer: buyer.publicKey,
+  });
+
+  await t.throwsAsync(
+    drawWithGate(buyerUmi, sellerUmi, jellybeanMachine, gateAccounts),
+    { message: /GateAssetUsed/ }
+  );
+});
@@ -0,0 +1,124 @@
+import { some } from '@metaplex-foundation/umi';
+import { generateSignerWithSol } from '@metaplex-foundation/umi-bundle-tests';
+import test from 'ava';
+import {
+  endScheduledSale,
+  fetchJellybeanMachine,
+  JellybeanState,
+  startSale,
+  updateSettings,
+} from '../src';
+import {
+  create,
+  createMasterEdition,
+  createUmi,
+  getDefaultFeeAccounts,
+  tomorrow,
+  yesterday,
+} from './_setup';
+
+test('it cannot end a scheduled sale before end_at', async (t) => {
+  const sellerUmi = await createUmi();
+  const collectionSigner = await createMasterEdition(sellerUmi);
+
+  const jellybeanMachine = await create(sellerUmi, {
+    args: {
+      endAt: tomorrow(),
+    },
+    items: [{ collection: collectionSigner.publicKey }],
+    startSale: true,
+  });
+
+  const crankUmi = await createUmi(await generateSignerWithSol(sellerUmi));
+  await t.throwsAsync(
+    endScheduledSale(crankUmi, { jellybeanMachine }).sendAndConfirm(crankUmi),
+    { message: /InvalidState/ }
+  );
+
+  t.is(
+    (await fetchJellybeanMachine(sellerUmi, jellybeanMachine)).state,
+    JellybeanState.SaleLive
+  );
+});
+
+test('it cannot end a sale without end_at', async (t) => {
+  const sellerUmi = await createUmi();
+  const collectionSigner = await createMasterEdition(sellerUmi);
+
+  const jellybeanMachine = await create(sellerUmi, {
+    items: [{ collection: collectionSigner.publicKey }],
+    startSale: true,
+  });
+
+  const crankUmi = await createUmi(await generateSignerWithSol(sellerUmi));
+  await t.throwsAsync(
+    endScheduledSale(crankUmi, { jellybeanMachine }).sendAndConfirm(crankUmi),
+    { message: /InvalidState/ }
+  );
+});
+
+test('anyone can end a scheduled sale after end_at', async (t) => {
+  const sellerUmi = await createUmi();
+  const collectionSigner = await createMasterEdition(sellerUmi);
+
+  const jellybeanMachine = await create(sellerUmi, {
+    args: {
+      endAt: yesterday(),
+    },
+    items: [{ collection: collectionSigner.publicKey }],
+    startSale: true,
+  });
+
+  const crankUmi = await createUmi(await generateSignerWithSol(sellerUmi));
+  await endScheduledSale(crankUmi, { jellybeanMachine }).sendAndConfirm(
+    crankUmi
+  );
+
+  t.is(
+    (await fetchJellybeanMachine(sellerUmi, jellybeanMachine)).state,
+    JellybeanState.SaleEnded
+  );
+
+  // An ended sale can't be ended again
+  await t.throwsAsync(
+    endScheduledSale(crankUmi, { jellybeanMachine }).sendAndConfirm(crankUmi),
+    { message: /InvalidState/ }
+  );
+});
+
+test('it can reopen a scheduled sale after moving end_at', async (t) => {
+  const sellerUmi = await createUmi();
+  const collectionSigner = await createMasterEdition(sellerUmi);
+
+  const jellybeanMachine = await create(sellerUmi, {
+    args: {
+      endAt: yesterday(),
+    },
+    items: [{ collection: collectionSigner.publicKey }],
+    startSale: true,
+  });
+
+  await endScheduledSale(sellerUmi, { jellybeanMachine }).sendAndConfirm(
+    sellerUmi
+  );
+
+  // Settings of an ended sale can still be updated to reopen it
+  const endAt = tomorrow();
+  await updateSettings(sellerUmi, {
+    jellybeanMachine,
+    args: {
+      uri: 'https://example.com/jellybean-machine.json',
+      feeAccounts: getDefaultFeeAccounts(sellerUmi.identity.publicKey),
+      endAt,
+    },
+  })
+    .add(startSale(sellerUmi, { jellybeanMachine }))
+    .sendAndConfirm(sellerUmi);
+
+  const jellybeanMachineAccount = await fetchJellybeanMachine(
+    sellerUmi,
+    jellybeanMachine
+  );
+  t.is(jellybeanMachineAccount.state, JellybeanState.SaleLive);
+  t.deepEqual(jellybeanMachineAccount.endAt, some(endAt));
+});
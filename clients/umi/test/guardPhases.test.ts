@@ -0,0 +1,356 @@
+import { getMerkleProof, getMerkleRoot } from '@mallow-labs/mallow-gumball';
+import {
+  generateSigner,
+  isEqualToAmount,
+  sol,
+  some,
+} from '@metaplex-foundation/umi';
+import { generateSignerWithSol } from '@metaplex-foundation/umi-bundle-tests';
+import test from 'ava';
+import {
+  cancelSale,
+  closeGuardRecord,
+  draw,
+  fetchGuardPhasesFromSeeds,
+  fetchUnclaimedPrizesFromSeeds,
+  findAllowlistProofPda,
+  proveAllowlist,
+  safeFetchAllowlistProof,
+  setGuardPhases,
+  withdraw,
+} from '../src';
+import {
+  create,
+  createMasterEdition,
+  createUmi,
+  getGuardAccounts,
+  guardPhase,
+  tomorrow,
+  yesterday,
+} from './_setup';
+
+test('it charges the price of the active phase', async (t) => {
+  const sellerUmi = await createUmi();
+  const collectionSigner = await createMasterEdition(sellerUmi);
+  const feeAccount = generateSigner(sellerUmi).publicKey;
+
+  const jellybeanMachine = await create(sellerUmi, {
+    args: {
+      feeAccounts: [{ address: feeAccount, basisPoints: 10000 }],
+      publicDraw: true,
+    },
+    items: [
+      {
+        collection: collectionSigner.publicKey,
+      },
+    ],
+    guards: {},
+    phases: [
+      guardPhase({ startAt: yesterday(), price: sol(0.2).basisPoints }),
+      guardPhase({ startAt: tomorrow(), price: sol(0.5).basisPoints }),
+    ],
+    startSale: true,
+  });
+
+  const buyer = await generateSignerWithSol(sellerUmi);
+  const buyerUmi = await createUmi(buyer);
+
+  await draw(buyerUmi, {
+    jellybeanMachine,
+    mintAuthority: sellerUmi.identity.publicKey,
+  })
+    .addRemainingAccounts(
+      getGuardAccounts(buyerUmi, jellybeanMachine, {
+        buyer: buyer.publicKey,
+        feeAccounts: [feeAccount],
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+
+  const unclaimedPrizes = await fetchUnclaimedPrizesFromSeeds(sellerUmi, {
+    jellybeanMachine,
+    buyer: buyer.publicKey,
+  });
+  t.is(unclaimedPrizes.prizes.length, 1);
+
+  // Only the first phase has started
+  const feeAccountBalance = await sellerUmi.rpc.getBalance(feeAccount);
+  t.true(isEqualToAmount(feeAccountBalance, sol(0.2)));
+});
+
+test('it cannot draw before the first phase starts', async (t) => {
+  const sellerUmi = await createUmi();
+  const collectionSigner = await createMasterEdition(sellerUmi);
+
+  const jellybeanMachine = await create(sellerUmi, {
+    args: {
+      publicDraw: true,
+    },
+    items: [
+      {
+        collection: collectionSigner.publicKey,
+      },
+    ],
+    guards: {},
+    phases: [guardPhase({ startAt: tomorrow() })],
+    startSale: true,
+  });
+
+  const buyer = await generateSignerWithSol(sellerUmi);
+  const buyerUmi = await createUmi(buyer);
+
+  const promise = draw(buyerUmi, {
+    jellybeanMachine,
+    mintAuthority: sellerUmi.identity.publicKey,
+  })
+    .addRemainingAccounts(
+      getGuardAccounts(buyerUmi, jellybeanMachine, {
+        buyer: buyer.publicKey,
+        feeAccounts: [sellerUmi.identity.publicKey],
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+
+  await t.throwsAsync(promise, { message: /NoActivePhase/ });
+});
+
+test('it only lets buyers who proved they are in the allowlist draw', async (t) => {
+  const sellerUmi = await createUmi();
+  const collectionSigner = await createMasterEdition(sellerUmi);
+  const buyer = await generateSignerWithSol(sellerUmi);
+  const buyerUmi = await createUmi(buyer);
+  const otherBuyer = await generateSignerWithSol(sellerUmi);
+  const otherBuyerUmi = await createUmi(otherBuyer);
+
+  const allowList = [
+    buyer.publicKey,
+    'Ur1CbWSGsXCdedknRbJsEk7urwAvu1uddmQv51nAnXB',
+    'GjwcWFQYzemBtpUoN5fMAP2FZviTtMRWCmrppGuTthJS',
+  ];
+  const merkleRoot = getMerkleRoot(allowList);
+
+  const jellybeanMachine = await create(sellerUmi, {
+    args: {
+      publicDraw: true,
+    },
+    items: [
+      {
+        collection: collectionSigner.publicKey,
+      },
+    ],
+    guards: {},
+    phases: [guardPhase({ allowlistRoot: some(merkleRoot) })],
+    startSale: true,
+  });
+
+  await proveAllowlist(buyerUmi, {
+    jellybeanMachine,
+    merkleRoot,
+    proof: getMerkleProof(allowList, buyer.publicKey),
+  }).sendAndConfirm(buyerUmi);
+
+  await draw(buyerUmi, {
+    jellybeanMachine,
+    mintAuthority: sellerUmi.identity.publicKey,
+  })
+    .addRemainingAccounts(
+      getGuardAccounts(buyerUmi, jellybeanMachine, {
+        buyer: buyer.publicKey,
+        allowlistRoot: merkleRoot,
+        feeAccounts: [sellerUmi.identity.publicKey],
+      })
+    )
+    .sendAndConfirm(buyerUmi);
+
+  const unclaimedPrizes = await fetchUnclaimedPrizesFromSeeds(sellerUmi, {
+    jellybeanMachine,
+    buyer: buyer.publicKey,
+  });
+  t.is(unclaimedPrizes.prizes.length, 1);
+
+  // A buyer outside the allowlist can neither prove it nor draw
+  await t.throwsAsync(
+    proveAllowlist(otherBuyerUmi, {
+      jellybeanMachine,
+      merkleRoot,
+      proof: getMerkleProof(allowList, buyer.publicKey),
+    }).sendAndConfirm(otherBuyerUmi),
+    { message: /NotAllowlisted/ }
+  );
+
+  const promise = draw(otherBuyerUmi, {
+    jellybeanMachine,
+    mintAuthority: sellerUmi.identity.publicKey,
+  })
+    .addRemainingAccounts(
+      getGuardAccounts(otherBuyerUmi, jellybeanMachine, {
+        buyer: otherBuyer.publicKey,
+        allowlistRoot: merkleRoot,
+        feeAccounts: [sellerUmi.identity.publicKey],
+      })
+    )
+    .sendAndConfirm(otherBuyerUmi);
+
+  await t.throwsAsync(promise, { message: /NotAllowlisted/ });
+});
+
+test('it keeps the order of the phases once the sale has started', async (t) => {
+  const umi = await createUmi();
+  const collectionSigner = await createMasterEdition(umi);
+  const firstStart = yesterday();
+  const secondStart = tomorrow();
+
+  const jellybeanMachine = await create(umi, {
+    items: [
+      {
+        collection: collectionSigner.publicKey,
+      },
+    ],
+    guards: {},
+    phases: [guardPhase({ startAt: firstStart })],
+    startSale: true,
+  });
+
+  await t.throwsAsync(
+    setGuardPhases(umi, {
+      jellybeanMachine,
+      phases: [
+        guardPhase({ startAt: secondStart }),
+        guardPhase({ startAt: firstStart }),
+      ],
+    }).sendAndConfirm(umi),
+    { message: /InvalidGuardPhases/ }
+  );
+
+  await t.throwsAsync(
+    setGuardPhases(umi, {
+      jellybeanMachine,
+      phases: [guardPhase({ startAt: secondStart })],
+    }).sendAndConfirm(umi),
+    { message: /GuardPhasesLocked/ }
+  );
+
+  // Phases can still be added after the existing ones
+  await setGuardPhases(umi, {
+    jellybeanMachine,
+    phases: [
+      guardPhase({ startAt: firstStart }),
+      guardPhase({ startAt: secondStart, price: sol(0.5).basisPoints }),
+    ],
+  }).sendAndConfirm(umi);
+
+  const guardPhases = await fetchGuardPhasesFromSeeds(umi, {
+    jellybeanMachine,
+  });
+  t.is(guardPhases.phases.length, 2);
+  t.is(guardPhases.phases[1].price, sol(0.5).basisPoints);
+});
+
+test('it returns the rent of an allowlist proof once the machine is withdrawn', async (t) => {
+  const sellerUmi = await createUmi();
+  const buyer = await generateSignerWithSol(sellerUmi);
+  const buyerUmi = await createUmi(buyer);
+
+  const allowList = [
+    buyer.publicKey,
+    'Ur1CbWSGsXCdedknRbJsEk7urwAvu1uddmQv51nAnXB',
+  ];
+  const merkleRoot = getMerkleRoot(allowList);
+
+  const jellybeanMachine = await create(sellerUmi, {
+    guards: {},
+    phases: [guardPhase({ allowlistRoot: some(merkleRoot) })],
+  });
+
+  await proveAllowlist(buyerUmi, {
+    jellybeanMachine,
+    merkleRoot,
+    proof: getMerkleProof(allowList, buyer.publicKey),
+  }).sendAndConfirm(buyerUmi);
+
+  const [allowlistProof] = findAllowlistProofPda(sellerUmi, {
+    jellybeanMachine,
+    merkleRoot,
+    buyer: buyer.publicKey,
+  });
+
+  // The proof is kept while the machine exists
+  await t.throwsAsync(
+    closeGuardRecord(sellerUmi, {
+      jellybeanMachine,
+      guardRecord: allowlistProof,
+      buyer: buyer.publicKey,
+    }).sendAndConfirm(sellerUmi),
+    { message: /GuardRecordInUse/ }
+  );
+
+  await withdraw(sellerUmi, { jellybeanMachine })
+    .add(
+      closeGuardRecord(sellerUmi, {
+        jellybeanMachine,
+        guardRecord: allowlistProof,
+        buyer: buyer.publicKey,
+      })
+    )
+    .sendAndConfirm(sellerUmi);
+
+  t.falsy(await safeFetchAllowlistProof(sellerUmi, allowlistProof));
+});
+
+test('it returns the rent of an allowlist proof once the sale is cancelled', async (t) => {
+  const sellerUmi = await createUmi();
+  const collectionSigner = await createMasterEdition(sellerUmi);
+  const buyer = await generateSignerWithSol(sellerUmi);
+  const buyerUmi = await createUmi(buyer);
+
+  const allowList = [
+    buyer.publicKey,
+    'Ur1CbWSGsXCdedknRbJsEk7urwAvu1uddmQv51nAnXB',
+  ];
+  const merkleRoot = getMerkleRoot(allowList);
+
+  const jellybeanMachine = await create(sellerUmi, {
+    items: [
+      {
+        collection: collectionSigner.publicKey,
+      },
+    ],
+    guards: {},
+    phases: [guardPhase({ allowlistRoot: some(merkleRoot) })],
+    startSale: true,
+  });
+
+  await proveAllowlist(buyerUmi, {
+    jellybeanMachine,
+    merkleRoot,
+    proof: getMerkleProof(allowList, buyer.publicKey),
+  }).sendAndConfirm(buyerUmi);
+
+  const [allowlistProof] = findAllowlistProofPda(sellerUmi, {
+    jellybeanMachine,
+    merkleRoot,
+    buyer: buyer.publicKey,
+  });
+
+  // The proof is kept while buyers can still draw
+  await t.throwsAsync(
+    closeGuardRecord(sellerUmi, {
+      jellybeanMachine,
+      guardRecord: allowlistProof,
+      buyer: buyer.publicKey,
+    }).sendAndConfirm(sellerUmi),
+    { message: /GuardRecordInUse/ }
+  );
+
+  await cancelSale(sellerUmi, { jellybeanMachine })
+    .add(
+      closeGuardRecord(sellerUmi, {
+        jellybeanMachine,
+        guardRecord: allowlistProof,
+        buyer: buyer.publicKey,
+      })
+    )
+    .sendAndConfirm(sellerUmi);
+
+  t.falsy(await safeFetchAllowlistProof(sellerUmi, allowlistProof));
+});
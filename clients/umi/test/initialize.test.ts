@@ -1,4 +1,4 @@
-import { generateSigner, sol } from '@metaplex-foundation/umi';
+import { generateSigner, none, some, sol } from '@metaplex-foundation/umi';
 import { generateSignerWithSol } from '@metaplex-foundation/umi-bundle-tests';
 import test from 'ava';
 import {
@@ -12,6 +12,8 @@ import {
   createUmi,
   DEFAULT_MARKETPLACE_FEE_BASIS_POINTS,
   getDefaultFeeAccounts,
+  tomorrow,
+  yesterday,
 } from './_setup';
 
 test('it can initialize a jellybean machine with basic parameters', async (t) => {
@@ -34,7 +36,7 @@ test('it can initialize a jellybean machine with basic parameters', async (t) =>
 
   t.like(jellybeanMachineAccount, {
     publicKey: jellybeanMachine.publicKey,
-    version: 0,
+    version: 1,
     authority: umi.identity.publicKey,
     mintAuthority: umi.identity.publicKey,
     itemsLoaded: 0,
@@ -75,7 +77,7 @@ test('it can initialize a jellybean machine with multiple fee accounts', async (
 
   t.like(jellybeanMachineAccount, {
     publicKey: jellybeanMachine.publicKey,
-    version: 0,
+    version: 1,
     authority: umi.identity.publicKey,
     mintAuthority: umi.identity.publicKey,
     itemsLoaded: 0,
@@ -118,7 +120,7 @@ test('it can initialize a jellybean machine with custom authority', async (t) =>
 
   t.like(jellybeanMachineAccount, {
     publicKey: jellybeanMachine.publicKey,
-    version: 0,
+    version: 1,
     authority: customAuthority.publicKey,
     mintAuthority: customAuthority.publicKey,
     itemsLoaded: 0,
@@ -155,7 +157,7 @@ test('it can initialize a jellybean machine with empty fee accounts', async (t)
 
   t.like(jellybeanMachineAccount, {
     publicKey: jellybeanMachine.publicKey,
-    version: 0,
+    version: 1,
     authority: umi.identity.publicKey,
     mintAuthority: umi.identity.publicKey,
     itemsLoaded: 0,
@@ -388,3 +390,47 @@ test('it allows initialization with properly distributed fee accounts', async (t
     ],
   });
 });
+
+test('it fails to initialize with a claim deadline before the sale end', async (t) => {
+  const umi = await createUmi();
+  const jellybeanMachine = generateSigner(umi);
+  const uri = 'https://example.com/metadata.json';
+  const feeAccounts = getDefaultFeeAccounts(umi.identity.publicKey);
+
+  await t.throwsAsync(
+    () =>
+      createJellybeanMachine(umi, {
+        jellybeanMachine,
+        args: {
+          feeAccounts,
+          uri,
+          endAt: tomorrow(),
+          claimDeadline: yesterday(),
+        },
+      }).then((tx) => tx.sendAndConfirm(umi)),
+    { message: /InvalidSchedule/ }
+  );
+});
+
+test('it can initialize with a claim deadline and no sale end', async (t) => {
+  const umi = await createUmi();
+  const jellybeanMachine = generateSigner(umi);
+  const uri = 'https://example.com/metadata.json';
+  const feeAccounts = getDefaultFeeAccounts(umi.identity.publicKey);
+  const claimDeadline = tomorrow();
+
+  await (
+    await createJellybeanMachine(umi, {
+      jellybeanMachine,
+      args: { feeAccounts, uri, claimDeadline },
+    })
+  ).sendAndConfirm(umi);
+
+  const jellybeanMachineAccount = await fetchJellybeanMachine(
+    umi,
+    jellybeanMachine.publicKey
+  );
+
+  t.deepEqual(jellybeanMachineAccount.endAt, none());
+  t.deepEqual(jellybeanMachineAccount.claimDeadline, some(claimDeadline));
+});
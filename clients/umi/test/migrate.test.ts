@@ -0,0 +1,230 @@
+import { drawJellybean, wrap } from '@mallow-labs/mallow-gumball';
+import { fetchAsset } from '@metaplex-foundation/mpl-core';
+import {
+  createSignerFromKeypair,
+  signerIdentity,
+  some,
+  Umi,
+} from '@metaplex-foundation/umi';
+import { generateSignerWithSol } from '@metaplex-foundation/umi-bundle-tests';
+import test from 'ava';
+import {
+  claimCoreItem,
+  fetchJellybeanMachineWithItems,
+  fetchUnclaimedPrizesFromSeeds,
+  findUnclaimedPrizesPda,
+  ItemKind,
+  JellybeanMachineAccountWithItemsData,
+  JellybeanState,
+  migrateJellybeanMachine,
+  migrateUnclaimedPrizes,
+  UnclaimedPrizes,
+} from '../src';
+import {
+  create,
+  createCoreAsset,
+  createGumballGuard,
+  createUmi,
+  DEFAULT_SOL_PAYMENT_LAMPORTS,
+} from './_setup';
+
+/**
+ * Keypairs of the version 0 accounts in test/fixtures, written by
+ * scripts/client/fixtures-umi.mjs from the same seeds.
+ */
+const fixtureSigner = (umi: Umi, seed: number) =>
+  createSignerFromKeypair(
+    umi,
+    umi.eddsa.createKeypairFromSeed(new Uint8Array(32).fill(seed))
+  );
+
+test('it migrates version 0 accounts, then draws and claims from them', async (t) => {
+  const umi = await createUmi();
+  const authority = fixtureSigner(umi, 1);
+  const buyer = fixtureSigner(umi, 2);
+  const firstAsset = fixtureSigner(umi, 3).publicKey;
+  const secondAsset = fixtureSigner(umi, 4).publicKey;
+  const jellybeanMachineSigner = fixtureSigner(umi, 5);
+  const jellybeanMachine = jellybeanMachineSigner.publicKey;
+
+  // Fixture keypairs hold no lamports, fees are paid by funded payers
+  const authorityUmi = (await createUmi()).use(
+    signerIdentity(authority, false)
+  );
+  const buyerUmi = (await createUmi()).use(signerIdentity(buyer, false));
+
+  await migrateJellybeanMachine(authorityUmi, {
+    jellybeanMachine,
+  }).sendAndConfirm(authorityUmi);
+
+  t.like(
+    await fetchJellybeanMachineWithItems(umi, jellybeanMachine),
+    <JellybeanMachineAccountWithItemsData>{
+      version: 1,
+      authority: authority.publicKey,
+      mintAuthority: authority.publicKey,
+      itemsLoaded: 2,
+      supplyLoaded: 2n,
+      supplyRedeemed: 1n,
+      state: JellybeanState.SaleLive,
+      uri: 'https://example.com/jellybean-machine.json',
+      items: [
+        {
+          index: 0,
+          mint: firstAsset,
+          supplyLoaded: 1,
+          supplyRedeemed: 1,
+          supplyClaimed: 0,
+          escrowAmount: 0n,
+          existingPrints: 0,
+          kind: ItemKind.CoreAsset,
+        },
+        {
+          index: 1,
+          mint: secondAsset,
+          supplyLoaded: 1,
+          supplyRedeemed: 0,
+          supplyClaimed: 0,
+          escrowAmount: 0n,
+          existingPrints: 0,
+          kind: ItemKind.CoreAsset,
+        },
+      ],
+    }
+  );
+
+  // A machine can only be migrated once
+  await t.throwsAsync(
+    migrateJellybeanMachine(authorityUmi, {
+      jellybeanMachine,
+    }).sendAndConfirm(authorityUmi),
+    { message: /InvalidVersion/ }
+  );
+
+  // Wrap the machine to draw through the gumball guard
+  const gumballGuard = await createGumballGuard(authorityUmi, {
+    base: jellybeanMachineSigner,
+    guards: { solPayment: { lamports: DEFAULT_SOL_PAYMENT_LAMPORTS } },
+  });
+  await wrap(authorityUmi, {
+    machine: jellybeanMachine,
+    gumballGuard,
+    machineProgram: umi.programs.get('mallowJellybean').publicKey,
+  }).sendAndConfirm(authorityUmi);
+
+  const draw = () =>
+    drawJellybean(buyerUmi, {
+      jellybeanMachine,
+      mintArgs: {
+        solPayment: some({ feeAccounts: [authority.publicKey] }),
+      },
+    }).sendAndConfirm(buyerUmi);
+
+  // The buyer's prizes can't be read until they are migrated
+  await t.throwsAsync(draw(), { message: /AccountDidNotDeserialize/ });
+
+  await migrateUnclaimedPrizes(umi, {
+    unclaimedPrizes: findUnclaimedPrizesPda(umi, {
+      jellybeanMachine,
+      buyer: buyer.publicKey,
+    }),
+  }).sendAndConfirm(umi);
+
+  t.like(
+    await fetchUnclaimedPrizesFromSeeds(umi, {
+      jellybeanMachine,
+      buyer: buyer.publicKey,
+    }),
+    <UnclaimedPrizes>{
+      version: 1,
+      jellybeanMachine,
+      buyer: buyer.publicKey,
+      prizes: [{ itemIndex: 0, editionNumber: 1, drawSlot: 0n, price: 0n }],
+      wins: [],
+    }
+  );
+
+  await t.throwsAsync(
+    migrateUnclaimedPrizes(umi, {
+      unclaimedPrizes: findUnclaimedPrizesPda(umi, {
+        jellybeanMachine,
+        buyer: buyer.publicKey,
+      }),
+    }).sendAndConfirm(umi),
+    { message: /InvalidVersion/ }
+  );
+
+  // The migrated buyer draws the last item and claims both prizes
+  await draw();
+
+  const unclaimedPrizes = await fetchUnclaimedPrizesFromSeeds(umi, {
+    jellybeanMachine,
+    buyer: buyer.publicKey,
+  });
+  t.deepEqual(
+    unclaimedPrizes.prizes.map((prize) => prize.itemIndex),
+    [0, 1]
+  );
+
+  await claimCoreItem(umi, {
+    jellybeanMachine,
+    buyer: buyer.publicKey,
+    index: 0,
+    asset: firstAsset,
+  }).sendAndConfirm(umi);
+  await claimCoreItem(umi, {
+    jellybeanMachine,
+    buyer: buyer.publicKey,
+    index: 1,
+    asset: secondAsset,
+  }).sendAndConfirm(umi);
+
+  t.is((await fetchAsset(umi, firstAsset)).owner, buyer.publicKey);
+  t.is((await fetchAsset(umi, secondAsset)).owner, buyer.publicKey);
+  t.like(
+    await fetchJellybeanMachineWithItems(umi, jellybeanMachine),
+    <JellybeanMachineAccountWithItemsData>{
+      supplyRedeemed: 2n,
+      state: JellybeanState.SaleEnded,
+      items: [{ supplyClaimed: 1 }, { supplyClaimed: 1 }],
+    }
+  );
+});
+
+test('it cannot migrate a current jellybean machine', async (t) => {
+  const umi = await createUmi();
+  const jellybeanMachine = await create(umi);
+
+  await t.throwsAsync(
+    migrateJellybeanMachine(umi, { jellybeanMachine }).sendAndConfirm(umi),
+    { message: /InvalidVersion/ }
+  );
+});
+
+test('it cannot migrate current unclaimed prizes', async (t) => {
+  const sellerUmi = await createUmi();
+  const asset = await createCoreAsset(sellerUmi);
+  const jellybeanMachine = await create(sellerUmi, {
+    items: [{ asset: asset.publicKey }],
+    startSale: true,
+  });
+
+  const buyer = await generateSignerWithSol(sellerUmi);
+  const buyerUmi = await createUmi(buyer);
+  await drawJellybean(buyerUmi, {
+    jellybeanMachine,
+    mintArgs: {
+      solPayment: some({ feeAccounts: [sellerUmi.identity.publicKey] }),
+    },
+  }).sendAndConfirm(buyerUmi);
+
+  await t.throwsAsync(
+    migrateUnclaimedPrizes(buyerUmi, {
+      unclaimedPrizes: findUnclaimedPrizesPda(buyerUmi, {
+        jellybeanMachine,
+        buyer: buyer.publicKey,
+      }),
+    }).sendAndConfirm(buyerUmi),
+    { message: /InvalidVersion/ }
+  );
+});
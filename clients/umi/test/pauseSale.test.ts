@@ -0,0 +1,84 @@
+import { drawJellybean } from '@mallow-labs/mallow-gumball';
+import { some } from '@metaplex-foundation/umi';
+import { generateSignerWithSol } from '@metaplex-foundation/umi-bundle-tests';
+import test from 'ava';
+import {
+  fetchJellybeanMachine,
+  fetchUnclaimedPrizesFromSeeds,
+  JellybeanState,
+  pauseSale,
+  resumeSale,
+} from '../src';
+import { create, createMasterEdition, createUmi } from './_setup';
+
+test('it blocks draws while the sale is paused', async (t) => {
+  const sellerUmi = await createUmi();
+  const collectionSigner = await createMasterEdition(sellerUmi);
+
+  const jellybeanMachine = await create(sellerUmi, {
+    items: [
+      {
+        collection: collectionSigner.publicKey,
+      },
+    ],
+    startSale: true,
+  });
+
+  await pauseSale(sellerUmi, { jellybeanMachine }).sendAndConfirm(sellerUmi);
+
+  t.is(
+    (await fetchJellybeanMachine(sellerUmi, jellybeanMachine)).state,
+    JellybeanState.Paused
+  );
+
+  const buyer = await generateSignerWithSol(sellerUmi);
+  const buyerUmi = await createUmi(buyer);
+  const draw = () =>
+    drawJellybean(buyerUmi, {
+      jellybeanMachine,
+      mintArgs: {
+        solPayment: some({
+          feeAccounts: [sellerUmi.identity.publicKey],
+        }),
+      },
+    }).sendAndConfirm(buyerUmi);
+
+  await t.throwsAsync(draw(), { message: /SalePaused/ });
+
+  await resumeSale(sellerUmi, { jellybeanMachine }).sendAndConfirm(sellerUmi);
+
+  t.is(
+    (await fetchJellybeanMachine(sellerUmi, jellybeanMachine)).state,
+    JellybeanState.SaleLive
+  );
+
+  await draw();
+
+  const unclaimedPrizes = await fetchUnclaimedPrizesFromSeeds(sellerUmi, {
+    jellybeanMachine,
+    buyer: buyer.publicKey,
+  });
+  t.is(unclaimedPrizes.prizes.length, 1);
+});
+
+test('it cannot pause a sale that is not live', async (t) => {
+  const umi = await createUmi();
+  const collectionSigner = await createMasterEdition(umi);
+
+  const jellybeanMachine = await create(umi, {
+    items: [
+      {
+        collection: collectionSigner.publicKey,
+      },
+    ],
+  });
+
+  await t.throwsAsync(
+    pauseSale(umi, { jellybeanMachine }).sendAndConfirm(umi),
+    { message: /InvalidState/ }
+  );
+  await t.throwsAsync(
+    resumeSale(umi, { jellybeanMachine }).sendAndConfirm(umi),
+    { message: /InvalidState/ }
+  );
+});
@@ -0,0 +1,178 @@
+import { drawJellybean } from '@mallow-labs/mallow-gumball';
+import { fetchAsset } from '@metaplex-foundation/mpl-core';
+import { now, some } from '@metaplex-foundation/umi';
+import { generateSignerWithSol } from '@metaplex-foundation/umi-bundle-tests';
+import test from 'ava';
+import {
+  fetchJellybeanMachineWithItems,
+  fetchUnclaimedPrizesFromSeeds,
+  JellybeanMachineAccountWithItemsData,
+  reclaimPrize,
+  updateSettings,
+} from '../src';
+import {
+  create,
+  createCoreAsset,
+  createUmi,
+  getDefaultFeeAccounts,
+  tomorrow,
+} from './_setup';
+
+test('it only lets the claim deadline be pushed back once the sale has started', async (t) => {
+  const umi = await createUmi();
+  const assetSigner = await createCoreAsset(umi);
+  const endAt = tomorrow();
+  const claimDeadline = endAt + 3600n;
+
+  const jellybeanMachine = await create(umi, {
+    args: {
+      endAt,
+      claimDeadline,
+    },
+    items: [
+      {
+        asset: assetSigner.publicKey,
+      },
+    ],
+    startSale: true,
+  });
+
+  const updateClaimDeadline = (newClaimDeadline: bigint) =>
+    updateSettings(umi, {
+      jellybeanMachine,
+      args: {
+        uri: 'https://example.com/jellybean-machine.json',
+        feeAccounts: getDefaultFeeAccounts(umi.identity.publicKey),
+        endAt,
+        claimDeadline: newClaimDeadline,
+      },
+    }).sendAndConfirm(umi);
+
+  await t.throwsAsync(updateClaimDeadline(claimDeadline - 1800n), {
+    message: /InvalidSchedule/,
+  });
+
+  await updateClaimDeadline(claimDeadline + 3600n);
+
+  const jellybeanMachineAccount = await fetchJellybeanMachineWithItems(
+    umi,
+    jellybeanMachine
+  );
+  t.like(jellybeanMachineAccount, <JellybeanMachineAccountWithItemsData>{
+    claimDeadline: some(claimDeadline + 3600n),
+  });
+});
+
+test('it cannot reclaim a prize before the claim deadline', async (t) => {
+  const sellerUmi = await createUmi();
+  const assetSigner = await createCoreAsset(sellerUmi);
+  const endAt = tomorrow();
+
+  const jellybeanMachine = await create(sellerUmi, {
+    args: {
+      endAt,
+      claimDeadline: endAt + 3600n,
+    },
+    items: [
+      {
+        asset: assetSigner.publicKey,
+      },
+    ],
+    startSale: true,
+  });
+
+  const buyer = await generateSignerWithSol(sellerUmi);
+  const buyerUmi = await createUmi(buyer);
+
+  await drawJellybean(buyerUmi, {
+    jellybeanMachine,
+    mintArgs: {
+      solPayment: some({
+        feeAccounts: [sellerUmi.identity.publicKey],
+      }),
+    },
+  }).sendAndConfirm(buyerUmi);
+
+  const promise = reclaimPrize(sellerUmi, {
+    jellybeanMachine,
+    buyer: buyer.publicKey,
+    asset: assetSigner.publicKey,
+    index: 0,
+  }).sendAndConfirm(sellerUmi);
+
+  await t.throwsAsync(promise, { message: /ClaimDeadlineNotReached/ });
+});
+
+test('it delivers a prize reclaimed after the claim deadline', async (t) => {
+  const sellerUmi = await createUmi();
+  const assetSigner = await createCoreAsset(sellerUmi);
+  const endAt = now() + 5n;
+  const claimDeadline = endAt + 1n;
+
+  const jellybeanMachine = await create(sellerUmi, {
+    args: {
+      endAt,
+      claimDeadline,
+    },
+    items: [
+      {
+        asset: assetSigner.publicKey,
+      },
+    ],
+    startSale: true,
+  });
+
+  const buyer = await generateSignerWithSol(sellerUmi);
+  const buyerUmi = await createUmi(buyer);
+
+  await drawJellybean(buyerUmi, {
+    jellybeanMachine,
+    mintArgs: {
+      solPayment: some({
+        feeAccounts: [sellerUmi.identity.publicKey],
+      }),
+    },
+  }).sendAndConfirm(buyerUmi);
+
+  // Wait for the claim deadline to pass, no draw is accepted after it
+  await new Promise((resolve) => {
+    setTimeout(resolve, Number(claimDeadline - now() + 2n) * 1000);
+  });
+
+  await t.throwsAsync(
+    drawJellybean(buyerUmi, {
+      jellybeanMachine,
+      mintArgs: {
+        solPayment: some({
+          feeAccounts: [sellerUmi.identity.publicKey],
+        }),
+      },
+    }).sendAndConfirm(buyerUmi),
+    { message: /SaleOver/ }
+  );
+
+  await reclaimPrize(sellerUmi, {
+    jellybeanMachine,
+    buyer: buyer.publicKey,
+    asset: assetSigner.publicKey,
+    index: 0,
+  }).sendAndConfirm(sellerUmi);
+
+  // The prize is delivered to its buyer rather than kept by the seller
+  const asset = await fetchAsset(sellerUmi, assetSigner.publicKey);
+  t.is(asset.owner, buyer.publicKey);
+
+  const jellybeanMachineAccount = await fetchJellybeanMachineWithItems(
+    sellerUmi,
+    jellybeanMachine
+  );
+  t.like(jellybeanMachineAccount, <JellybeanMachineAccountWithItemsData>{
+    items: [{ index: 0, supplyClaimed: 1 }],
+  });
+
+  const unclaimedPrizes = await fetchUnclaimedPrizesFromSeeds(sellerUmi, {
+    jellybeanMachine,
+    buyer: buyer.publicKey,
+  });
+  t.is(unclaimedPrizes.prizes.length, 0);
+});
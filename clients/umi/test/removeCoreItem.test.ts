@@ -1,6 +1,10 @@
 import { drawJellybean } from '@mallow-labs/mallow-gumball';
 import { fetchAsset, fetchCollection } from '@metaplex-foundation/mpl-core';
-import { isGreaterThanAmount, some } from '@metaplex-foundation/umi';
+import {
+  defaultPublicKey,
+  isGreaterThanAmount,
+  some,
+} from '@metaplex-foundation/umi';
 import { generateSignerWithSol } from '@metaplex-foundation/umi-bundle-tests';
 import test from 'ava';
 import {
@@ -8,6 +12,8 @@ import {
   claimCoreItem,
   endSale,
   fetchJellybeanMachineWithItems,
+  fetchUnclaimedPrizesFromSeeds,
+  findAuthorityPda,
   JellybeanState,
   removeCoreItem,
 } from '../src';
@@ -39,9 +45,17 @@ test('it can remove a one-of-one asset from a jellybean machine', async (t) => {
   t.is(jellybeanMachineAccount.itemsLoaded, 1);
   t.is(jellybeanMachineAccount.supplyLoaded, 1n);
 
-  // Verify the asset is owned by the authority PDA before removal
+  // Verify the asset stays with the seller, frozen and delegated to the authority PDA
+  const [authorityPda] = findAuthorityPda(umi, { jellybeanMachine });
   let asset = await fetchAsset(umi, assetSigner.publicKey);
-  t.not(asset.owner, umi.identity.publicKey);
+  t.is(asset.owner, umi.identity.publicKey);
+  t.like(asset.freezeDelegate, {
+    frozen: true,
+    authority: { type: 'Address', address: authorityPda },
+  });
+  t.like(asset.transferDelegate, {
+    authority: { type: 'Address', address: authorityPda },
+  });
 
   // Remove the asset
   await removeCoreItem(umi, {
@@ -58,9 +72,16 @@ test('it can remove a one-of-one asset from a jellybean machine', async (t) => {
   t.is(jellybeanMachineAccount.itemsLoaded, 0);
   t.is(jellybeanMachineAccount.supplyLoaded, 0n);
 
-  // Verify the asset is now owned by the authority
+  // Verify the asset is thawed and its delegates revoked
   asset = await fetchAsset(umi, assetSigner.publicKey);
   t.is(asset.owner, umi.identity.publicKey);
+  t.like(asset.freezeDelegate, {
+    frozen: false,
+    authority: { type: 'Owner' },
+  });
+  t.like(asset.transferDelegate, {
+    authority: { type: 'Owner' },
+  });
 });
 
 test('it can remove a master edition from a jellybean machine', async (t) => {
@@ -623,3 +644,155 @@ test('it fails when trying to remove a master edition before being fully claimed
     { message: /ItemNotFullyClaimed/ }
   );
 });
+
+test('it keeps item indices stable when removing an item before later items are claimed', async (t) => {
+  const umi = await createUmi();
+  const assetSigners = await Promise.all([
+    createCoreAsset(umi),
+    createCoreAsset(umi),
+  ]);
+
+  const jellybeanMachine = await create(umi, {
+    items: [
+      {
+        asset: assetSigners[0].publicKey,
+      },
+      {
+        asset: assetSigners[1].publicKey,
+      },
+    ],
+    startSale: true,
+  });
+
+  // Draw both items, which ends the sale
+  const buyerUmi = await createUmi();
+  for (let i = 0; i < 2; i += 1) {
+    await drawJellybean(buyerUmi, {
+      jellybeanMachine,
+      mintArgs: {
+        solPayment: {
+          feeAccounts: [umi.identity.publicKey],
+        },
+      },
+    }).sendAndConfirm(buyerUmi);
+  }
+
+  // Claim and remove the first item while the second is still unclaimed
+  await claimCoreItem(buyerUmi, {
+    jellybeanMachine,
+    asset: assetSigners[0].publicKey,
+    index: 0,
+  }).sendAndConfirm(buyerUmi);
+
+  await removeCoreItem(umi, {
+    jellybeanMachine,
+    asset: assetSigners[0].publicKey,
+    index: 0,
+  }).sendAndConfirm(umi);
+
+  // The removed slot is kept so the unclaimed prize still points at its item
+  let jellybeanMachineAccount = await fetchJellybeanMachineWithItems(
+    umi,
+    jellybeanMachine
+  );
+  t.is(jellybeanMachineAccount.itemsLoaded, 2);
+  t.is(jellybeanMachineAccount.supplyLoaded, 1n);
+  t.is(jellybeanMachineAccount.items[0].mint, defaultPublicKey());
+  t.is(jellybeanMachineAccount.items[1].mint, assetSigners[1].publicKey);
+
+  const unclaimedPrizes = await fetchUnclaimedPrizesFromSeeds(umi, {
+    jellybeanMachine,
+    buyer: buyerUmi.identity.publicKey,
+  });
+  t.deepEqual(unclaimedPrizes.prizes.map((prize) => prize.itemIndex), [1]);
+
+  await claimCoreItem(buyerUmi, {
+    jellybeanMachine,
+    asset: assetSigners[1].publicKey,
+    index: 1,
+  }).sendAndConfirm(buyerUmi);
+
+  await removeCoreItem(umi, {
+    jellybeanMachine,
+    asset: assetSigners[1].publicKey,
+    index: 1,
+  }).sendAndConfirm(umi);
+
+  // Removing the last item also drops the tombstoned slot
+  jellybeanMachineAccount = await fetchJellybeanMachineWithItems(
+    umi,
+    jellybeanMachine
+  );
+  t.is(jellybeanMachineAccount.itemsLoaded, 0);
+  t.is(jellybeanMachineAccount.supplyLoaded, 0n);
+
+  const asset = await fetchAsset(umi, assetSigners[1].publicKey);
+  t.is(asset.owner, buyerUmi.identity.publicKey);
+});
+
+test('it can remove a tombstoned slot once the prizes after it are claimed', async (t) => {
+  const umi = await createUmi();
+  const assetSigners = await Promise.all([
+    createCoreAsset(umi),
+    createCoreAsset(umi),
+  ]);
+
+  const jellybeanMachine = await create(umi, {
+    items: [
+      {
+        asset: assetSigners[0].publicKey,
+      },
+      {
+        asset: assetSigners[1].publicKey,
+      },
+    ],
+    startSale: true,
+  });
+
+  // Draw both items, which ends the sale
+  const buyerUmi = await createUmi();
+  for (let i = 0; i < 2; i += 1) {
+    await drawJellybean(buyerUmi, {
+      jellybeanMachine,
+      mintArgs: {
+        solPayment: {
+          feeAccounts: [umi.identity.publicKey],
+        },
+      },
+    }).sendAndConfirm(buyerUmi);
+  }
+
+  // Claim and remove the first item, its slot is kept for the unclaimed prize
+  await claimCoreItem(buyerUmi, {
+    jellybeanMachine,
+    asset: assetSigners[0].publicKey,
+    index: 0,
+  }).sendAndConfirm(buyerUmi);
+
+  await removeCoreItem(umi, {
+    jellybeanMachine,
+    asset: assetSigners[0].publicKey,
+    index: 0,
+  }).sendAndConfirm(umi);
+
+  await claimCoreItem(buyerUmi, {
+    jellybeanMachine,
+    asset: assetSigners[1].publicKey,
+    index: 1,
+  }).sendAndConfirm(buyerUmi);
+
+  // The tombstoned slot has nothing to return, so it needs no accounts
+  await removeCoreItem(umi, {
+    jellybeanMachine,
+    index: 0,
+  }).sendAndConfirm(umi);
+
+  // With every prize claimed, the remaining item is shifted over the slot
+  const jellybeanMachineAccount = await fetchJellybeanMachineWithItems(
+    umi,
+    jellybeanMachine
+  );
+  t.is(jellybeanMachineAccount.itemsLoaded, 1);
+  t.is(jellybeanMachineAccount.supplyLoaded, 1n);
+  t.is(jellybeanMachineAccount.items[0].mint, assetSigners[1].publicKey);
+});
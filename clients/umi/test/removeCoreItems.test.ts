@@ -0,0 +1,158 @@
+import { fetchAsset, fetchCollection } from '@metaplex-foundation/mpl-core';
+import test from 'ava';
+import {
+  fetchJellybeanMachineWithItems,
+  JellybeanMachineAccountWithItemsData,
+  removeCoreItems,
+} from '../src';
+import {
+  create,
+  createCoreAsset,
+  createMasterEdition,
+  createUmi,
+  DEFAULT_MAX_SUPPLY,
+  getRemoveItemAccounts,
+} from './_setup';
+
+test('it can remove a set of items from a jellybean machine', async (t) => {
+  const umi = await createUmi();
+  const asset1Signer = await createCoreAsset(umi);
+  const collectionSigner = await createMasterEdition(umi);
+  const asset2Signer = await createCoreAsset(umi);
+  const asset3Signer = await createCoreAsset(umi);
+
+  const jellybeanMachine = await create(umi, {
+    items: [
+      {
+        asset: asset1Signer.publicKey,
+      },
+      {
+        collection: collectionSigner.publicKey,
+      },
+      {
+        asset: asset2Signer.publicKey,
+      },
+      {
+        asset: asset3Signer.publicKey,
+      },
+    ],
+  });
+
+  // Remove the first item, the master edition and the last item
+  await removeCoreItems(umi, {
+    jellybeanMachine,
+    indices: [0, 1, 3],
+  })
+    .addRemainingAccounts(
+      getRemoveItemAccounts(umi, jellybeanMachine, [
+        { asset: asset1Signer.publicKey },
+        { collection: collectionSigner.publicKey },
+        { asset: asset3Signer.publicKey },
+      ])
+    )
+    .sendAndConfirm(umi);
+
+  const jellybeanMachineAccount = await fetchJellybeanMachineWithItems(
+    umi,
+    jellybeanMachine
+  );
+  t.like(jellybeanMachineAccount, <JellybeanMachineAccountWithItemsData>{
+    itemsLoaded: 1,
+    supplyLoaded: 1n,
+    items: [
+      {
+        index: 0,
+        mint: asset2Signer.publicKey,
+      },
+    ],
+  });
+
+  // Verify the removed assets are thawed and the master edition is returned
+  for (const signer of [asset1Signer, asset3Signer]) {
+    const asset = await fetchAsset(umi, signer.publicKey);
+    t.is(asset.owner, umi.identity.publicKey);
+    t.like(asset.freezeDelegate, { frozen: false });
+  }
+  const collection = await fetchCollection(umi, collectionSigner.publicKey);
+  t.is(collection.updateAuthority, umi.identity.publicKey);
+
+  // The remaining asset is still frozen in the machine
+  const asset2 = await fetchAsset(umi, asset2Signer.publicKey);
+  t.like(asset2.freezeDelegate, { frozen: true });
+});
+
+test('it cannot remove items with unsorted indices', async (t) => {
+  const umi = await createUmi();
+  const asset1Signer = await createCoreAsset(umi);
+  const asset2Signer = await createCoreAsset(umi);
+
+  const jellybeanMachine = await create(umi, {
+    items: [
+      {
+        asset: asset1Signer.publicKey,
+      },
+      {
+        asset: asset2Signer.publicKey,
+      },
+    ],
+  });
+
+  const promise = removeCoreItems(umi, {
+    jellybeanMachine,
+    indices: [1, 0],
+  })
+    .addRemainingAccounts(
+      getRemoveItemAccounts(umi, jellybeanMachine, [
+        { asset: asset2Signer.publicKey },
+        { asset: asset1Signer.publicKey },
+      ])
+    )
+    .sendAndConfirm(umi);
+
+  await t.throwsAsync(promise, { message: /InvalidInputLength/ });
+
+  const jellybeanMachineAccount = await fetchJellybeanMachineWithItems(
+    umi,
+    jellybeanMachine
+  );
+  t.is(jellybeanMachineAccount.itemsLoaded, 2);
+  t.is(jellybeanMachineAccount.supplyLoaded, 2n);
+});
+
+test('it cannot remove items while the sale is live', async (t) => {
+  const umi = await createUmi();
+  const assetSigner = await createCoreAsset(umi);
+  const collectionSigner = await createMasterEdition(umi);
+
+  const jellybeanMachine = await create(umi, {
+    items: [
+      {
+        asset: assetSigner.publicKey,
+      },
+      {
+        collection: collectionSigner.publicKey,
+      },
+    ],
+    startSale: true,
+  });
+
+  const promise = removeCoreItems(umi, {
+    jellybeanMachine,
+    indices: [0, 1],
+  })
+    .addRemainingAccounts(
+      getRemoveItemAccounts(umi, jellybeanMachine, [
+        { asset: assetSigner.publicKey },
+        { collection: collectionSigner.publicKey },
+      ])
+    )
+    .sendAndConfirm(umi);
+
+  await t.throwsAsync(promise, { message: /InvalidState/ });
+
+  const jellybeanMachineAccount = await fetchJellybeanMachineWithItems(
+    umi,
+    jellybeanMachine
+  );
+  t.is(jellybeanMachineAccount.supplyLoaded, BigInt(1 + DEFAULT_MAX_SUPPLY));
+});
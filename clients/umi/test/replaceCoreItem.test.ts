@@ -0,0 +1,183 @@
+import { drawJellybean } from '@mallow-labs/mallow-gumball';
+import {
+  createV1,
+  fetchAsset,
+  fetchCollection,
+} from '@metaplex-foundation/mpl-core';
+import { generateSigner, some } from '@metaplex-foundation/umi';
+import { generateSignerWithSol } from '@metaplex-foundation/umi-bundle-tests';
+import test from 'ava';
+import {
+  fetchJellybeanMachineWithItems,
+  findAuthorityPda,
+  JellybeanMachineAccountWithItemsData,
+  JellybeanState,
+  replaceCoreItem,
+} from '../src';
+import {
+  create,
+  createCoreAsset,
+  createMasterEdition,
+  createUmi,
+  DEFAULT_MAX_SUPPLY,
+} from './_setup';
+
+test('it can replace an item in place', async (t) => {
+  const umi = await createUmi();
+  const asset1Signer = await createCoreAsset(umi);
+  const asset2Signer = await createCoreAsset(umi);
+  const asset3Signer = await createCoreAsset(umi);
+  const newAssetSigner = await createCoreAsset(umi);
+
+  const jellybeanMachine = await create(umi, {
+    items: [
+      {
+        asset: asset1Signer.publicKey,
+      },
+      {
+        asset: asset2Signer.publicKey,
+      },
+      {
+        asset: asset3Signer.publicKey,
+      },
+    ],
+  });
+
+  await replaceCoreItem(umi, {
+    jellybeanMachine,
+    asset: asset2Signer.publicKey,
+    newAsset: newAssetSigner.publicKey,
+    index: 1,
+  }).sendAndConfirm(umi);
+
+  const jellybeanMachineAccount = await fetchJellybeanMachineWithItems(
+    umi,
+    jellybeanMachine
+  );
+  t.like(jellybeanMachineAccount, <JellybeanMachineAccountWithItemsData>{
+    itemsLoaded: 3,
+    supplyLoaded: 3n,
+    items: [
+      { index: 0, mint: asset1Signer.publicKey },
+      { index: 1, mint: newAssetSigner.publicKey, supplyLoaded: 1 },
+      { index: 2, mint: asset3Signer.publicKey },
+    ],
+  });
+
+  // The replaced asset is thawed and the new one frozen in the seller's wallet
+  const oldAsset = await fetchAsset(umi, asset2Signer.publicKey);
+  t.is(oldAsset.owner, umi.identity.publicKey);
+  t.like(oldAsset.freezeDelegate, { frozen: false });
+
+  const [authorityPda] = findAuthorityPda(umi, { jellybeanMachine });
+  const newAsset = await fetchAsset(umi, newAssetSigner.publicKey);
+  t.is(newAsset.owner, umi.identity.publicKey);
+  t.like(newAsset.freezeDelegate, {
+    frozen: true,
+    authority: { type: 'Address', address: authorityPda },
+  });
+});
+
+test('it can replace a master edition whose only prints predate loading on a live sale', async (t) => {
+  const umi = await createUmi();
+  const collectionSigner = await createMasterEdition(umi);
+  const newAssetSigner = await createCoreAsset(umi);
+
+  // Mint a print from the master edition before loading it
+  await createV1(umi, {
+    asset: generateSigner(umi),
+    collection: collectionSigner.publicKey,
+    name: 'My Asset',
+    uri: 'https://example.com/my-asset.json',
+  }).sendAndConfirm(umi);
+
+  const jellybeanMachine = await create(umi, {
+    items: [
+      {
+        collection: collectionSigner.publicKey,
+      },
+    ],
+    startSale: true,
+  });
+
+  let jellybeanMachineAccount = await fetchJellybeanMachineWithItems(
+    umi,
+    jellybeanMachine
+  );
+  t.like(jellybeanMachineAccount, <JellybeanMachineAccountWithItemsData>{
+    supplyLoaded: BigInt(DEFAULT_MAX_SUPPLY),
+    supplyRedeemed: 1n,
+  });
+
+  await replaceCoreItem(umi, {
+    jellybeanMachine,
+    collection: collectionSigner.publicKey,
+    newAsset: newAssetSigner.publicKey,
+    index: 0,
+  }).sendAndConfirm(umi);
+
+  // The existing print no longer counts once its master edition is replaced
+  jellybeanMachineAccount = await fetchJellybeanMachineWithItems(
+    umi,
+    jellybeanMachine
+  );
+  t.like(jellybeanMachineAccount, <JellybeanMachineAccountWithItemsData>{
+    state: JellybeanState.SaleLive,
+    itemsLoaded: 1,
+    supplyLoaded: 1n,
+    supplyRedeemed: 0n,
+    items: [
+      {
+        index: 0,
+        mint: newAssetSigner.publicKey,
+        supplyLoaded: 1,
+        supplyRedeemed: 0,
+      },
+    ],
+  });
+
+  const collection = await fetchCollection(umi, collectionSigner.publicKey);
+  t.is(collection.updateAuthority, umi.identity.publicKey);
+});
+
+test('it cannot replace an item that has been drawn', async (t) => {
+  const sellerUmi = await createUmi();
+  const collectionSigner = await createMasterEdition(sellerUmi);
+  const newAssetSigner = await createCoreAsset(sellerUmi);
+
+  const jellybeanMachine = await create(sellerUmi, {
+    items: [
+      {
+        collection: collectionSigner.publicKey,
+      },
+    ],
+    startSale: true,
+  });
+
+  const buyer = await generateSignerWithSol(sellerUmi);
+  const buyerUmi = await createUmi(buyer);
+
+  await drawJellybean(buyerUmi, {
+    jellybeanMachine,
+    mintArgs: {
+      solPayment: some({
+        feeAccounts: [sellerUmi.identity.publicKey],
+      }),
+    },
+  }).sendAndConfirm(buyerUmi);
+
+  const promise = replaceCoreItem(sellerUmi, {
+    jellybeanMachine,
+    collection: collectionSigner.publicKey,
+    newAsset: newAssetSigner.publicKey,
+    index: 0,
+  }).sendAndConfirm(sellerUmi);
+
+  await t.throwsAsync(promise, { message: /InvalidState/ });
+
+  const jellybeanMachineAccount = await fetchJellybeanMachineWithItems(
+    sellerUmi,
+    jellybeanMachine
+  );
+  t.is(jellybeanMachineAccount.items[0].mint, collectionSigner.publicKey);
+});
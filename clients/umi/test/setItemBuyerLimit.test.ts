@@ -0,0 +1,253 @@
+import { drawJellybean } from '@mallow-labs/mallow-gumball';
+import {
+  generateSigner,
+  PublicKey,
+  some,
+  Umi,
+} from '@metaplex-foundation/umi';
+import { generateSignerWithSol } from '@metaplex-foundation/umi-bundle-tests';
+import test from 'ava';
+import {
+  cancelSale,
+  claimCoreItem,
+  closeUnclaimedPrizes,
+  endSale,
+  fetchJellybeanMachine,
+  fetchUnclaimedPrizesFromSeeds,
+  removeCoreItem,
+  safeFetchUnclaimedPrizesFromSeeds,
+  setItemBuyerLimit,
+  setItemEnabled,
+  startSale,
+  UnclaimedPrizes,
+} from '../src';
+import {
+  create,
+  createCoreAsset,
+  createMasterEdition,
+  createUmi,
+} from './_setup';
+
+const drawItem = (
+  buyerUmi: Umi,
+  sellerUmi: Umi,
+  jellybeanMachine: PublicKey
+) =>
+  drawJellybean(buyerUmi, {
+    jellybeanMachine,
+    mintArgs: {
+      solPayment: some({
+        feeAccounts: [sellerUmi.identity.publicKey],
+      }),
+    },
+  }).sendAndConfirm(buyerUmi);
+
+test('it limits how many units of an item a buyer can win', async (t) => {
+  const sellerUmi = await createUmi();
+  const collectionSigner = await createMasterEdition(sellerUmi);
+
+  const jellybeanMachine = await create(sellerUmi, {
+    items: [
+      {
+        collection: collectionSigner.publicKey,
+      },
+    ],
+    startSale: true,
+  });
+
+  await setItemBuyerLimit(sellerUmi, {
+    jellybeanMachine,
+    index: 0,
+    maxPerBuyer: some(1),
+  }).sendAndConfirm(sellerUmi);
+
+  t.deepEqual(
+    (await fetchJellybeanMachine(sellerUmi, jellybeanMachine)).buyerLimits,
+    [{ itemIndex: 0, maxPerBuyer: 1 }]
+  );
+
+  const buyer = await generateSignerWithSol(sellerUmi);
+  const buyerUmi = await createUmi(buyer);
+
+  await drawItem(buyerUmi, sellerUmi, jellybeanMachine);
+
+  t.like(
+    await fetchUnclaimedPrizesFromSeeds(sellerUmi, {
+      jellybeanMachine,
+      buyer: buyer.publicKey,
+    }),
+    <UnclaimedPrizes>{
+      wins: [{ mint: collectionSigner.publicKey, count: 1 }],
+    }
+  );
+
+  // The buyer has won the only item as many times as allowed
+  await t.throwsAsync(drawItem(buyerUmi, sellerUmi, jellybeanMachine), {
+    message: /BuyerLimitReached/,
+  });
+
+  // Other buyers can still draw it
+  const otherBuyer = await generateSignerWithSol(sellerUmi);
+  const otherBuyerUmi = await createUmi(otherBuyer);
+  await drawItem(otherBuyerUmi, sellerUmi, jellybeanMachine);
+
+  const otherUnclaimedPrizes = await fetchUnclaimedPrizesFromSeeds(sellerUmi, {
+    jellybeanMachine,
+    buyer: otherBuyer.publicKey,
+  });
+  t.is(otherUnclaimedPrizes.prizes.length, 1);
+});
+
+test('it closes the unclaimed prizes of a buyer once the sale is cancelled', async (t) => {
+  const sellerUmi = await createUmi();
+  const collectionSigner = await createMasterEdition(sellerUmi);
+
+  const jellybeanMachine = await create(sellerUmi, {
+    items: [
+      {
+        collection: collectionSigner.publicKey,
+      },
+    ],
+    startSale: true,
+  });
+
+  await setItemBuyerLimit(sellerUmi, {
+    jellybeanMachine,
+    index: 0,
+    maxPerBuyer: some(1),
+  }).sendAndConfirm(sellerUmi);
+
+  const buyer = await generateSignerWithSol(sellerUmi);
+  const buyerUmi = await createUmi(buyer);
+
+  await drawItem(buyerUmi, sellerUmi, jellybeanMachine);
+
+  await claimCoreItem(buyerUmi, {
+    jellybeanMachine,
+    buyer: buyer.publicKey,
+    collection: collectionSigner.publicKey,
+    index: 0,
+    printAsset: generateSigner(buyerUmi),
+  }).sendAndConfirm(buyerUmi);
+
+  // The account is kept to record the win while the sale is live
+  t.like(
+    await fetchUnclaimedPrizesFromSeeds(sellerUmi, {
+      jellybeanMachine,
+      buyer: buyer.publicKey,
+    }),
+    <UnclaimedPrizes>{
+      prizes: [],
+      wins: [{ mint: collectionSigner.publicKey, count: 1 }],
+    }
+  );
+
+  await t.throwsAsync(
+    closeUnclaimedPrizes(sellerUmi, {
+      jellybeanMachine,
+      buyer: buyer.publicKey,
+    }).sendAndConfirm(sellerUmi),
+    { message: /InvalidState/ }
+  );
+
+  // An ended sale can be reopened, so the win still counts
+  await endSale(sellerUmi, { jellybeanMachine }).sendAndConfirm(sellerUmi);
+  await t.throwsAsync(
+    closeUnclaimedPrizes(sellerUmi, {
+      jellybeanMachine,
+      buyer: buyer.publicKey,
+    }).sendAndConfirm(sellerUmi),
+    { message: /InvalidState/ }
+  );
+
+  await cancelSale(sellerUmi, { jellybeanMachine })
+    .add(
+      closeUnclaimedPrizes(sellerUmi, {
+        jellybeanMachine,
+        buyer: buyer.publicKey,
+      })
+    )
+    .sendAndConfirm(sellerUmi);
+
+  t.falsy(
+    await safeFetchUnclaimedPrizesFromSeeds(sellerUmi, {
+      jellybeanMachine,
+      buyer: buyer.publicKey,
+    })
+  );
+});
+
+test('it keeps limiting a buyer after earlier items are removed', async (t) => {
+  const sellerUmi = await createUmi();
+  const asset = await createCoreAsset(sellerUmi);
+  const collectionSigner = await createMasterEdition(sellerUmi);
+
+  const jellybeanMachine = await create(sellerUmi, {
+    items: [
+      {
+        asset: asset.publicKey,
+      },
+      {
+        collection: collectionSigner.publicKey,
+      },
+    ],
+  });
+
+  // Only the master edition can be drawn, at most once per buyer
+  await setItemEnabled(sellerUmi, {
+    jellybeanMachine,
+    index: 0,
+    enabled: false,
+  })
+    .add(
+      setItemBuyerLimit(sellerUmi, {
+        jellybeanMachine,
+        index: 1,
+        maxPerBuyer: some(1),
+      })
+    )
+    .add(startSale(sellerUmi, { jellybeanMachine }))
+    .sendAndConfirm(sellerUmi);
+
+  const buyer = await generateSignerWithSol(sellerUmi);
+  const buyerUmi = await createUmi(buyer);
+
+  await drawItem(buyerUmi, sellerUmi, jellybeanMachine);
+  await claimCoreItem(buyerUmi, {
+    jellybeanMachine,
+    buyer: buyer.publicKey,
+    collection: collectionSigner.publicKey,
+    index: 1,
+    printAsset: generateSigner(buyerUmi),
+  }).sendAndConfirm(buyerUmi);
+
+  // Removing the first item moves the master edition to index 0
+  await endSale(sellerUmi, { jellybeanMachine })
+    .add(
+      removeCoreItem(sellerUmi, {
+        jellybeanMachine,
+        asset: asset.publicKey,
+        index: 0,
+      })
+    )
+    .add(startSale(sellerUmi, { jellybeanMachine }))
+    .sendAndConfirm(sellerUmi);
+
+  t.deepEqual(
+    (await fetchJellybeanMachine(sellerUmi, jellybeanMachine)).buyerLimits,
+    [{ itemIndex: 0, maxPerBuyer: 1 }]
+  );
+  t.like(
+    await fetchUnclaimedPrizesFromSeeds(sellerUmi, {
+      jellybeanMachine,
+      buyer: buyer.publicKey,
+    }),
+    <UnclaimedPrizes>{
+      wins: [{ mint: collectionSigner.publicKey, count: 1 }],
+    }
+  );
+
+  await t.throwsAsync(drawItem(buyerUmi, sellerUmi, jellybeanMachine), {
+    message: /BuyerLimitReached/,
+  });
+});
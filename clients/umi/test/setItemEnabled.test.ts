@@ -0,0 +1,138 @@
+import { drawJellybean } from '@mallow-labs/mallow-gumball';
+import { some } from '@metaplex-foundation/umi';
+import { generateSignerWithSol } from '@metaplex-foundation/umi-bundle-tests';
+import test from 'ava';
+import {
+  fetchJellybeanMachineWithItems,
+  fetchUnclaimedPrizesFromSeeds,
+  setItemEnabled,
+} from '../src';
+import { create, createCoreAsset, createUmi } from './_setup';
+
+test('it does not draw a disabled item', async (t) => {
+  const sellerUmi = await createUmi();
+  const asset1Signer = await createCoreAsset(sellerUmi);
+  const asset2Signer = await createCoreAsset(sellerUmi);
+
+  const jellybeanMachine = await create(sellerUmi, {
+    items: [
+      {
+        asset: asset1Signer.publicKey,
+      },
+      {
+        asset: asset2Signer.publicKey,
+      },
+    ],
+    startSale: true,
+  });
+
+  await setItemEnabled(sellerUmi, {
+    jellybeanMachine,
+    index: 0,
+    enabled: false,
+  }).sendAndConfirm(sellerUmi);
+
+  let jellybeanMachineAccount = await fetchJellybeanMachineWithItems(
+    sellerUmi,
+    jellybeanMachine
+  );
+  t.deepEqual(jellybeanMachineAccount.disabledItems, [0]);
+
+  const buyer = await generateSignerWithSol(sellerUmi);
+  const buyerUmi = await createUmi(buyer);
+
+  await drawJellybean(buyerUmi, {
+    jellybeanMachine,
+    mintArgs: {
+      solPayment: some({
+        feeAccounts: [sellerUmi.identity.publicKey],
+      }),
+    },
+  }).sendAndConfirm(buyerUmi);
+
+  const unclaimedPrizes = await fetchUnclaimedPrizesFromSeeds(sellerUmi, {
+    jellybeanMachine,
+    buyer: buyer.publicKey,
+  });
+  t.is(unclaimedPrizes.prizes.length, 1);
+  t.is(unclaimedPrizes.prizes[0].itemIndex, 1);
+
+  // The disabled item is still loaded
+  jellybeanMachineAccount = await fetchJellybeanMachineWithItems(
+    sellerUmi,
+    jellybeanMachine
+  );
+  t.is(jellybeanMachineAccount.itemsLoaded, 2);
+  t.is(jellybeanMachineAccount.items[0].supplyRedeemed, 0);
+});
+
+test('it can draw an item again once it is re-enabled', async (t) => {
+  const sellerUmi = await createUmi();
+  const assetSigner = await createCoreAsset(sellerUmi);
+
+  const jellybeanMachine = await create(sellerUmi, {
+    items: [
+      {
+        asset: assetSigner.publicKey,
+      },
+    ],
+    startSale: true,
+  });
+
+  await setItemEnabled(sellerUmi, {
+    jellybeanMachine,
+    index: 0,
+    enabled: false,
+  }).sendAndConfirm(sellerUmi);
+
+  const buyer = await generateSignerWithSol(sellerUmi);
+  const buyerUmi = await createUmi(buyer);
+  const draw = () =>
+    drawJellybean(buyerUmi, {
+      jellybeanMachine,
+      mintArgs: {
+        solPayment: some({
+          feeAccounts: [sellerUmi.identity.publicKey],
+        }),
+      },
+    }).sendAndConfirm(buyerUmi);
+
+  // Only the disabled item is left to draw
+  await t.throwsAsync(draw(), { message: /JellybeanMachineEmpty/ });
+
+  await setItemEnabled(sellerUmi, {
+    jellybeanMachine,
+    index: 0,
+    enabled: true,
+  }).sendAndConfirm(sellerUmi);
+
+  await draw();
+
+  const jellybeanMachineAccount = await fetchJellybeanMachineWithItems(
+    sellerUmi,
+    jellybeanMachine
+  );
+  t.deepEqual(jellybeanMachineAccount.disabledItems, []);
+  t.is(jellybeanMachineAccount.items[0].supplyRedeemed, 1);
+});
+
+test('it cannot disable an item that is not loaded', async (t) => {
+  const umi = await createUmi();
+  const assetSigner = await createCoreAsset(umi);
+
+  const jellybeanMachine = await create(umi, {
+    items: [
+      {
+        asset: assetSigner.publicKey,
+      },
+    ],
+  });
+
+  const promise = setItemEnabled(umi, {
+    jellybeanMachine,
+    index: 1,
+    enabled: false,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(promise, { message: /IndexGreaterThanLength/ });
+});
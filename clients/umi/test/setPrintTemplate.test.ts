@@ -0,0 +1,264 @@
+import { drawJellybean } from '@mallow-labs/mallow-gumball';
+import { AssetV1, fetchAsset } from '@metaplex-foundation/mpl-core';
+import { generateSigner, PublicKey, some, Umi } from '@metaplex-foundation/umi';
+import { generateSignerWithSol } from '@metaplex-foundation/umi-bundle-tests';
+import test from 'ava';
+import {
+  claimCoreItem,
+  fetchJellybeanMachineWithItems,
+  fetchPrintTemplateFromSeeds,
+  fetchUnclaimedPrizesFromSeeds,
+  PrintTemplate,
+  setPrintTemplate,
+} from '../src';
+import {
+  create,
+  createMasterEdition,
+  createUmi,
+  defaultAssetData,
+} from './_setup';
+
+const drawAndClaimPrint = async (
+  sellerUmi: Umi,
+  jellybeanMachine: PublicKey,
+  collection: PublicKey
+) => {
+  const buyer = await generateSignerWithSol(sellerUmi);
+  const buyerUmi = await createUmi(buyer);
+
+  await drawJellybean(buyerUmi, {
+    jellybeanMachine,
+    mintArgs: {
+      solPayment: some({
+        feeAccounts: [sellerUmi.identity.publicKey],
+      }),
+    },
+  }).sendAndConfirm(buyerUmi);
+
+  const unclaimedPrizes = await fetchUnclaimedPrizesFromSeeds(sellerUmi, {
+    jellybeanMachine,
+    buyer: buyer.publicKey,
+  });
+  const { editionNumber } = unclaimedPrizes.prizes[0];
+
+  const printAsset = generateSigner(buyerUmi);
+  await claimCoreItem(buyerUmi, {
+    jellybeanMachine,
+    buyer: buyer.publicKey,
+    collection,
+    index: 0,
+    printAsset,
+  }).sendAndConfirm(buyerUmi);
+
+  return {
+    editionNumber,
+    printedAsset: await fetchAsset(sellerUmi, printAsset.publicKey),
+  };
+};
+
+test('it prints editions with the templated name and uri', async (t) => {
+  const sellerUmi = await createUmi();
+  const collectionSigner = await createMasterEdition(sellerUmi);
+  const jellybeanMachine = await create(sellerUmi, {
+    items: [{ collection: collectionSigner.publicKey }],
+    startSale: true,
+  });
+
+  const escrowAmountBefore = (
+    await fetchJellybeanMachineWithItems(sellerUmi, jellybeanMachine)
+  ).items[0].escrowAmount;
+
+  await setPrintTemplate(sellerUmi, {
+    jellybeanMachine,
+    collection: collectionSigner.publicKey,
+    index: 0,
+    name: '{name} #{edition}',
+    uri: '{uri}?edition={edition}',
+  }).sendAndConfirm(sellerUmi);
+
+  t.like(
+    await fetchPrintTemplateFromSeeds(sellerUmi, {
+      jellybeanMachine,
+      collection: collectionSigner.publicKey,
+    }),
+    <PrintTemplate>{
+      version: 1,
+      jellybeanMachine,
+      collection: collectionSigner.publicKey,
+      name: '{name} #{edition}',
+      uri: '{uri}?edition={edition}',
+    }
+  );
+
+  // The escrow covers the longer expanded name and uri
+  const escrowAmountAfter = (
+    await fetchJellybeanMachineWithItems(sellerUmi, jellybeanMachine)
+  ).items[0].escrowAmount;
+  t.true(escrowAmountAfter > escrowAmountBefore);
+
+  const { editionNumber, printedAsset } = await drawAndClaimPrint(
+    sellerUmi,
+    jellybeanMachine,
+    collectionSigner.publicKey
+  );
+
+  const assetData = defaultAssetData();
+  t.like(printedAsset, <AssetV1>{
+    name: `${assetData.name} #${editionNumber}`,
+    uri: `${assetData.uri}?edition=${editionNumber}`,
+    edition: { number: editionNumber },
+  });
+});
+
+test('it keeps text around and without placeholders', async (t) => {
+  const sellerUmi = await createUmi();
+  const collectionSigner = await createMasterEdition(sellerUmi);
+  const jellybeanMachine = await create(sellerUmi, {
+    items: [{ collection: collectionSigner.publicKey }],
+    startSale: true,
+  });
+
+  await setPrintTemplate(sellerUmi, {
+    jellybeanMachine,
+    collection: collectionSigner.publicKey,
+    index: 0,
+    name: 'Print {edition} of {edition} {unknown}',
+    uri: 'https://example.com/print.json',
+  }).sendAndConfirm(sellerUmi);
+
+  const { editionNumber, printedAsset } = await drawAndClaimPrint(
+    sellerUmi,
+    jellybeanMachine,
+    collectionSigner.publicKey
+  );
+
+  t.like(printedAsset, <AssetV1>{
+    name: `Print ${editionNumber} of ${editionNumber} {unknown}`,
+    uri: 'https://example.com/print.json',
+  });
+});
+
+test('it replaces an existing print template', async (t) => {
+  const sellerUmi = await createUmi();
+  const collectionSigner = await createMasterEdition(sellerUmi);
+  const jellybeanMachine = await create(sellerUmi, {
+    items: [{ collection: collectionSigner.publicKey }],
+    startSale: true,
+  });
+
+  await setPrintTemplate(sellerUmi, {
+    jellybeanMachine,
+    collection: collectionSigner.publicKey,
+    index: 0,
+    name: '{name} #{edition}',
+    uri: '{uri}',
+  }).sendAndConfirm(sellerUmi);
+  await setPrintTemplate(sellerUmi, {
+    jellybeanMachine,
+    collection: collectionSigner.publicKey,
+    index: 0,
+    name: 'Edition {edition}',
+    uri: '{uri}',
+  }).sendAndConfirm(sellerUmi);
+
+  const { editionNumber, printedAsset } = await drawAndClaimPrint(
+    sellerUmi,
+    jellybeanMachine,
+    collectionSigner.publicKey
+  );
+
+  t.is(printedAsset.name, `Edition ${editionNumber}`);
+});
+
+test('it accepts templates of the maximum length', async (t) => {
+  const sellerUmi = await createUmi();
+  const collectionSigner = await createMasterEdition(sellerUmi);
+  const jellybeanMachine = await create(sellerUmi, {
+    items: [{ collection: collectionSigner.publicKey }],
+  });
+
+  await setPrintTemplate(sellerUmi, {
+    jellybeanMachine,
+    collection: collectionSigner.publicKey,
+    index: 0,
+    name: 'n'.repeat(64),
+    uri: 'u'.repeat(196),
+  }).sendAndConfirm(sellerUmi);
+
+  t.like(
+    await fetchPrintTemplateFromSeeds(sellerUmi, {
+      jellybeanMachine,
+      collection: collectionSigner.publicKey,
+    }),
+    <PrintTemplate>{ name: 'n'.repeat(64), uri: 'u'.repeat(196) }
+  );
+});
+
+test('it cannot set a name template that is too long', async (t) => {
+  const sellerUmi = await createUmi();
+  const collectionSigner = await createMasterEdition(sellerUmi);
+  const jellybeanMachine = await create(sellerUmi, {
+    items: [{ collection: collectionSigner.publicKey }],
+  });
+
+  await t.throwsAsync(
+    setPrintTemplate(sellerUmi, {
+      jellybeanMachine,
+      collection: collectionSigner.publicKey,
+      index: 0,
+      name: 'n'.repeat(65),
+      uri: '{uri}',
+    }).sendAndConfirm(sellerUmi),
+    { message: /PrintTemplateTooLong/ }
+  );
+});
+
+test('it cannot set a uri template that is too long', async (t) => {
+  const sellerUmi = await createUmi();
+  const collectionSigner = await createMasterEdition(sellerUmi);
+  const jellybeanMachine = await create(sellerUmi, {
+    items: [{ collection: collectionSigner.publicKey }],
+  });
+
+  await t.throwsAsync(
+    setPrintTemplate(sellerUmi, {
+      jellybeanMachine,
+      collection: collectionSigner.publicKey,
+      index: 0,
+      name: '{name}',
+      uri: 'u'.repeat(197),
+    }).sendAndConfirm(sellerUmi),
+    { message: /PrintTemplateTooLong/ }
+  );
+});
+
+test('it cannot set a template while prints are unclaimed', async (t) => {
+  const sellerUmi = await createUmi();
+  const collectionSigner = await createMasterEdition(sellerUmi);
+  const jellybeanMachine = await create(sellerUmi, {
+    items: [{ collection: collectionSigner.publicKey }],
+    startSale: true,
+  });
+
+  const buyer = await generateSignerWithSol(sellerUmi);
+  const buyerUmi = await createUmi(buyer);
+  await drawJellybean(buyerUmi, {
+    jellybeanMachine,
+    mintArgs: {
+      solPayment: some({
+        feeAccounts: [sellerUmi.identity.publicKey],
+      }),
+    },
+  }).sendAndConfirm(buyerUmi);
+
+  await t.throwsAsync(
+    setPrintTemplate(sellerUmi, {
+      jellybeanMachine,
+      collection: collectionSigner.publicKey,
+      index: 0,
+      name: '{name} #{edition}',
+      uri: '{uri}',
+    }).sendAndConfirm(sellerUmi),
+    { message: /ItemNotFullyClaimed/ }
+  );
+});
@@ -2,12 +2,17 @@ import { generateSigner } from '@metaplex-foundation/umi';
 import { generateSignerWithSol } from '@metaplex-foundation/umi-bundle-tests';
 import test from 'ava';
 import {
-  endSale,
+  addCoreItem,
   fetchJellybeanMachine,
   JellybeanState,
   startSale,
 } from '../src';
-import { create, createCoreAsset, createUmi } from './_setup';
+import {
+  create,
+  createCoreAsset,
+  createUmi,
+  drawRemainingItems,
+} from './_setup';
 
 test('it can start a sale', async (t) => {
   const umi = await createUmi();
@@ -141,31 +146,61 @@ test('it fails to start a sale that is already live', async (t) => {
   );
 });
 
-test('it fails to start a sale that is already ended', async (t) => {
+test('it can reopen an ended sale after restocking', async (t) => {
   const umi = await createUmi();
-  const jellybeanMachine = generateSigner(umi);
-  const assetSigner = await createCoreAsset(umi);
+  const jellybeanMachine = await create(umi, {
+    startSale: true,
+    items: [
+      {
+        asset: (await createCoreAsset(umi)).publicKey,
+      },
+    ],
+  });
 
-  // Create jellybean machine and start sale
-  await create(umi, {
+  // Drawing the last item ends the sale
+  await drawRemainingItems(umi, jellybeanMachine, 1);
+  let jellybeanMachineAccount = await fetchJellybeanMachine(
+    umi,
+    jellybeanMachine
+  );
+  t.is(jellybeanMachineAccount.state, JellybeanState.SaleEnded);
+
+  // Restock and reopen the sale
+  await addCoreItem(umi, {
     jellybeanMachine,
+    asset: (await createCoreAsset(umi)).publicKey,
+  }).sendAndConfirm(umi);
+
+  await startSale(umi, {
+    jellybeanMachine,
+  }).sendAndConfirm(umi);
+
+  jellybeanMachineAccount = await fetchJellybeanMachine(umi, jellybeanMachine);
+  t.like(jellybeanMachineAccount, {
+    state: JellybeanState.SaleLive,
+    itemsLoaded: 2,
+    supplyLoaded: 2n,
+    supplyRedeemed: 1n,
+  });
+});
+
+test('it fails to reopen an ended sale without remaining supply', async (t) => {
+  const umi = await createUmi();
+  const jellybeanMachine = await create(umi, {
     startSale: true,
     items: [
       {
-        asset: assetSigner.publicKey,
+        asset: (await createCoreAsset(umi)).publicKey,
       },
     ],
   });
 
-  // End the sale
-  await endSale(umi, {
-    jellybeanMachine: jellybeanMachine.publicKey,
-  }).sendAndConfirm(umi);
+  await drawRemainingItems(umi, jellybeanMachine, 1);
 
   // Verify state is SaleEnded
   const jellybeanMachineAccount = await fetchJellybeanMachine(
     umi,
-    jellybeanMachine.publicKey
+    jellybeanMachine
   );
   t.is(jellybeanMachineAccount.state, JellybeanState.SaleEnded);
 
@@ -173,7 +208,7 @@ test('it fails to start a sale that is already ended', async (t) => {
   await t.throwsAsync(
     () =>
       startSale(umi, {
-        jellybeanMachine: jellybeanMachine.publicKey,
+        jellybeanMachine,
       }).sendAndConfirm(umi),
     {
       message: /InvalidState/,
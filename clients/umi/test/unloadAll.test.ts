@@ -0,0 +1,140 @@
+import { fetchAsset, fetchCollection } from '@metaplex-foundation/mpl-core';
+import test from 'ava';
+import {
+  fetchJellybeanMachineWithItems,
+  JellybeanMachineAccountWithItemsData,
+  unloadAll,
+} from '../src';
+import {
+  create,
+  createCoreAsset,
+  createMasterEdition,
+  createUmi,
+  getRemoveItemAccounts,
+} from './_setup';
+
+test('it can unload all items from a jellybean machine', async (t) => {
+  const umi = await createUmi();
+  const asset1Signer = await createCoreAsset(umi);
+  const asset2Signer = await createCoreAsset(umi);
+  const collectionSigner = await createMasterEdition(umi);
+
+  const jellybeanMachine = await create(umi, {
+    items: [
+      {
+        asset: asset1Signer.publicKey,
+      },
+      {
+        asset: asset2Signer.publicKey,
+      },
+      {
+        collection: collectionSigner.publicKey,
+      },
+    ],
+  });
+
+  await unloadAll(umi, {
+    jellybeanMachine,
+  })
+    .addRemainingAccounts(
+      getRemoveItemAccounts(umi, jellybeanMachine, [
+        { asset: asset1Signer.publicKey },
+        { asset: asset2Signer.publicKey },
+        { collection: collectionSigner.publicKey },
+      ])
+    )
+    .sendAndConfirm(umi);
+
+  const jellybeanMachineAccount = await fetchJellybeanMachineWithItems(
+    umi,
+    jellybeanMachine
+  );
+  t.is(jellybeanMachineAccount.itemsLoaded, 0);
+  t.is(jellybeanMachineAccount.supplyLoaded, 0n);
+
+  for (const signer of [asset1Signer, asset2Signer]) {
+    const asset = await fetchAsset(umi, signer.publicKey);
+    t.is(asset.owner, umi.identity.publicKey);
+    t.like(asset.freezeDelegate, { frozen: false });
+  }
+  const collection = await fetchCollection(umi, collectionSigner.publicKey);
+  t.is(collection.updateAuthority, umi.identity.publicKey);
+});
+
+test('it unloads the last items when given the accounts of some of them', async (t) => {
+  const umi = await createUmi();
+  const asset1Signer = await createCoreAsset(umi);
+  const asset2Signer = await createCoreAsset(umi);
+  const asset3Signer = await createCoreAsset(umi);
+
+  const jellybeanMachine = await create(umi, {
+    items: [
+      {
+        asset: asset1Signer.publicKey,
+      },
+      {
+        asset: asset2Signer.publicKey,
+      },
+      {
+        asset: asset3Signer.publicKey,
+      },
+    ],
+  });
+
+  // Only the accounts of the last two items are passed
+  await unloadAll(umi, {
+    jellybeanMachine,
+  })
+    .addRemainingAccounts(
+      getRemoveItemAccounts(umi, jellybeanMachine, [
+        { asset: asset2Signer.publicKey },
+        { asset: asset3Signer.publicKey },
+      ])
+    )
+    .sendAndConfirm(umi);
+
+  const jellybeanMachineAccount = await fetchJellybeanMachineWithItems(
+    umi,
+    jellybeanMachine
+  );
+  t.like(jellybeanMachineAccount, <JellybeanMachineAccountWithItemsData>{
+    itemsLoaded: 1,
+    supplyLoaded: 1n,
+    items: [
+      {
+        index: 0,
+        mint: asset1Signer.publicKey,
+      },
+    ],
+  });
+});
+
+test('it cannot unload items with the accounts of other items', async (t) => {
+  const umi = await createUmi();
+  const asset1Signer = await createCoreAsset(umi);
+  const asset2Signer = await createCoreAsset(umi);
+
+  const jellybeanMachine = await create(umi, {
+    items: [
+      {
+        asset: asset1Signer.publicKey,
+      },
+      {
+        asset: asset2Signer.publicKey,
+      },
+    ],
+  });
+
+  // The last item is asset 2, not asset 1
+  const promise = unloadAll(umi, {
+    jellybeanMachine,
+  })
+    .addRemainingAccounts(
+      getRemoveItemAccounts(umi, jellybeanMachine, [
+        { asset: asset1Signer.publicKey },
+      ])
+    )
+    .sendAndConfirm(umi);
+
+  await t.throwsAsync(promise, { message: /Invalid asset/ });
+});
@@ -2,11 +2,20 @@ import { generateSigner, none, some } from '@metaplex-foundation/umi';
 import { generateSignerWithSol } from '@metaplex-foundation/umi-bundle-tests';
 import test from 'ava';
 import {
+  cancelSale,
   createJellybeanMachine,
   fetchJellybeanMachine,
+  findGuardPhasesPda,
+  setGuardPhases,
   updateSettings,
 } from '../src';
-import { createUmi, getDefaultFeeAccounts } from './_setup';
+import {
+  create,
+  createUmi,
+  getDefaultFeeAccounts,
+  guardPhase,
+  tomorrow,
+} from './_setup';
 
 test('it can update the settings', async (t) => {
   const umi = await createUmi();
@@ -247,3 +256,57 @@ test('it fails to update settings with a long uri', async (t) => {
     message: /UriTooLong/,
   });
 });
+
+test('it fails to update settings of a cancelled sale', async (t) => {
+  const umi = await createUmi();
+  const jellybeanMachine = await create(umi, { startSale: true });
+
+  await cancelSale(umi, { jellybeanMachine }).sendAndConfirm(umi);
+
+  await t.throwsAsync(
+    updateSettings(umi, {
+      jellybeanMachine,
+      args: {
+        uri: 'https://new.example.com/metadata.json',
+        feeAccounts: getDefaultFeeAccounts(umi.identity.publicKey),
+      },
+    }).sendAndConfirm(umi),
+    { message: /InvalidState/ }
+  );
+});
+
+test('it cannot allow public draws while a guard phase is free', async (t) => {
+  const umi = await createUmi();
+  const jellybeanMachine = await create(umi, {
+    guards: {},
+    phases: [guardPhase(), guardPhase({ startAt: tomorrow(), price: 0 })],
+  });
+  const guardPhases = findGuardPhasesPda(umi, { jellybeanMachine });
+
+  const allowPublicDraws = (input: { guardPhases?: typeof guardPhases }) =>
+    updateSettings(umi, {
+      jellybeanMachine,
+      args: {
+        uri: 'https://example.com/jellybean-machine.json',
+        feeAccounts: getDefaultFeeAccounts(umi.identity.publicKey),
+        publicDraw: true,
+      },
+      ...input,
+    }).sendAndConfirm(umi);
+
+  await t.throwsAsync(allowPublicDraws({ guardPhases }), {
+    message: /PublicDrawWithoutPrice/,
+  });
+  // The guard phases are required to check their prices
+  await t.throwsAsync(allowPublicDraws({}), {
+    message: /PublicDrawWithoutPrice/,
+  });
+
+  await setGuardPhases(umi, {
+    jellybeanMachine,
+    phases: [guardPhase(), guardPhase({ startAt: tomorrow() })],
+  }).sendAndConfirm(umi);
+  await allowPublicDraws({ guardPhases });
+
+  t.true((await fetchJellybeanMachine(umi, jellybeanMachine)).publicDraw);
+});
@@ -0,0 +1,163 @@
+import { some } from '@metaplex-foundation/umi';
+import { generateSignerWithSol } from '@metaplex-foundation/umi-bundle-tests';
+import test from 'ava';
+import { BuyerRecord, draw, fetchBuyerRecordFromSeeds } from '../src';
+import {
+  create,
+  createMasterEdition,
+  createUmi,
+  getGuardAccounts,
+  guardPhase,
+} from './_setup';
+
+test('it limits the draws of a wallet over the whole sale', async (t) => {
+  const sellerUmi = await createUmi();
+  const collectionSigner = await createMasterEdition(sellerUmi);
+
+  const jellybeanMachine = await create(sellerUmi, {
+    args: {
+      maxDrawsPerWallet: 1,
+      publicDraw: true,
+    },
+    items: [
+      {
+        collection: collectionSigner.publicKey,
+      },
+    ],
+    guards: {},
+    phases: [guardPhase()],
+    startSale: true,
+  });
+
+  const buyer = await generateSignerWithSol(sellerUmi);
+  const buyerUmi = await createUmi(buyer);
+  const drawWithLimit = () =>
+    draw(buyerUmi, {
+      jellybeanMachine,
+      mintAuthority: sellerUmi.identity.publicKey,
+    })
+      .addRemainingAccounts(
+        getGuardAccounts(buyerUmi, jellybeanMachine, {
+          buyer: buyer.publicKey,
+          buyerRecord: true,
+          feeAccounts: [sellerUmi.identity.publicKey],
+        })
+      )
+      .sendAndConfirm(buyerUmi);
+
+  await drawWithLimit();
+
+  t.like(
+    await fetchBuyerRecordFromSeeds(sellerUmi, {
+      jellybeanMachine,
+      buyer: buyer.publicKey,
+    }),
+    <BuyerRecord>{
+      jellybeanMachine,
+      buyer: buyer.publicKey,
+      draws: 1,
+    }
+  );
+
+  await t.throwsAsync(drawWithLimit(), { message: /WalletLimitReached/ });
+});
+
+test('it limits the draws of a wallet in a phase', async (t) => {
+  const sellerUmi = await createUmi();
+  const collectionSigner = await createMasterEdition(sellerUmi);
+
+  const jellybeanMachine = await create(sellerUmi, {
+    args: {
+      publicDraw: true,
+    },
+    items: [
+      {
+        collection: collectionSigner.publicKey,
+      },
+    ],
+    guards: {},
+    phases: [guardPhase({ maxDrawsPerWallet: some(2) })],
+    startSale: true,
+  });
+
+  const buyer = await generateSignerWithSol(sellerUmi);
+  const buyerUmi = await createUmi(buyer);
+  const drawWithLimit = () =>
+    draw(buyerUmi, {
+      jellybeanMachine,
+      mintAuthority: sellerUmi.identity.publicKey,
+    })
+      .addRemainingAccounts(
+        getGuardAccounts(buyerUmi, jellybeanMachine, {
+          buyer: buyer.publicKey,
+          buyerRecord: true,
+          feeAccounts: [sellerUmi.identity.publicKey],
+        })
+      )
+      .sendAndConfirm(buyerUmi);
+
+  await drawWithLimit();
+  await drawWithLimit();
+
+  const buyerRecord = await fetchBuyerRecordFromSeeds(sellerUmi, {
+    jellybeanMachine,
+    buyer: buyer.publicKey,
+  });
+  t.is(buyerRecord.draws, 2);
+  t.is(buyerRecord.phaseDraws[0], 2);
+
+  await t.throwsAsync(drawWithLimit(), { message: /WalletLimitReached/ });
+});
+
+test('it requires the buyer to sign a draw with a wallet limit', async (t) => {
+  const sellerUmi = await createUmi();
+  const collectionSigner = await createMasterEdition(sellerUmi);
+
+  const jellybeanMachine = await create(sellerUmi, {
+    args: {
+      maxDrawsPerWallet: 1,
+    },
+    items: [
+      {
+        collection: collectionSigner.publicKey,
+      },
+    ],
+    guards: {},
+    startSale: true,
+  });
+
+  const buyer = await generateSignerWithSol(sellerUmi);
+
+  // The mint authority pays for a buyer that doesn't sign
+  const promise = draw(sellerUmi, {
+    jellybeanMachine,
+    buyer: buyer.publicKey,
+  })
+    .addRemainingAccounts(
+      getGuardAccounts(sellerUmi, jellybeanMachine, {
+        buyer: buyer.publicKey,
+        guardPhases: false,
+        buyerRecord: true,
+      })
+    )
+    .sendAndConfirm(sellerUmi);
+
+  await t.throwsAsync(promise, { message: /MissingBuyerSignature/ });
+
+  // Draws the mint authority makes for itself are signed by the buyer
+  await draw(sellerUmi, { jellybeanMachine })
+    .addRemainingAccounts(
+      getGuardAccounts(sellerUmi, jellybeanMachine, {
+        buyer: sellerUmi.identity.publicKey,
+        guardPhases: false,
+        buyerRecord: true,
+      })
+    )
+    .sendAndConfirm(sellerUmi);
+
+  const buyerRecord = await fetchBuyerRecordFromSeeds(sellerUmi, {
+    jellybeanMachine,
+    buyer: sellerUmi.identity.publicKey,
+  });
+  t.is(buyerRecord.draws, 1);
+});
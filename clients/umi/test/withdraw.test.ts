@@ -1,10 +1,20 @@
 import { drawJellybean } from '@mallow-labs/mallow-gumball';
-import { generateSigner, some } from '@metaplex-foundation/umi';
+import {
+  fetchToken,
+  findAssociatedTokenPda,
+  mintTokensTo,
+} from '@metaplex-foundation/mpl-toolbox';
+import { generateSigner, publicKey, some } from '@metaplex-foundation/umi';
 import test from 'ava';
 import {
+  addBundleComponent,
   claimCoreItem,
   closeJellybeanMachine,
   createJellybeanMachine,
+  fetchJellybeanMachine,
+  findAuthorityPda,
+  findBundlePda,
+  removeBundleComponent,
   removeCoreItem,
   safeFetchJellybeanMachine,
   withdraw,
@@ -12,8 +22,10 @@ import {
 import {
   create,
   createCoreAsset,
+  createMintWithHolders,
   createUmi,
   getDefaultFeeAccounts,
+  getWithdrawTokenAccounts,
 } from './_setup';
 
 test('it can close a jellybean machine', async (t) => {
@@ -40,6 +52,130 @@ test('it can close a jellybean machine', async (t) => {
   t.falsy(jellybeanMachineAccount);
 });
 
+test('it sweeps the authority pda when closing a jellybean machine', async (t) => {
+  const umi = await createUmi();
+  const jellybeanMachine = generateSigner(umi);
+  const uri = 'https://example.com/metadata.json';
+  const feeAccounts = getDefaultFeeAccounts(umi.identity.publicKey);
+
+  await (
+    await createJellybeanMachine(umi, {
+      jellybeanMachine,
+      args: { feeAccounts, uri },
+    })
+  ).sendAndConfirm(umi);
+
+  const authorityPda = publicKey(
+    findAuthorityPda(umi, { jellybeanMachine: jellybeanMachine.publicKey })
+  );
+  t.true(await umi.rpc.accountExists(authorityPda));
+
+  await withdraw(umi, {
+    jellybeanMachine: jellybeanMachine.publicKey,
+  }).sendAndConfirm(umi);
+
+  t.false(await umi.rpc.accountExists(authorityPda));
+});
+
+test('it sweeps the token accounts of the authority pda when closing a jellybean machine', async (t) => {
+  const umi = await createUmi();
+  const jellybeanMachine = generateSigner(umi);
+  const uri = 'https://example.com/metadata.json';
+  const feeAccounts = getDefaultFeeAccounts(umi.identity.publicKey);
+
+  await (
+    await createJellybeanMachine(umi, {
+      jellybeanMachine,
+      args: { feeAccounts, uri },
+    })
+  ).sendAndConfirm(umi);
+
+  const [authorityPda] = findAuthorityPda(umi, {
+    jellybeanMachine: jellybeanMachine.publicKey,
+  });
+  const [bundle] = findBundlePda(umi, {
+    jellybeanMachine: jellybeanMachine.publicKey,
+    id: 0,
+  });
+  const mints = [];
+  for (let i = 0; i < 2; i += 1) {
+    const [mint, authorityTokenAccount] = await createMintWithHolders(umi, {
+      holders: [{ owner: umi.identity.publicKey, amount: 10 }],
+    });
+    const [authorityPdaTokenAccount] = findAssociatedTokenPda(umi, {
+      mint: mint.publicKey,
+      owner: authorityPda,
+    });
+    mints.push({ mint, authorityTokenAccount, authorityPdaTokenAccount });
+
+    await addBundleComponent(umi, {
+      jellybeanMachine: jellybeanMachine.publicKey,
+      id: 0,
+      amount: 10,
+      mint: mint.publicKey,
+      authorityTokenAccount,
+      authorityPdaTokenAccount,
+    }).sendAndConfirm(umi);
+  }
+
+  t.is(
+    (await fetchJellybeanMachine(umi, jellybeanMachine.publicKey))
+      .openTokenAccounts,
+    2
+  );
+
+  // Returning the components leaves the emptied token accounts open
+  for (let component = mints.length - 1; component >= 0; component -= 1) {
+    const { mint, authorityTokenAccount, authorityPdaTokenAccount } =
+      mints[component];
+    await removeBundleComponent(umi, {
+      jellybeanMachine: jellybeanMachine.publicKey,
+      bundle,
+      mint: mint.publicKey,
+      authorityTokenAccount,
+      authorityPdaTokenAccount,
+      component,
+    }).sendAndConfirm(umi);
+  }
+  t.true(await umi.rpc.accountExists(mints[0].authorityPdaTokenAccount));
+
+  // Tokens sent to the authority pda afterwards are swept too
+  await mintTokensTo(umi, {
+    mint: mints[0].mint.publicKey,
+    token: mints[0].authorityPdaTokenAccount,
+    amount: 5,
+  }).sendAndConfirm(umi);
+
+  // Every token account opened by a deposit must be passed
+  await t.throwsAsync(
+    withdraw(umi, { jellybeanMachine: jellybeanMachine.publicKey })
+      .addRemainingAccounts(
+        getWithdrawTokenAccounts(umi, jellybeanMachine.publicKey, [
+          mints[0].mint.publicKey,
+        ])
+      )
+      .sendAndConfirm(umi),
+    { message: /TokenAccountsStillOpen/ }
+  );
+
+  await withdraw(umi, { jellybeanMachine: jellybeanMachine.publicKey })
+    .addRemainingAccounts(
+      getWithdrawTokenAccounts(
+        umi,
+        jellybeanMachine.publicKey,
+        mints.map(({ mint }) => mint.publicKey)
+      )
+    )
+    .sendAndConfirm(umi);
+
+  t.falsy(await safeFetchJellybeanMachine(umi, jellybeanMachine.publicKey));
+  for (const { authorityPdaTokenAccount } of mints) {
+    t.false(await umi.rpc.accountExists(authorityPdaTokenAccount));
+  }
+  t.is((await fetchToken(umi, mints[0].authorityTokenAccount)).amount, 15n);
+  t.is((await fetchToken(umi, mints[1].authorityTokenAccount)).amount, 10n);
+});
+
 test('it can close a jellybean machine with a guard', async (t) => {
   const umi = await createUmi();
   const jellybeanMachine = generateSigner(umi);
@@ -54,4 +54,76 @@ pub enum JellybeanError {
     TooManyItems,
     #[msg("Invalid fee accounts length")]
     InvalidFeeAccountsLength,
+    #[msg("Invalid account version")]
+    InvalidVersion,
+    #[msg("Missing print template")]
+    MissingPrintTemplate,
+    #[msg("Print template too long")]
+    PrintTemplateTooLong,
+    #[msg("Print escrow too small for the print name and uri")]
+    InsufficientPrintEscrow,
+    #[msg("The authority pda must be the update authority or an update delegate of the asset")]
+    MachineCannotUpdateAsset,
+    #[msg("Bundle is loaded in the machine")]
+    BundleLoaded,
+    #[msg("Too many bundle components")]
+    TooManyBundleComponents,
+    #[msg("Invalid bundle component")]
+    InvalidBundleComponent,
+    #[msg("Too many disabled items")]
+    TooManyDisabledItems,
+    #[msg("Reveal is not enabled")]
+    RevealNotEnabled,
+    #[msg("Asset is already revealed")]
+    AlreadyRevealed,
+    #[msg("Buyer has reached the limit of every remaining item")]
+    BuyerLimitReached,
+    #[msg("Too many buyer limits")]
+    TooManyBuyerLimits,
+    #[msg("Sale end time must be after its go live time")]
+    InvalidSchedule,
+    #[msg("Sale has not gone live yet")]
+    SaleNotStarted,
+    #[msg("Sale end time has passed")]
+    SaleOver,
+    #[msg("Sale is paused")]
+    SalePaused,
+    #[msg("Too many guard phases")]
+    TooManyGuardPhases,
+    #[msg("Guard phases must be ordered by start time")]
+    InvalidGuardPhases,
+    #[msg("Missing guard account")]
+    MissingGuardAccount,
+    #[msg("No guard phase is active")]
+    NoActivePhase,
+    #[msg("Buyer must sign gated or limited draws")]
+    MissingBuyerSignature,
+    #[msg("Buyer is not in the allowlist")]
+    NotAllowlisted,
+    #[msg("Buyer does not hold the gate token")]
+    MissingGateToken,
+    #[msg("Buyer has reached the draw limit")]
+    WalletLimitReached,
+    #[msg("Guard phases can only be added after the existing ones once the sale has started")]
+    GuardPhasesLocked,
+    #[msg("Guard records can only be closed once the machine is withdrawn or cancelled")]
+    GuardRecordInUse,
+    #[msg("Invalid gate asset")]
+    InvalidGateAsset,
+    #[msg("Gate asset has already been used")]
+    GateAssetUsed,
+    #[msg("Bundle has already been partially delivered")]
+    BundlePartiallyDelivered,
+    #[msg("Claim deadline has not passed yet")]
+    ClaimDeadlineNotReached,
+    #[msg("Bundles still hold escrowed components")]
+    BundlesStillOpen,
+    #[msg("Missing token account")]
+    MissingTokenAccount,
+    #[msg("Public draws require guard phases with a price")]
+    PublicDrawWithoutPrice,
+    #[msg("Missing print fee account")]
+    MissingPrintFeeAccount,
+    #[msg("Authority pda token accounts must be swept")]
+    TokenAccountsStillOpen,
 }
@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+use solana_program::pubkey::Pubkey;
+
+#[event]
+pub struct AddItemEvent {
+    pub authority: Pubkey,
+    pub index: u32,
+    pub count: u32,
+    pub supply_loaded: u64,
+}
@@ -1,5 +1,15 @@
+pub mod add_item_event;
+pub mod add_prints_event;
 pub mod claim_item_event;
 pub mod draw_item_event;
+pub mod reclaim_prize_event;
+pub mod refund_prize_event;
+pub mod reveal_item_event;
 
+pub use add_item_event::*;
+pub use add_prints_event::*;
 pub use claim_item_event::*;
 pub use draw_item_event::*;
+pub use reclaim_prize_event::*;
+pub use refund_prize_event::*;
+pub use reveal_item_event::*;
@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+use solana_program::pubkey::Pubkey;
+
+#[event]
+pub struct ReclaimPrizeEvent {
+    pub authority: Pubkey,
+    pub buyer: Pubkey,
+    pub index: u32,
+    pub edition_number: u32,
+    pub delivered: bool,
+}
@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+use solana_program::pubkey::Pubkey;
+
+#[event]
+pub struct RefundPrizeEvent {
+    pub authority: Pubkey,
+    pub buyer: Pubkey,
+    pub index: u32,
+    pub amount: u64,
+}
@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+use solana_program::pubkey::Pubkey;
+
+#[event]
+pub struct RevealItemEvent {
+    pub authority: Pubkey,
+    pub asset: Pubkey,
+    pub uri: String,
+}
@@ -0,0 +1,175 @@
+use crate::{
+    constants::AUTHORITY_SEED, processors::BundleComponentAccounts, state::JellybeanMachine,
+    Bundle, BundleComponentKind, JellybeanError, MAX_BUNDLE_COMPONENTS,
+};
+use anchor_lang::prelude::*;
+
+/// Escrow a Core asset or tokens in a bundle that isn't loaded yet.
+#[derive(Accounts)]
+#[instruction(id: u32)]
+pub struct AddBundleComponent<'info> {
+    /// Gumball Machine account.
+    #[account(
+        mut,
+        constraint = jellybean_machine.version == JellybeanMachine::CURRENT_VERSION @ JellybeanError::InvalidVersion,
+        has_one = authority @ JellybeanError::InvalidAuthority,
+        constraint = jellybean_machine.can_add_items() @ JellybeanError::InvalidState,
+    )]
+    jellybean_machine: Box<Account<'info, JellybeanMachine>>,
+
+    /// CHECK: Safe due to seeds constraint
+    #[account(
+        seeds = [
+            AUTHORITY_SEED.as_bytes(),
+            jellybean_machine.key().as_ref()
+        ],
+        bump
+    )]
+    authority_pda: UncheckedAccount<'info>,
+
+    /// Authority of the jellybean machine, owner of the escrowed component.
+    authority: Signer<'info>,
+
+    /// Payer for the bundle and token accounts.
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// Bundle account.
+    #[account(
+        init_if_needed,
+        seeds = [
+            Bundle::SEED_PREFIX.as_bytes(),
+            jellybean_machine.key().as_ref(),
+            &id.to_le_bytes(),
+        ],
+        bump,
+        space = Bundle::SIZE,
+        payer = payer,
+    )]
+    bundle: Box<Account<'info, Bundle>>,
+
+    /// Core asset to escrow.
+    /// CHECK: Verified in mpl_core processors
+    #[account(mut)]
+    asset: Option<UncheckedAccount<'info>>,
+
+    /// Core asset's collection if it's part of one.
+    /// CHECK: Verified in mpl_core processors
+    #[account(mut)]
+    collection: Option<UncheckedAccount<'info>>,
+
+    /// Mint of the tokens to escrow.
+    /// CHECK: Verified by the token program
+    mint: Option<UncheckedAccount<'info>>,
+
+    /// Authority token account the tokens are taken from.
+    /// CHECK: Verified by the token program
+    #[account(mut)]
+    authority_token_account: Option<UncheckedAccount<'info>>,
+
+    /// Authority PDA associated token account escrowing the tokens.
+    /// CHECK: Verified by the associated token program
+    #[account(mut)]
+    authority_pda_token_account: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Safe due to address constraint
+    #[account(address = mpl_core::ID)]
+    mpl_core_program: UncheckedAccount<'info>,
+
+    /// CHECK: Safe due to address constraint
+    #[account(address = anchor_spl::token::ID)]
+    token_program: UncheckedAccount<'info>,
+
+    /// CHECK: Safe due to address constraint
+    #[account(address = anchor_spl::associated_token::ID)]
+    associated_token_program: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn add_bundle_component(ctx: Context<AddBundleComponent>, id: u32, amount: u64) -> Result<()> {
+    let bundle = &mut ctx.accounts.bundle;
+
+    if bundle.jellybean_machine == Pubkey::default() {
+        bundle.version = Bundle::CURRENT_VERSION;
+        bundle.jellybean_machine = ctx.accounts.jellybean_machine.key();
+        bundle.id = id;
+
+        let jellybean_machine = &mut ctx.accounts.jellybean_machine;
+        jellybean_machine.open_bundles = jellybean_machine
+            .open_bundles
+            .checked_add(1)
+            .ok_or(JellybeanError::NumericalOverflowError)?;
+    }
+
+    require!(!bundle.loaded, JellybeanError::BundleLoaded);
+    require!(
+        bundle.components.len() < MAX_BUNDLE_COMPONENTS,
+        JellybeanError::TooManyBundleComponents
+    );
+
+    let kind = if ctx.accounts.asset.is_some() {
+        BundleComponentKind::CoreAsset
+    } else {
+        BundleComponentKind::Token
+    };
+
+    // The deposit creates the authority pda token account if it doesn't exist yet, it must
+    // then be swept by the withdraw
+    let opens_token_account = kind == BundleComponentKind::Token
+        && ctx
+            .accounts
+            .authority_pda_token_account
+            .as_ref()
+            .is_some_and(|a| a.data_is_empty());
+
+    let accounts = BundleComponentAccounts {
+        payer: ctx.accounts.payer.to_account_info(),
+        authority_pda: ctx.accounts.authority_pda.to_account_info(),
+        asset: ctx.accounts.asset.as_ref().map(|a| a.to_account_info()),
+        collection: ctx
+            .accounts
+            .collection
+            .as_ref()
+            .map(|a| a.to_account_info()),
+        mint: ctx.accounts.mint.as_ref().map(|a| a.to_account_info()),
+        source_token_account: ctx
+            .accounts
+            .authority_token_account
+            .as_ref()
+            .map(|a| a.to_account_info()),
+        destination_token_account: ctx
+            .accounts
+            .authority_pda_token_account
+            .as_ref()
+            .map(|a| a.to_account_info()),
+        mpl_core_program: ctx.accounts.mpl_core_program.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+    };
+
+    let component = crate::processors::deposit_bundle_component(
+        kind,
+        amount,
+        &ctx.accounts.authority.to_account_info(),
+        &accounts,
+    )?;
+
+    msg!(
+        "Added bundle component: address={}, amount={}",
+        component.address,
+        component.amount
+    );
+    bundle.components.push(component);
+
+    if opens_token_account {
+        let jellybean_machine = &mut ctx.accounts.jellybean_machine;
+        jellybean_machine.open_token_accounts = jellybean_machine
+            .open_token_accounts
+            .checked_add(1)
+            .ok_or(JellybeanError::NumericalOverflowError)?;
+    }
+
+    Ok(())
+}
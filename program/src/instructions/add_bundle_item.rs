@@ -0,0 +1,68 @@
+use crate::{
+    events::AddItemEvent, state::JellybeanMachine, Bundle, ItemKind, JellybeanError, LoadedItem,
+};
+use anchor_lang::prelude::*;
+
+/// Load a bundle as a single item of a jellybean machine. Its components are locked
+/// until the bundle is claimed or removed.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AddBundleItem<'info> {
+    /// Gumball Machine account.
+    #[account(
+        mut,
+        constraint = jellybean_machine.version == JellybeanMachine::CURRENT_VERSION @ JellybeanError::InvalidVersion,
+        has_one = authority @ JellybeanError::InvalidAuthority,
+        constraint = jellybean_machine.can_add_items() @ JellybeanError::InvalidState,
+    )]
+    jellybean_machine: Box<Account<'info, JellybeanMachine>>,
+
+    /// Authority of the jellybean machine.
+    authority: Signer<'info>,
+
+    /// Payer for account reallocation
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// Bundle account.
+    #[account(
+        mut,
+        has_one = jellybean_machine @ JellybeanError::InvalidJellybeanMachine,
+        constraint = !bundle.loaded @ JellybeanError::BundleLoaded,
+        constraint = !bundle.components.is_empty() @ JellybeanError::InvalidBundleComponent,
+    )]
+    bundle: Box<Account<'info, Bundle>>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn add_bundle_item(ctx: Context<AddBundleItem>) -> Result<()> {
+    let payer = &ctx.accounts.payer.to_account_info();
+    let system_program = &ctx.accounts.system_program.to_account_info();
+    let bundle = &mut ctx.accounts.bundle;
+    let jellybean_machine = &mut ctx.accounts.jellybean_machine;
+
+    bundle.loaded = true;
+
+    let loaded_item = LoadedItem {
+        mint: bundle.key(),
+        supply_loaded: 1,
+        supply_redeemed: 0,
+        supply_claimed: 0,
+        escrow_amount: 0,
+        existing_prints: 0,
+        kind: ItemKind::Bundle,
+    };
+
+    let index = jellybean_machine.items_loaded;
+    crate::processors::add_item(jellybean_machine, loaded_item, payer, system_program)?;
+
+    emit_cpi!(AddItemEvent {
+        authority: jellybean_machine.authority,
+        index: index as u32,
+        count: 1,
+        supply_loaded: jellybean_machine.supply_loaded,
+    });
+
+    Ok(())
+}
@@ -1,16 +1,16 @@
-use crate::{constants::AUTHORITY_SEED, state::JellybeanMachine, JellybeanError, LoadedItem};
-use anchor_lang::prelude::*;
-use mpl_core::{
-    instructions::{TransferV1CpiBuilder, UpdateCollectionV1CpiBuilder},
-    Collection,
+use crate::{
+    constants::AUTHORITY_SEED, events::AddItemEvent, state::JellybeanMachine, JellybeanError,
 };
+use anchor_lang::prelude::*;
 
 /// Add core asset to a jellybean machine.
+#[event_cpi]
 #[derive(Accounts)]
 pub struct AddCoreItem<'info> {
     /// Gumball Machine account.
     #[account(
         mut,
+        constraint = jellybean_machine.version == JellybeanMachine::CURRENT_VERSION @ JellybeanError::InvalidVersion,
         has_one = authority @ JellybeanError::InvalidAuthority,
         constraint = jellybean_machine.can_add_items() @ JellybeanError::InvalidState,
     )]
@@ -51,13 +51,14 @@ pub struct AddCoreItem<'info> {
     system_program: Program<'info, System>,
 }
 
-pub fn add_core_item(ctx: Context<AddCoreItem>) -> Result<()> {
+pub fn add_core_item(ctx: Context<AddCoreItem>, supply: Option<u32>) -> Result<()> {
     let authority = &ctx.accounts.authority.to_account_info();
     let authority_pda = &ctx.accounts.authority_pda.to_account_info();
     let mpl_core_program = &ctx.accounts.mpl_core_program.to_account_info();
     let system_program = &ctx.accounts.system_program.to_account_info();
     let payer = &ctx.accounts.payer.to_account_info();
     let jellybean_machine = &mut ctx.accounts.jellybean_machine;
+    let jellybean_machine_info = jellybean_machine.to_account_info();
 
     let collection_info = ctx
         .accounts
@@ -66,79 +67,45 @@ pub fn add_core_item(ctx: Context<AddCoreItem>) -> Result<()> {
         .map(|account| account.to_account_info());
     let collection = collection_info.as_ref();
 
-    let loaded_item = if let Some(asset) = &ctx.accounts.asset {
-        // Transfer the core asset to the authority pda
-        TransferV1CpiBuilder::new(mpl_core_program)
-            .asset(asset)
-            .collection(collection)
-            .payer(authority)
-            .new_owner(authority_pda)
-            .system_program(Some(system_program))
-            .invoke()?;
+    let auth_seeds = [
+        AUTHORITY_SEED.as_bytes(),
+        jellybean_machine_info.key.as_ref(),
+        &[ctx.bumps.authority_pda],
+    ];
 
-        LoadedItem {
-            mint: asset.key(),
-            supply_loaded: 1,
-            supply_redeemed: 0,
-            supply_claimed: 0,
-            escrow_amount: 0,
-        }
+    let loaded_item = if let Some(asset) = &ctx.accounts.asset {
+        crate::processors::load_core_asset(
+            jellybean_machine,
+            asset,
+            collection,
+            authority,
+            authority_pda,
+            mpl_core_program,
+            system_program,
+            &auth_seeds,
+        )?
     } else if let Some(collection_account) = &ctx.accounts.collection {
-        let collection_info = collection_account.to_account_info();
-        let collection = Box::<Collection>::try_from(&collection_info)?;
-
-        require!(
-            collection.base.current_size == 0,
-            JellybeanError::MasterEditionNotEmpty
-        );
-
-        if let Some(master_edition) = collection.plugin_list.master_edition {
-            if let Some(max_supply) = master_edition.master_edition.max_supply {
-                // Update the master edition authority to the authority pda
-                UpdateCollectionV1CpiBuilder::new(mpl_core_program)
-                    .collection(collection_account)
-                    .payer(authority)
-                    .new_update_authority(Some(authority_pda))
-                    .system_program(system_program)
-                    .invoke()?;
-
-                let rent = Rent::get()?;
-                let name = if let Some(name) = master_edition.master_edition.name {
-                    name
-                } else {
-                    collection.base.name
-                };
-
-                let uri = if let Some(uri) = master_edition.master_edition.uri {
-                    uri
-                } else {
-                    collection.base.uri
-                };
-
-                // We escrow funds from the buyer to cover printing fees, this allows the buyer or seller to settle the sale
-                let escrow_amount =
-                // AssetV1 + Edition plugin base size + name + uri size
-                    rent.minimum_balance(108_usize + name.len() + uri.len())
-                        + 1_500_000; // Metaplex fee
-
-                LoadedItem {
-                    mint: collection_account.key(),
-                    supply_loaded: max_supply,
-                    supply_redeemed: collection.base.current_size,
-                    supply_claimed: 0,
-                    escrow_amount,
-                }
-            } else {
-                return err!(JellybeanError::InvalidMasterEditionSupply);
-            }
-        } else {
-            return err!(JellybeanError::MissingMasterEdition);
-        }
+        crate::processors::load_master_edition(
+            collection_account,
+            supply,
+            authority,
+            authority_pda,
+            mpl_core_program,
+            system_program,
+        )?
     } else {
         return err!(JellybeanError::InvalidAsset);
     };
 
+    let index = jellybean_machine.items_loaded;
     crate::processors::add_item(jellybean_machine, loaded_item, payer, system_program)?;
 
+    emit_cpi!(AddItemEvent {
+        authority: jellybean_machine.authority,
+        index: index as u32,
+        count: 1,
+        supply_loaded: jellybean_machine.supply_loaded,
+    });
+
     Ok(())
 }
@@ -0,0 +1,125 @@
+use crate::{
+    constants::AUTHORITY_SEED, events::AddItemEvent, state::JellybeanMachine, JellybeanError,
+};
+use anchor_lang::prelude::*;
+
+/// Add multiple core assets and master editions to a jellybean machine.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AddCoreItems<'info> {
+    /// Gumball Machine account.
+    #[account(
+        mut,
+        constraint = jellybean_machine.version == JellybeanMachine::CURRENT_VERSION @ JellybeanError::InvalidVersion,
+        has_one = authority @ JellybeanError::InvalidAuthority,
+        constraint = jellybean_machine.can_add_items() @ JellybeanError::InvalidState,
+    )]
+    jellybean_machine: Box<Account<'info, JellybeanMachine>>,
+
+    /// CHECK: Safe due to seeds constraint
+    #[account(
+        mut,
+        seeds = [
+            AUTHORITY_SEED.as_bytes(),
+            jellybean_machine.key().as_ref()
+        ],
+        bump
+    )]
+    authority_pda: UncheckedAccount<'info>,
+
+    /// Authority of the jellybean machine.
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    /// Payer for account reallocation
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// CHECK: Safe due to address constraint
+    #[account(address = mpl_core::ID)]
+    mpl_core_program: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+    // Remaining accounts, one pair per item:
+    // - `[writable]` Asset account, or this program's id for a master edition
+    // - `[writable]` Collection account, or this program's id if the asset has no collection
+}
+
+pub fn add_core_items<'info>(
+    ctx: Context<'_, '_, '_, 'info, AddCoreItems<'info>>,
+    supplies: Vec<Option<u32>>,
+) -> Result<()> {
+    let authority = &ctx.accounts.authority.to_account_info();
+    let authority_pda = &ctx.accounts.authority_pda.to_account_info();
+    let mpl_core_program = &ctx.accounts.mpl_core_program.to_account_info();
+    let system_program = &ctx.accounts.system_program.to_account_info();
+    let payer = &ctx.accounts.payer.to_account_info();
+    let jellybean_machine = &mut ctx.accounts.jellybean_machine;
+
+    let item_accounts = ctx.remaining_accounts.chunks_exact(2);
+    if ctx.remaining_accounts.is_empty()
+        || !item_accounts.remainder().is_empty()
+        || item_accounts.len() != supplies.len()
+    {
+        return err!(JellybeanError::InvalidInputLength);
+    }
+
+    let jellybean_machine_info = jellybean_machine.to_account_info();
+    let auth_seeds = [
+        AUTHORITY_SEED.as_bytes(),
+        jellybean_machine_info.key.as_ref(),
+        &[ctx.bumps.authority_pda],
+    ];
+
+    let mut loaded_items = Vec::with_capacity(item_accounts.len());
+
+    for (accounts, supply) in item_accounts.zip(supplies) {
+        let collection = if accounts[1].key() == crate::ID {
+            None
+        } else {
+            Some(&accounts[1])
+        };
+
+        let loaded_item = if accounts[0].key() != crate::ID {
+            // Supplies only apply to master editions
+            require!(supply.is_none(), JellybeanError::InvalidMasterEditionSupply);
+
+            crate::processors::load_core_asset(
+                jellybean_machine,
+                &accounts[0],
+                collection,
+                authority,
+                authority_pda,
+                mpl_core_program,
+                system_program,
+                &auth_seeds,
+            )?
+        } else if let Some(collection) = collection {
+            crate::processors::load_master_edition(
+                collection,
+                supply,
+                authority,
+                authority_pda,
+                mpl_core_program,
+                system_program,
+            )?
+        } else {
+            return err!(JellybeanError::InvalidAsset);
+        };
+
+        loaded_items.push(loaded_item);
+    }
+
+    let index = jellybean_machine.items_loaded;
+    let count = loaded_items.len();
+    crate::processors::add_items(jellybean_machine, loaded_items, payer, system_program)?;
+
+    emit_cpi!(AddItemEvent {
+        authority: jellybean_machine.authority,
+        index: index as u32,
+        count: count as u32,
+        supply_loaded: jellybean_machine.supply_loaded,
+    });
+
+    Ok(())
+}
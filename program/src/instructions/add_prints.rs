@@ -0,0 +1,80 @@
+use crate::{
+    assert_keys_equal, events::AddPrintsEvent, state::JellybeanMachine, JellybeanError,
+    LOADED_ITEM_SUPPLY_LOADED_OFFSET,
+};
+use anchor_lang::prelude::*;
+use mpl_core::Collection;
+
+/// Offer more prints of a master edition already loaded in a jellybean machine.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AddPrints<'info> {
+    /// Gumball Machine account.
+    #[account(
+        mut,
+        constraint = jellybean_machine.version == JellybeanMachine::CURRENT_VERSION @ JellybeanError::InvalidVersion,
+        has_one = authority @ JellybeanError::InvalidAuthority,
+        constraint = jellybean_machine.can_add_items() @ JellybeanError::InvalidState,
+    )]
+    jellybean_machine: Box<Account<'info, JellybeanMachine>>,
+
+    /// Authority of the jellybean machine.
+    authority: Signer<'info>,
+
+    /// Master edition collection of the loaded item.
+    /// CHECK: Verified against the loaded item
+    collection: UncheckedAccount<'info>,
+}
+
+pub fn add_prints(ctx: Context<AddPrints>, index: u16, amount: u32) -> Result<()> {
+    require!(amount > 0, JellybeanError::InvalidMasterEditionSupply);
+
+    let collection_info = ctx.accounts.collection.to_account_info();
+    let jellybean_machine = &mut ctx.accounts.jellybean_machine;
+    let jellybean_machine_info = jellybean_machine.to_account_info();
+
+    let mut data = jellybean_machine_info.data.borrow_mut();
+    let loaded_item = jellybean_machine.get_loaded_item_at_index(&data, index as usize)?;
+
+    assert_keys_equal(collection_info.key(), loaded_item.mint, "Invalid mint")?;
+
+    let collection = Box::<Collection>::try_from(&collection_info)?;
+    let master_edition = collection
+        .plugin_list
+        .master_edition
+        .ok_or(JellybeanError::MissingMasterEdition)?
+        .master_edition;
+
+    let supply_loaded = loaded_item
+        .supply_loaded
+        .checked_add(amount)
+        .ok_or(JellybeanError::NumericalOverflowError)?;
+
+    if let Some(max_supply) = master_edition.max_supply {
+        require!(
+            supply_loaded <= max_supply,
+            JellybeanError::InvalidMasterEditionSupply
+        );
+    }
+
+    let item_position = jellybean_machine.get_loaded_item_position(index as usize);
+    let supply_loaded_slice: &mut [u8] = &mut data[item_position + LOADED_ITEM_SUPPLY_LOADED_OFFSET
+        ..item_position + LOADED_ITEM_SUPPLY_LOADED_OFFSET + 4];
+    supply_loaded_slice.copy_from_slice(&u32::to_le_bytes(supply_loaded));
+
+    drop(data);
+
+    jellybean_machine.supply_loaded = jellybean_machine
+        .supply_loaded
+        .checked_add(amount as u64)
+        .ok_or(JellybeanError::NumericalOverflowError)?;
+
+    emit_cpi!(AddPrintsEvent {
+        authority: jellybean_machine.authority,
+        index: index as u32,
+        amount,
+        supply_loaded: jellybean_machine.supply_loaded,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use crate::{constants::AUTHORITY_SEED, state::JellybeanMachine, JellybeanError, JellybeanState};
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+
+/// Calls off a sale. Draws and claims stop, and every unclaimed prize can be refunded
+/// with refund_prize.
+#[derive(Accounts)]
+pub struct CancelSale<'info> {
+    /// Jellybean machine account.
+    #[account(
+        mut,
+        constraint = jellybean_machine.version == JellybeanMachine::CURRENT_VERSION @ JellybeanError::InvalidVersion,
+        has_one = authority @ JellybeanError::InvalidAuthority,
+        constraint = jellybean_machine.can_claim_items() @ JellybeanError::InvalidState
+    )]
+    jellybean_machine: Box<Account<'info, JellybeanMachine>>,
+
+    /// CHECK: Safe due to seeds constraint
+    #[account(
+        mut,
+        seeds = [
+            AUTHORITY_SEED.as_bytes(),
+            jellybean_machine.key().as_ref()
+        ],
+        bump
+    )]
+    authority_pda: UncheckedAccount<'info>,
+
+    /// Authority of the jellybean machine, funds the refunds of the draw prices.
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn cancel_sale(ctx: Context<CancelSale>) -> Result<()> {
+    let jellybean_machine = &mut ctx.accounts.jellybean_machine;
+
+    // Draw proceeds went to the fee accounts, so the authority escrows the prices paid for
+    // every prize not claimed yet. Edition escrows are already held by the authority pda.
+    let refund_amount = jellybean_machine.unclaimed_price;
+    if refund_amount > 0 {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: ctx.accounts.authority_pda.to_account_info(),
+                },
+            ),
+            refund_amount,
+        )?;
+    }
+
+    jellybean_machine.state = JellybeanState::Cancelled;
+
+    msg!("Cancelled sale: refund amount={}", refund_amount);
+
+    Ok(())
+}
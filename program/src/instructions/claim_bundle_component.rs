@@ -0,0 +1,222 @@
+use crate::{
+    assert_keys_equal, constants::AUTHORITY_SEED, events::ClaimItemEvent, processors,
+    processors::BundleComponentAccounts, state::JellybeanMachine, Bundle, JellybeanError,
+    JellybeanState, UnclaimedPrizes, LOADED_ITEM_SUPPLY_CLAIMED_OFFSET,
+};
+use anchor_lang::prelude::*;
+
+/// Deliver one component of a drawn bundle to its buyer. The prize is settled once every
+/// component has been delivered.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimBundleComponent<'info> {
+    /// Anyone can settle the sale
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// Jellybean machine account.
+    #[account(
+        mut,
+        constraint = jellybean_machine.version == JellybeanMachine::CURRENT_VERSION @ JellybeanError::InvalidVersion,
+        constraint = jellybean_machine.can_claim_items() || jellybean_machine.state == JellybeanState::Cancelled @ JellybeanError::InvalidState
+    )]
+    jellybean_machine: Box<Account<'info, JellybeanMachine>>,
+
+    /// CHECK: Safe due to seeds constraint
+    #[account(
+        mut,
+        seeds = [
+            AUTHORITY_SEED.as_bytes(),
+            jellybean_machine.key().as_ref()
+        ],
+        bump
+    )]
+    authority_pda: UncheckedAccount<'info>,
+
+    /// Authority of the jellybean machine, receives the bundle rent once delivered.
+    /// CHECK: Safe due to address constraint
+    #[account(mut, address = jellybean_machine.authority @ JellybeanError::InvalidAuthority)]
+    authority: UncheckedAccount<'info>,
+
+    /// buyer of the bundle
+    /// CHECK: Safe due to unclaimed prizes check
+    #[account(mut)]
+    buyer: UncheckedAccount<'info>,
+
+    /// Buyer unclaimed draws account.
+    #[account(
+        mut,
+        seeds = [
+            UnclaimedPrizes::SEED_PREFIX.as_bytes(),
+            jellybean_machine.key().as_ref(),
+            buyer.key().as_ref(),
+        ],
+        bump,
+        constraint = unclaimed_prizes.version == UnclaimedPrizes::CURRENT_VERSION @ JellybeanError::InvalidVersion,
+        has_one = buyer @ JellybeanError::InvalidBuyer,
+        has_one = jellybean_machine @ JellybeanError::InvalidJellybeanMachine
+    )]
+    unclaimed_prizes: Box<Account<'info, UnclaimedPrizes>>,
+
+    /// Bundle account.
+    #[account(
+        mut,
+        has_one = jellybean_machine @ JellybeanError::InvalidJellybeanMachine,
+    )]
+    bundle: Box<Account<'info, Bundle>>,
+
+    /// Core asset to deliver.
+    /// CHECK: Verified against the bundle component
+    #[account(mut)]
+    asset: Option<UncheckedAccount<'info>>,
+
+    /// Core asset's collection if it's part of one.
+    /// CHECK: Verified in mpl_core processors
+    #[account(mut)]
+    collection: Option<UncheckedAccount<'info>>,
+
+    /// Mint of the tokens to deliver.
+    /// CHECK: Verified against the bundle component
+    mint: Option<UncheckedAccount<'info>>,
+
+    /// Authority PDA associated token account escrowing the tokens.
+    /// CHECK: Verified in processors
+    #[account(mut)]
+    authority_pda_token_account: Option<UncheckedAccount<'info>>,
+
+    /// Buyer associated token account receiving the tokens.
+    /// CHECK: Verified by the associated token program
+    #[account(mut)]
+    buyer_token_account: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Safe due to address constraint
+    #[account(address = mpl_core::ID)]
+    mpl_core_program: UncheckedAccount<'info>,
+
+    /// CHECK: Safe due to address constraint
+    #[account(address = anchor_spl::token::ID)]
+    token_program: UncheckedAccount<'info>,
+
+    /// CHECK: Safe due to address constraint
+    #[account(address = anchor_spl::associated_token::ID)]
+    associated_token_program: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn claim_bundle_component<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClaimBundleComponent<'info>>,
+    index: u16,
+    component: u8,
+) -> Result<()> {
+    let payer = &ctx.accounts.payer.to_account_info();
+    let buyer = &ctx.accounts.buyer.to_account_info();
+    let jellybean_machine = &ctx.accounts.jellybean_machine;
+    let jellybean_machine_info = &jellybean_machine.to_account_info();
+    let unclaimed_prizes = &mut ctx.accounts.unclaimed_prizes;
+    let bundle = &mut ctx.accounts.bundle;
+
+    // The prize stays with the buyer until the whole bundle is delivered
+    require!(
+        unclaimed_prizes
+            .prizes
+            .iter()
+            .any(|prize| prize.item_index == index),
+        JellybeanError::InvalidItemIndex
+    );
+
+    let mut data = jellybean_machine_info.data.borrow_mut();
+    let loaded_item = jellybean_machine.get_loaded_item_at_index(&data, index as usize)?;
+    assert_keys_equal(bundle.key(), loaded_item.mint, "Invalid bundle")?;
+
+    // A cancelled sale only finishes delivering the bundles it can no longer refund
+    require!(
+        jellybean_machine.state != JellybeanState::Cancelled
+            || bundle
+                .components
+                .iter()
+                .any(|component| component.delivered),
+        JellybeanError::InvalidState
+    );
+
+    let bundle_component = bundle
+        .components
+        .get(component as usize)
+        .filter(|bundle_component| !bundle_component.delivered)
+        .ok_or(JellybeanError::InvalidBundleComponent)?
+        .clone();
+
+    let accounts = BundleComponentAccounts {
+        payer: payer.clone(),
+        authority_pda: ctx.accounts.authority_pda.to_account_info(),
+        asset: ctx.accounts.asset.as_ref().map(|a| a.to_account_info()),
+        collection: ctx
+            .accounts
+            .collection
+            .as_ref()
+            .map(|a| a.to_account_info()),
+        mint: ctx.accounts.mint.as_ref().map(|a| a.to_account_info()),
+        source_token_account: ctx
+            .accounts
+            .authority_pda_token_account
+            .as_ref()
+            .map(|a| a.to_account_info()),
+        destination_token_account: ctx
+            .accounts
+            .buyer_token_account
+            .as_ref()
+            .map(|a| a.to_account_info()),
+        mpl_core_program: ctx.accounts.mpl_core_program.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+    };
+
+    let auth_seeds = [
+        AUTHORITY_SEED.as_bytes(),
+        jellybean_machine_info.key.as_ref(),
+        &[ctx.bumps.authority_pda],
+    ];
+
+    processors::deliver_bundle_component(&bundle_component, buyer, &accounts, &auth_seeds)?;
+
+    bundle.components[component as usize].delivered = true;
+
+    if !bundle.is_delivered() {
+        msg!("Delivered bundle component: {}", component);
+        return Ok(());
+    }
+
+    let prize = unclaimed_prizes.claim_item(index)?;
+
+    let item_position = jellybean_machine.get_loaded_item_position(index as usize);
+    let supply_claimed_slice: &mut [u8] = &mut data[item_position
+        + LOADED_ITEM_SUPPLY_CLAIMED_OFFSET
+        ..item_position + LOADED_ITEM_SUPPLY_CLAIMED_OFFSET + 4];
+    supply_claimed_slice.copy_from_slice(&u32::to_le_bytes(loaded_item.supply_claimed + 1));
+
+    drop(data);
+
+    processors::settle_unclaimed_prizes(
+        &ctx.accounts.jellybean_machine,
+        unclaimed_prizes,
+        buyer,
+        payer,
+    )?;
+
+    bundle.close(ctx.accounts.authority.to_account_info())?;
+
+    let jellybean_machine = &mut ctx.accounts.jellybean_machine;
+    jellybean_machine.open_bundles = jellybean_machine.open_bundles.saturating_sub(1);
+    // A claimed prize can no longer be refunded
+    jellybean_machine.unclaimed_price = jellybean_machine
+        .unclaimed_price
+        .saturating_sub(prize.price);
+
+    emit_cpi!(ClaimItemEvent {
+        authority: ctx.accounts.jellybean_machine.authority.key(),
+        edition_number: prize.edition_number,
+    });
+
+    Ok(())
+}
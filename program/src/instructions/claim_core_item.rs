@@ -1,13 +1,8 @@
 use crate::{
-    assert_keys_equal, constants::AUTHORITY_SEED, events::ClaimItemEvent, state::JellybeanMachine,
-    JellybeanError, JellybeanState, UnclaimedPrizes,
+    assert_keys_equal, constants::AUTHORITY_SEED, events::ClaimItemEvent, processors,
+    state::JellybeanMachine, JellybeanError, UnclaimedPrizes, LOADED_ITEM_SUPPLY_CLAIMED_OFFSET,
 };
 use anchor_lang::prelude::*;
-use mpl_core::{
-    instructions::{CreateV1CpiBuilder, TransferV1CpiBuilder},
-    types::{Edition, Plugin, PluginAuthorityPair},
-    Collection,
-};
 
 #[event_cpi]
 #[derive(Accounts)]
@@ -19,7 +14,8 @@ pub struct ClaimCoreItem<'info> {
     /// Jellybean machine account.
     #[account(
         mut,
-        constraint = jellybean_machine.state == JellybeanState::SaleLive || jellybean_machine.state == JellybeanState::SaleEnded @ JellybeanError::InvalidState
+        constraint = jellybean_machine.version == JellybeanMachine::CURRENT_VERSION @ JellybeanError::InvalidVersion,
+        constraint = jellybean_machine.can_claim_items() @ JellybeanError::InvalidState
     )]
     jellybean_machine: Box<Account<'info, JellybeanMachine>>,
 
@@ -48,6 +44,7 @@ pub struct ClaimCoreItem<'info> {
             buyer.key().as_ref(),
         ],
         bump,
+        constraint = unclaimed_prizes.version == UnclaimedPrizes::CURRENT_VERSION @ JellybeanError::InvalidVersion,
         has_one = buyer @ JellybeanError::InvalidBuyer,
         has_one = jellybean_machine @ JellybeanError::InvalidJellybeanMachine
     )]
@@ -65,6 +62,10 @@ pub struct ClaimCoreItem<'info> {
     #[account(mut)]
     print_asset: Option<Signer<'info>>,
 
+    /// Print template of the master edition, required when claiming an edition.
+    /// CHECK: Verified in processors
+    print_template: Option<UncheckedAccount<'info>>,
+
     /// CHECK: Safe due to constraint
     #[account(address = mpl_core::ID)]
     mpl_core_program: UncheckedAccount<'info>,
@@ -74,14 +75,14 @@ pub struct ClaimCoreItem<'info> {
 
 pub fn claim_core_item<'info>(
     ctx: Context<'_, '_, '_, 'info, ClaimCoreItem<'info>>,
-    index: u8,
+    index: u16,
 ) -> Result<()> {
     let unclaimed_prizes = &mut ctx.accounts.unclaimed_prizes;
     let jellybean_machine = &ctx.accounts.jellybean_machine;
     let jellybean_machine_info = &jellybean_machine.to_account_info();
     let payer = &ctx.accounts.payer.to_account_info();
     let buyer = &ctx.accounts.buyer.to_account_info();
-    let authority_pda = &mut ctx.accounts.authority_pda.to_account_info();
+    let authority_pda = &ctx.accounts.authority_pda.to_account_info();
     let mpl_core_program = &ctx.accounts.mpl_core_program.to_account_info();
     let system_program = &ctx.accounts.system_program.to_account_info();
 
@@ -103,64 +104,47 @@ pub fn claim_core_item<'info>(
     let mut data = jellybean_machine_info.data.borrow_mut();
     let loaded_item = jellybean_machine.get_loaded_item_at_index(&data, index as usize)?;
 
+    let accounts = processors::DeliverAccounts {
+        payer,
+        buyer,
+        authority_pda,
+        mpl_core_program,
+        system_program,
+    };
+
     let mint = if let Some(asset) = &ctx.accounts.asset {
-        // Transfer the core asset to the buyer
-        TransferV1CpiBuilder::new(mpl_core_program)
-            .asset(asset)
-            .collection(collection)
-            .payer(payer)
-            .authority(Some(authority_pda))
-            .new_owner(buyer)
-            .system_program(Some(system_program))
-            .invoke_signed(&[&auth_seeds])?;
+        processors::deliver_core_asset(
+            jellybean_machine,
+            &prize,
+            asset,
+            collection,
+            &accounts,
+            &auth_seeds,
+        )?;
 
         asset.key()
     } else if let Some(collection_account) = &ctx.accounts.collection {
-        let collection_info = collection_account.to_account_info();
-        let collection = Box::<Collection>::try_from(&collection_info)?;
-        let plugin = collection.plugin_list.master_edition.unwrap();
-
-        let edition_number = prize.edition_number;
         let print_asset = ctx
             .accounts
             .print_asset
             .as_ref()
             .ok_or(JellybeanError::MissingPrintAsset)?;
-
-        msg!("printing edition number: {}", edition_number);
-
-        // Send any escrow amount to the payer first
-        if loaded_item.escrow_amount > 0 {
-            let mut authority_lamports = authority_pda.try_borrow_mut_lamports()?;
-            **authority_lamports -= loaded_item.escrow_amount;
-            let mut payer_lamports = payer.try_borrow_mut_lamports()?;
-            **payer_lamports += loaded_item.escrow_amount;
-        }
-
-        CreateV1CpiBuilder::new(mpl_core_program)
-            .asset(print_asset)
-            .collection(Some(collection_account))
-            .payer(payer)
-            .name(if let Some(name) = plugin.master_edition.name {
-                name
-            } else {
-                collection.base.name
-            })
-            .uri(if let Some(uri) = plugin.master_edition.uri {
-                uri
-            } else {
-                collection.base.uri
-            })
-            .owner(Some(buyer))
-            .plugins(vec![PluginAuthorityPair {
-                authority: None,
-                plugin: Plugin::Edition(Edition {
-                    number: edition_number,
-                }),
-            }])
-            .system_program(system_program)
-            .authority(Some(authority_pda))
-            .invoke_signed(&[&auth_seeds])?;
+        let print_template_info = ctx
+            .accounts
+            .print_template
+            .as_ref()
+            .map(|account| account.to_account_info());
+
+        processors::print_core_edition(
+            jellybean_machine,
+            &loaded_item,
+            &prize,
+            collection_account,
+            print_asset,
+            print_template_info.as_ref(),
+            &accounts,
+            &auth_seeds,
+        )?;
 
         collection_account.key()
     } else {
@@ -170,42 +154,25 @@ pub fn claim_core_item<'info>(
     assert_keys_equal(mint, loaded_item.mint, "Invalid mint")?;
 
     let item_position = jellybean_machine.get_loaded_item_position(index as usize);
-    let supply_claimed_slice: &mut [u8] = &mut data[item_position + 40..item_position + 44];
+    let supply_claimed_slice: &mut [u8] = &mut data[item_position
+        + LOADED_ITEM_SUPPLY_CLAIMED_OFFSET
+        ..item_position + LOADED_ITEM_SUPPLY_CLAIMED_OFFSET + 4];
     supply_claimed_slice.copy_from_slice(&u32::to_le_bytes(loaded_item.supply_claimed + 1));
 
     drop(data);
 
-    // Close unclaimed_prize account back to the buyer if it's empty
-    // If buyer account is closed (0 lamports), refund to payer instead
-    if unclaimed_prizes.prizes.is_empty() {
-        let refund_destination = if buyer.lamports() > 0 {
-            buyer.to_account_info()
-        } else {
-            payer.to_account_info()
-        };
-        unclaimed_prizes.close(refund_destination)?;
-    } else {
-        // Calculate new space needed and reallocate if smaller
-        let new_space = UnclaimedPrizes::space(unclaimed_prizes.prizes.len());
-        let rent = Rent::get()?;
-        let new_rent_minimum = rent.minimum_balance(new_space);
-        let current_lamports = unclaimed_prizes.to_account_info().lamports();
-
-        // Reallocate the account
-        unclaimed_prizes
-            .to_account_info()
-            .realloc(new_space, false)?;
-
-        // Refund excess rent to buyer, or payer if buyer account is closed
-        let excess_lamports = current_lamports - new_rent_minimum;
-        if excess_lamports > 0 {
-            let refund_destination = if buyer.lamports() > 0 { buyer } else { payer };
-            **unclaimed_prizes
-                .to_account_info()
-                .try_borrow_mut_lamports()? -= excess_lamports;
-            **refund_destination.try_borrow_mut_lamports()? += excess_lamports;
-        }
-    }
+    processors::settle_unclaimed_prizes(
+        &ctx.accounts.jellybean_machine,
+        unclaimed_prizes,
+        buyer,
+        payer,
+    )?;
+
+    // A claimed prize can no longer be refunded
+    let jellybean_machine = &mut ctx.accounts.jellybean_machine;
+    jellybean_machine.unclaimed_price = jellybean_machine
+        .unclaimed_price
+        .saturating_sub(prize.price);
 
     emit_cpi!(ClaimItemEvent {
         authority: ctx.accounts.jellybean_machine.authority.key(),
@@ -0,0 +1,76 @@
+use crate::{
+    assert_keys_equal, AllowlistProof, BuyerRecord, JellybeanError, JellybeanMachine,
+    JellybeanState, UsedGateAsset,
+};
+use anchor_lang::{prelude::*, Discriminator};
+
+/// Close a buyer record, allowlist proof or used gate asset account of a machine that was
+/// withdrawn or cancelled, and send its rent back to the buyer.
+#[derive(Accounts)]
+pub struct CloseGuardRecord<'info> {
+    /// Gumball Machine account, closed once it has been withdrawn.
+    /// CHECK: Safe due to record and state checks
+    jellybean_machine: UncheckedAccount<'info>,
+
+    /// Buyer record, allowlist proof or used gate asset account.
+    /// CHECK: Safe due to owner and discriminator checks
+    #[account(mut, owner = crate::ID)]
+    guard_record: UncheckedAccount<'info>,
+
+    /// Buyer of the record, gets the rent back.
+    /// CHECK: Safe due to record check
+    #[account(mut)]
+    buyer: UncheckedAccount<'info>,
+}
+
+pub fn close_guard_record(ctx: Context<CloseGuardRecord>) -> Result<()> {
+    let jellybean_machine = &ctx.accounts.jellybean_machine.to_account_info();
+    let guard_record = &ctx.accounts.guard_record.to_account_info();
+    let buyer = &ctx.accounts.buyer.to_account_info();
+
+    let (record_machine, record_buyer) = {
+        let data = guard_record.data.borrow();
+        let discriminator = data
+            .get(..8)
+            .ok_or(ErrorCode::AccountDiscriminatorNotFound)?;
+
+        if discriminator == BuyerRecord::DISCRIMINATOR {
+            let record = BuyerRecord::try_deserialize(&mut &data[..])?;
+            (record.jellybean_machine, record.buyer)
+        } else if discriminator == AllowlistProof::DISCRIMINATOR {
+            let record = AllowlistProof::try_deserialize(&mut &data[..])?;
+            (record.jellybean_machine, record.buyer)
+        } else if discriminator == UsedGateAsset::DISCRIMINATOR {
+            let record = UsedGateAsset::try_deserialize(&mut &data[..])?;
+            (record.jellybean_machine, record.buyer)
+        } else {
+            return err!(ErrorCode::AccountDiscriminatorMismatch);
+        }
+    };
+
+    assert_keys_equal(
+        record_machine,
+        jellybean_machine.key(),
+        "Invalid jellybean machine",
+    )?;
+    assert_keys_equal(record_buyer, buyer.key(), "Invalid buyer")?;
+
+    // Records keep holding limits and used gates while the sale can still go on
+    let is_settled = jellybean_machine.data_is_empty()
+        || (jellybean_machine.owner == &crate::ID
+            && JellybeanMachine::try_deserialize(&mut &jellybean_machine.data.borrow()[..])?.state
+                == JellybeanState::Cancelled);
+    require!(is_settled, JellybeanError::GuardRecordInUse);
+
+    let mut buyer_lamports = buyer.try_borrow_mut_lamports()?;
+    **buyer_lamports = buyer_lamports
+        .checked_add(guard_record.lamports())
+        .ok_or(JellybeanError::NumericalOverflowError)?;
+    drop(buyer_lamports);
+    **guard_record.try_borrow_mut_lamports()? = 0;
+
+    guard_record.assign(&System::id());
+    guard_record.realloc(0, false)?;
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use crate::{JellybeanError, JellybeanMachine, JellybeanState, UnclaimedPrizes};
+use anchor_lang::prelude::*;
+
+/// Close a buyer's unclaimed prizes account that only holds item wins once the sale is
+/// cancelled or the machine withdrawn, and send its rent back to the buyer.
+#[derive(Accounts)]
+pub struct CloseUnclaimedPrizes<'info> {
+    /// Gumball Machine account, closed once it has been withdrawn.
+    /// CHECK: Safe due to unclaimed prizes and state checks
+    jellybean_machine: UncheckedAccount<'info>,
+
+    /// Buyer unclaimed draws account.
+    #[account(
+        mut,
+        close = buyer,
+        constraint = unclaimed_prizes.version == UnclaimedPrizes::CURRENT_VERSION @ JellybeanError::InvalidVersion,
+        has_one = buyer @ JellybeanError::InvalidBuyer,
+        has_one = jellybean_machine @ JellybeanError::InvalidJellybeanMachine,
+        constraint = unclaimed_prizes.prizes.is_empty() @ JellybeanError::ItemNotFullyClaimed
+    )]
+    unclaimed_prizes: Account<'info, UnclaimedPrizes>,
+
+    /// Buyer of the prizes, gets the rent back.
+    /// CHECK: Safe due to unclaimed prizes check
+    #[account(mut)]
+    buyer: UncheckedAccount<'info>,
+}
+
+pub fn close_unclaimed_prizes(ctx: Context<CloseUnclaimedPrizes>) -> Result<()> {
+    let jellybean_machine = &ctx.accounts.jellybean_machine.to_account_info();
+
+    // Item wins limit the buyer's draws while the sale can still go on, an ended sale can
+    // be reopened
+    let is_settled = jellybean_machine.data_is_empty()
+        || (jellybean_machine.owner == &crate::ID
+            && JellybeanMachine::try_deserialize(&mut &jellybean_machine.data.borrow()[..])?.state
+                == JellybeanState::Cancelled);
+    require!(is_settled, JellybeanError::InvalidState);
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use crate::{
-    assert_keys_equal, constants::AUTHORITY_SEED, events::DrawItemEvent, JellybeanError,
-    JellybeanMachine, JellybeanState, LoadedItem, Prize, UnclaimedPrizes,
+    assert_keys_equal, constants::AUTHORITY_SEED, events::DrawItemEvent, processors,
+    processors::GuardAccounts, ItemKind, JellybeanError, JellybeanMachine, JellybeanState,
+    LoadedItem, Prize, UnclaimedPrizes, LOADED_ITEM_SIZE, LOADED_ITEM_SUPPLY_LOADED_OFFSET,
     LOADED_ITEM_SUPPLY_REDEMED_OFFSET,
 };
 use anchor_lang::{
@@ -17,8 +18,9 @@ pub struct Draw<'info> {
     /// Jellybean machine account.
     #[account(
         mut,
+        constraint = jellybean_machine.version == JellybeanMachine::CURRENT_VERSION @ JellybeanError::InvalidVersion,
         has_one = mint_authority,
-        constraint = jellybean_machine.state == JellybeanState::SaleLive @ JellybeanError::InvalidState
+        constraint = jellybean_machine.public_draw || mint_authority.is_signer @ JellybeanError::InvalidMintAuthority,
     )]
     jellybean_machine: Box<Account<'info, JellybeanMachine>>,
 
@@ -34,7 +36,10 @@ pub struct Draw<'info> {
     authority_pda: UncheckedAccount<'info>,
 
     /// Jellybean machine mint authority (mint only allowed for the mint_authority).
-    mint_authority: Signer<'info>,
+    /// Only required to sign if the machine doesn't allow public draws.
+    ///
+    /// CHECK: Safe due to has_one and signer constraints
+    mint_authority: UncheckedAccount<'info>,
 
     /// Payer for the transaction and account allocation (rent).
     #[account(mut)]
@@ -54,7 +59,7 @@ pub struct Draw<'info> {
             buyer.key().as_ref(),
         ],
         bump,
-        space = if unclaimed_prizes.data_is_empty() {UnclaimedPrizes::space(0)} else {unclaimed_prizes.data_len()},
+        space = if unclaimed_prizes.data_is_empty() {UnclaimedPrizes::space(0, 0)} else {unclaimed_prizes.data_len()},
         payer = payer,
     )]
     unclaimed_prizes: Box<Account<'info, UnclaimedPrizes>>,
@@ -91,15 +96,123 @@ pub fn draw<'info>(ctx: Context<'_, '_, '_, 'info, Draw<'info>>) -> Result<()> {
     let jellybean_machine = &mut ctx.accounts.jellybean_machine;
     let unclaimed_prizes = &mut ctx.accounts.unclaimed_prizes;
 
-    if unclaimed_prizes.jellybean_machine == Pubkey::default() {
+    let is_new_unclaimed_prizes = unclaimed_prizes.jellybean_machine == Pubkey::default();
+    if is_new_unclaimed_prizes {
         unclaimed_prizes.version = UnclaimedPrizes::CURRENT_VERSION;
         unclaimed_prizes.jellybean_machine = jellybean_machine.key();
         unclaimed_prizes.buyer = ctx.accounts.buyer.key();
+    } else {
+        // Older accounts must be migrated with migrate_unclaimed_prizes before drawing
+        require!(
+            unclaimed_prizes.version == UnclaimedPrizes::CURRENT_VERSION,
+            JellybeanError::InvalidVersion
+        );
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    if jellybean_machine.is_scheduled_to_go_live(now) {
+        jellybean_machine.state = JellybeanState::SaleLive;
     }
 
-    // Calculate space needed for one more prize
-    let current_len = unclaimed_prizes.prizes.len();
-    let new_space = UnclaimedPrizes::space(current_len + 1);
+    let jellybean_machine_info = jellybean_machine.to_account_info();
+    let excluded_items = jellybean_machine
+        .get_excluded_items(&jellybean_machine_info.data.borrow(), unclaimed_prizes)?;
+
+    let is_public_draw = !ctx.accounts.mint_authority.is_signer;
+    let result =
+        check_draw_rules(jellybean_machine, &excluded_items, now).and_then(|supply_drawable| {
+            let mut price = 0;
+            if is_public_draw
+                || jellybean_machine.has_guard_phases
+                || jellybean_machine.max_draws_per_wallet.is_some()
+            {
+                price = processors::apply_guards(
+                    jellybean_machine,
+                    GuardAccounts {
+                        buyer: &ctx.accounts.buyer.to_account_info(),
+                        payer: &ctx.accounts.payer.to_account_info(),
+                        system_program: &ctx.accounts.system_program.to_account_info(),
+                        remaining_accounts: ctx.remaining_accounts,
+                    },
+                    now,
+                    is_public_draw,
+                )?;
+            }
+            Ok((supply_drawable, price))
+        });
+
+    let (supply_drawable, price) = match result {
+        Ok(result) => result,
+        Err(error) => match jellybean_machine.bot_tax {
+            // Nothing has been drawn, burned or paid yet, so the draw can end successfully.
+            // Only direct public draws are taxed, a wrapping program may have charged already.
+            // The tax is held by the machine account and paid to the authority with its rent.
+            Some(bot_tax) if is_public_draw && is_bot_taxed(&error) => {
+                transfer(
+                    CpiContext::new(
+                        ctx.accounts.system_program.to_account_info(),
+                        Transfer {
+                            from: ctx.accounts.payer.to_account_info(),
+                            to: jellybean_machine_info,
+                        },
+                    ),
+                    bot_tax,
+                )?;
+                msg!("Jellybean Machine Botting is taxed: {}", error);
+
+                // No prize was drawn, so a buyer account created by this draw isn't kept
+                if is_new_unclaimed_prizes {
+                    unclaimed_prizes.close(ctx.accounts.payer.to_account_info())?;
+                }
+                return Ok(());
+            }
+            _ => return Err(error),
+        },
+    };
+
+    let accounts = DrawAccounts {
+        recent_slothashes: ctx.accounts.recent_slothashes.to_account_info(),
+        payer: ctx.accounts.payer.to_account_info(),
+        authority_pda: ctx.accounts.authority_pda.to_account_info(),
+        print_fee_account: ctx
+            .accounts
+            .print_fee_account
+            .as_ref()
+            .map(|a| a.to_account_info()),
+        system_program: ctx.accounts.system_program.to_account_info(),
+    };
+
+    let (mut prize, item) = process_draw(
+        jellybean_machine,
+        &excluded_items,
+        supply_drawable,
+        accounts,
+    )?;
+
+    msg!(
+        "Drew item at index: {} edition: {}",
+        prize.item_index,
+        prize.edition_number
+    );
+
+    // The price is refunded if the sale is cancelled before the prize is claimed
+    prize.price = price;
+    jellybean_machine.unclaimed_price = jellybean_machine
+        .unclaimed_price
+        .checked_add(price)
+        .ok_or(JellybeanError::NumericalOverflowError)?;
+    unclaimed_prizes.prizes.push(prize);
+
+    // Tally the wins of limited items so the buyer can't exceed them in later draws
+    if jellybean_machine
+        .get_buyer_limit(prize.item_index)
+        .is_some()
+    {
+        unclaimed_prizes.record_win(item.mint);
+    }
+
+    // Calculate space needed for the new prize and item wins
+    let new_space = unclaimed_prizes.current_space();
 
     let rent = Rent::get()?;
     let new_rent_minimum = rent.minimum_balance(new_space);
@@ -120,33 +233,11 @@ pub fn draw<'info>(ctx: Context<'_, '_, '_, 'info, Draw<'info>>) -> Result<()> {
         )?;
     }
 
-    // Reallocate the account
+    // Reallocate the account, the prizes are written when the instruction exits
     unclaimed_prizes
         .to_account_info()
         .realloc(new_space, false)?;
 
-    let accounts = DrawAccounts {
-        recent_slothashes: ctx.accounts.recent_slothashes.to_account_info(),
-        payer: ctx.accounts.payer.to_account_info(),
-        authority_pda: ctx.accounts.authority_pda.to_account_info(),
-        print_fee_account: ctx
-            .accounts
-            .print_fee_account
-            .as_ref()
-            .map(|a| a.to_account_info()),
-        system_program: ctx.accounts.system_program.to_account_info(),
-    };
-
-    let prize = process_draw(jellybean_machine, accounts)?;
-
-    msg!(
-        "Drew item at index: {} edition: {}",
-        prize.item_index,
-        prize.edition_number
-    );
-    // Append prize to unclaimed_prizes - this should now have enough space
-    unclaimed_prizes.prizes.push(prize);
-
     emit_cpi!(DrawItemEvent {
         authority: ctx.accounts.jellybean_machine.authority.key(),
         index: prize.item_index as u32,
@@ -156,6 +247,60 @@ pub fn draw<'info>(ctx: Context<'_, '_, '_, 'info, Draw<'info>>) -> Result<()> {
     Ok(())
 }
 
+/// Checks the rules of the machine a draw must satisfy: the sale is live and open at this
+/// time, and there is supply the buyer can draw. Returns the drawable supply.
+fn check_draw_rules(
+    jellybean_machine: &Account<'_, JellybeanMachine>,
+    excluded_items: &[u16],
+    now: i64,
+) -> Result<u64> {
+    require!(
+        jellybean_machine.state != JellybeanState::Paused,
+        JellybeanError::SalePaused
+    );
+    require!(
+        jellybean_machine.state == JellybeanState::SaleLive,
+        JellybeanError::InvalidState
+    );
+    require!(
+        !jellybean_machine.is_before_go_live(now),
+        JellybeanError::SaleNotStarted
+    );
+    require!(
+        !jellybean_machine.is_past_end(now) && !jellybean_machine.is_past_claim_deadline(now),
+        JellybeanError::SaleOver
+    );
+
+    let account_info = jellybean_machine.to_account_info();
+    let account_data = account_info.data.borrow();
+    jellybean_machine.get_drawable_supply(&account_data, excluded_items)
+}
+
+/// Whether a failed draw broke a machine rule, and can be taxed instead of failing.
+fn is_bot_taxed(error: &Error) -> bool {
+    const BOT_TAXED_ERRORS: [JellybeanError; 12] = [
+        JellybeanError::InvalidState,
+        JellybeanError::SalePaused,
+        JellybeanError::SaleNotStarted,
+        JellybeanError::SaleOver,
+        JellybeanError::JellybeanMachineEmpty,
+        JellybeanError::BuyerLimitReached,
+        JellybeanError::NoActivePhase,
+        JellybeanError::NotAllowlisted,
+        JellybeanError::MissingGateToken,
+        JellybeanError::InvalidGateAsset,
+        JellybeanError::GateAssetUsed,
+        JellybeanError::WalletLimitReached,
+    ];
+
+    match error {
+        Error::AnchorError(error) => BOT_TAXED_ERRORS
+            .into_iter()
+            .any(|code| error.error_code_number == u32::from(code)),
+        Error::ProgramError(_) => false,
+    }
+}
+
 /// Mint a new NFT.
 ///
 /// The index minted depends on the configuration of the jellybean machine: it could be
@@ -163,15 +308,12 @@ pub fn draw<'info>(ctx: Context<'_, '_, '_, 'info, Draw<'info>>) -> Result<()> {
 /// specific index, the jellybean machine does not allow to mint the same index again.
 pub(crate) fn process_draw<'a>(
     jellybean_machine: &mut Box<Account<'a, JellybeanMachine>>,
+    excluded_items: &[u16],
+    supply_drawable: u64,
     accounts: DrawAccounts<'a>,
-) -> Result<Prize> {
+) -> Result<(Prize, LoadedItem)> {
     let supply_loaded = jellybean_machine.supply_loaded;
 
-    // are there items to be minted?
-    if jellybean_machine.supply_redeemed >= supply_loaded {
-        return err!(JellybeanError::JellybeanMachineEmpty);
-    }
-
     // (2) selecting an item to mint
     let recent_slothashes = &accounts.recent_slothashes;
     let data = recent_slothashes.data.borrow();
@@ -182,12 +324,13 @@ pub(crate) fn process_draw<'a>(
     let seed = u64::from_le_bytes(*most_recent).saturating_sub(clock.unix_timestamp as u64);
 
     let target_supply_index: usize =
-        seed.checked_rem(supply_loaded - jellybean_machine.supply_redeemed)
+        seed.checked_rem(supply_drawable)
             .ok_or(JellybeanError::NumericalOverflowError)? as usize;
 
     let (prize, item) = get_prize_and_update_supply_redeemed(
         jellybean_machine,
         jellybean_machine.items_loaded,
+        excluded_items,
         target_supply_index,
     )?;
 
@@ -215,11 +358,13 @@ pub(crate) fn process_draw<'a>(
         )?;
     }
 
-    // Is master edition?
-    if item.supply_loaded > 1 {
+    if item.kind == ItemKind::MasterEdition {
         if let Some(print_fee_config) = &jellybean_machine.print_fee_config {
             if print_fee_config.amount > 0 {
-                let fee_account = accounts.print_fee_account.as_ref().unwrap();
+                let fee_account = accounts
+                    .print_fee_account
+                    .as_ref()
+                    .ok_or(JellybeanError::MissingPrintFeeAccount)?;
                 assert_keys_equal(
                     fee_account.key(),
                     print_fee_config.address,
@@ -243,57 +388,80 @@ pub(crate) fn process_draw<'a>(
     // release the data borrow
     drop(data);
 
-    Ok(prize)
+    Ok((prize, item))
 }
 
 /// Get the prize for a given target supply index.
 /// The target supply index is the index of the item in the remaining supply across all items.
+/// Prints that existed before a master edition was loaded are counted in its supply_redeemed,
+/// so they are not drawable and edition numbers continue after them. Excluded items, disabled
+/// or at the buyer's limit, are skipped.
 fn get_prize_and_update_supply_redeemed(
     jellybean_machine: &mut Box<Account<'_, JellybeanMachine>>,
-    items_loaded: u8,
+    items_loaded: u16,
+    excluded_items: &[u16],
     target_supply_index: usize,
 ) -> Result<(Prize, LoadedItem)> {
     let account_info = jellybean_machine.to_account_info();
     let mut account_data = account_info.data.borrow_mut();
 
-    // Iterate the loaded items section of the account data
+    // Find the item the target falls in, only reading the supply fields of the loaded items
+    // section so a draw stays within the compute budget with MAX_ITEMS items
+    let items_start = jellybean_machine.get_loaded_item_position(0);
+    let items_end = jellybean_machine.get_loaded_item_position(items_loaded as usize);
     let mut remaining_supply_covered = 0;
-
-    for i in 0..items_loaded {
-        let item = jellybean_machine.get_loaded_item_at_index(&account_data, i as usize)?;
-        let remaining_supply = item.supply_loaded - item.supply_redeemed;
+    let mut prize_index = None;
+
+    for (i, item_data) in account_data[items_start..items_end]
+        .chunks_exact(LOADED_ITEM_SIZE)
+        .enumerate()
+    {
+        let supply_loaded =
+            u32::from_le_bytes(*array_ref![item_data, LOADED_ITEM_SUPPLY_LOADED_OFFSET, 4]);
+        let supply_redeemed =
+            u32::from_le_bytes(*array_ref![item_data, LOADED_ITEM_SUPPLY_REDEMED_OFFSET, 4]);
+        let remaining_supply = supply_loaded.saturating_sub(supply_redeemed) as usize;
 
         // Skip items with no remaining supply
         if remaining_supply == 0 {
             continue;
         }
 
+        // Skip items taken out of the draw pool or that the buyer can't win anymore
+        if !excluded_items.is_empty() && excluded_items.contains(&(i as u16)) {
+            continue;
+        }
+
         // Check if the target index falls within this item's remaining supply
-        if target_supply_index < remaining_supply_covered + remaining_supply as usize {
-            // Update the supply_redeemed count for the item
-            let new_supply_redeemed = item
-                .supply_redeemed
-                .checked_add(1)
-                .ok_or(JellybeanError::NumericalOverflowError)?;
-
-            let item_position = jellybean_machine.get_loaded_item_position(i as usize);
-            // 36 is the offset of the supply_redeemed field in the LoadedItem struct
-            let supply_redeemed_slice = &mut account_data[item_position
-                + LOADED_ITEM_SUPPLY_REDEMED_OFFSET
-                ..item_position + LOADED_ITEM_SUPPLY_REDEMED_OFFSET + 4];
-            supply_redeemed_slice.copy_from_slice(&u32::to_le_bytes(new_supply_redeemed));
-
-            return Ok((
-                Prize {
-                    item_index: i,
-                    edition_number: new_supply_redeemed,
-                },
-                item,
-            ));
+        if target_supply_index < remaining_supply_covered + remaining_supply {
+            prize_index = Some(i as u16);
+            break;
         }
 
-        remaining_supply_covered += remaining_supply as usize;
+        remaining_supply_covered += remaining_supply;
     }
 
-    err!(JellybeanError::IndexGreaterThanLength)
+    let index = prize_index.ok_or(JellybeanError::IndexGreaterThanLength)?;
+    let item = jellybean_machine.get_loaded_item_at_index(&account_data, index as usize)?;
+
+    // Update the supply_redeemed count for the item
+    let new_supply_redeemed = item
+        .supply_redeemed
+        .checked_add(1)
+        .ok_or(JellybeanError::NumericalOverflowError)?;
+
+    let item_position = jellybean_machine.get_loaded_item_position(index as usize);
+    let supply_redeemed_slice = &mut account_data[item_position + LOADED_ITEM_SUPPLY_REDEMED_OFFSET
+        ..item_position + LOADED_ITEM_SUPPLY_REDEMED_OFFSET + 4];
+    supply_redeemed_slice.copy_from_slice(&u32::to_le_bytes(new_supply_redeemed));
+
+    Ok((
+        Prize {
+            item_index: index,
+            edition_number: new_supply_redeemed,
+            draw_slot: Clock::get()?.slot,
+            price: 0,
+        },
+        item,
+    ))
 }
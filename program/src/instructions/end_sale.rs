@@ -7,8 +7,9 @@ pub struct EndSale<'info> {
     /// Gumball machine account.
     #[account(
         mut,
+        constraint = jellybean_machine.version == JellybeanMachine::CURRENT_VERSION @ JellybeanError::InvalidVersion,
         has_one = authority,
-        constraint = jellybean_machine.state != JellybeanState::SaleEnded @ JellybeanError::InvalidState
+        constraint = !jellybean_machine.is_closed() @ JellybeanError::InvalidState
     )]
     jellybean_machine: Box<Account<'info, JellybeanMachine>>,
 
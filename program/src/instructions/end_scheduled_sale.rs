@@ -0,0 +1,28 @@
+use crate::{state::JellybeanMachine, JellybeanError, JellybeanState};
+use anchor_lang::prelude::*;
+
+/// Ends a sale once its end time has passed. Anyone can crank it.
+#[derive(Accounts)]
+pub struct EndScheduledSale<'info> {
+    /// Jellybean machine account.
+    #[account(
+        mut,
+        constraint = jellybean_machine.version == JellybeanMachine::CURRENT_VERSION @ JellybeanError::InvalidVersion,
+        constraint = !jellybean_machine.is_closed() @ JellybeanError::InvalidState
+    )]
+    jellybean_machine: Box<Account<'info, JellybeanMachine>>,
+}
+
+pub fn end_scheduled_sale(ctx: Context<EndScheduledSale>) -> Result<()> {
+    let jellybean_machine = &mut ctx.accounts.jellybean_machine;
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        jellybean_machine.is_past_end(now),
+        JellybeanError::InvalidState
+    );
+
+    jellybean_machine.state = JellybeanState::SaleEnded;
+
+    Ok(())
+}
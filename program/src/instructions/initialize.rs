@@ -46,8 +46,8 @@ pub struct Initialize<'info> {
 pub fn initialize(ctx: Context<Initialize>, args: SettingsArgs) -> Result<()> {
     let jellybean_machine_account = &mut ctx.accounts.jellybean_machine;
 
-    // Validate settings arguments
-    validate_settings_args(&args)?;
+    // Validate settings arguments, guard phases can only be set once the machine exists
+    validate_settings_args(&args, &[])?;
 
     let jellybean_machine = JellybeanMachine {
         version: JellybeanMachine::CURRENT_VERSION,
@@ -60,7 +60,22 @@ pub fn initialize(ctx: Context<Initialize>, args: SettingsArgs) -> Result<()> {
         supply_redeemed: 0,
         state: JellybeanState::None,
         uri: args.uri,
-        padding: [0; 320],
+        provenance_config: args.provenance_config,
+        disabled_items: vec![],
+        reveal_config: args.reveal_config,
+        buyer_limits: vec![],
+        max_wins_per_item: args.max_wins_per_item,
+        go_live_at: args.go_live_at,
+        end_at: args.end_at,
+        has_guard_phases: false,
+        max_draws_per_wallet: args.max_draws_per_wallet,
+        bot_tax: args.bot_tax,
+        unclaimed_price: 0,
+        claim_deadline: args.claim_deadline,
+        public_draw: args.public_draw,
+        open_bundles: 0,
+        open_token_accounts: 0,
+        padding: [0; 20],
     };
 
     let mut struct_data = JellybeanMachine::discriminator().try_to_vec().unwrap();
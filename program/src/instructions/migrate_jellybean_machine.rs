@@ -0,0 +1,150 @@
+use crate::{
+    assert_keys_equal, FeeAccount, ItemKind, JellybeanError, JellybeanMachine, JellybeanState,
+    PrintFeeConfig, LOADED_ITEM_ESCROW_AMOUNT_OFFSET, LOADED_ITEM_SIZE,
+};
+use anchor_lang::{prelude::*, Discriminator};
+use arrayref::array_ref;
+
+/// Migrates a jellybean machine account to the current version.
+#[derive(Accounts)]
+pub struct MigrateJellybeanMachine<'info> {
+    /// Jellybean machine account.
+    ///
+    /// CHECK: account is deserialized manually using its version's layout
+    #[account(mut, owner = crate::ID)]
+    jellybean_machine: UncheckedAccount<'info>,
+
+    /// Gumball Machine authority. This is the address that controls the upate of the jellybean machine.
+    authority: Signer<'info>,
+
+    /// Payer for the rent of the larger loaded items.
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+/// Version 0 loaded item size, before existing prints and the item kind were recorded.
+const LOADED_ITEM_SIZE_V0: usize = LOADED_ITEM_SIZE - 5;
+
+/// Version 0 layout, before item indices were widened to u16.
+#[derive(AnchorDeserialize)]
+struct JellybeanMachineV0 {
+    version: u8,
+    authority: Pubkey,
+    mint_authority: Pubkey,
+    fee_accounts: Vec<FeeAccount>,
+    print_fee_config: Option<PrintFeeConfig>,
+    items_loaded: u8,
+    supply_loaded: u64,
+    supply_redeemed: u64,
+    state: JellybeanState,
+    uri: String,
+    padding: [u8; 320],
+}
+
+pub fn migrate_jellybean_machine(ctx: Context<MigrateJellybeanMachine>) -> Result<()> {
+    let account_info = ctx.accounts.jellybean_machine.to_account_info();
+    let mut data = account_info.data.borrow_mut();
+
+    require!(
+        data.len() > 8 && data[0..8] == JellybeanMachine::discriminator(),
+        ErrorCode::AccountDiscriminatorMismatch
+    );
+    require!(data[8] == 0, JellybeanError::InvalidVersion);
+
+    let legacy = JellybeanMachineV0::deserialize(&mut &data[8..])?;
+    assert_keys_equal(
+        legacy.authority,
+        ctx.accounts.authority.key(),
+        "Invalid authority",
+    )?;
+
+    // The widened items_loaded and the fields added since take their bytes from the
+    // padding, so the serialized size and the position of the first loaded item are
+    // unchanged.
+    let mut padding = [0; 20];
+    padding.copy_from_slice(&legacy.padding[..20]);
+
+    let jellybean_machine = JellybeanMachine {
+        version: JellybeanMachine::CURRENT_VERSION,
+        authority: legacy.authority,
+        mint_authority: legacy.mint_authority,
+        fee_accounts: legacy.fee_accounts,
+        print_fee_config: legacy.print_fee_config,
+        items_loaded: legacy.items_loaded as u16,
+        supply_loaded: legacy.supply_loaded,
+        supply_redeemed: legacy.supply_redeemed,
+        state: legacy.state,
+        uri: legacy.uri,
+        provenance_config: None,
+        disabled_items: vec![],
+        reveal_config: None,
+        buyer_limits: vec![],
+        max_wins_per_item: None,
+        go_live_at: None,
+        end_at: None,
+        has_guard_phases: false,
+        max_draws_per_wallet: None,
+        bot_tax: None,
+        unclaimed_price: 0,
+        claim_deadline: None,
+        public_draw: false,
+        open_bundles: 0,
+        open_token_accounts: 0,
+        padding,
+    };
+
+    msg!(
+        "Migrated jellybean machine from version {} to {}",
+        legacy.version,
+        jellybean_machine.version
+    );
+
+    let struct_data = jellybean_machine.try_to_vec()?;
+    data[8..8 + struct_data.len()].copy_from_slice(&struct_data);
+    drop(data);
+
+    // Loaded items gained their existing prints and kind
+    let new_space = jellybean_machine.get_size(jellybean_machine.items_loaded as u64);
+    let additional_lamports = Rent::get()?
+        .minimum_balance(new_space)
+        .saturating_sub(account_info.lamports());
+    if additional_lamports > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: account_info.clone(),
+                },
+            ),
+            additional_lamports,
+        )?;
+    }
+    account_info.realloc(new_space, false)?;
+
+    let base_size = jellybean_machine.get_base_size();
+    let mut data = account_info.data.borrow_mut();
+    for index in (0..jellybean_machine.items_loaded as usize).rev() {
+        let source = base_size + index * LOADED_ITEM_SIZE_V0;
+        let destination = jellybean_machine.get_loaded_item_position(index);
+        data.copy_within(source..source + LOADED_ITEM_SIZE_V0, destination);
+        data[destination + LOADED_ITEM_SIZE_V0..destination + LOADED_ITEM_SIZE].fill(0);
+
+        // Version 0 counted no existing prints and only escrowed for master editions
+        let escrow_amount = u64::from_le_bytes(*array_ref![
+            data,
+            destination + LOADED_ITEM_ESCROW_AMOUNT_OFFSET,
+            8
+        ]);
+        let kind = if escrow_amount > 0 {
+            ItemKind::MasterEdition
+        } else {
+            ItemKind::CoreAsset
+        };
+        data[destination + LOADED_ITEM_SIZE - 1] = kind as u8;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use crate::{JellybeanError, Prize, UnclaimedPrizes};
+use anchor_lang::{prelude::*, Discriminator};
+
+/// Migrates a buyer's unclaimed prizes account to the current version.
+#[derive(Accounts)]
+pub struct MigrateUnclaimedPrizes<'info> {
+    /// Payer for the account reallocation (anyone can migrate).
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// Buyer unclaimed draws account.
+    ///
+    /// CHECK: account is deserialized manually using its version's layout
+    #[account(mut, owner = crate::ID)]
+    unclaimed_prizes: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+/// Version 0 layout, before item indices were widened to u16 and item wins were recorded.
+#[derive(AnchorDeserialize)]
+struct UnclaimedPrizesV0 {
+    version: u8,
+    jellybean_machine: Pubkey,
+    buyer: Pubkey,
+    prizes: Vec<PrizeV0>,
+}
+
+/// Version 0 prize, before the draw slot was recorded.
+#[derive(AnchorDeserialize)]
+struct PrizeV0 {
+    item_index: u8,
+    edition_number: u32,
+}
+
+pub fn migrate_unclaimed_prizes(ctx: Context<MigrateUnclaimedPrizes>) -> Result<()> {
+    let account_info = ctx.accounts.unclaimed_prizes.to_account_info();
+
+    let legacy = {
+        let data = account_info.data.borrow();
+        require!(
+            data.len() > 8 && data[0..8] == UnclaimedPrizes::discriminator(),
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        require!(data[8] == 0, JellybeanError::InvalidVersion);
+        UnclaimedPrizesV0::deserialize(&mut &data[8..])?
+    };
+
+    // Draw slots of legacy prizes are unknown and left at zero
+    let unclaimed_prizes = UnclaimedPrizes {
+        version: UnclaimedPrizes::CURRENT_VERSION,
+        jellybean_machine: legacy.jellybean_machine,
+        buyer: legacy.buyer,
+        prizes: legacy
+            .prizes
+            .iter()
+            .map(|prize| Prize {
+                item_index: prize.item_index as u16,
+                edition_number: prize.edition_number,
+                draw_slot: 0,
+                price: 0,
+            })
+            .collect(),
+        wins: vec![],
+    };
+
+    // The account grows with the wider prizes, top up the rent before reallocating
+    let new_space = unclaimed_prizes.current_space();
+    let rent = Rent::get()?;
+    let additional_lamports = rent
+        .minimum_balance(new_space)
+        .saturating_sub(account_info.lamports());
+
+    if additional_lamports > 0 {
+        anchor_lang::system_program::transfer(
+            anchor_lang::context::CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: account_info.clone(),
+                },
+            ),
+            additional_lamports,
+        )?;
+    }
+
+    account_info.realloc(new_space, false)?;
+
+    msg!(
+        "Migrated unclaimed prizes from version {} to {}",
+        legacy.version,
+        unclaimed_prizes.version
+    );
+
+    let struct_data = unclaimed_prizes.try_to_vec()?;
+    let mut data = account_info.data.borrow_mut();
+    data[8..8 + struct_data.len()].copy_from_slice(&struct_data);
+
+    Ok(())
+}
@@ -1,21 +1,73 @@
+pub mod add_bundle_component;
+pub mod add_bundle_item;
 pub mod add_core_item;
+pub mod add_core_items;
+pub mod add_prints;
+pub mod cancel_sale;
+pub mod claim_bundle_component;
 pub mod claim_core_item;
+pub mod close_guard_record;
+pub mod close_unclaimed_prizes;
 pub mod draw;
 pub mod end_sale;
+pub mod end_scheduled_sale;
 pub mod initialize;
+pub mod migrate_jellybean_machine;
+pub mod migrate_unclaimed_prizes;
+pub mod pause_sale;
+pub mod prove_allowlist;
+pub mod reclaim_prize;
+pub mod refund_prize;
+pub mod remove_bundle_component;
+pub mod remove_bundle_item;
 pub mod remove_core_item;
+pub mod remove_core_items;
+pub mod replace_core_item;
+pub mod resume_sale;
+pub mod reveal_items;
+pub mod set_guard_phases;
+pub mod set_item_buyer_limit;
+pub mod set_item_enabled;
 pub mod set_mint_authority;
+pub mod set_print_template;
 pub mod start_sale;
+pub mod unload_all;
 pub mod update_settings;
 pub mod withdraw;
 
+pub use add_bundle_component::*;
+pub use add_bundle_item::*;
 pub use add_core_item::*;
+pub use add_core_items::*;
+pub use add_prints::*;
+pub use cancel_sale::*;
+pub use claim_bundle_component::*;
 pub use claim_core_item::*;
+pub use close_guard_record::*;
+pub use close_unclaimed_prizes::*;
 pub use draw::*;
 pub use end_sale::*;
+pub use end_scheduled_sale::*;
 pub use initialize::*;
+pub use migrate_jellybean_machine::*;
+pub use migrate_unclaimed_prizes::*;
+pub use pause_sale::*;
+pub use prove_allowlist::*;
+pub use reclaim_prize::*;
+pub use refund_prize::*;
+pub use remove_bundle_component::*;
+pub use remove_bundle_item::*;
 pub use remove_core_item::*;
+pub use remove_core_items::*;
+pub use replace_core_item::*;
+pub use resume_sale::*;
+pub use reveal_items::*;
+pub use set_guard_phases::*;
+pub use set_item_buyer_limit::*;
+pub use set_item_enabled::*;
 pub use set_mint_authority::*;
+pub use set_print_template::*;
 pub use start_sale::*;
+pub use unload_all::*;
 pub use update_settings::*;
 pub use withdraw::*;
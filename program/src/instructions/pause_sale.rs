@@ -0,0 +1,24 @@
+use crate::{state::JellybeanMachine, JellybeanError, JellybeanState};
+use anchor_lang::prelude::*;
+
+/// Temporarily blocks draws on a live sale, leaving claims and settings untouched.
+#[derive(Accounts)]
+pub struct PauseSale<'info> {
+    /// Jellybean machine account.
+    #[account(
+        mut,
+        constraint = jellybean_machine.version == JellybeanMachine::CURRENT_VERSION @ JellybeanError::InvalidVersion,
+        constraint = authority.key() == jellybean_machine.authority || authority.key() == jellybean_machine.mint_authority @ JellybeanError::InvalidAuthority,
+        constraint = jellybean_machine.state == JellybeanState::SaleLive @ JellybeanError::InvalidState
+    )]
+    jellybean_machine: Box<Account<'info, JellybeanMachine>>,
+
+    /// Jellybean Machine authority. This can be the mint authority or the authority.
+    authority: Signer<'info>,
+}
+
+pub fn pause_sale(ctx: Context<PauseSale>) -> Result<()> {
+    ctx.accounts.jellybean_machine.state = JellybeanState::Paused;
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use crate::{
+    state::JellybeanMachine, verify_merkle_proof, AllowlistProof, GuardPhases, JellybeanError,
+};
+use anchor_lang::prelude::*;
+
+/// Records that the buyer is in the allowlist of a guard phase.
+#[derive(Accounts)]
+#[instruction(merkle_root: [u8; 32])]
+pub struct ProveAllowlist<'info> {
+    /// Gumball Machine account.
+    #[account(
+        constraint = jellybean_machine.version == JellybeanMachine::CURRENT_VERSION @ JellybeanError::InvalidVersion,
+    )]
+    jellybean_machine: Box<Account<'info, JellybeanMachine>>,
+
+    /// Guard phases account.
+    #[account(
+        seeds = [
+            GuardPhases::SEED_PREFIX.as_bytes(),
+            jellybean_machine.key().as_ref(),
+        ],
+        bump,
+    )]
+    guard_phases: Box<Account<'info, GuardPhases>>,
+
+    /// Buyer in the allowlist.
+    buyer: Signer<'info>,
+
+    /// Payer for the allowlist proof account.
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// Allowlist proof account.
+    #[account(
+        init,
+        seeds = [
+            AllowlistProof::SEED_PREFIX.as_bytes(),
+            jellybean_machine.key().as_ref(),
+            merkle_root.as_ref(),
+            buyer.key().as_ref(),
+        ],
+        bump,
+        space = AllowlistProof::SIZE,
+        payer = payer,
+    )]
+    allowlist_proof: Box<Account<'info, AllowlistProof>>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn prove_allowlist(
+    ctx: Context<ProveAllowlist>,
+    merkle_root: [u8; 32],
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let buyer = ctx.accounts.buyer.key();
+
+    require!(
+        ctx.accounts
+            .guard_phases
+            .phases
+            .iter()
+            .any(|phase| phase.allowlist_root == Some(merkle_root)),
+        JellybeanError::NotAllowlisted
+    );
+    require!(
+        verify_merkle_proof(&proof, &merkle_root, &buyer),
+        JellybeanError::NotAllowlisted
+    );
+
+    let allowlist_proof = &mut ctx.accounts.allowlist_proof;
+    allowlist_proof.jellybean_machine = ctx.accounts.jellybean_machine.key();
+    allowlist_proof.merkle_root = merkle_root;
+    allowlist_proof.buyer = buyer;
+
+    Ok(())
+}
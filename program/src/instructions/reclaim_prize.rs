@@ -0,0 +1,216 @@
+use crate::{
+    assert_keys_equal, constants::AUTHORITY_SEED, events::ReclaimPrizeEvent, processors,
+    state::JellybeanMachine, JellybeanError, UnclaimedPrizes, LOADED_ITEM_SUPPLY_CLAIMED_OFFSET,
+};
+use anchor_lang::prelude::*;
+
+/// Settle a prize its buyer didn't claim before the machine's claim deadline. The prize is
+/// delivered to the buyer whenever possible, and only written off when the asset can no
+/// longer be moved, so the item can be removed.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ReclaimPrize<'info> {
+    /// Jellybean machine account.
+    #[account(
+        mut,
+        constraint = jellybean_machine.version == JellybeanMachine::CURRENT_VERSION @ JellybeanError::InvalidVersion,
+        has_one = authority @ JellybeanError::InvalidAuthority,
+        constraint = jellybean_machine.can_claim_items() @ JellybeanError::InvalidState
+    )]
+    jellybean_machine: Box<Account<'info, JellybeanMachine>>,
+
+    /// CHECK: Safe due to seeds constraint
+    #[account(
+        mut,
+        seeds = [
+            AUTHORITY_SEED.as_bytes(),
+            jellybean_machine.key().as_ref()
+        ],
+        bump
+    )]
+    authority_pda: UncheckedAccount<'info>,
+
+    /// Authority of the jellybean machine.
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    /// buyer of the prize
+    /// CHECK: Safe due to unclaimed prizes check
+    #[account(mut)]
+    buyer: UncheckedAccount<'info>,
+
+    /// Buyer unclaimed draws account.
+    #[account(
+        mut,
+        seeds = [
+            UnclaimedPrizes::SEED_PREFIX.as_bytes(),
+            jellybean_machine.key().as_ref(),
+            buyer.key().as_ref(),
+        ],
+        bump,
+        constraint = unclaimed_prizes.version == UnclaimedPrizes::CURRENT_VERSION @ JellybeanError::InvalidVersion,
+        has_one = buyer @ JellybeanError::InvalidBuyer,
+        has_one = jellybean_machine @ JellybeanError::InvalidJellybeanMachine
+    )]
+    unclaimed_prizes: Box<Account<'info, UnclaimedPrizes>>,
+
+    /// CHECK: Safe due to item check
+    #[account(mut)]
+    asset: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Safe due to item check
+    #[account(mut)]
+    collection: Option<UncheckedAccount<'info>>,
+
+    /// New print asset, required when the prize is an edition.
+    #[account(mut)]
+    print_asset: Option<Signer<'info>>,
+
+    /// Print template of the master edition, required when printing an edition.
+    /// CHECK: Verified in processors
+    print_template: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Safe due to constraint
+    #[account(address = mpl_core::ID)]
+    mpl_core_program: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn reclaim_prize<'info>(
+    ctx: Context<'_, '_, '_, 'info, ReclaimPrize<'info>>,
+    index: u16,
+) -> Result<()> {
+    let authority = &ctx.accounts.authority.to_account_info();
+    let buyer = &ctx.accounts.buyer.to_account_info();
+    let authority_pda = &ctx.accounts.authority_pda.to_account_info();
+    let mpl_core_program = &ctx.accounts.mpl_core_program.to_account_info();
+    let system_program = &ctx.accounts.system_program.to_account_info();
+    let unclaimed_prizes = &mut ctx.accounts.unclaimed_prizes;
+    let jellybean_machine = &ctx.accounts.jellybean_machine;
+    let jellybean_machine_info = jellybean_machine.to_account_info();
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        jellybean_machine.is_past_claim_deadline(now),
+        JellybeanError::ClaimDeadlineNotReached
+    );
+
+    let prize = unclaimed_prizes.claim_item(index)?;
+
+    let mut data = jellybean_machine_info.data.borrow_mut();
+    let loaded_item = jellybean_machine.get_loaded_item_at_index(&data, index as usize)?;
+
+    let collection_info = ctx
+        .accounts
+        .collection
+        .as_ref()
+        .map(|account| account.to_account_info());
+    let collection = collection_info.as_ref();
+
+    let auth_seeds = [
+        AUTHORITY_SEED.as_bytes(),
+        jellybean_machine_info.key.as_ref(),
+        &[ctx.bumps.authority_pda],
+    ];
+
+    let accounts = processors::DeliverAccounts {
+        payer: authority,
+        buyer,
+        authority_pda,
+        mpl_core_program,
+        system_program,
+    };
+
+    let delivered = if let Some(asset) = &ctx.accounts.asset {
+        assert_keys_equal(asset.key(), loaded_item.mint, "Invalid asset")?;
+
+        // An asset its seller took back out of the machine's control can't be delivered
+        if processors::can_deliver_core_asset(asset, authority_pda)? {
+            processors::deliver_core_asset(
+                jellybean_machine,
+                &prize,
+                asset,
+                collection,
+                &accounts,
+                &auth_seeds,
+            )?;
+            true
+        } else {
+            false
+        }
+    } else if let Some(collection_account) = collection {
+        assert_keys_equal(
+            collection_account.key(),
+            loaded_item.mint,
+            "Invalid collection",
+        )?;
+
+        // The machine is the update authority of a loaded master edition, so it can always
+        // print the edition
+        let print_asset = ctx
+            .accounts
+            .print_asset
+            .as_ref()
+            .ok_or(JellybeanError::MissingPrintAsset)?;
+        let print_template_info = ctx
+            .accounts
+            .print_template
+            .as_ref()
+            .map(|account| account.to_account_info());
+
+        processors::print_core_edition(
+            jellybean_machine,
+            &loaded_item,
+            &prize,
+            collection_account,
+            print_asset,
+            print_template_info.as_ref(),
+            &accounts,
+            &auth_seeds,
+        )?;
+        true
+    } else {
+        // Bundles can always be delivered with claim_bundle_component
+        return err!(JellybeanError::InvalidAsset);
+    };
+
+    // The prize counts as claimed so the item can be removed
+    let item_position = jellybean_machine.get_loaded_item_position(index as usize);
+    let supply_claimed_slice: &mut [u8] = &mut data[item_position
+        + LOADED_ITEM_SUPPLY_CLAIMED_OFFSET
+        ..item_position + LOADED_ITEM_SUPPLY_CLAIMED_OFFSET + 4];
+    supply_claimed_slice.copy_from_slice(&u32::to_le_bytes(loaded_item.supply_claimed + 1));
+
+    drop(data);
+
+    processors::settle_unclaimed_prizes(
+        &ctx.accounts.jellybean_machine,
+        unclaimed_prizes,
+        buyer,
+        authority,
+    )?;
+
+    // A reclaimed prize can no longer be refunded
+    let jellybean_machine = &mut ctx.accounts.jellybean_machine;
+    jellybean_machine.unclaimed_price = jellybean_machine
+        .unclaimed_price
+        .saturating_sub(prize.price);
+
+    msg!(
+        "Reclaimed prize: index={}, edition={}, delivered={}",
+        index,
+        prize.edition_number,
+        delivered
+    );
+
+    emit_cpi!(ReclaimPrizeEvent {
+        authority: jellybean_machine.authority,
+        buyer: buyer.key(),
+        index: index as u32,
+        edition_number: prize.edition_number,
+        delivered,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,135 @@
+use crate::{
+    assert_keys_equal, constants::AUTHORITY_SEED, events::RefundPrizeEvent, processors,
+    state::JellybeanMachine, Bundle, JellybeanError, JellybeanState, UnclaimedPrizes,
+    LOADED_ITEM_SUPPLY_REDEMED_OFFSET,
+};
+use anchor_lang::prelude::*;
+
+/// Refund an unclaimed prize of a cancelled sale to its buyer, and return the prize supply
+/// to the item so it can be removed.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RefundPrize<'info> {
+    /// Anyone can refund the prizes of a cancelled sale
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// Jellybean machine account.
+    #[account(
+        mut,
+        constraint = jellybean_machine.version == JellybeanMachine::CURRENT_VERSION @ JellybeanError::InvalidVersion,
+        constraint = jellybean_machine.state == JellybeanState::Cancelled @ JellybeanError::InvalidState
+    )]
+    jellybean_machine: Box<Account<'info, JellybeanMachine>>,
+
+    /// CHECK: Safe due to seeds constraint
+    #[account(
+        mut,
+        seeds = [
+            AUTHORITY_SEED.as_bytes(),
+            jellybean_machine.key().as_ref()
+        ],
+        bump
+    )]
+    authority_pda: UncheckedAccount<'info>,
+
+    /// buyer of the prize
+    /// CHECK: Safe due to unclaimed prizes check
+    #[account(mut)]
+    buyer: UncheckedAccount<'info>,
+
+    /// Buyer unclaimed draws account.
+    #[account(
+        mut,
+        seeds = [
+            UnclaimedPrizes::SEED_PREFIX.as_bytes(),
+            jellybean_machine.key().as_ref(),
+            buyer.key().as_ref(),
+        ],
+        bump,
+        constraint = unclaimed_prizes.version == UnclaimedPrizes::CURRENT_VERSION @ JellybeanError::InvalidVersion,
+        has_one = buyer @ JellybeanError::InvalidBuyer,
+        has_one = jellybean_machine @ JellybeanError::InvalidJellybeanMachine
+    )]
+    unclaimed_prizes: Box<Account<'info, UnclaimedPrizes>>,
+
+    /// Asset, master edition collection or bundle of the item.
+    /// CHECK: Safe due to item check
+    item: UncheckedAccount<'info>,
+}
+
+pub fn refund_prize<'info>(
+    ctx: Context<'_, '_, '_, 'info, RefundPrize<'info>>,
+    index: u16,
+) -> Result<()> {
+    let payer = &ctx.accounts.payer.to_account_info();
+    let buyer = &ctx.accounts.buyer.to_account_info();
+    let authority_pda = &ctx.accounts.authority_pda.to_account_info();
+    let item = &ctx.accounts.item.to_account_info();
+    let unclaimed_prizes = &mut ctx.accounts.unclaimed_prizes;
+    let jellybean_machine = &mut ctx.accounts.jellybean_machine;
+    let jellybean_machine_info = jellybean_machine.to_account_info();
+
+    let prize = unclaimed_prizes.claim_item(index)?;
+
+    let mut data = jellybean_machine_info.data.borrow_mut();
+    let loaded_item = jellybean_machine.get_loaded_item_at_index(&data, index as usize)?;
+    assert_keys_equal(item.key(), loaded_item.mint, "Invalid item")?;
+
+    // Bundles that started being delivered are finished with claim_bundle_component instead
+    if item.owner == &crate::ID {
+        let bundle = Bundle::try_deserialize(&mut &item.data.borrow()[..])?;
+        require!(
+            !bundle
+                .components
+                .iter()
+                .any(|component| component.delivered),
+            JellybeanError::BundlePartiallyDelivered
+        );
+    }
+
+    // The price paid for the prize was escrowed by cancel_sale, and any edition escrow since
+    // the draw. It always goes to the buyer, a drained wallet can still receive lamports.
+    let amount = prize
+        .price
+        .checked_add(loaded_item.escrow_amount)
+        .ok_or(JellybeanError::NumericalOverflowError)?;
+    if amount > 0 {
+        **authority_pda.try_borrow_mut_lamports()? -= amount;
+        **buyer.try_borrow_mut_lamports()? += amount;
+    }
+
+    let item_position = jellybean_machine.get_loaded_item_position(index as usize);
+    let supply_redeemed_slice: &mut [u8] = &mut data[item_position
+        + LOADED_ITEM_SUPPLY_REDEMED_OFFSET
+        ..item_position + LOADED_ITEM_SUPPLY_REDEMED_OFFSET + 4];
+    supply_redeemed_slice.copy_from_slice(&u32::to_le_bytes(loaded_item.supply_redeemed - 1));
+
+    drop(data);
+
+    jellybean_machine.supply_redeemed = jellybean_machine
+        .supply_redeemed
+        .checked_sub(1)
+        .ok_or(JellybeanError::NumericalOverflowError)?;
+    jellybean_machine.unclaimed_price = jellybean_machine
+        .unclaimed_price
+        .saturating_sub(prize.price);
+
+    processors::settle_unclaimed_prizes(jellybean_machine, unclaimed_prizes, buyer, payer)?;
+
+    msg!(
+        "Refunded prize: index={}, edition={}, amount={}",
+        index,
+        prize.edition_number,
+        amount
+    );
+
+    emit_cpi!(RefundPrizeEvent {
+        authority: jellybean_machine.authority,
+        buyer: buyer.key(),
+        index: index as u32,
+        amount,
+    });
+
+    Ok(())
+}
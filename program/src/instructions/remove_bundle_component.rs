@@ -0,0 +1,133 @@
+use crate::{
+    constants::AUTHORITY_SEED, processors::BundleComponentAccounts, state::JellybeanMachine,
+    Bundle, JellybeanError,
+};
+use anchor_lang::prelude::*;
+
+/// Return a component of a bundle that isn't loaded to the authority. The bundle is
+/// closed once its last component is removed.
+#[derive(Accounts)]
+pub struct RemoveBundleComponent<'info> {
+    /// Gumball Machine account.
+    #[account(
+        mut,
+        constraint = jellybean_machine.version == JellybeanMachine::CURRENT_VERSION @ JellybeanError::InvalidVersion,
+        has_one = authority @ JellybeanError::InvalidAuthority,
+    )]
+    jellybean_machine: Box<Account<'info, JellybeanMachine>>,
+
+    /// CHECK: Safe due to seeds constraint
+    #[account(
+        seeds = [
+            AUTHORITY_SEED.as_bytes(),
+            jellybean_machine.key().as_ref()
+        ],
+        bump
+    )]
+    authority_pda: UncheckedAccount<'info>,
+
+    /// Authority of the jellybean machine.
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    /// Bundle account.
+    #[account(
+        mut,
+        has_one = jellybean_machine @ JellybeanError::InvalidJellybeanMachine,
+        constraint = !bundle.loaded @ JellybeanError::BundleLoaded,
+    )]
+    bundle: Box<Account<'info, Bundle>>,
+
+    /// Core asset to return.
+    /// CHECK: Verified against the bundle component
+    #[account(mut)]
+    asset: Option<UncheckedAccount<'info>>,
+
+    /// Core asset's collection if it's part of one.
+    /// CHECK: Verified in mpl_core processors
+    #[account(mut)]
+    collection: Option<UncheckedAccount<'info>>,
+
+    /// Mint of the tokens to return.
+    /// CHECK: Verified against the bundle component
+    mint: Option<UncheckedAccount<'info>>,
+
+    /// Authority PDA associated token account escrowing the tokens.
+    /// CHECK: Verified in processors
+    #[account(mut)]
+    authority_pda_token_account: Option<UncheckedAccount<'info>>,
+
+    /// Authority associated token account receiving the tokens.
+    /// CHECK: Verified by the associated token program
+    #[account(mut)]
+    authority_token_account: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Safe due to address constraint
+    #[account(address = mpl_core::ID)]
+    mpl_core_program: UncheckedAccount<'info>,
+
+    /// CHECK: Safe due to address constraint
+    #[account(address = anchor_spl::token::ID)]
+    token_program: UncheckedAccount<'info>,
+
+    /// CHECK: Safe due to address constraint
+    #[account(address = anchor_spl::associated_token::ID)]
+    associated_token_program: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn remove_bundle_component(ctx: Context<RemoveBundleComponent>, component: u8) -> Result<()> {
+    let authority = &ctx.accounts.authority.to_account_info();
+    let jellybean_machine_key = ctx.accounts.jellybean_machine.key();
+    let bundle = &mut ctx.accounts.bundle;
+
+    require!(
+        (component as usize) < bundle.components.len(),
+        JellybeanError::InvalidBundleComponent
+    );
+    let removed = bundle.components.remove(component as usize);
+
+    let accounts = BundleComponentAccounts {
+        payer: authority.clone(),
+        authority_pda: ctx.accounts.authority_pda.to_account_info(),
+        asset: ctx.accounts.asset.as_ref().map(|a| a.to_account_info()),
+        collection: ctx
+            .accounts
+            .collection
+            .as_ref()
+            .map(|a| a.to_account_info()),
+        mint: ctx.accounts.mint.as_ref().map(|a| a.to_account_info()),
+        source_token_account: ctx
+            .accounts
+            .authority_pda_token_account
+            .as_ref()
+            .map(|a| a.to_account_info()),
+        destination_token_account: ctx
+            .accounts
+            .authority_token_account
+            .as_ref()
+            .map(|a| a.to_account_info()),
+        mpl_core_program: ctx.accounts.mpl_core_program.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+    };
+
+    let auth_seeds = [
+        AUTHORITY_SEED.as_bytes(),
+        jellybean_machine_key.as_ref(),
+        &[ctx.bumps.authority_pda],
+    ];
+
+    crate::processors::deliver_bundle_component(&removed, authority, &accounts, &auth_seeds)?;
+
+    if bundle.components.is_empty() {
+        bundle.close(authority.clone())?;
+
+        let jellybean_machine = &mut ctx.accounts.jellybean_machine;
+        jellybean_machine.open_bundles = jellybean_machine.open_bundles.saturating_sub(1);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use crate::{processors, state::JellybeanMachine, JellybeanError};
+use anchor_lang::prelude::*;
+
+/// Remove a bundle from a Jellybean Machine, unlocking its components if it hasn't been
+/// drawn. A drawn bundle can be removed once delivered. This can be done before starting a
+/// sale or after it has ended.
+#[derive(Accounts)]
+pub struct RemoveBundleItem<'info> {
+    /// Gumball Machine account.
+    #[account(
+        mut,
+        constraint = jellybean_machine.version == JellybeanMachine::CURRENT_VERSION @ JellybeanError::InvalidVersion,
+        has_one = authority @ JellybeanError::InvalidAuthority,
+        constraint = jellybean_machine.can_remove_items() @ JellybeanError::InvalidState,
+    )]
+    jellybean_machine: Account<'info, JellybeanMachine>,
+
+    /// Authority of the jellybean machine.
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    /// Bundle account, closed once a drawn bundle has been delivered.
+    /// CHECK: Verified in processors
+    #[account(mut)]
+    bundle: UncheckedAccount<'info>,
+}
+
+pub fn remove_bundle_item(ctx: Context<RemoveBundleItem>, index: u16) -> Result<()> {
+    let authority = &ctx.accounts.authority.to_account_info();
+    let jellybean_machine = &mut ctx.accounts.jellybean_machine;
+    let jellybean_machine_info = jellybean_machine.to_account_info();
+
+    let data = jellybean_machine_info.data.borrow();
+    let loaded_item = jellybean_machine.get_loaded_item_at_index(&data, index as usize)?;
+    drop(data);
+
+    processors::return_bundle_item(
+        jellybean_machine_info.key,
+        &loaded_item,
+        &ctx.accounts.bundle.to_account_info(),
+    )?;
+
+    processors::remove_items(jellybean_machine, &[index], authority)?;
+
+    Ok(())
+}
@@ -1,16 +1,13 @@
-use crate::{
-    assert_keys_equal, constants::AUTHORITY_SEED, processors, state::JellybeanMachine,
-    JellybeanError,
-};
+use crate::{constants::AUTHORITY_SEED, processors, state::JellybeanMachine, JellybeanError};
 use anchor_lang::prelude::*;
-use mpl_core::instructions::{TransferV1CpiBuilder, UpdateCollectionV1CpiBuilder};
 
-/// Remove a Core item from a Jellybean Machine. This can be done before starting a sale or after it has ended.
+/// Remove a Core item from a Jellybean Machine, or clear a tombstoned slot. This can be done before starting a sale or after it has ended.
 #[derive(Accounts)]
 pub struct RemoveCoreItem<'info> {
     /// Gumball Machine account.
     #[account(
         mut,
+        constraint = jellybean_machine.version == JellybeanMachine::CURRENT_VERSION @ JellybeanError::InvalidVersion,
         has_one = authority @ JellybeanError::InvalidAuthority,
         constraint = jellybean_machine.can_remove_items() @ JellybeanError::InvalidState,
     )]
@@ -40,6 +37,11 @@ pub struct RemoveCoreItem<'info> {
     #[account(mut)]
     collection: Option<UncheckedAccount<'info>>,
 
+    /// Print template of the master edition, required when removing one.
+    /// CHECK: Verified in processors
+    #[account(mut)]
+    print_template: Option<UncheckedAccount<'info>>,
+
     /// CHECK: Safe due to address constraint
     #[account(address = mpl_core::ID)]
     mpl_core_program: UncheckedAccount<'info>,
@@ -47,7 +49,7 @@ pub struct RemoveCoreItem<'info> {
     system_program: Program<'info, System>,
 }
 
-pub fn remove_core_item(ctx: Context<RemoveCoreItem>, index: u8) -> Result<()> {
+pub fn remove_core_item(ctx: Context<RemoveCoreItem>, index: u16) -> Result<()> {
     let authority = &ctx.accounts.authority.to_account_info();
     let mpl_core_program = &ctx.accounts.mpl_core_program.to_account_info();
     let system_program = &ctx.accounts.system_program.to_account_info();
@@ -57,59 +59,47 @@ pub fn remove_core_item(ctx: Context<RemoveCoreItem>, index: u8) -> Result<()> {
 
     let data = jellybean_machine_info.data.borrow();
     let loaded_item = jellybean_machine.get_loaded_item_at_index(&data, index as usize)?;
-
-    require!(
-        loaded_item.supply_claimed == loaded_item.supply_redeemed,
-        JellybeanError::ItemNotFullyClaimed
-    );
-
     drop(data);
 
+    let asset_info = ctx
+        .accounts
+        .asset
+        .as_ref()
+        .map(|account| account.to_account_info());
     let collection_info = ctx
         .accounts
         .collection
         .as_ref()
         .map(|account| account.to_account_info());
-    let collection = collection_info.as_ref();
+
+    let print_template_info = ctx
+        .accounts
+        .print_template
+        .as_ref()
+        .map(|account| account.to_account_info());
 
     let auth_seeds = [
         AUTHORITY_SEED.as_bytes(),
-        jellybean_machine.to_account_info().key.as_ref(),
+        jellybean_machine_info.key.as_ref(),
         &[ctx.bumps.authority_pda],
     ];
 
-    if let Some(asset) = &ctx.accounts.asset {
-        assert_keys_equal(loaded_item.mint, *asset.key, "Invalid asset")?;
-
-        // Only need to transfer if the asset hasn't been redeemed
-        if loaded_item.supply_redeemed == 0 {
-            // Transfer the core asset to the authority pda
-            TransferV1CpiBuilder::new(mpl_core_program)
-                .asset(asset)
-                .collection(collection)
-                .payer(authority)
-                .authority(Some(authority_pda))
-                .new_owner(authority)
-                .system_program(Some(system_program))
-                .invoke_signed(&[&auth_seeds])?;
-        }
-    } else if let Some(collection) = collection {
-        assert_keys_equal(loaded_item.mint, *collection.key, "Invalid collection")?;
-
-        // Update the master edition authority to the authority pda
-        UpdateCollectionV1CpiBuilder::new(mpl_core_program)
-            .collection(collection)
-            .payer(authority)
-            .authority(Some(authority_pda))
-            .new_update_authority(Some(authority))
-            .system_program(system_program)
-            .invoke_signed(&[&auth_seeds])?;
-    } else {
-        return err!(JellybeanError::InvalidAsset);
-    };
+    processors::return_core_item(
+        jellybean_machine_info.key,
+        &loaded_item,
+        jellybean_machine.reveal_config.is_some(),
+        asset_info.as_ref(),
+        collection_info.as_ref(),
+        print_template_info.as_ref(),
+        authority,
+        authority_pda,
+        mpl_core_program,
+        system_program,
+        &auth_seeds,
+    )?;
 
     // Remove the item from the jellybean machine
-    processors::remove_multiple_items_span(jellybean_machine, index, index, authority)?;
+    processors::remove_items(jellybean_machine, &[index], authority)?;
 
     Ok(())
 }
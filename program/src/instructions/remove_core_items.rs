@@ -0,0 +1,74 @@
+use crate::{constants::AUTHORITY_SEED, processors, state::JellybeanMachine, JellybeanError};
+use anchor_lang::prelude::*;
+
+/// Remove a set of Core items from a Jellybean Machine. This can be done before starting a sale or after it has ended.
+#[derive(Accounts)]
+pub struct RemoveCoreItems<'info> {
+    /// Gumball Machine account.
+    #[account(
+        mut,
+        constraint = jellybean_machine.version == JellybeanMachine::CURRENT_VERSION @ JellybeanError::InvalidVersion,
+        has_one = authority @ JellybeanError::InvalidAuthority,
+        constraint = jellybean_machine.can_remove_items() @ JellybeanError::InvalidState,
+    )]
+    jellybean_machine: Account<'info, JellybeanMachine>,
+
+    /// CHECK: Safe due to seeds constraint
+    #[account(
+        mut,
+        seeds = [
+            AUTHORITY_SEED.as_bytes(),
+            jellybean_machine.key().as_ref()
+        ],
+        bump
+    )]
+    authority_pda: UncheckedAccount<'info>,
+
+    /// Authority of the jellybean machine.
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    /// CHECK: Safe due to address constraint
+    #[account(address = mpl_core::ID)]
+    mpl_core_program: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+    // Remaining accounts, three per item in the order of the indices, as in remove_core_item.
+    // Accounts an item doesn't use are set to this program's id:
+    // - `[writable]` Asset or bundle account
+    // - `[writable]` Collection of the asset, or the master edition collection
+    // - `[writable]` Print template of the master edition
+}
+
+pub fn remove_core_items<'info>(
+    ctx: Context<'_, '_, '_, 'info, RemoveCoreItems<'info>>,
+    indices: Vec<u16>,
+) -> Result<()> {
+    let authority = &ctx.accounts.authority.to_account_info();
+    let mpl_core_program = &ctx.accounts.mpl_core_program.to_account_info();
+    let system_program = &ctx.accounts.system_program.to_account_info();
+    let authority_pda = &ctx.accounts.authority_pda.to_account_info();
+    let jellybean_machine = &mut ctx.accounts.jellybean_machine;
+
+    let auth_seeds = [
+        AUTHORITY_SEED.as_bytes(),
+        jellybean_machine.to_account_info().key.as_ref(),
+        &[ctx.bumps.authority_pda],
+    ];
+
+    processors::return_core_items(
+        jellybean_machine,
+        &indices,
+        ctx.remaining_accounts,
+        authority,
+        authority_pda,
+        mpl_core_program,
+        system_program,
+        &auth_seeds,
+    )?;
+
+    // Remove the items from the jellybean machine
+    processors::remove_items(jellybean_machine, &indices, authority)?;
+
+    Ok(())
+}
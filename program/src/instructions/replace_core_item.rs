@@ -0,0 +1,177 @@
+use crate::{
+    constants::AUTHORITY_SEED, events::AddItemEvent, processors, state::JellybeanMachine,
+    JellybeanError, JellybeanState, LOADED_ITEM_SIZE,
+};
+use anchor_lang::prelude::*;
+
+/// Swap a loaded item for another Core asset in the same slot, so later items keep their
+/// indices.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ReplaceCoreItem<'info> {
+    /// Gumball Machine account.
+    #[account(
+        mut,
+        constraint = jellybean_machine.version == JellybeanMachine::CURRENT_VERSION @ JellybeanError::InvalidVersion,
+        has_one = authority @ JellybeanError::InvalidAuthority,
+        constraint = jellybean_machine.can_add_items() @ JellybeanError::InvalidState,
+    )]
+    jellybean_machine: Box<Account<'info, JellybeanMachine>>,
+
+    /// CHECK: Safe due to seeds constraint
+    #[account(
+        mut,
+        seeds = [
+            AUTHORITY_SEED.as_bytes(),
+            jellybean_machine.key().as_ref()
+        ],
+        bump
+    )]
+    authority_pda: UncheckedAccount<'info>,
+
+    /// Authority of the jellybean machine.
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    /// Asset being replaced.
+    /// CHECK: Verified in processors
+    #[account(mut)]
+    asset: Option<UncheckedAccount<'info>>,
+
+    /// Collection of the asset being replaced, or the master edition collection being replaced.
+    /// CHECK: Verified in processors
+    #[account(mut)]
+    collection: Option<UncheckedAccount<'info>>,
+
+    /// Print template of the master edition being replaced, if it has one.
+    /// CHECK: Verified in processors
+    #[account(mut)]
+    print_template: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Safe due to freeze
+    #[account(mut)]
+    new_asset: UncheckedAccount<'info>,
+
+    /// New asset's collection if it's part of one.
+    /// CHECK: Verified in mpl_core processors
+    #[account(mut)]
+    new_collection: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Safe due to address constraint
+    #[account(address = mpl_core::ID)]
+    mpl_core_program: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn replace_core_item(ctx: Context<ReplaceCoreItem>, index: u16) -> Result<()> {
+    let authority = &ctx.accounts.authority.to_account_info();
+    let authority_pda = &ctx.accounts.authority_pda.to_account_info();
+    let mpl_core_program = &ctx.accounts.mpl_core_program.to_account_info();
+    let system_program = &ctx.accounts.system_program.to_account_info();
+    let jellybean_machine = &mut ctx.accounts.jellybean_machine;
+    let jellybean_machine_info = jellybean_machine.to_account_info();
+
+    require!(
+        index < jellybean_machine.items_loaded,
+        JellybeanError::IndexGreaterThanLength
+    );
+
+    let data = jellybean_machine_info.data.borrow();
+    let old_item = jellybean_machine.get_loaded_item_at_index(&data, index as usize)?;
+    drop(data);
+
+    // Once a sale has started, only items that were never drawn can be swapped out. Prints
+    // of a master edition minted before it was loaded were not drawn from the machine.
+    require!(
+        jellybean_machine.state == JellybeanState::None
+            || old_item.supply_redeemed == old_item.existing_prints,
+        JellybeanError::InvalidState
+    );
+
+    let asset_info = ctx
+        .accounts
+        .asset
+        .as_ref()
+        .map(|account| account.to_account_info());
+    let collection_info = ctx
+        .accounts
+        .collection
+        .as_ref()
+        .map(|account| account.to_account_info());
+    let print_template_info = ctx
+        .accounts
+        .print_template
+        .as_ref()
+        .map(|account| account.to_account_info());
+    let new_collection_info = ctx
+        .accounts
+        .new_collection
+        .as_ref()
+        .map(|account| account.to_account_info());
+
+    let auth_seeds = [
+        AUTHORITY_SEED.as_bytes(),
+        jellybean_machine_info.key.as_ref(),
+        &[ctx.bumps.authority_pda],
+    ];
+
+    processors::return_core_item(
+        jellybean_machine_info.key,
+        &old_item,
+        jellybean_machine.reveal_config.is_some(),
+        asset_info.as_ref(),
+        collection_info.as_ref(),
+        print_template_info.as_ref(),
+        authority,
+        authority_pda,
+        mpl_core_program,
+        system_program,
+        &auth_seeds,
+    )?;
+
+    // Freeze the new core asset in the seller's wallet, the authority pda transfers it on claim
+    let new_item = processors::load_core_asset(
+        jellybean_machine,
+        &ctx.accounts.new_asset,
+        new_collection_info.as_ref(),
+        authority,
+        authority_pda,
+        mpl_core_program,
+        system_program,
+        &auth_seeds,
+    )?;
+
+    // Prints of a replaced master edition minted before loading no longer count
+    jellybean_machine.supply_loaded = jellybean_machine
+        .supply_loaded
+        .checked_sub(old_item.supply_loaded as u64)
+        .and_then(|supply| supply.checked_add(new_item.supply_loaded as u64))
+        .ok_or(JellybeanError::NumericalOverflowError)?;
+    jellybean_machine.supply_redeemed = jellybean_machine
+        .supply_redeemed
+        .checked_sub(old_item.supply_redeemed as u64)
+        .ok_or(JellybeanError::NumericalOverflowError)?;
+
+    // Overwrite the slot in place, the account size doesn't change
+    let position = jellybean_machine.get_loaded_item_position(index as usize);
+    let mut data = jellybean_machine_info.data.borrow_mut();
+    data[position..position + LOADED_ITEM_SIZE].copy_from_slice(&new_item.try_to_vec()?);
+    drop(data);
+
+    msg!(
+        "Replaced item: index={}, old mint={}, new mint={}",
+        index,
+        old_item.mint,
+        new_item.mint
+    );
+
+    emit_cpi!(AddItemEvent {
+        authority: jellybean_machine.authority,
+        index: index as u32,
+        count: 1,
+        supply_loaded: jellybean_machine.supply_loaded,
+    });
+
+    Ok(())
+}
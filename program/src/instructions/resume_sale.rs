@@ -0,0 +1,24 @@
+use crate::{state::JellybeanMachine, JellybeanError, JellybeanState};
+use anchor_lang::prelude::*;
+
+/// Reopens draws on a paused sale.
+#[derive(Accounts)]
+pub struct ResumeSale<'info> {
+    /// Jellybean machine account.
+    #[account(
+        mut,
+        constraint = jellybean_machine.version == JellybeanMachine::CURRENT_VERSION @ JellybeanError::InvalidVersion,
+        constraint = authority.key() == jellybean_machine.authority || authority.key() == jellybean_machine.mint_authority @ JellybeanError::InvalidAuthority,
+        constraint = jellybean_machine.state == JellybeanState::Paused @ JellybeanError::InvalidState
+    )]
+    jellybean_machine: Box<Account<'info, JellybeanMachine>>,
+
+    /// Jellybean Machine authority. This can be the mint authority or the authority.
+    authority: Signer<'info>,
+}
+
+pub fn resume_sale(ctx: Context<ResumeSale>) -> Result<()> {
+    ctx.accounts.jellybean_machine.state = JellybeanState::SaleLive;
+
+    Ok(())
+}
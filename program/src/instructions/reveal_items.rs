@@ -0,0 +1,102 @@
+use crate::{
+    constants::AUTHORITY_SEED, events::RevealItemEvent, processors, state::JellybeanMachine,
+    utils::validate_uri_length, JellybeanError,
+};
+use anchor_lang::prelude::*;
+
+/// Reveals the final metadata of claimed assets still showing the placeholder uri.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RevealItems<'info> {
+    /// Gumball Machine account.
+    #[account(
+        constraint = jellybean_machine.version == JellybeanMachine::CURRENT_VERSION @ JellybeanError::InvalidVersion,
+        has_one = authority @ JellybeanError::InvalidAuthority,
+    )]
+    jellybean_machine: Box<Account<'info, JellybeanMachine>>,
+
+    /// CHECK: Safe due to seeds constraint
+    #[account(
+        seeds = [
+            AUTHORITY_SEED.as_bytes(),
+            jellybean_machine.key().as_ref()
+        ],
+        bump
+    )]
+    authority_pda: UncheckedAccount<'info>,
+
+    /// Authority of the jellybean machine, pays for the longer uris.
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    /// CHECK: Safe due to address constraint
+    #[account(address = mpl_core::ID)]
+    mpl_core_program: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+    // Remaining accounts, one pair per uri:
+    // - `[writable]` Asset account
+    // - `[writable]` Collection account, or this program's id if the asset has no collection
+}
+
+pub fn reveal_items<'info>(
+    ctx: Context<'_, '_, '_, 'info, RevealItems<'info>>,
+    uris: Vec<String>,
+) -> Result<()> {
+    let authority = &ctx.accounts.authority.to_account_info();
+    let authority_pda = &ctx.accounts.authority_pda.to_account_info();
+    let mpl_core_program = &ctx.accounts.mpl_core_program.to_account_info();
+    let system_program = &ctx.accounts.system_program.to_account_info();
+    let jellybean_machine = &ctx.accounts.jellybean_machine;
+
+    let reveal_config = jellybean_machine
+        .reveal_config
+        .as_ref()
+        .ok_or(JellybeanError::RevealNotEnabled)?;
+
+    let asset_accounts = ctx.remaining_accounts.chunks_exact(2);
+    if uris.is_empty()
+        || !asset_accounts.remainder().is_empty()
+        || asset_accounts.len() != uris.len()
+    {
+        return err!(JellybeanError::InvalidInputLength);
+    }
+
+    let jellybean_machine_key = jellybean_machine.key();
+    let auth_seeds = [
+        AUTHORITY_SEED.as_bytes(),
+        jellybean_machine_key.as_ref(),
+        &[ctx.bumps.authority_pda],
+    ];
+
+    for (accounts, uri) in asset_accounts.zip(uris) {
+        validate_uri_length(&uri)?;
+
+        let asset = &accounts[0];
+        let collection = if accounts[1].key() == crate::ID {
+            None
+        } else {
+            Some(&accounts[1])
+        };
+
+        processors::reveal_core_asset(
+            asset,
+            collection,
+            authority,
+            authority_pda,
+            mpl_core_program,
+            system_program,
+            &auth_seeds,
+            &reveal_config.placeholder_uri,
+            uri.clone(),
+        )?;
+
+        emit_cpi!(RevealItemEvent {
+            authority: jellybean_machine.authority,
+            asset: asset.key(),
+            uri,
+        });
+    }
+
+    Ok(())
+}
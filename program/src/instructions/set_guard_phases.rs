@@ -0,0 +1,102 @@
+use crate::{
+    state::JellybeanMachine, utils::validate_public_draw_phases, DrawGate, DrawGateAction,
+    DrawGateKind, GuardPhase, GuardPhases, JellybeanError, JellybeanState, MAX_GUARD_PHASES,
+};
+use anchor_lang::prelude::*;
+
+/// Set the phases applied to draws. An empty list removes the guard from the machine.
+#[derive(Accounts)]
+pub struct SetGuardPhases<'info> {
+    /// Gumball Machine account.
+    #[account(
+        mut,
+        constraint = jellybean_machine.version == JellybeanMachine::CURRENT_VERSION @ JellybeanError::InvalidVersion,
+        has_one = authority @ JellybeanError::InvalidAuthority,
+    )]
+    jellybean_machine: Box<Account<'info, JellybeanMachine>>,
+
+    /// Authority of the jellybean machine.
+    authority: Signer<'info>,
+
+    /// Payer for the guard phases account.
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// Guard phases account.
+    #[account(
+        init_if_needed,
+        seeds = [
+            GuardPhases::SEED_PREFIX.as_bytes(),
+            jellybean_machine.key().as_ref(),
+        ],
+        bump,
+        space = GuardPhases::SIZE,
+        payer = payer,
+    )]
+    guard_phases: Box<Account<'info, GuardPhases>>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn set_guard_phases(ctx: Context<SetGuardPhases>, phases: Vec<GuardPhase>) -> Result<()> {
+    let jellybean_machine = &mut ctx.accounts.jellybean_machine;
+
+    require!(
+        phases.len() <= MAX_GUARD_PHASES,
+        JellybeanError::TooManyGuardPhases
+    );
+    require!(
+        phases
+            .windows(2)
+            .all(|pair| pair[0].start_at < pair[1].start_at),
+        JellybeanError::InvalidGuardPhases
+    );
+
+    // Only Core assets can be marked as used, tokens are fungible
+    require!(
+        phases.iter().all(|phase| match &phase.gate {
+            Some(DrawGate {
+                kind: DrawGateKind::Token { amount, .. },
+                action,
+            }) => *amount > 0 && *action != DrawGateAction::MarkUsed,
+            _ => true,
+        }),
+        JellybeanError::InvalidGuardPhases
+    );
+
+    // Buyer records count phase draws by index, so once the sale has started phases keep
+    // their place and new ones can only be added after them
+    let guard_phases = &mut ctx.accounts.guard_phases;
+    if jellybean_machine.state != JellybeanState::None {
+        require!(
+            phases.len() >= guard_phases.phases.len()
+                && guard_phases
+                    .phases
+                    .iter()
+                    .zip(phases.iter())
+                    .all(|(old, new)| old.start_at == new.start_at),
+            JellybeanError::GuardPhasesLocked
+        );
+    }
+
+    // Public draws skip the mint authority, so every phase must charge for them
+    if jellybean_machine.public_draw {
+        validate_public_draw_phases(&phases)?;
+    }
+
+    // Draw prices are split between the fee accounts
+    if phases.iter().any(|phase| phase.price > 0) {
+        require!(
+            !jellybean_machine.fee_accounts.is_empty(),
+            JellybeanError::InvalidFeeAccountsLength
+        );
+    }
+
+    guard_phases.version = GuardPhases::CURRENT_VERSION;
+    guard_phases.jellybean_machine = jellybean_machine.key();
+    guard_phases.phases = phases;
+
+    jellybean_machine.has_guard_phases = !guard_phases.phases.is_empty();
+
+    Ok(())
+}
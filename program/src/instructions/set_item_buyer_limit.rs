@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::{BuyerLimit, JellybeanError, JellybeanMachine, MAX_BUYER_LIMITS};
+
+/// Sets, or clears, the maximum number of units of an item a single buyer can win.
+#[derive(Accounts)]
+pub struct SetItemBuyerLimit<'info> {
+    /// Gumball Machine account.
+    #[account(
+        mut,
+        constraint = jellybean_machine.version == JellybeanMachine::CURRENT_VERSION @ JellybeanError::InvalidVersion,
+        has_one = authority @ JellybeanError::InvalidAuthority,
+    )]
+    jellybean_machine: Account<'info, JellybeanMachine>,
+
+    /// Gumball Machine authority
+    authority: Signer<'info>,
+}
+
+pub fn set_item_buyer_limit(
+    ctx: Context<SetItemBuyerLimit>,
+    index: u16,
+    max_per_buyer: Option<u16>,
+) -> Result<()> {
+    let jellybean_machine = &mut ctx.accounts.jellybean_machine;
+
+    require!(
+        index < jellybean_machine.items_loaded,
+        JellybeanError::IndexGreaterThanLength
+    );
+
+    jellybean_machine
+        .buyer_limits
+        .retain(|buyer_limit| buyer_limit.item_index != index);
+
+    if let Some(max_per_buyer) = max_per_buyer {
+        require!(
+            jellybean_machine.buyer_limits.len() < MAX_BUYER_LIMITS,
+            JellybeanError::TooManyBuyerLimits
+        );
+        jellybean_machine.buyer_limits.push(BuyerLimit {
+            item_index: index,
+            max_per_buyer,
+        });
+    }
+
+    msg!("Item {} buyer limit: {:?}", index, max_per_buyer);
+
+    Ok(())
+}
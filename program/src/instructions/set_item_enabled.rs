@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::{JellybeanError, JellybeanMachine, MAX_DISABLED_ITEMS};
+
+/// Takes a loaded item out of the draw pool, or puts it back, without removing it.
+#[derive(Accounts)]
+pub struct SetItemEnabled<'info> {
+    /// Gumball Machine account.
+    #[account(
+        mut,
+        constraint = jellybean_machine.version == JellybeanMachine::CURRENT_VERSION @ JellybeanError::InvalidVersion,
+        has_one = authority @ JellybeanError::InvalidAuthority,
+    )]
+    jellybean_machine: Account<'info, JellybeanMachine>,
+
+    /// Gumball Machine authority
+    authority: Signer<'info>,
+}
+
+pub fn set_item_enabled(ctx: Context<SetItemEnabled>, index: u16, enabled: bool) -> Result<()> {
+    let jellybean_machine = &mut ctx.accounts.jellybean_machine;
+
+    require!(
+        index < jellybean_machine.items_loaded,
+        JellybeanError::IndexGreaterThanLength
+    );
+
+    if enabled {
+        jellybean_machine.disabled_items.retain(|i| *i != index);
+    } else if !jellybean_machine.is_item_disabled(index) {
+        require!(
+            jellybean_machine.disabled_items.len() < MAX_DISABLED_ITEMS,
+            JellybeanError::TooManyDisabledItems
+        );
+        jellybean_machine.disabled_items.push(index);
+    }
+
+    msg!("Item {} enabled: {}", index, enabled);
+
+    Ok(())
+}
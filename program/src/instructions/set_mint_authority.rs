@@ -1,12 +1,16 @@
 use anchor_lang::prelude::*;
 
-use crate::JellybeanMachine;
+use crate::{JellybeanError, JellybeanMachine};
 
 /// Sets a new jellybean machine authority.
 #[derive(Accounts)]
 pub struct SetMintAuthority<'info> {
     /// Gumball Machine account.
-    #[account(mut, has_one = authority)]
+    #[account(
+        mut,
+        constraint = jellybean_machine.version == JellybeanMachine::CURRENT_VERSION @ JellybeanError::InvalidVersion,
+        has_one = authority,
+    )]
     jellybean_machine: Account<'info, JellybeanMachine>,
 
     /// Gumball Machine authority
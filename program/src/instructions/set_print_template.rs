@@ -0,0 +1,101 @@
+use crate::{
+    assert_keys_equal, state::JellybeanMachine, JellybeanError, LoadedItem, PrintTemplate,
+    LOADED_ITEM_ESCROW_AMOUNT_OFFSET, MAX_NAME_TEMPLATE_LENGTH, MAX_URI_LENGTH,
+};
+use anchor_lang::prelude::*;
+use mpl_core::Collection;
+
+/// Set the name and uri templates used to print editions of a loaded master edition.
+#[derive(Accounts)]
+pub struct SetPrintTemplate<'info> {
+    /// Gumball Machine account.
+    #[account(
+        mut,
+        constraint = jellybean_machine.version == JellybeanMachine::CURRENT_VERSION @ JellybeanError::InvalidVersion,
+        has_one = authority @ JellybeanError::InvalidAuthority,
+    )]
+    jellybean_machine: Box<Account<'info, JellybeanMachine>>,
+
+    /// Authority of the jellybean machine.
+    authority: Signer<'info>,
+
+    /// Payer for the print template account.
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// Master edition collection of the loaded item.
+    /// CHECK: Verified against the loaded item
+    collection: UncheckedAccount<'info>,
+
+    /// Print template account.
+    #[account(
+        init_if_needed,
+        seeds = [
+            PrintTemplate::SEED_PREFIX.as_bytes(),
+            jellybean_machine.key().as_ref(),
+            collection.key().as_ref(),
+        ],
+        bump,
+        space = PrintTemplate::SIZE,
+        payer = payer,
+    )]
+    print_template: Box<Account<'info, PrintTemplate>>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn set_print_template(
+    ctx: Context<SetPrintTemplate>,
+    index: u16,
+    name: String,
+    uri: String,
+) -> Result<()> {
+    require!(
+        name.len() <= MAX_NAME_TEMPLATE_LENGTH && uri.len() <= MAX_URI_LENGTH,
+        JellybeanError::PrintTemplateTooLong
+    );
+
+    let collection_info = ctx.accounts.collection.to_account_info();
+    let jellybean_machine = &mut ctx.accounts.jellybean_machine;
+    let jellybean_machine_info = jellybean_machine.to_account_info();
+
+    let mut data = jellybean_machine_info.data.borrow_mut();
+    let loaded_item = jellybean_machine.get_loaded_item_at_index(&data, index as usize)?;
+
+    assert_keys_equal(collection_info.key(), loaded_item.mint, "Invalid mint")?;
+
+    // Outstanding prizes were escrowed for the current template
+    require!(
+        loaded_item.supply_claimed == loaded_item.supply_redeemed,
+        JellybeanError::ItemNotFullyClaimed
+    );
+
+    let collection = Box::<Collection>::try_from(&collection_info)?;
+    let master_edition = collection
+        .plugin_list
+        .master_edition
+        .ok_or(JellybeanError::MissingMasterEdition)?
+        .master_edition;
+
+    let print_template = &mut ctx.accounts.print_template;
+    print_template.version = PrintTemplate::CURRENT_VERSION;
+    print_template.jellybean_machine = jellybean_machine.key();
+    print_template.collection = collection_info.key();
+    print_template.name = name;
+    print_template.uri = uri;
+
+    // Escrow enough for the longest edition number a print can have
+    let (name, uri) = print_template.expand(
+        &master_edition.name.unwrap_or(collection.base.name),
+        &master_edition.uri.unwrap_or(collection.base.uri),
+        u32::MAX,
+    );
+    let escrow_amount = LoadedItem::print_escrow_amount(name.len(), uri.len())?;
+
+    let item_position = jellybean_machine.get_loaded_item_position(index as usize);
+    let escrow_amount_slice: &mut [u8] = &mut data[item_position + LOADED_ITEM_ESCROW_AMOUNT_OFFSET
+        ..item_position + LOADED_ITEM_ESCROW_AMOUNT_OFFSET + 8];
+    escrow_amount_slice.copy_from_slice(&u64::to_le_bytes(escrow_amount));
+
+    Ok(())
+}
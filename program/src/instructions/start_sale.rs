@@ -1,23 +1,47 @@
-use crate::{state::JellybeanMachine, JellybeanError, JellybeanState};
+use crate::{
+    state::JellybeanMachine, utils::validate_public_draw_phases, GuardPhases, JellybeanError,
+    JellybeanState,
+};
 use anchor_lang::prelude::*;
 
-/// Manually starts a sale.
+/// Manually starts a sale, or reopens an ended sale that has been restocked.
 #[derive(Accounts)]
 pub struct StartSale<'info> {
     /// Jellybean machine account.
     #[account(
         mut,
+        constraint = jellybean_machine.version == JellybeanMachine::CURRENT_VERSION @ JellybeanError::InvalidVersion,
         constraint = authority.key() == jellybean_machine.authority || authority.key() == jellybean_machine.mint_authority @ JellybeanError::InvalidAuthority,
-        constraint = jellybean_machine.state == JellybeanState::None @ JellybeanError::InvalidState,
+        constraint = jellybean_machine.can_start_sale() @ JellybeanError::InvalidState,
         constraint = jellybean_machine.items_loaded > 0 @ JellybeanError::JellybeanMachineEmpty
     )]
     jellybean_machine: Box<Account<'info, JellybeanMachine>>,
 
     /// Jellybean Machine authority. This can be the mint authority or the authority.
     authority: Signer<'info>,
+
+    /// Guard phases account, required for machines with public draws.
+    #[account(
+        seeds = [
+            GuardPhases::SEED_PREFIX.as_bytes(),
+            jellybean_machine.key().as_ref(),
+        ],
+        bump
+    )]
+    guard_phases: Option<Box<Account<'info, GuardPhases>>>,
 }
 
 pub fn start_sale(ctx: Context<StartSale>) -> Result<()> {
+    // Public draws skip the mint authority, so they can only be sold through paid phases
+    if ctx.accounts.jellybean_machine.public_draw {
+        let guard_phases = ctx
+            .accounts
+            .guard_phases
+            .as_ref()
+            .ok_or(JellybeanError::PublicDrawWithoutPrice)?;
+        validate_public_draw_phases(&guard_phases.phases)?;
+    }
+
     ctx.accounts.jellybean_machine.state = JellybeanState::SaleLive;
 
     Ok(())
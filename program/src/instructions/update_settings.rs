@@ -1,6 +1,6 @@
 use crate::{
-    state::JellybeanMachine, utils::validate_settings_args, JellybeanError, JellybeanState,
-    SettingsArgs,
+    state::JellybeanMachine, utils::validate_settings_args, GuardPhases, JellybeanError,
+    JellybeanState, SettingsArgs,
 };
 use anchor_lang::prelude::*;
 
@@ -10,14 +10,25 @@ pub struct UpdateSettings<'info> {
     /// Gumball machine account.
     #[account(
         mut,
+        constraint = jellybean_machine.version == JellybeanMachine::CURRENT_VERSION @ JellybeanError::InvalidVersion,
         has_one = authority,
-        constraint = jellybean_machine.state != JellybeanState::SaleEnded @ JellybeanError::InvalidState
+        constraint = jellybean_machine.state != JellybeanState::Cancelled @ JellybeanError::InvalidState
     )]
     jellybean_machine: Box<Account<'info, JellybeanMachine>>,
 
     /// Gumball Machine authority. This is the address that controls the upate of the jellybean machine.
     #[account(mut)]
     authority: Signer<'info>,
+
+    /// Guard phases account, required for machines with public draws.
+    #[account(
+        seeds = [
+            GuardPhases::SEED_PREFIX.as_bytes(),
+            jellybean_machine.key().as_ref(),
+        ],
+        bump
+    )]
+    guard_phases: Option<Box<Account<'info, GuardPhases>>>,
 }
 
 pub fn update_settings(ctx: Context<UpdateSettings>, args: SettingsArgs) -> Result<()> {
@@ -29,11 +40,53 @@ pub fn update_settings(ctx: Context<UpdateSettings>, args: SettingsArgs) -> Resu
         JellybeanError::InvalidFeeAccountsLength
     );
 
-    // Validate settings arguments
-    validate_settings_args(&args)?;
+    // Validate settings arguments, public draws are checked against the guard phases
+    let phases = ctx
+        .accounts
+        .guard_phases
+        .as_ref()
+        .map_or(&[][..], |guard_phases| &guard_phases.phases[..]);
+    validate_settings_args(&args, phases)?;
+
+    // Loaded assets are only checked to be updatable by the authority pda while provenance
+    // or delayed reveal is enabled
+    require!(
+        jellybean_machine.items_loaded == 0
+            || jellybean_machine.provenance_config.is_some()
+            || args.provenance_config.is_none(),
+        JellybeanError::MachineCannotUpdateAsset
+    );
+    require!(
+        jellybean_machine.items_loaded == 0
+            || jellybean_machine.reveal_config.is_some()
+            || args.reveal_config.is_none(),
+        JellybeanError::MachineCannotUpdateAsset
+    );
+
+    // Buyers draw knowing the claim deadline, so once the sale has started it can only
+    // be pushed back
+    if jellybean_machine.state != JellybeanState::None {
+        require!(
+            match (jellybean_machine.claim_deadline, args.claim_deadline) {
+                (None, None) => true,
+                (Some(current), Some(new)) => new >= current,
+                _ => false,
+            },
+            JellybeanError::InvalidSchedule
+        );
+    }
 
     jellybean_machine.fee_accounts = args.fee_accounts;
     jellybean_machine.print_fee_config = args.print_fee_config;
+    jellybean_machine.provenance_config = args.provenance_config;
+    jellybean_machine.reveal_config = args.reveal_config;
+    jellybean_machine.max_wins_per_item = args.max_wins_per_item;
+    jellybean_machine.go_live_at = args.go_live_at;
+    jellybean_machine.end_at = args.end_at;
+    jellybean_machine.max_draws_per_wallet = args.max_draws_per_wallet;
+    jellybean_machine.bot_tax = args.bot_tax;
+    jellybean_machine.claim_deadline = args.claim_deadline;
+    jellybean_machine.public_draw = args.public_draw;
     jellybean_machine.uri = args.uri;
 
     Ok(())
@@ -1,5 +1,11 @@
-use crate::{JellybeanError, JellybeanMachine};
+use crate::{
+    assert_is_ata, constants::AUTHORITY_SEED, GuardPhases, JellybeanError, JellybeanMachine,
+};
 use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::{create_idempotent, Create},
+    token::{close_account, transfer, CloseAccount, Transfer},
+};
 
 /// Withdraw the rent SOL from the jellybean machine account.
 #[derive(Accounts)]
@@ -7,10 +13,12 @@ pub struct Withdraw<'info> {
     /// Gumball Machine acccount.
     #[account(
         mut,
+        constraint = jellybean_machine.version == JellybeanMachine::CURRENT_VERSION @ JellybeanError::InvalidVersion,
         close = authority,
         has_one = authority @ JellybeanError::InvalidAuthority,
         has_one = mint_authority @ JellybeanError::InvalidMintAuthority,
-        constraint = jellybean_machine.items_loaded == 0 @ JellybeanError::ItemsStillLoaded
+        constraint = jellybean_machine.items_loaded == 0 @ JellybeanError::ItemsStillLoaded,
+        constraint = jellybean_machine.open_bundles == 0 @ JellybeanError::BundlesStillOpen
     )]
     jellybean_machine: Account<'info, JellybeanMachine>,
 
@@ -21,8 +29,149 @@ pub struct Withdraw<'info> {
     /// Mint authority of the jellybean machine.
     #[account(mut)]
     mint_authority: Signer<'info>,
+
+    /// Guard phases account, closed with the machine if it exists.
+    /// CHECK: Safe due to seeds constraint
+    #[account(
+        mut,
+        seeds = [
+            GuardPhases::SEED_PREFIX.as_bytes(),
+            jellybean_machine.key().as_ref(),
+        ],
+        bump
+    )]
+    guard_phases: UncheckedAccount<'info>,
+
+    /// CHECK: Safe due to seeds constraint
+    #[account(
+        mut,
+        seeds = [
+            AUTHORITY_SEED.as_bytes(),
+            jellybean_machine.key().as_ref()
+        ],
+        bump
+    )]
+    authority_pda: UncheckedAccount<'info>,
+
+    /// CHECK: Safe due to address constraint
+    #[account(address = anchor_spl::token::ID)]
+    token_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Safe due to address constraint
+    #[account(address = anchor_spl::associated_token::ID)]
+    associated_token_program: Option<UncheckedAccount<'info>>,
+
+    system_program: Program<'info, System>,
+    // Remaining accounts, three per token account of the authority pda:
+    // - `[]` Mint of the tokens
+    // - `[writable]` Authority pda associated token account, closed by the withdraw
+    // - `[writable]` Authority associated token account receiving the tokens
 }
 
-pub fn withdraw<'info>(_: Context<'_, '_, '_, 'info, Withdraw<'info>>) -> Result<()> {
+pub fn withdraw<'info>(ctx: Context<'_, '_, '_, 'info, Withdraw<'info>>) -> Result<()> {
+    let authority = &ctx.accounts.authority.to_account_info();
+    let authority_pda = &ctx.accounts.authority_pda.to_account_info();
+
+    let guard_phases = &ctx.accounts.guard_phases.to_account_info();
+    if !guard_phases.data_is_empty() {
+        let lamports = guard_phases.lamports();
+        **guard_phases.try_borrow_mut_lamports()? = 0;
+        **authority.try_borrow_mut_lamports()? += lamports;
+
+        guard_phases.assign(&System::id());
+        guard_phases.realloc(0, false)?;
+    }
+
+    let jellybean_machine = ctx.accounts.jellybean_machine.key();
+    let auth_seeds = [
+        AUTHORITY_SEED.as_bytes(),
+        jellybean_machine.as_ref(),
+        &[ctx.bumps.authority_pda],
+    ];
+
+    let token_accounts = ctx.remaining_accounts.chunks_exact(3);
+    require!(
+        token_accounts.remainder().is_empty(),
+        JellybeanError::InvalidInputLength
+    );
+
+    // Every token account opened by a deposit must be swept and closed, tokens left in an
+    // account of the authority pda could not be moved once the machine is closed
+    let mut open_token_accounts = ctx.accounts.jellybean_machine.open_token_accounts;
+    if token_accounts.len() > 0 {
+        let (Some(token_program), Some(associated_token_program)) = (
+            &ctx.accounts.token_program,
+            &ctx.accounts.associated_token_program,
+        ) else {
+            return err!(JellybeanError::MissingTokenAccount);
+        };
+
+        for accounts in token_accounts {
+            let mint = &accounts[0];
+            let authority_pda_token_account = &accounts[1];
+            let authority_token_account = &accounts[2];
+            let token_account =
+                assert_is_ata(authority_pda_token_account, authority_pda.key, mint.key)?;
+
+            create_idempotent(CpiContext::new(
+                associated_token_program.to_account_info(),
+                Create {
+                    payer: authority.clone(),
+                    associated_token: authority_token_account.clone(),
+                    authority: authority.clone(),
+                    mint: mint.clone(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    token_program: token_program.to_account_info(),
+                },
+            ))?;
+
+            if token_account.amount > 0 {
+                transfer(
+                    CpiContext::new_with_signer(
+                        token_program.to_account_info(),
+                        Transfer {
+                            from: authority_pda_token_account.clone(),
+                            to: authority_token_account.clone(),
+                            authority: authority_pda.clone(),
+                        },
+                        &[&auth_seeds],
+                    ),
+                    token_account.amount,
+                )?;
+            }
+
+            close_account(CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                CloseAccount {
+                    account: authority_pda_token_account.clone(),
+                    destination: authority.clone(),
+                    authority: authority_pda.clone(),
+                },
+                &[&auth_seeds],
+            ))?;
+
+            msg!(
+                "Swept authority pda token account: mint={}, amount={}",
+                mint.key,
+                token_account.amount
+            );
+            open_token_accounts = open_token_accounts.saturating_sub(1);
+        }
+    }
+    require!(
+        open_token_accounts == 0,
+        JellybeanError::TokenAccountsStillOpen
+    );
+
+    // Every item and bundle has been removed, so no prize or escrow is left to settle and
+    // the rent and any escrow left from editions that were never printed can be swept
+    let lamports = authority_pda.lamports();
+    if lamports > 0 {
+        **authority_pda.try_borrow_mut_lamports()? = 0;
+        **authority.try_borrow_mut_lamports()? += lamports;
+    }
+
+    msg!("Swept authority pda: lamports={}", lamports);
+
     Ok(())
 }
@@ -31,17 +31,27 @@ pub mod mallow_jellybean {
         instructions::initialize(ctx, args)
     }
 
-    /// Updates jellybean machine settings.
+    /// Updates jellybean machine settings. Public draws can only be allowed once every guard
+    /// phase charges a price.
     ///
     /// # Accounts
     ///
     ///   0. `[writable]` Gumball Machine account
     ///   1. `[signer, writable]` Gumball Machine authority
+    ///   2. `[optional]` Guard phases (PDA, seeds: ["guard_phases", jellybean_machine]), required if public draws are allowed
     pub fn update_settings(ctx: Context<UpdateSettings>, args: SettingsArgs) -> Result<()> {
         instructions::update_settings(ctx, args)
     }
 
-    /// Add Core assets to the jellybean machine.
+    /// Add Core assets to the jellybean machine. Assets stay in the seller's wallet, frozen
+    /// with freeze and transfer delegates held by the authority PDA until they are claimed.
+    /// Items can be restocked while the sale is live or after it has ended, and each
+    /// addition emits an `AddItemEvent`.
+    /// For a master edition, `supply` sets the number of prints to offer. It is required for
+    /// open editions without a max supply and defaults to the remaining max supply otherwise.
+    /// Master editions with existing prints can be loaded, and edition numbers continue after them.
+    /// When the machine writes provenance or is in delayed reveal mode, the authority PDA must
+    /// already be the update authority or an update delegate of the asset or its collection.
     ///
     /// # Accounts
     ///
@@ -53,12 +63,71 @@ pub mod mallow_jellybean {
     ///   5. `[writable, optional]` Collection account
     ///   6. `[]` MPL Core program
     ///   7. `[]` System program
-    pub fn add_core_item(ctx: Context<AddCoreItem>) -> Result<()> {
-        instructions::add_core_item(ctx)
+    pub fn add_core_item(ctx: Context<AddCoreItem>, supply: Option<u32>) -> Result<()> {
+        instructions::add_core_item(ctx, supply)
+    }
+
+    /// Add multiple Core assets and master editions to the jellybean machine in a single
+    /// instruction, loading each of them like `add_core_item`. `supplies` has one entry per
+    /// item, the number of prints to offer for a master edition and none for an asset.
+    /// The account is reallocated and its rent topped up once for the whole batch.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account
+    ///   1. `[writable]` Authority PDA (PDA, seeds: ["authority", jellybean_machine])
+    ///   2. `[signer, writable]` Authority
+    ///   3. `[signer, writable]` Payer for account reallocation
+    ///   4. `[]` MPL Core program
+    ///   5. `[]` System program
+    ///   Remaining accounts (one pair per item):
+    ///     - `[writable]` Asset account, or the jellybean program id for a master edition
+    ///     - `[writable]` Collection account, or the jellybean program id if none
+    pub fn add_core_items<'info>(
+        ctx: Context<'_, '_, '_, 'info, AddCoreItems<'info>>,
+        supplies: Vec<Option<u32>>,
+    ) -> Result<()> {
+        instructions::add_core_items(ctx, supplies)
+    }
+
+    /// Offer more prints of a master edition already loaded in the jellybean machine.
+    /// The new supply cannot exceed the collection's max supply, if it has one.
+    /// Emits an `AddPrintsEvent` with the number of prints added.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account
+    ///   1. `[signer]` Gumball Machine authority
+    ///   2. `[]` Master edition collection account
+    pub fn add_prints(ctx: Context<AddPrints>, index: u16, amount: u32) -> Result<()> {
+        instructions::add_prints(ctx, index, amount)
+    }
+
+    /// Set the name and uri templates used to print editions of a loaded master edition,
+    /// e.g. "{name} #{edition}" and "{uri}/{edition}.json". The item's print escrow is resized
+    /// for the longest expansion, so it cannot have unclaimed prizes.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account
+    ///   1. `[signer]` Gumball Machine authority
+    ///   2. `[signer, writable]` Payer for the print template account
+    ///   3. `[]` Master edition collection account
+    ///   4. `[writable]` Print template (PDA, seeds: ["print_template", jellybean_machine, collection])
+    ///   5. `[]` System program
+    pub fn set_print_template(
+        ctx: Context<SetPrintTemplate>,
+        index: u16,
+        name: String,
+        uri: String,
+    ) -> Result<()> {
+        instructions::set_print_template(ctx, index, name, uri)
     }
 
     /// Remove Core asset from the jellybean machine.
     /// Thaws and revokes delegate from the seller's asset and removes it from the config lines.
+    /// A slot tombstoned by an earlier removal can be removed without item accounts, which
+    /// compacts the machine once the prizes that kept it in place have been claimed.
     /// The signer can be the Gumball Machine authority or the seller of the specific item.
     ///
     /// # Accounts
@@ -70,22 +139,189 @@ pub mod mallow_jellybean {
     ///   4. `[writable]` Seller account (owner of the asset).
     ///   5. `[writable]` Asset account.
     ///   6. `[writable, optional]` Collection account if asset is part of one.
-    ///   7. `[]` MPL Core program.
-    ///   8. `[]` System program.
-    pub fn remove_core_item(ctx: Context<RemoveCoreItem>, index: u8) -> Result<()> {
+    ///   7. `[writable, optional]` Print template (PDA, seeds: ["print_template", jellybean_machine, collection]), required for master editions.
+    ///   8. `[]` MPL Core program.
+    ///   9. `[]` System program.
+    pub fn remove_core_item(ctx: Context<RemoveCoreItem>, index: u16) -> Result<()> {
         instructions::remove_core_item(ctx, index)
     }
 
-    /// Allows minting to begin.
+    /// Remove a set of Core assets, master editions or bundles from the jellybean machine,
+    /// returning each of them to the authority. The account is shrunk once for the whole set.
+    /// Items up to the last one with unclaimed prizes are tombstoned in place, so outstanding
+    /// prize indices stay valid. Later items are shifted down, along with tombstones left by
+    /// earlier removals once their blocking prizes have been claimed.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account.
+    ///   1. `[writable]` Authority PDA (PDA, seeds: ["authority", jellybean_machine]).
+    ///   2. `[signer, writable]` Gumball Machine authority.
+    ///   3. `[]` MPL Core program.
+    ///   4. `[]` System program.
+    ///   Remaining accounts (three per item, in the order of the sorted indices, set to the
+    ///   jellybean program id when unused):
+    ///     - `[writable]` Asset or bundle account
+    ///     - `[writable]` Collection of the asset, or the master edition collection
+    ///     - `[writable]` Print template (PDA, seeds: ["print_template", jellybean_machine, collection]) of a master edition
+    pub fn remove_core_items<'info>(
+        ctx: Context<'_, '_, '_, 'info, RemoveCoreItems<'info>>,
+        indices: Vec<u16>,
+    ) -> Result<()> {
+        instructions::remove_core_items(ctx, indices)
+    }
+
+    /// Remove the last items of the jellybean machine, one per three remaining accounts laid
+    /// out like remove_core_items. Repeating it until no item is loaded unloads the whole
+    /// machine over several transactions.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account.
+    ///   1. `[writable]` Authority PDA (PDA, seeds: ["authority", jellybean_machine]).
+    ///   2. `[signer, writable]` Gumball Machine authority.
+    ///   3. `[]` MPL Core program.
+    ///   4. `[]` System program.
+    ///   Remaining accounts (three per item, from the first to the last item removed):
+    ///     - `[writable]` Asset or bundle account
+    ///     - `[writable]` Collection of the asset, or the master edition collection
+    ///     - `[writable]` Print template of a master edition
+    pub fn unload_all<'info>(ctx: Context<'_, '_, '_, 'info, UnloadAll<'info>>) -> Result<()> {
+        instructions::unload_all(ctx)
+    }
+
+    /// Replace a loaded item with another Core asset in the same slot, without shifting the
+    /// later items. The old asset or master edition is returned to the authority like
+    /// `remove_core_item`. Allowed before the sale starts, or for items that were never drawn.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account
+    ///   1. `[writable]` Authority PDA (PDA, seeds: ["authority", jellybean_machine])
+    ///   2. `[signer, writable]` Gumball Machine authority
+    ///   3. `[writable, optional]` Asset account being replaced
+    ///   4. `[writable, optional]` Collection account of the replaced asset, or the replaced master edition
+    ///   5. `[writable, optional]` Print template (PDA, seeds: ["print_template", jellybean_machine, collection]) of a replaced master edition
+    ///   6. `[writable]` New asset account
+    ///   7. `[writable, optional]` New asset's collection account if it's part of one
+    ///   8. `[]` MPL Core program
+    ///   9. `[]` System program
+    pub fn replace_core_item(ctx: Context<ReplaceCoreItem>, index: u16) -> Result<()> {
+        instructions::replace_core_item(ctx, index)
+    }
+
+    /// Escrow a Core asset, or an amount of tokens, in a bundle that isn't loaded yet.
+    /// The bundle is created with the first component.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account
+    ///   1. `[]` Authority PDA (PDA, seeds: ["authority", jellybean_machine])
+    ///   2. `[signer]` Gumball Machine authority, owner of the component
+    ///   3. `[signer, writable]` Payer
+    ///   4. `[writable]` Bundle (PDA, seeds: ["bundle", jellybean_machine, id])
+    ///   5. `[writable, optional]` Asset account for a Core asset component
+    ///   6. `[writable, optional]` Collection account if asset is part of one
+    ///   7. `[optional]` Mint account for a token component
+    ///   8. `[writable, optional]` Authority token account
+    ///   9. `[writable, optional]` Authority PDA associated token account
+    ///   10. `[]` MPL Core program
+    ///   11. `[]` Token program
+    ///   12. `[]` Associated token program
+    ///   13. `[]` System program
+    pub fn add_bundle_component(
+        ctx: Context<AddBundleComponent>,
+        id: u32,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::add_bundle_component(ctx, id, amount)
+    }
+
+    /// Return a component of a bundle that isn't loaded to the authority.
+    /// The bundle is closed once its last component is removed.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account
+    ///   1. `[]` Authority PDA (PDA, seeds: ["authority", jellybean_machine])
+    ///   2. `[signer, writable]` Gumball Machine authority
+    ///   3. `[writable]` Bundle account
+    ///   4. `[writable, optional]` Asset account for a Core asset component
+    ///   5. `[writable, optional]` Collection account if asset is part of one
+    ///   6. `[optional]` Mint account for a token component
+    ///   7. `[writable, optional]` Authority PDA associated token account
+    ///   8. `[writable, optional]` Authority associated token account
+    ///   9. `[]` MPL Core program
+    ///   10. `[]` Token program
+    ///   11. `[]` Associated token program
+    ///   12. `[]` System program
+    pub fn remove_bundle_component(
+        ctx: Context<RemoveBundleComponent>,
+        component: u8,
+    ) -> Result<()> {
+        instructions::remove_bundle_component(ctx, component)
+    }
+
+    /// Load a bundle as a single item with a supply of one. A draw awards every component.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account
+    ///   1. `[signer]` Gumball Machine authority
+    ///   2. `[signer, writable]` Payer
+    ///   3. `[writable]` Bundle account
+    ///   4. `[]` System program
+    pub fn add_bundle_item(ctx: Context<AddBundleItem>) -> Result<()> {
+        instructions::add_bundle_item(ctx)
+    }
+
+    /// Remove a bundle from the jellybean machine. A bundle that hasn't been drawn has its
+    /// components unlocked, so the authority can take them back with remove_bundle_component.
+    /// A drawn bundle can be removed once all its components have been delivered, which
+    /// closes the bundle account.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account
+    ///   1. `[signer, writable]` Gumball Machine authority
+    ///   2. `[writable]` Bundle account (PDA, seeds: ["bundle", jellybean_machine, id])
+    pub fn remove_bundle_item(ctx: Context<RemoveBundleItem>, index: u16) -> Result<()> {
+        instructions::remove_bundle_item(ctx, index)
+    }
+
+    /// Allows minting to begin. An ended sale can be reopened once it has been
+    /// restocked with supply left to draw.
     ///
     /// # Accounts
     ///
     ///   0. `[writable]` Gumball Machine account
     ///   1. `[signer]` Gumball Machine authority (authority or mint_authority)
+    ///   2. `[optional]` Guard phases (PDA, seeds: ["guard_phases", jellybean_machine]), required if public draws are allowed
     pub fn start_sale(ctx: Context<StartSale>) -> Result<()> {
         instructions::start_sale(ctx)
     }
 
+    /// Blocks draws on a live sale without ending it. Prizes can still be claimed and
+    /// settings are kept, so the sale can be resumed once the issue is fixed.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account
+    ///   1. `[signer]` Gumball Machine authority (authority or mint_authority)
+    pub fn pause_sale(ctx: Context<PauseSale>) -> Result<()> {
+        instructions::pause_sale(ctx)
+    }
+
+    /// Allows minting again on a paused sale.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account
+    ///   1. `[signer]` Gumball Machine authority (authority or mint_authority)
+    pub fn resume_sale(ctx: Context<ResumeSale>) -> Result<()> {
+        instructions::resume_sale(ctx)
+    }
+
     /// Disables minting and allows sales to be settled.
     ///
     /// # Accounts
@@ -96,42 +332,277 @@ pub mod mallow_jellybean {
         instructions::end_sale(ctx)
     }
 
+    /// Ends the sale once the machine's end_at time has passed. Permissionless, so a sale
+    /// with a scheduled end can be settled without the authority.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account
+    pub fn end_scheduled_sale(ctx: Context<EndScheduledSale>) -> Result<()> {
+        instructions::end_scheduled_sale(ctx)
+    }
+
+    /// Calls off the sale. Draws and claims stop, and the authority escrows the prices paid for
+    /// every unclaimed prize in the authority PDA so each buyer can be refunded. Prices charged
+    /// by a wrapping program are not held by the machine and are not refunded.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account
+    ///   1. `[writable]` Authority PDA (PDA, seeds: ["authority", jellybean_machine])
+    ///   2. `[signer, writable]` Gumball Machine authority
+    ///   3. `[]` System program
+    pub fn cancel_sale(ctx: Context<CancelSale>) -> Result<()> {
+        instructions::cancel_sale(ctx)
+    }
+
+    /// Refunds an unclaimed prize of a cancelled sale: the price paid for it plus any edition
+    /// escrow go back to the buyer, and the prize supply goes back to the item.
+    /// Permissionless, so any account can crank refunds.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[signer, writable]` Payer
+    ///   1. `[writable]` Gumball Machine account
+    ///   2. `[writable]` Authority PDA (PDA, seeds: ["authority", jellybean_machine])
+    ///   3. `[writable]` Buyer account
+    ///   4. `[writable]` Unclaimed prizes account (PDA, seeds: ["unclaimed_prizes", jellybean_machine, buyer])
+    ///   5. `[]` Asset, master edition collection or bundle of the item
+    pub fn refund_prize<'info>(
+        ctx: Context<'_, '_, '_, 'info, RefundPrize<'info>>,
+        index: u16,
+    ) -> Result<()> {
+        instructions::refund_prize(ctx, index)
+    }
+
+    /// Settles a prize its buyer hasn't claimed once the machine's claim_deadline has passed.
+    /// The deadline comes after the sale's end_at, no draw is accepted past it, and once the
+    /// sale has started it can only be pushed back.
+    /// The prize is delivered to the buyer like claim_core_item: the asset is transferred, or
+    /// the edition printed. Only an asset the authority PDA can no longer move is written off.
+    /// Bundles are delivered with claim_bundle_component. The item can then be removed.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account
+    ///   1. `[writable]` Authority PDA (PDA, seeds: ["authority", jellybean_machine])
+    ///   2. `[signer, writable]` Gumball Machine authority, pays for prints
+    ///   3. `[writable]` Buyer account
+    ///   4. `[writable]` Unclaimed prizes account (PDA, seeds: ["unclaimed_prizes", jellybean_machine, buyer])
+    ///   5. `[writable, optional]` Asset
+    ///   6. `[writable, optional]` Collection of the asset, or the master edition collection
+    ///   7. `[signer, writable, optional]` Print asset, required for editions
+    ///   8. `[optional]` Print template (PDA, seeds: ["print_template", jellybean_machine, collection])
+    ///   9. `[]` MPL Core program
+    ///   10. `[]` System program
+    pub fn reclaim_prize<'info>(
+        ctx: Context<'_, '_, '_, 'info, ReclaimPrize<'info>>,
+        index: u16,
+    ) -> Result<()> {
+        instructions::reclaim_prize(ctx, index)
+    }
+
     /// Draw for a random item from the jellybean machine.
-    /// Only the jellybean machine mint authority is allowed to draw.
+    /// Only the jellybean machine mint authority is allowed to draw, unless the machine allows
+    /// public draws, where the buyer only signs as payer. Public draws must be paid through
+    /// the machine's guard phases, and are rejected when the active phase has no price.
+    /// Draws are only open between the machine's go_live_at and end_at times, if set, and a
+    /// scheduled machine goes live on its first draw after go_live_at.
+    /// A guarded machine applies the rules of its active phase and charges its price, and a
+    /// machine with a per-wallet limit counts the buyer's lifetime draws in a buyer record.
+    /// With a bot tax set, a public draw breaking one of these rules charges the tax to the
+    /// payer and succeeds without drawing. The tax is held by the machine account and paid to
+    /// the authority with its rent, when items are removed or the machine is withdrawn.
     ///
     /// # Accounts
     ///
     ///   0. `[writable]` Gumball Machine account
-    ///   1. `[signer]` Gumball Machine mint authority
+    ///   1. `[]` Gumball Machine mint authority, must sign unless the machine allows public draws
     ///   2. `[signer, writable]` Payer
     ///   3. `[]` Buyer account
     ///   4. `[]` System program
     ///   5. `[]` SlotHashes sysvar cluster data
+    ///   Remaining accounts, if the machine has guard phases or a per-wallet limit:
+    ///     - `[]` Guard phases (PDA, seeds: ["guard_phases", jellybean_machine]), if the machine has guard phases
+    ///     - `[]` Allowlist proof (PDA, seeds: ["allowlist_proof", jellybean_machine, merkle_root, buyer]), if the phase has an allowlist
+    ///     - Gate accounts, if the phase has a gate:
+    ///       - Token gate: `[writable]` buyer's associated token account, then to burn `[writable]` mint and `[]` Token program
+    ///       - Core collection gate: `[writable]` asset, then to burn `[writable]` collection and `[]` MPL Core program,
+    ///         or to mark it used `[writable]` used gate asset (PDA, seeds: ["used_gate_asset", jellybean_machine, asset])
+    ///     - `[writable]` Buyer record (PDA, seeds: ["buyer_record", jellybean_machine, buyer]), if the machine or the phase has a per-wallet limit
+    ///     - `[writable]` Machine fee accounts in order, if the phase has a price
     pub fn draw<'info>(ctx: Context<'_, '_, '_, 'info, Draw<'info>>) -> Result<()> {
         instructions::draw(ctx)
     }
 
     /// Claims a Core asset from the jellybean machine for a specific buyer.
-    /// Transfers the asset from the PDA to the buyer.
+    /// Thaws the asset in the seller's wallet and transfers it to the buyer using the
+    /// authority PDA's transfer delegate. Assets held by the PDA itself are transferred directly.
+    /// Editions are printed with the master edition's print template, if one is set.
+    /// With a provenance config, the machine, draw slot, edition and tier are recorded in an
+    /// Attributes plugin on the claimed asset.
     ///
     /// # Accounts
     ///
     ///   0. `[signer, writable]` Payer (anyone can claim the item)
-    ///   1. `[writable]` Gumball Machine account (must be in SaleLive or SaleEnded state)
+    ///   1. `[writable]` Gumball Machine account (must be in SaleLive, Paused or SaleEnded state)
     ///   2. `[writable]` Authority PDA (PDA, seeds: ["authority", jellybean_machine])
     ///   3. `[writable]` Seller account
     ///   4. `[]` Buyer account
     ///   5. `[]` System program
     ///   6. `[writable]` Asset account
     ///   7. `[writable, optional]` Collection account if asset is part of one.
-    ///   8. `[]` MPL Core program.
+    ///   8. `[signer, optional]` Print asset account for editions.
+    ///   9. `[optional]` Print template (PDA, seeds: ["print_template", jellybean_machine, collection]), required for editions.
+    ///   10. `[]` MPL Core program.
     pub fn claim_core_item<'info>(
         ctx: Context<'_, '_, '_, 'info, ClaimCoreItem<'info>>,
-        index: u8,
+        index: u16,
     ) -> Result<()> {
         instructions::claim_core_item(ctx, index)
     }
 
+    /// Deliver one component of a drawn bundle to its buyer. Components can be claimed
+    /// across several transactions; the prize is settled and the bundle closed once the
+    /// last one is delivered.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[signer, writable]` Payer (anyone can claim the bundle)
+    ///   1. `[writable]` Gumball Machine account (must be in SaleLive, Paused or SaleEnded state)
+    ///   2. `[writable]` Authority PDA (PDA, seeds: ["authority", jellybean_machine])
+    ///   3. `[writable]` Gumball Machine authority, receives the bundle rent
+    ///   4. `[writable]` Buyer account
+    ///   5. `[writable]` Unclaimed prizes (PDA, seeds: ["unclaimed_prizes", jellybean_machine, buyer])
+    ///   6. `[writable]` Bundle account
+    ///   7. `[writable, optional]` Asset account for a Core asset component
+    ///   8. `[writable, optional]` Collection account if asset is part of one
+    ///   9. `[optional]` Mint account for a token component
+    ///   10. `[writable, optional]` Authority PDA associated token account
+    ///   11. `[writable, optional]` Buyer associated token account
+    ///   12. `[]` MPL Core program
+    ///   13. `[]` Token program
+    ///   14. `[]` Associated token program
+    ///   15. `[]` System program
+    pub fn claim_bundle_component<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimBundleComponent<'info>>,
+        index: u16,
+        component: u8,
+    ) -> Result<()> {
+        instructions::claim_bundle_component(ctx, index, component)
+    }
+
+    /// Reveal the final uri of claimed assets still showing the placeholder uri of a
+    /// machine in delayed reveal mode. Assets can be revealed one at a time or in bulk.
+    /// Removed master editions keep the authority PDA as an update delegate, so their prints
+    /// can still be revealed.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[]` Gumball Machine account
+    ///   1. `[]` Authority PDA (PDA, seeds: ["authority", jellybean_machine])
+    ///   2. `[signer, writable]` Gumball Machine authority
+    ///   3. `[]` MPL Core program
+    ///   4. `[]` System program
+    ///   Remaining accounts (one pair per uri):
+    ///     - `[writable]` Asset account
+    ///     - `[writable]` Collection account, or the jellybean program id if none
+    pub fn reveal_items<'info>(
+        ctx: Context<'_, '_, '_, 'info, RevealItems<'info>>,
+        uris: Vec<String>,
+    ) -> Result<()> {
+        instructions::reveal_items(ctx, uris)
+    }
+
+    /// Set the ordered phases of the sale, e.g. an allowlist presale then a public sale. Each
+    /// phase has a start time, a price split between the fee accounts, and optionally an
+    /// allowlist Merkle root, a token or Core collection gate and a per-wallet draw limit.
+    /// Gate tokens or assets can be burned by the draw, and Core assets marked as used.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account
+    ///   1. `[signer]` Gumball Machine authority
+    ///   2. `[signer, writable]` Payer for the guard phases account
+    ///   3. `[writable]` Guard phases (PDA, seeds: ["guard_phases", jellybean_machine])
+    ///   4. `[]` System program
+    pub fn set_guard_phases(ctx: Context<SetGuardPhases>, phases: Vec<GuardPhase>) -> Result<()> {
+        instructions::set_guard_phases(ctx, phases)
+    }
+
+    /// Prove that the buyer is in the allowlist of a guard phase, so later draws only pass
+    /// the allowlist proof account. Leaves are keccak256 hashes of the buyer address and
+    /// pairs are hashed in sorted order.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[]` Gumball Machine account
+    ///   1. `[]` Guard phases (PDA, seeds: ["guard_phases", jellybean_machine])
+    ///   2. `[signer]` Buyer
+    ///   3. `[signer, writable]` Payer for the allowlist proof account
+    ///   4. `[writable]` Allowlist proof (PDA, seeds: ["allowlist_proof", jellybean_machine, merkle_root, buyer])
+    ///   5. `[]` System program
+    pub fn prove_allowlist(
+        ctx: Context<ProveAllowlist>,
+        merkle_root: [u8; 32],
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::prove_allowlist(ctx, merkle_root, proof)
+    }
+
+    /// Close a buyer record, allowlist proof or used gate asset account once its machine
+    /// has been withdrawn or cancelled, sending the rent back to the buyer. Anyone can close
+    /// them.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[]` Gumball Machine account
+    ///   1. `[writable]` Buyer record, allowlist proof or used gate asset account
+    ///   2. `[writable]` Buyer of the record
+    pub fn close_guard_record(ctx: Context<CloseGuardRecord>) -> Result<()> {
+        instructions::close_guard_record(ctx)
+    }
+
+    /// Close a buyer's unclaimed prizes account once every prize has been claimed and the
+    /// sale is cancelled or the machine withdrawn, sending the rent back to the buyer. The
+    /// account is otherwise kept to hold the buyer's item wins. Anyone can close it.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[]` Gumball Machine account
+    ///   1. `[writable]` Buyer unclaimed draws account (PDA, seeds: ["unclaimed_prizes", jellybean_machine, buyer])
+    ///   2. `[writable]` Buyer of the prizes
+    pub fn close_unclaimed_prizes(ctx: Context<CloseUnclaimedPrizes>) -> Result<()> {
+        instructions::close_unclaimed_prizes(ctx)
+    }
+
+    /// Take a loaded item out of the draw pool, or put it back, without removing it.
+    /// Up to 16 items can be disabled at once, in any state of the machine.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account
+    ///   1. `[signer]` Gumball Machine authority
+    pub fn set_item_enabled(ctx: Context<SetItemEnabled>, index: u16, enabled: bool) -> Result<()> {
+        instructions::set_item_enabled(ctx, index, enabled)
+    }
+
+    /// Set, or clear, the maximum number of units of an item a single buyer can win. Draws
+    /// re-select among the other items once a buyer reaches it. Up to 16 items can have their
+    /// own limit, the others use the machine's max_wins_per_item setting.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account
+    ///   1. `[signer]` Gumball Machine authority
+    pub fn set_item_buyer_limit(
+        ctx: Context<SetItemBuyerLimit>,
+        index: u16,
+        max_per_buyer: Option<u16>,
+    ) -> Result<()> {
+        instructions::set_item_buyer_limit(ctx, index, max_per_buyer)
+    }
+
     /// Set a new mint authority of the jellybean machine.
     ///
     /// # Accounts
@@ -143,25 +614,52 @@ pub mod mallow_jellybean {
         instructions::set_mint_authority(ctx)
     }
 
-    /// Withdraw the rent lamports and send them to the authority address.
-    /// If a non-native payment mint was used, also closes the PDA payment token account,
-    /// sending its balance to the authority's associated token account.
+    /// Withdraw the rent lamports, with any bot tax collected, and send them to the authority
+    /// address. Every item must have been removed and every bundle closed. The guard phases
+    /// account is closed with the machine if it exists, and the authority PDA is swept: its
+    /// rent and any escrow left from editions that were never printed go to the authority.
+    /// Every token account the authority PDA opened for bundle deposits must be passed: each
+    /// one is closed, sending its balance to the authority's associated token account.
     ///
     /// # Accounts
     ///
     ///   0. `[writable]` Gumball Machine account (will be closed)
     ///   1. `[signer, writable]` Gumball Machine authority
     ///   2. `[signer, writable]` Gumball Machine mint authority
-    ///   3. `[writable]` Authority PDA (PDA, seeds: ["authority", jellybean_machine])
-    ///   4. `[writable, optional]` Authority PDA payment account
-    ///   5. `[]` Token program
-    ///   Remaining accounts (if closing non-native payment account):
-    ///     - `[]` Payment Mint
-    ///     - `[writable]` Authority's token account for payment mint
-    ///     - `[]` Associated Token program
-    ///     - `[]` System program
-    ///     - `[]` Rent sysvar
+    ///   3. `[writable]` Guard phases (PDA, seeds: ["guard_phases", jellybean_machine])
+    ///   4. `[writable]` Authority PDA (PDA, seeds: ["authority", jellybean_machine])
+    ///   5. `[optional]` Token program (required with token accounts)
+    ///   6. `[optional]` Associated Token program (required with token accounts)
+    ///   7. `[]` System program
+    ///   Remaining accounts (three per token account of the authority PDA):
+    ///     - `[]` Mint of the tokens
+    ///     - `[writable]` Authority PDA associated token account
+    ///     - `[writable]` Authority associated token account
     pub fn withdraw<'info>(ctx: Context<'_, '_, '_, 'info, Withdraw<'info>>) -> Result<()> {
         instructions::withdraw(ctx)
     }
+
+    /// Migrate a jellybean machine account to the current version.
+    /// Loaded items are widened in place, the payer covers the extra rent.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[writable]` Gumball Machine account
+    ///   1. `[signer]` Gumball Machine authority
+    ///   2. `[signer, writable]` Payer
+    ///   3. `[]` System program
+    pub fn migrate_jellybean_machine(ctx: Context<MigrateJellybeanMachine>) -> Result<()> {
+        instructions::migrate_jellybean_machine(ctx)
+    }
+
+    /// Migrate a buyer's unclaimed prizes account to the current version.
+    ///
+    /// # Accounts
+    ///
+    ///   0. `[signer, writable]` Payer (anyone can migrate the account)
+    ///   1. `[writable]` Unclaimed prizes account
+    ///   2. `[]` System program
+    pub fn migrate_unclaimed_prizes(ctx: Context<MigrateUnclaimedPrizes>) -> Result<()> {
+        instructions::migrate_unclaimed_prizes(ctx)
+    }
 }
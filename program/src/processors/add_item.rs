@@ -1,5 +1,126 @@
-use crate::{JellybeanError, JellybeanMachine, LoadedItem, LOADED_ITEM_SIZE, MAX_ITEMS};
+use crate::{
+    processors::{assert_machine_can_update_core_asset, delegate_core_asset},
+    ItemKind, JellybeanError, JellybeanMachine, LoadedItem, LOADED_ITEM_SIZE, MAX_ITEMS,
+};
 use anchor_lang::prelude::*;
+use mpl_core::{instructions::UpdateCollectionV1CpiBuilder, Collection};
+
+/// Freezes a one of one Core asset in the seller's wallet, the authority pda transfers it
+/// on claim.
+#[allow(clippy::too_many_arguments)]
+pub fn load_core_asset<'info>(
+    jellybean_machine: &JellybeanMachine,
+    asset: &AccountInfo<'info>,
+    collection: Option<&AccountInfo<'info>>,
+    authority: &AccountInfo<'info>,
+    authority_pda: &AccountInfo<'info>,
+    mpl_core_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    auth_seeds: &[&[u8]],
+) -> Result<LoadedItem> {
+    assert_machine_can_update_core_asset(jellybean_machine, asset, collection, authority_pda)?;
+
+    delegate_core_asset(
+        asset,
+        collection,
+        authority,
+        authority_pda,
+        mpl_core_program,
+        system_program,
+        auth_seeds,
+    )?;
+
+    Ok(LoadedItem {
+        mint: asset.key(),
+        supply_loaded: 1,
+        supply_redeemed: 0,
+        supply_claimed: 0,
+        escrow_amount: 0,
+        existing_prints: 0,
+        kind: ItemKind::CoreAsset,
+    })
+}
+
+/// Makes the authority pda the update authority of a master edition collection so it can
+/// print editions. `supply` is the number of prints to offer, required for open editions
+/// and defaulting to the remaining max supply otherwise.
+pub fn load_master_edition<'info>(
+    collection_account: &AccountInfo<'info>,
+    supply: Option<u32>,
+    authority: &AccountInfo<'info>,
+    authority_pda: &AccountInfo<'info>,
+    mpl_core_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<LoadedItem> {
+    let collection = Box::<Collection>::try_from(collection_account)?;
+
+    let master_edition = collection
+        .plugin_list
+        .master_edition
+        .ok_or(JellybeanError::MissingMasterEdition)?
+        .master_edition;
+
+    // Prints minted before loading are counted as already redeemed and claimed, so they
+    // are never drawn and edition numbers continue after them. num_minted is used rather
+    // than current_size so burned prints don't lead to duplicate edition numbers.
+    let existing_prints = collection.base.num_minted;
+
+    // Open editions have no max supply, so the number of prints to offer must be explicit
+    let supply_offered = match (master_edition.max_supply, supply) {
+        (Some(max_supply), None) => max_supply.saturating_sub(existing_prints),
+        (_, Some(supply)) => supply,
+        (None, None) => return err!(JellybeanError::InvalidMasterEditionSupply),
+    };
+
+    require!(
+        supply_offered > 0,
+        JellybeanError::InvalidMasterEditionSupply
+    );
+
+    let supply_loaded = existing_prints
+        .checked_add(supply_offered)
+        .ok_or(JellybeanError::NumericalOverflowError)?;
+
+    if let Some(max_supply) = master_edition.max_supply {
+        require!(
+            supply_loaded <= max_supply,
+            JellybeanError::InvalidMasterEditionSupply
+        );
+    }
+
+    // Update the master edition authority to the authority pda
+    UpdateCollectionV1CpiBuilder::new(mpl_core_program)
+        .collection(collection_account)
+        .payer(authority)
+        .new_update_authority(Some(authority_pda))
+        .system_program(system_program)
+        .invoke()?;
+
+    let name = if let Some(name) = master_edition.name {
+        name
+    } else {
+        collection.base.name
+    };
+
+    let uri = if let Some(uri) = master_edition.uri {
+        uri
+    } else {
+        collection.base.uri
+    };
+
+    // We escrow funds from the buyer to cover printing fees, this allows the buyer or seller to settle the sale
+    let escrow_amount = LoadedItem::print_escrow_amount(name.len(), uri.len())?;
+
+    Ok(LoadedItem {
+        mint: collection_account.key(),
+        supply_loaded,
+        supply_redeemed: existing_prints,
+        supply_claimed: existing_prints,
+        escrow_amount,
+        existing_prints,
+        kind: ItemKind::MasterEdition,
+    })
+}
 
 pub fn add_item<'info>(
     jellybean_machine: &mut Account<'info, JellybeanMachine>,
@@ -7,14 +128,36 @@ pub fn add_item<'info>(
     payer: &AccountInfo<'info>,
     system_program: &AccountInfo<'info>,
 ) -> Result<()> {
-    // Calculate space needed for one more item
+    add_items(jellybean_machine, vec![item], payer, system_program)
+}
+
+/// Appends the items to the loaded items section, reallocating the account and
+/// topping up its rent once for the whole batch.
+pub fn add_items<'info>(
+    jellybean_machine: &mut Account<'info, JellybeanMachine>,
+    items: Vec<LoadedItem>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    if items.is_empty() {
+        return err!(JellybeanError::InvalidInputLength);
+    }
+
     let current_items_loaded = jellybean_machine.items_loaded as usize;
-    let new_space = jellybean_machine.get_size((current_items_loaded + 1) as u64);
+    let new_items_loaded = current_items_loaded + items.len();
+
+    require!(
+        new_items_loaded <= MAX_ITEMS as usize,
+        JellybeanError::TooManyItems
+    );
+
+    // Calculate space needed for the new items
+    let new_space = jellybean_machine.get_size(new_items_loaded as u64);
     let rent = Rent::get()?;
     let new_rent_minimum = rent.minimum_balance(new_space);
     let current_lamports = jellybean_machine.to_account_info().lamports();
 
-    let additional_lamports = new_rent_minimum - current_lamports;
+    let additional_lamports = new_rent_minimum.saturating_sub(current_lamports);
 
     if additional_lamports > 0 {
         // Transfer additional lamports from payer
@@ -37,36 +180,39 @@ pub fn add_item<'info>(
 
     let account_info = jellybean_machine.to_account_info();
     let mut data = account_info.data.borrow_mut();
-    let new_item_index = current_items_loaded;
 
-    require!(
-        jellybean_machine.items_loaded < MAX_ITEMS,
-        JellybeanError::TooManyItems
-    );
+    for (offset, item) in items.iter().enumerate() {
+        let new_item_index = current_items_loaded + offset;
 
-    jellybean_machine.items_loaded = jellybean_machine
-        .items_loaded
-        .checked_add(1)
-        .ok_or(JellybeanError::NumericalOverflowError)?;
+        jellybean_machine.items_loaded = jellybean_machine
+            .items_loaded
+            .checked_add(1)
+            .ok_or(JellybeanError::NumericalOverflowError)?;
 
-    jellybean_machine.supply_loaded = jellybean_machine
-        .supply_loaded
-        .checked_add(item.supply_loaded as u64)
-        .ok_or(JellybeanError::NumericalOverflowError)?;
+        jellybean_machine.supply_loaded = jellybean_machine
+            .supply_loaded
+            .checked_add(item.supply_loaded as u64)
+            .ok_or(JellybeanError::NumericalOverflowError)?;
 
-    let position = jellybean_machine.get_loaded_item_position(new_item_index);
-    msg!(
-        "space: {}, item_index: {}, item_position: {}",
-        new_space,
-        new_item_index,
-        position
-    );
-    let item_slice: &mut [u8] = &mut data[position..position + LOADED_ITEM_SIZE];
-    item_slice.copy_from_slice(&item.try_to_vec()?);
+        jellybean_machine.supply_redeemed = jellybean_machine
+            .supply_redeemed
+            .checked_add(item.supply_redeemed as u64)
+            .ok_or(JellybeanError::NumericalOverflowError)?;
+
+        let position = jellybean_machine.get_loaded_item_position(new_item_index);
+        let item_slice: &mut [u8] = &mut data[position..position + LOADED_ITEM_SIZE];
+        item_slice.copy_from_slice(&item.try_to_vec()?);
+
+        msg!(
+            "Added item: mint={}, item_index={}",
+            item.mint,
+            new_item_index
+        );
+    }
 
     msg!(
-        "Added item: mint={}, new items_loaded={}, new supply_loaded={}",
-        item.mint,
+        "space: {}, new items_loaded={}, new supply_loaded={}",
+        new_space,
         jellybean_machine.items_loaded,
         jellybean_machine.supply_loaded
     );
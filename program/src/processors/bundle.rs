@@ -0,0 +1,170 @@
+use crate::{
+    assert_is_ata, assert_keys_equal, BundleComponent, BundleComponentKind, JellybeanError,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::{create_idempotent, Create},
+    token::{transfer, Transfer},
+};
+use mpl_core::instructions::TransferV1CpiBuilder;
+
+/// Accounts to move a bundle component in or out of the authority pda.
+pub struct BundleComponentAccounts<'info> {
+    pub payer: AccountInfo<'info>,
+    pub authority_pda: AccountInfo<'info>,
+    pub asset: Option<AccountInfo<'info>>,
+    pub collection: Option<AccountInfo<'info>>,
+    pub mint: Option<AccountInfo<'info>>,
+    pub source_token_account: Option<AccountInfo<'info>>,
+    pub destination_token_account: Option<AccountInfo<'info>>,
+    pub mpl_core_program: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub associated_token_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+/// Escrows a component with the authority pda: the Core asset itself, or the tokens in
+/// the pda's associated token account.
+pub fn deposit_bundle_component<'info>(
+    kind: BundleComponentKind,
+    amount: u64,
+    owner: &AccountInfo<'info>,
+    accounts: &BundleComponentAccounts<'info>,
+) -> Result<BundleComponent> {
+    let address = match kind {
+        BundleComponentKind::CoreAsset => {
+            let asset = accounts
+                .asset
+                .as_ref()
+                .ok_or(JellybeanError::InvalidBundleComponent)?;
+
+            TransferV1CpiBuilder::new(&accounts.mpl_core_program)
+                .asset(asset)
+                .collection(accounts.collection.as_ref())
+                .payer(&accounts.payer)
+                .authority(Some(owner))
+                .new_owner(&accounts.authority_pda)
+                .system_program(Some(&accounts.system_program))
+                .invoke()?;
+
+            asset.key()
+        }
+        BundleComponentKind::Token => {
+            let (mint, source, destination) = token_accounts(accounts)?;
+            require!(amount > 0, JellybeanError::InvalidBundleComponent);
+
+            create_token_account(accounts, &accounts.authority_pda, destination, mint)?;
+
+            transfer(
+                CpiContext::new(
+                    accounts.token_program.clone(),
+                    Transfer {
+                        from: source.clone(),
+                        to: destination.clone(),
+                        authority: owner.clone(),
+                    },
+                ),
+                amount,
+            )?;
+
+            mint.key()
+        }
+    };
+
+    Ok(BundleComponent {
+        kind,
+        address,
+        amount: if kind == BundleComponentKind::CoreAsset {
+            1
+        } else {
+            amount
+        },
+        delivered: false,
+    })
+}
+
+/// Sends an escrowed component from the authority pda to the recipient wallet.
+pub fn deliver_bundle_component<'info>(
+    component: &BundleComponent,
+    recipient: &AccountInfo<'info>,
+    accounts: &BundleComponentAccounts<'info>,
+    auth_seeds: &[&[u8]],
+) -> Result<()> {
+    match component.kind {
+        BundleComponentKind::CoreAsset => {
+            let asset = accounts
+                .asset
+                .as_ref()
+                .ok_or(JellybeanError::InvalidBundleComponent)?;
+            assert_keys_equal(asset.key(), component.address, "Invalid asset")?;
+
+            TransferV1CpiBuilder::new(&accounts.mpl_core_program)
+                .asset(asset)
+                .collection(accounts.collection.as_ref())
+                .payer(&accounts.payer)
+                .authority(Some(&accounts.authority_pda))
+                .new_owner(recipient)
+                .system_program(Some(&accounts.system_program))
+                .invoke_signed(&[auth_seeds])?;
+        }
+        BundleComponentKind::Token => {
+            let (mint, source, destination) = token_accounts(accounts)?;
+            assert_keys_equal(mint.key(), component.address, "Invalid mint")?;
+            assert_is_ata(source, &accounts.authority_pda.key(), &mint.key())?;
+
+            create_token_account(accounts, recipient, destination, mint)?;
+
+            transfer(
+                CpiContext::new_with_signer(
+                    accounts.token_program.clone(),
+                    Transfer {
+                        from: source.clone(),
+                        to: destination.clone(),
+                        authority: accounts.authority_pda.clone(),
+                    },
+                    &[auth_seeds],
+                ),
+                component.amount,
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+fn token_accounts<'a, 'info>(
+    accounts: &'a BundleComponentAccounts<'info>,
+) -> Result<(
+    &'a AccountInfo<'info>,
+    &'a AccountInfo<'info>,
+    &'a AccountInfo<'info>,
+)> {
+    match (
+        &accounts.mint,
+        &accounts.source_token_account,
+        &accounts.destination_token_account,
+    ) {
+        (Some(mint), Some(source), Some(destination)) => Ok((mint, source, destination)),
+        _ => err!(JellybeanError::InvalidBundleComponent),
+    }
+}
+
+/// Creates the wallet's associated token account if it doesn't exist yet.
+fn create_token_account<'info>(
+    accounts: &BundleComponentAccounts<'info>,
+    wallet: &AccountInfo<'info>,
+    token_account: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+) -> Result<()> {
+    create_idempotent(CpiContext::new(
+        accounts.associated_token_program.clone(),
+        Create {
+            payer: accounts.payer.clone(),
+            associated_token: token_account.clone(),
+            authority: wallet.clone(),
+            mint: mint.clone(),
+            system_program: accounts.system_program.clone(),
+            token_program: accounts.token_program.clone(),
+        },
+    ))
+}
@@ -0,0 +1,216 @@
+use crate::{
+    processors::{
+        hide_core_asset, is_delegated_core_asset, load_print_template, provenance_attributes,
+        thaw_core_asset, write_provenance_attributes,
+    },
+    JellybeanError, JellybeanMachine, JellybeanState, LoadedItem, Prize, UnclaimedPrizes,
+};
+use anchor_lang::prelude::*;
+use mpl_core::{
+    instructions::{CreateV1CpiBuilder, TransferV1CpiBuilder},
+    types::{Attributes, Edition, Plugin, PluginAuthorityPair},
+    Collection,
+};
+
+/// Shrinks the buyer's unclaimed prizes account after a claim, closing it once it's empty
+/// and its item wins are no longer needed. Rent goes back to the buyer, or to the payer if
+/// the buyer account is closed.
+pub fn settle_unclaimed_prizes<'info>(
+    jellybean_machine: &JellybeanMachine,
+    unclaimed_prizes: &mut Account<'info, UnclaimedPrizes>,
+    buyer: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+) -> Result<()> {
+    // Close unclaimed_prize account back to the buyer if it's empty, item wins limit draws
+    // until the sale is cancelled since an ended sale can be reopened
+    // If buyer account is closed (0 lamports), refund to payer instead
+    if unclaimed_prizes.prizes.is_empty()
+        && (unclaimed_prizes.wins.is_empty()
+            || jellybean_machine.state == JellybeanState::Cancelled)
+    {
+        let refund_destination = if buyer.lamports() > 0 {
+            buyer.to_account_info()
+        } else {
+            payer.to_account_info()
+        };
+        unclaimed_prizes.close(refund_destination)?;
+    } else {
+        // Calculate new space needed and reallocate if smaller
+        let new_space = unclaimed_prizes.current_space();
+        let rent = Rent::get()?;
+        let new_rent_minimum = rent.minimum_balance(new_space);
+        let current_lamports = unclaimed_prizes.to_account_info().lamports();
+
+        // Reallocate the account
+        unclaimed_prizes
+            .to_account_info()
+            .realloc(new_space, false)?;
+
+        // Refund excess rent to buyer, or payer if buyer account is closed
+        let excess_lamports = current_lamports - new_rent_minimum;
+        if excess_lamports > 0 {
+            let refund_destination = if buyer.lamports() > 0 { buyer } else { payer };
+            **unclaimed_prizes
+                .to_account_info()
+                .try_borrow_mut_lamports()? -= excess_lamports;
+            **refund_destination.try_borrow_mut_lamports()? += excess_lamports;
+        }
+    }
+
+    Ok(())
+}
+
+/// Accounts a prize is delivered to its buyer with.
+pub struct DeliverAccounts<'a, 'info> {
+    pub payer: &'a AccountInfo<'info>,
+    pub buyer: &'a AccountInfo<'info>,
+    pub authority_pda: &'a AccountInfo<'info>,
+    pub mpl_core_program: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+/// Transfers a loaded Core asset to the buyer, thawing it first if it was loaded without
+/// custody, then writes its provenance and hides it for delayed reveal if configured.
+pub fn deliver_core_asset<'info>(
+    jellybean_machine: &Account<'info, JellybeanMachine>,
+    prize: &Prize,
+    asset: &AccountInfo<'info>,
+    collection: Option<&AccountInfo<'info>>,
+    accounts: &DeliverAccounts<'_, 'info>,
+    auth_seeds: &[&[u8]],
+) -> Result<()> {
+    // Assets loaded without custody are frozen in the seller's wallet
+    if is_delegated_core_asset(asset, accounts.authority_pda)? {
+        thaw_core_asset(
+            asset,
+            collection,
+            accounts.payer,
+            accounts.authority_pda,
+            accounts.mpl_core_program,
+            accounts.system_program,
+            auth_seeds,
+        )?;
+    }
+
+    TransferV1CpiBuilder::new(accounts.mpl_core_program)
+        .asset(asset)
+        .collection(collection)
+        .payer(accounts.payer)
+        .authority(Some(accounts.authority_pda))
+        .new_owner(accounts.buyer)
+        .system_program(Some(accounts.system_program))
+        .invoke_signed(&[auth_seeds])?;
+
+    if let Some(provenance_config) = &jellybean_machine.provenance_config {
+        write_provenance_attributes(
+            asset,
+            collection,
+            accounts.payer,
+            accounts.authority_pda,
+            accounts.mpl_core_program,
+            accounts.system_program,
+            auth_seeds,
+            provenance_config.authority.clone().into(),
+            provenance_attributes(&jellybean_machine.key(), prize, false),
+        )?;
+    }
+
+    if let Some(reveal_config) = &jellybean_machine.reveal_config {
+        hide_core_asset(
+            asset,
+            collection,
+            accounts.payer,
+            accounts.authority_pda,
+            accounts.mpl_core_program,
+            accounts.system_program,
+            auth_seeds,
+            &reveal_config.placeholder_uri,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Prints the edition of a loaded master edition to the buyer. The item's print escrow
+/// goes to the payer, who pays for the print account.
+#[allow(clippy::too_many_arguments)]
+pub fn print_core_edition<'info>(
+    jellybean_machine: &Account<'info, JellybeanMachine>,
+    loaded_item: &LoadedItem,
+    prize: &Prize,
+    collection_account: &AccountInfo<'info>,
+    print_asset: &AccountInfo<'info>,
+    print_template: Option<&AccountInfo<'info>>,
+    accounts: &DeliverAccounts<'_, 'info>,
+    auth_seeds: &[&[u8]],
+) -> Result<()> {
+    let collection = Box::<Collection>::try_from(collection_account)?;
+    let plugin = collection
+        .plugin_list
+        .master_edition
+        .ok_or(JellybeanError::MissingMasterEdition)?;
+
+    let edition_number = prize.edition_number;
+    let name = plugin.master_edition.name.unwrap_or(collection.base.name);
+    let uri = plugin.master_edition.uri.unwrap_or(collection.base.uri);
+
+    let (name, uri) = match load_print_template(
+        print_template,
+        &jellybean_machine.key(),
+        &collection_account.key(),
+    )? {
+        Some(print_template) => print_template.expand(&name, &uri, edition_number),
+        None => (name, uri),
+    };
+
+    // Prints of a machine in delayed reveal mode start with the placeholder uri
+    let uri = match &jellybean_machine.reveal_config {
+        Some(reveal_config) => reveal_config.placeholder_uri.clone(),
+        None => uri,
+    };
+
+    // The escrow was sized for the longest possible name and uri when the template was set
+    require!(
+        LoadedItem::print_escrow_amount(name.len(), uri.len())? <= loaded_item.escrow_amount,
+        JellybeanError::InsufficientPrintEscrow
+    );
+
+    msg!("printing edition number: {}", edition_number);
+
+    // Send any escrow amount to the payer first
+    if loaded_item.escrow_amount > 0 {
+        let mut authority_lamports = accounts.authority_pda.try_borrow_mut_lamports()?;
+        **authority_lamports -= loaded_item.escrow_amount;
+        let mut payer_lamports = accounts.payer.try_borrow_mut_lamports()?;
+        **payer_lamports += loaded_item.escrow_amount;
+    }
+
+    let mut plugins = vec![PluginAuthorityPair {
+        authority: None,
+        plugin: Plugin::Edition(Edition {
+            number: edition_number,
+        }),
+    }];
+    if let Some(provenance_config) = &jellybean_machine.provenance_config {
+        plugins.push(PluginAuthorityPair {
+            authority: Some(provenance_config.authority.clone().into()),
+            plugin: Plugin::Attributes(Attributes {
+                attribute_list: provenance_attributes(&jellybean_machine.key(), prize, true),
+            }),
+        });
+    }
+
+    CreateV1CpiBuilder::new(accounts.mpl_core_program)
+        .asset(print_asset)
+        .collection(Some(collection_account))
+        .payer(accounts.payer)
+        .name(name)
+        .uri(uri)
+        .owner(Some(accounts.buyer))
+        .plugins(plugins)
+        .system_program(accounts.system_program)
+        .authority(Some(accounts.authority_pda))
+        .invoke_signed(&[auth_seeds])?;
+
+    Ok(())
+}
@@ -0,0 +1,187 @@
+use anchor_lang::prelude::*;
+use mpl_core::{
+    instructions::{
+        AddPluginV1CpiBuilder, ApprovePluginAuthorityV1CpiBuilder,
+        RevokePluginAuthorityV1CpiBuilder, UpdatePluginV1CpiBuilder,
+    },
+    types::{FreezeDelegate, Plugin, PluginAuthority, PluginType, TransferDelegate},
+    Asset, BaseAuthority,
+};
+
+/// Returns true if the asset was loaded without transferring it, i.e. the authority pda
+/// holds its freeze and transfer delegates while the seller keeps it.
+pub fn is_delegated_core_asset(
+    asset: &AccountInfo<'_>,
+    authority_pda: &AccountInfo<'_>,
+) -> Result<bool> {
+    let asset = Box::<Asset>::try_from(asset)?;
+    let is_authority_pda =
+        |authority: &BaseAuthority| authority.address == Some(authority_pda.key());
+
+    Ok(asset.base.owner != authority_pda.key()
+        && asset
+            .plugin_list
+            .freeze_delegate
+            .as_ref()
+            .is_some_and(|plugin| is_authority_pda(&plugin.base.authority))
+        && asset
+            .plugin_list
+            .transfer_delegate
+            .as_ref()
+            .is_some_and(|plugin| is_authority_pda(&plugin.base.authority)))
+}
+
+/// Returns true if the authority pda can still move the asset to a buyer: it holds the
+/// asset, or holds its transfer delegate and any freeze delegate keeping it frozen.
+pub fn can_deliver_core_asset(
+    asset: &AccountInfo<'_>,
+    authority_pda: &AccountInfo<'_>,
+) -> Result<bool> {
+    if asset.owner != &mpl_core::ID || asset.data_is_empty() {
+        return Ok(false);
+    }
+    let asset = match Box::<Asset>::try_from(asset) {
+        Ok(asset) => asset,
+        Err(_) => return Ok(false),
+    };
+
+    if asset.base.owner == authority_pda.key() {
+        return Ok(true);
+    }
+
+    let is_authority_pda =
+        |authority: &BaseAuthority| authority.address == Some(authority_pda.key());
+    let plugin_list = &asset.plugin_list;
+    Ok(plugin_list
+        .transfer_delegate
+        .as_ref()
+        .is_some_and(|plugin| is_authority_pda(&plugin.base.authority))
+        && !plugin_list.freeze_delegate.as_ref().is_some_and(|plugin| {
+            plugin.freeze_delegate.frozen && !is_authority_pda(&plugin.base.authority)
+        }))
+}
+
+/// Freezes the asset in the owner's wallet and delegates its freeze and transfer
+/// authorities to the authority pda.
+pub fn delegate_core_asset<'info>(
+    asset: &AccountInfo<'info>,
+    collection: Option<&AccountInfo<'info>>,
+    owner: &AccountInfo<'info>,
+    authority_pda: &AccountInfo<'info>,
+    mpl_core_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    auth_seeds: &[&[u8]],
+) -> Result<()> {
+    let plugin_list = Box::<Asset>::try_from(asset)?.plugin_list;
+    let delegate = PluginAuthority::Address {
+        address: authority_pda.key(),
+    };
+
+    // Owner managed plugins may already exist from a previous delegation
+    if plugin_list.freeze_delegate.is_some() {
+        ApprovePluginAuthorityV1CpiBuilder::new(mpl_core_program)
+            .asset(asset)
+            .collection(collection)
+            .payer(owner)
+            .plugin_type(PluginType::FreezeDelegate)
+            .new_authority(delegate.clone())
+            .system_program(system_program)
+            .invoke()?;
+
+        UpdatePluginV1CpiBuilder::new(mpl_core_program)
+            .asset(asset)
+            .collection(collection)
+            .payer(owner)
+            .authority(Some(authority_pda))
+            .plugin(Plugin::FreezeDelegate(FreezeDelegate { frozen: true }))
+            .system_program(system_program)
+            .invoke_signed(&[auth_seeds])?;
+    } else {
+        AddPluginV1CpiBuilder::new(mpl_core_program)
+            .asset(asset)
+            .collection(collection)
+            .payer(owner)
+            .plugin(Plugin::FreezeDelegate(FreezeDelegate { frozen: true }))
+            .init_authority(delegate.clone())
+            .system_program(system_program)
+            .invoke()?;
+    }
+
+    if plugin_list.transfer_delegate.is_some() {
+        ApprovePluginAuthorityV1CpiBuilder::new(mpl_core_program)
+            .asset(asset)
+            .collection(collection)
+            .payer(owner)
+            .plugin_type(PluginType::TransferDelegate)
+            .new_authority(delegate)
+            .system_program(system_program)
+            .invoke()?;
+    } else {
+        AddPluginV1CpiBuilder::new(mpl_core_program)
+            .asset(asset)
+            .collection(collection)
+            .payer(owner)
+            .plugin(Plugin::TransferDelegate(TransferDelegate {}))
+            .init_authority(delegate)
+            .system_program(system_program)
+            .invoke()?;
+    }
+
+    Ok(())
+}
+
+/// Thaws an asset frozen by the authority pda so it can be transferred.
+pub fn thaw_core_asset<'info>(
+    asset: &AccountInfo<'info>,
+    collection: Option<&AccountInfo<'info>>,
+    payer: &AccountInfo<'info>,
+    authority_pda: &AccountInfo<'info>,
+    mpl_core_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    auth_seeds: &[&[u8]],
+) -> Result<()> {
+    UpdatePluginV1CpiBuilder::new(mpl_core_program)
+        .asset(asset)
+        .collection(collection)
+        .payer(payer)
+        .authority(Some(authority_pda))
+        .plugin(Plugin::FreezeDelegate(FreezeDelegate { frozen: false }))
+        .system_program(system_program)
+        .invoke_signed(&[auth_seeds])?;
+
+    Ok(())
+}
+
+/// Thaws the asset and hands its freeze and transfer authorities back to the owner.
+pub fn revoke_core_asset<'info>(
+    asset: &AccountInfo<'info>,
+    collection: Option<&AccountInfo<'info>>,
+    payer: &AccountInfo<'info>,
+    authority_pda: &AccountInfo<'info>,
+    mpl_core_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    auth_seeds: &[&[u8]],
+) -> Result<()> {
+    thaw_core_asset(
+        asset,
+        collection,
+        payer,
+        authority_pda,
+        mpl_core_program,
+        system_program,
+        auth_seeds,
+    )?;
+
+    for plugin_type in [PluginType::FreezeDelegate, PluginType::TransferDelegate] {
+        RevokePluginAuthorityV1CpiBuilder::new(mpl_core_program)
+            .asset(asset)
+            .collection(collection)
+            .payer(payer)
+            .authority(Some(authority_pda))
+            .plugin_type(plugin_type)
+            .system_program(system_program)
+            .invoke_signed(&[auth_seeds])?;
+    }
+
+    Ok(())
+}
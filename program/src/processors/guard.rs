@@ -0,0 +1,489 @@
+use crate::{
+    assert_is_ata, assert_keys_equal, assert_owned_by, get_bps_of, AllowlistProof, BuyerRecord,
+    DrawGate, DrawGateAction, DrawGateKind, GuardPhases, JellybeanError, JellybeanMachine,
+    UsedGateAsset, MAX_GUARD_PHASES,
+};
+use anchor_lang::{
+    prelude::*,
+    system_program::{
+        allocate, assign, create_account, transfer, Allocate, Assign, CreateAccount, Transfer,
+    },
+};
+use anchor_spl::token::{burn, Burn};
+use mpl_core::{instructions::BurnV1CpiBuilder, types::UpdateAuthority, Asset};
+use std::slice::Iter;
+
+/// Accounts the guards of a draw are checked and charged with.
+pub struct GuardAccounts<'a, 'info> {
+    pub buyer: &'a AccountInfo<'info>,
+    pub payer: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    /// Guard phases account if the machine has phases, then in order the allowlist proof and
+    /// gate accounts if the active phase requires them, the buyer record if a per-wallet
+    /// limit applies, and the machine fee accounts if the phase has a price.
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+}
+
+/// Side effects of a guard, applied once every rule of the draw has passed.
+enum GuardEffect<'a, 'info> {
+    BurnTokens {
+        token_account: &'a AccountInfo<'info>,
+        mint: &'a AccountInfo<'info>,
+        token_program: &'a AccountInfo<'info>,
+        amount: u64,
+    },
+    BurnAsset {
+        asset: &'a AccountInfo<'info>,
+        collection: &'a AccountInfo<'info>,
+        mpl_core_program: &'a AccountInfo<'info>,
+    },
+    MarkAssetUsed {
+        asset: &'a AccountInfo<'info>,
+        used_gate_asset: &'a AccountInfo<'info>,
+        bump: u8,
+    },
+    RecordDraw {
+        buyer_record: &'a AccountInfo<'info>,
+        record: Box<BuyerRecord>,
+        bump: Option<u8>, // Set when the record has to be created
+    },
+    Pay {
+        fee_account: &'a AccountInfo<'info>,
+        amount: u64,
+    },
+}
+
+/// Applies the guards of a draw: the rules of the active guard phase (allowlist, gate,
+/// per-wallet limit and price), and the machine's lifetime per-wallet limit. Public draws
+/// without the mint authority must be paid.
+/// Every rule is checked before anything is burned, recorded or paid, so a draw that
+/// breaks one can be taxed without side effects. Returns the price paid.
+pub fn apply_guards(
+    jellybean_machine: &Account<'_, JellybeanMachine>,
+    accounts: GuardAccounts<'_, '_>,
+    now: i64,
+    require_price: bool,
+) -> Result<u64> {
+    let (effects, price) = check_guards(jellybean_machine, &accounts, now, require_price)?;
+
+    for effect in effects {
+        apply_guard_effect(&jellybean_machine.key(), effect, &accounts)?;
+    }
+
+    Ok(price)
+}
+
+fn check_guards<'a, 'info>(
+    jellybean_machine: &Account<'_, JellybeanMachine>,
+    accounts: &GuardAccounts<'a, 'info>,
+    now: i64,
+    require_price: bool,
+) -> Result<(Vec<GuardEffect<'a, 'info>>, u64)> {
+    let remaining_accounts = &mut accounts.remaining_accounts.iter();
+    let mut effects = vec![];
+
+    let active_phase = if jellybean_machine.has_guard_phases {
+        let guard_phases_info = next_account(remaining_accounts)?;
+        assert_keys_equal(
+            GuardPhases::find_address(&jellybean_machine.key()),
+            *guard_phases_info.key,
+            "Invalid guard phases",
+        )?;
+        assert_owned_by(guard_phases_info, &crate::ID)?;
+        let guard_phases = GuardPhases::try_deserialize(&mut &guard_phases_info.data.borrow()[..])?;
+
+        let (phase_index, phase) = guard_phases
+            .get_active_phase(now)
+            .ok_or(JellybeanError::NoActivePhase)?;
+        Some((phase_index, phase.clone()))
+    } else {
+        None
+    };
+
+    if let Some((_, phase)) = &active_phase {
+        // Gates apply to the buyer, so they can't be satisfied with someone else's address
+        if phase.allowlist_root.is_some() || phase.gate.is_some() {
+            require!(
+                accounts.buyer.is_signer,
+                JellybeanError::MissingBuyerSignature
+            );
+        }
+
+        if let Some(merkle_root) = phase.allowlist_root {
+            let allowlist_proof_info = next_account(remaining_accounts)?;
+            assert_keys_equal(
+                AllowlistProof::find_address(
+                    &jellybean_machine.key(),
+                    &merkle_root,
+                    accounts.buyer.key,
+                ),
+                *allowlist_proof_info.key,
+                "Invalid allowlist proof",
+            )?;
+            require!(
+                allowlist_proof_info.owner == &crate::ID && !allowlist_proof_info.data_is_empty(),
+                JellybeanError::NotAllowlisted
+            );
+        }
+
+        if let Some(gate) = &phase.gate {
+            if let Some(effect) =
+                check_gate(&jellybean_machine.key(), gate, accounts, remaining_accounts)?
+            {
+                effects.push(effect);
+            }
+        }
+    }
+
+    let phase_limit = active_phase
+        .as_ref()
+        .and_then(|(phase_index, phase)| Some((*phase_index, phase.max_draws_per_wallet?)));
+
+    if jellybean_machine.max_draws_per_wallet.is_some() || phase_limit.is_some() {
+        // Limits are counted for the buyer, so a payer can't spend another wallet's draws or
+        // spread its own over many buyer keys
+        require!(
+            accounts.buyer.is_signer,
+            JellybeanError::MissingBuyerSignature
+        );
+
+        let buyer_record_info = next_account(remaining_accounts)?;
+        let (address, bump) =
+            BuyerRecord::find_program_address(&jellybean_machine.key(), accounts.buyer.key);
+        assert_keys_equal(address, *buyer_record_info.key, "Invalid buyer record")?;
+
+        // Buyer records are created by the first draw that needs them, as the draw accounts
+        // are fixed by the programs wrapping the machine
+        let (mut record, bump) = if buyer_record_info.data_is_empty() {
+            let record = BuyerRecord {
+                version: BuyerRecord::CURRENT_VERSION,
+                jellybean_machine: jellybean_machine.key(),
+                buyer: *accounts.buyer.key,
+                draws: 0,
+                phase_draws: [0; MAX_GUARD_PHASES],
+            };
+            (Box::new(record), Some(bump))
+        } else {
+            assert_owned_by(buyer_record_info, &crate::ID)?;
+            let record = BuyerRecord::try_deserialize(&mut &buyer_record_info.data.borrow()[..])?;
+            (Box::new(record), None)
+        };
+
+        if let Some(max_draws_per_wallet) = jellybean_machine.max_draws_per_wallet {
+            require!(
+                record.draws < max_draws_per_wallet as u32,
+                JellybeanError::WalletLimitReached
+            );
+        }
+        record.draws += 1;
+
+        if let Some((phase_index, max_draws_per_wallet)) = phase_limit {
+            let phase_draws = &mut record.phase_draws[phase_index];
+            require!(
+                *phase_draws < max_draws_per_wallet,
+                JellybeanError::WalletLimitReached
+            );
+            *phase_draws += 1;
+        }
+
+        effects.push(GuardEffect::RecordDraw {
+            buyer_record: buyer_record_info,
+            record,
+            bump,
+        });
+    }
+
+    let price = active_phase.map_or(0, |(_, phase)| phase.price);
+    require!(
+        !require_price || price > 0,
+        JellybeanError::PublicDrawWithoutPrice
+    );
+    if price > 0 {
+        for fee_account in jellybean_machine.fee_accounts.iter() {
+            let fee_account_info = next_account(remaining_accounts)?;
+            assert_keys_equal(
+                fee_account.address,
+                *fee_account_info.key,
+                "Invalid fee account",
+            )?;
+
+            let amount = get_bps_of(price, fee_account.basis_points)?;
+            if amount > 0 {
+                effects.push(GuardEffect::Pay {
+                    fee_account: fee_account_info,
+                    amount,
+                });
+            }
+        }
+    }
+
+    Ok((effects, price))
+}
+
+/// Checks that the buyer holds the gate tokens or asset, and returns the burn or mark as used
+/// to apply. Token gates take the buyer's associated token account, then the mint and the
+/// token program to burn. Core gates take the asset, then the collection and the MPL Core
+/// program to burn, or the used gate asset record to mark it as used.
+fn check_gate<'a, 'info>(
+    jellybean_machine: &Pubkey,
+    gate: &DrawGate,
+    accounts: &GuardAccounts<'a, 'info>,
+    remaining_accounts: &mut Iter<'a, AccountInfo<'info>>,
+) -> Result<Option<GuardEffect<'a, 'info>>> {
+    match &gate.kind {
+        DrawGateKind::Token { mint, amount } => {
+            let token_account_info = next_account(remaining_accounts)?;
+            let token_account = assert_is_ata(token_account_info, accounts.buyer.key, mint)?;
+            require!(
+                token_account.amount >= *amount,
+                JellybeanError::MissingGateToken
+            );
+
+            if gate.action != DrawGateAction::Burn {
+                return Ok(None);
+            }
+
+            let mint_info = next_account(remaining_accounts)?;
+            assert_keys_equal(*mint, *mint_info.key, "Invalid gate mint")?;
+            let token_program = next_account(remaining_accounts)?;
+            assert_keys_equal(
+                anchor_spl::token::ID,
+                *token_program.key,
+                "Invalid token program",
+            )?;
+
+            Ok(Some(GuardEffect::BurnTokens {
+                token_account: token_account_info,
+                mint: mint_info,
+                token_program,
+                amount: *amount,
+            }))
+        }
+        DrawGateKind::CoreCollection { collection } => {
+            let asset_info = next_account(remaining_accounts)?;
+            assert_owned_by(asset_info, &mpl_core::ID)?;
+            let asset = Box::<Asset>::try_from(asset_info)?;
+            require!(
+                asset.base.owner == *accounts.buyer.key
+                    && asset.base.update_authority == UpdateAuthority::Collection(*collection),
+                JellybeanError::InvalidGateAsset
+            );
+
+            match gate.action {
+                DrawGateAction::Hold => Ok(None),
+                DrawGateAction::Burn => {
+                    let collection_info = next_account(remaining_accounts)?;
+                    assert_keys_equal(
+                        *collection,
+                        *collection_info.key,
+                        "Invalid gate collection",
+                    )?;
+                    let mpl_core_program = next_account(remaining_accounts)?;
+                    assert_keys_equal(
+                        mpl_core::ID,
+                        *mpl_core_program.key,
+                        "Invalid MPL Core program",
+                    )?;
+
+                    Ok(Some(GuardEffect::BurnAsset {
+                        asset: asset_info,
+                        collection: collection_info,
+                        mpl_core_program,
+                    }))
+                }
+                DrawGateAction::MarkUsed => {
+                    let used_gate_asset_info = next_account(remaining_accounts)?;
+                    let (address, bump) =
+                        UsedGateAsset::find_program_address(jellybean_machine, asset_info.key);
+                    assert_keys_equal(
+                        address,
+                        *used_gate_asset_info.key,
+                        "Invalid used gate asset",
+                    )?;
+                    require!(
+                        used_gate_asset_info.data_is_empty(),
+                        JellybeanError::GateAssetUsed
+                    );
+
+                    Ok(Some(GuardEffect::MarkAssetUsed {
+                        asset: asset_info,
+                        used_gate_asset: used_gate_asset_info,
+                        bump,
+                    }))
+                }
+            }
+        }
+    }
+}
+
+fn apply_guard_effect<'info>(
+    jellybean_machine: &Pubkey,
+    effect: GuardEffect<'_, 'info>,
+    accounts: &GuardAccounts<'_, 'info>,
+) -> Result<()> {
+    match effect {
+        GuardEffect::BurnTokens {
+            token_account,
+            mint,
+            token_program,
+            amount,
+        } => burn(
+            CpiContext::new(
+                token_program.clone(),
+                Burn {
+                    mint: mint.clone(),
+                    from: token_account.clone(),
+                    authority: accounts.buyer.clone(),
+                },
+            ),
+            amount,
+        ),
+        GuardEffect::BurnAsset {
+            asset,
+            collection,
+            mpl_core_program,
+        } => {
+            BurnV1CpiBuilder::new(mpl_core_program)
+                .asset(asset)
+                .collection(Some(collection))
+                .payer(accounts.payer)
+                .authority(Some(accounts.buyer))
+                .system_program(Some(accounts.system_program))
+                .invoke()?;
+            Ok(())
+        }
+        GuardEffect::MarkAssetUsed {
+            asset,
+            used_gate_asset,
+            bump,
+        } => {
+            create_pda_account(
+                used_gate_asset,
+                UsedGateAsset::SIZE,
+                &[
+                    UsedGateAsset::SEED_PREFIX.as_bytes(),
+                    jellybean_machine.as_ref(),
+                    asset.key.as_ref(),
+                    &[bump],
+                ],
+                accounts.payer,
+                accounts.system_program,
+            )?;
+
+            UsedGateAsset {
+                jellybean_machine: *jellybean_machine,
+                asset: *asset.key,
+                buyer: *accounts.buyer.key,
+            }
+            .try_serialize(&mut &mut used_gate_asset.data.borrow_mut()[..])
+        }
+        GuardEffect::RecordDraw {
+            buyer_record,
+            record,
+            bump,
+        } => {
+            if let Some(bump) = bump {
+                create_pda_account(
+                    buyer_record,
+                    BuyerRecord::SIZE,
+                    &[
+                        BuyerRecord::SEED_PREFIX.as_bytes(),
+                        jellybean_machine.as_ref(),
+                        accounts.buyer.key.as_ref(),
+                        &[bump],
+                    ],
+                    accounts.payer,
+                    accounts.system_program,
+                )?;
+            }
+
+            record.try_serialize(&mut &mut buyer_record.data.borrow_mut()[..])
+        }
+        GuardEffect::Pay {
+            fee_account,
+            amount,
+        } => transfer(
+            CpiContext::new(
+                accounts.system_program.clone(),
+                Transfer {
+                    from: accounts.payer.clone(),
+                    to: fee_account.clone(),
+                },
+            ),
+            amount,
+        ),
+    }
+}
+
+fn next_account<'a, 'info>(
+    remaining_accounts: &mut Iter<'a, AccountInfo<'info>>,
+) -> Result<&'a AccountInfo<'info>> {
+    remaining_accounts
+        .next()
+        .ok_or(error!(JellybeanError::MissingGuardAccount))
+}
+
+/// Create a program account at a PDA the way Anchor's `init` does, so lamports sent to the
+/// address beforehand can't block its creation.
+fn create_pda_account<'info>(
+    account: &AccountInfo<'info>,
+    space: usize,
+    seeds: &[&[u8]],
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let rent_exempt_lamports = Rent::get()?.minimum_balance(space);
+    let current_lamports = account.lamports();
+
+    if current_lamports == 0 {
+        return create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                CreateAccount {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+                &[seeds],
+            ),
+            rent_exempt_lamports,
+            space as u64,
+            &crate::ID,
+        );
+    }
+
+    let shortfall = rent_exempt_lamports.saturating_sub(current_lamports);
+    if shortfall > 0 {
+        transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+
+    allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Allocate {
+                account_to_allocate: account.clone(),
+            },
+            &[seeds],
+        ),
+        space as u64,
+    )?;
+
+    assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Assign {
+                account_to_assign: account.clone(),
+            },
+            &[seeds],
+        ),
+        &crate::ID,
+    )
+}
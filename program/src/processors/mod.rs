@@ -1,5 +1,19 @@
 pub mod add_item;
+pub mod bundle;
+pub mod claim_item;
+pub mod delegate_item;
+pub mod guard;
+pub mod print_template;
+pub mod provenance;
 pub mod remove_item;
+pub mod reveal;
 
 pub use add_item::*;
+pub use bundle::*;
+pub use claim_item::*;
+pub use delegate_item::*;
+pub use guard::*;
+pub use print_template::*;
+pub use provenance::*;
 pub use remove_item::*;
+pub use reveal::*;
@@ -0,0 +1,56 @@
+use crate::{assert_keys_equal, JellybeanError, PrintTemplate};
+use anchor_lang::prelude::*;
+
+/// Loads the print template of a master edition. The template account must always be
+/// provided so a claim cannot skip it; an empty account means the item has no template.
+pub fn load_print_template(
+    print_template: Option<&AccountInfo<'_>>,
+    jellybean_machine: &Pubkey,
+    collection: &Pubkey,
+) -> Result<Option<PrintTemplate>> {
+    let print_template = print_template.ok_or(JellybeanError::MissingPrintTemplate)?;
+    assert_keys_equal(
+        print_template.key(),
+        PrintTemplate::find_address(jellybean_machine, collection),
+        "Invalid print template",
+    )?;
+
+    if print_template.data_is_empty() {
+        return Ok(None);
+    }
+
+    let data = print_template.data.borrow();
+    Ok(Some(PrintTemplate::try_deserialize(&mut data.as_ref())?))
+}
+
+/// Closes the print template of a master edition being removed, so it doesn't apply if the
+/// master edition is loaded again.
+pub fn close_print_template<'info>(
+    print_template: Option<&AccountInfo<'info>>,
+    jellybean_machine: &Pubkey,
+    collection: &Pubkey,
+    destination: &AccountInfo<'info>,
+) -> Result<()> {
+    let print_template = print_template.ok_or(JellybeanError::MissingPrintTemplate)?;
+    assert_keys_equal(
+        print_template.key(),
+        PrintTemplate::find_address(jellybean_machine, collection),
+        "Invalid print template",
+    )?;
+
+    if print_template.data_is_empty() {
+        return Ok(());
+    }
+
+    let mut destination_lamports = destination.try_borrow_mut_lamports()?;
+    **destination_lamports = destination_lamports
+        .checked_add(print_template.lamports())
+        .ok_or(JellybeanError::NumericalOverflowError)?;
+    drop(destination_lamports);
+    **print_template.try_borrow_mut_lamports()? = 0;
+
+    print_template.assign(&System::id());
+    print_template.realloc(0, false)?;
+
+    Ok(())
+}
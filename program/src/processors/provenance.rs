@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use mpl_core::{
+    instructions::{AddPluginV1CpiBuilder, UpdatePluginV1CpiBuilder},
+    types::{Attribute, Attributes, Plugin, PluginAuthority},
+    Asset, AuthorityType,
+};
+
+use crate::{processors::is_machine_update_authority, Prize, ProvenanceAuthority};
+
+impl From<ProvenanceAuthority> for PluginAuthority {
+    fn from(authority: ProvenanceAuthority) -> Self {
+        match authority {
+            ProvenanceAuthority::None => PluginAuthority::None,
+            ProvenanceAuthority::UpdateAuthority => PluginAuthority::UpdateAuthority,
+            ProvenanceAuthority::Address { address } => PluginAuthority::Address { address },
+        }
+    }
+}
+
+/// Attributes recording which machine the prize was drawn from, when, and its tier: a print
+/// of a master edition or a one of one asset.
+pub fn provenance_attributes(
+    jellybean_machine: &Pubkey,
+    prize: &Prize,
+    is_print: bool,
+) -> Vec<Attribute> {
+    let mut attributes = vec![Attribute {
+        key: "jellybean_machine".to_string(),
+        value: jellybean_machine.to_string(),
+    }];
+
+    // Prizes migrated from older versions have no draw slot
+    if prize.draw_slot > 0 {
+        attributes.push(Attribute {
+            key: "draw_slot".to_string(),
+            value: prize.draw_slot.to_string(),
+        });
+    }
+
+    if is_print {
+        attributes.push(Attribute {
+            key: "edition".to_string(),
+            value: prize.edition_number.to_string(),
+        });
+    }
+
+    attributes.push(Attribute {
+        key: "tier".to_string(),
+        value: if is_print { "edition" } else { "one_of_one" }.to_string(),
+    });
+
+    attributes
+}
+
+/// Adds the provenance attributes to a delivered asset, or merges them into its existing
+/// Attributes plugin. Loading requires the authority pda to be able to update the asset, so
+/// this is only skipped if the seller revoked that afterwards or the existing Attributes
+/// plugin has another authority.
+#[allow(clippy::too_many_arguments)]
+pub fn write_provenance_attributes<'info>(
+    asset: &AccountInfo<'info>,
+    collection: Option<&AccountInfo<'info>>,
+    payer: &AccountInfo<'info>,
+    authority_pda: &AccountInfo<'info>,
+    mpl_core_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    auth_seeds: &[&[u8]],
+    authority: PluginAuthority,
+    attributes: Vec<Attribute>,
+) -> Result<()> {
+    let asset_data = Box::<Asset>::try_from(asset)?;
+    let is_update_authority = is_machine_update_authority(&asset_data, collection, authority_pda)?;
+
+    match asset_data.plugin_list.attributes {
+        Some(existing) => {
+            let plugin_authority = existing.base.authority;
+            let can_update = match plugin_authority.authority_type {
+                AuthorityType::UpdateAuthority => is_update_authority,
+                AuthorityType::Address => plugin_authority.address == Some(authority_pda.key()),
+                _ => false,
+            };
+            if !can_update {
+                msg!("Skipping provenance, the asset attributes cannot be updated");
+                return Ok(());
+            }
+
+            let mut attribute_list = existing.attributes.attribute_list;
+            attribute_list.retain(|attribute| !attributes.iter().any(|a| a.key == attribute.key));
+            attribute_list.extend(attributes);
+
+            UpdatePluginV1CpiBuilder::new(mpl_core_program)
+                .asset(asset)
+                .collection(collection)
+                .payer(payer)
+                .authority(Some(authority_pda))
+                .plugin(Plugin::Attributes(Attributes { attribute_list }))
+                .system_program(system_program)
+                .invoke_signed(&[auth_seeds])?;
+        }
+        None => {
+            if !is_update_authority {
+                msg!("Skipping provenance, the asset update authority is not the machine");
+                return Ok(());
+            }
+
+            AddPluginV1CpiBuilder::new(mpl_core_program)
+                .asset(asset)
+                .collection(collection)
+                .payer(payer)
+                .authority(Some(authority_pda))
+                .plugin(Plugin::Attributes(Attributes {
+                    attribute_list: attributes,
+                }))
+                .init_authority(authority)
+                .system_program(system_program)
+                .invoke_signed(&[auth_seeds])?;
+        }
+    }
+
+    Ok(())
+}
@@ -1,64 +1,303 @@
-use crate::{JellybeanError, JellybeanMachine, LOADED_ITEM_SIZE};
+use crate::{
+    assert_keys_equal, assert_owned_by,
+    processors::{
+        close_print_template, delegate_collection_update, is_delegated_core_asset,
+        revoke_core_asset,
+    },
+    Bundle, JellybeanError, JellybeanMachine, LoadedItem, LOADED_ITEM_SIZE,
+    LOADED_ITEM_SUPPLY_CLAIMED_OFFSET, LOADED_ITEM_SUPPLY_LOADED_OFFSET,
+    LOADED_ITEM_SUPPLY_REDEMED_OFFSET,
+};
 use anchor_lang::prelude::*;
 use arrayref::array_ref;
+use mpl_core::instructions::{TransferV1CpiBuilder, UpdateCollectionV1CpiBuilder};
 
-pub fn remove_multiple_items_span<'info>(
-    jellybean_machine: &mut Account<'info, JellybeanMachine>,
-    start_index: u8,
-    end_index: u8,
+/// Returns a loaded item to the authority: the asset itself if it hasn't been redeemed,
+/// or the update authority of the master edition collection, closing its print template.
+/// With `keep_update_delegate`, the authority pda stays an update delegate of the master
+/// edition so its prints can still be revealed.
+/// Bundles are unlocked, and tombstoned slots need no accounts.
+#[allow(clippy::too_many_arguments)]
+pub fn return_core_item<'info>(
+    jellybean_machine: &Pubkey,
+    loaded_item: &LoadedItem,
+    keep_update_delegate: bool,
+    asset: Option<&AccountInfo<'info>>,
+    collection: Option<&AccountInfo<'info>>,
+    print_template: Option<&AccountInfo<'info>>,
     authority: &AccountInfo<'info>,
+    authority_pda: &AccountInfo<'info>,
+    mpl_core_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    auth_seeds: &[&[u8]],
+) -> Result<()> {
+    // Slots tombstoned by an earlier removal have nothing left to return
+    if loaded_item.mint == Pubkey::default() {
+        return Ok(());
+    }
+
+    require!(
+        loaded_item.supply_claimed == loaded_item.supply_redeemed,
+        JellybeanError::ItemNotFullyClaimed
+    );
+
+    if let Some(asset) = asset.filter(|asset| asset.owner == &crate::ID) {
+        return return_bundle_item(jellybean_machine, loaded_item, asset);
+    }
+
+    if let Some(asset) = asset {
+        assert_keys_equal(loaded_item.mint, *asset.key, "Invalid asset")?;
+
+        // Only need to return the asset if it hasn't been redeemed
+        if loaded_item.supply_redeemed == 0 && is_delegated_core_asset(asset, authority_pda)? {
+            // Thaw and revoke the delegates from the seller's asset
+            revoke_core_asset(
+                asset,
+                collection,
+                authority,
+                authority_pda,
+                mpl_core_program,
+                system_program,
+                auth_seeds,
+            )?;
+        } else if loaded_item.supply_redeemed == 0 {
+            // Transfer the core asset back to the authority
+            TransferV1CpiBuilder::new(mpl_core_program)
+                .asset(asset)
+                .collection(collection)
+                .payer(authority)
+                .authority(Some(authority_pda))
+                .new_owner(authority)
+                .system_program(Some(system_program))
+                .invoke_signed(&[auth_seeds])?;
+        }
+    } else if let Some(collection) = collection {
+        assert_keys_equal(loaded_item.mint, *collection.key, "Invalid collection")?;
+
+        if keep_update_delegate {
+            delegate_collection_update(
+                collection,
+                authority,
+                authority_pda,
+                mpl_core_program,
+                system_program,
+                auth_seeds,
+            )?;
+        }
+
+        // Return the master edition update authority to the authority
+        UpdateCollectionV1CpiBuilder::new(mpl_core_program)
+            .collection(collection)
+            .payer(authority)
+            .authority(Some(authority_pda))
+            .new_update_authority(Some(authority))
+            .system_program(system_program)
+            .invoke_signed(&[auth_seeds])?;
+
+        close_print_template(print_template, jellybean_machine, collection.key, authority)?;
+    } else {
+        return err!(JellybeanError::InvalidAsset);
+    }
+
+    Ok(())
+}
+
+/// Returns the items at the given indices, each with three remaining accounts laid out
+/// like the optional accounts of remove_core_item: the asset or bundle, the collection and
+/// the print template. Accounts an item doesn't use are set to this program's id.
+#[allow(clippy::too_many_arguments)]
+pub fn return_core_items<'info>(
+    jellybean_machine: &Account<'info, JellybeanMachine>,
+    indices: &[u16],
+    remaining_accounts: &[AccountInfo<'info>],
+    authority: &AccountInfo<'info>,
+    authority_pda: &AccountInfo<'info>,
+    mpl_core_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    auth_seeds: &[&[u8]],
+) -> Result<()> {
+    let item_accounts = remaining_accounts.chunks_exact(3);
+    require!(
+        item_accounts.remainder().is_empty() && item_accounts.len() == indices.len(),
+        JellybeanError::InvalidInputLength
+    );
+
+    let jellybean_machine_info = jellybean_machine.to_account_info();
+    let optional = |account: &'_ AccountInfo<'info>| -> Option<AccountInfo<'info>> {
+        (account.key() != crate::ID).then(|| account.clone())
+    };
+
+    for (index, accounts) in indices.iter().zip(item_accounts) {
+        let data = jellybean_machine_info.data.borrow();
+        let loaded_item = jellybean_machine.get_loaded_item_at_index(&data, *index as usize)?;
+        drop(data);
+
+        let asset = optional(&accounts[0]);
+        let collection = optional(&accounts[1]);
+        let print_template = optional(&accounts[2]);
+
+        return_core_item(
+            jellybean_machine_info.key,
+            &loaded_item,
+            jellybean_machine.reveal_config.is_some(),
+            asset.as_ref(),
+            collection.as_ref(),
+            print_template.as_ref(),
+            authority,
+            authority_pda,
+            mpl_core_program,
+            system_program,
+            auth_seeds,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Unlocks the components of a bundle that hasn't been drawn. A drawn bundle must have been
+/// delivered, which closes its account.
+pub fn return_bundle_item(
+    jellybean_machine: &Pubkey,
+    loaded_item: &LoadedItem,
+    bundle: &AccountInfo<'_>,
 ) -> Result<()> {
-    if start_index > end_index {
-        return err!(JellybeanError::InvalidInputLength);
+    assert_keys_equal(loaded_item.mint, *bundle.key, "Invalid bundle")?;
+    require!(
+        loaded_item.supply_claimed == loaded_item.supply_redeemed,
+        JellybeanError::ItemNotFullyClaimed
+    );
+
+    if loaded_item.supply_redeemed > 0 {
+        return Ok(());
     }
 
+    assert_owned_by(bundle, &crate::ID)?;
+    let mut bundle_data = bundle.try_borrow_mut_data()?;
+    let mut bundle_account = Bundle::try_deserialize(&mut &bundle_data[..])?;
+    assert_keys_equal(
+        bundle_account.jellybean_machine,
+        *jellybean_machine,
+        "Invalid jellybean machine",
+    )?;
+
+    bundle_account.loaded = false;
+    bundle_account.try_serialize(&mut &mut bundle_data[..])
+}
+
+/// Removes the items at the given indices, which must be sorted and unique.
+/// Prizes reference items by index, so items up to the last one with unclaimed prizes
+/// can't move: removed slots among them are tombstoned in place. Every item after it is
+/// shifted down over the removed slots and any tombstones left by earlier removals, and
+/// disabled and limited items follow them.
+pub fn remove_items<'info>(
+    jellybean_machine: &mut Account<'info, JellybeanMachine>,
+    indices: &[u16],
+    authority: &AccountInfo<'info>,
+) -> Result<()> {
+    require!(
+        !indices.is_empty() && indices.windows(2).all(|pair| pair[0] < pair[1]),
+        JellybeanError::InvalidInputLength
+    );
+
     let account_info = jellybean_machine.to_account_info();
     let mut data = account_info.data.borrow_mut();
     let items_loaded = jellybean_machine.items_loaded;
 
     // Validate indices are within bounds
-    if end_index >= items_loaded {
+    if indices[indices.len() - 1] >= items_loaded {
         return err!(JellybeanError::IndexGreaterThanLength);
     }
 
-    // Calculate how many items we're removing
-    let items_to_remove = end_index - start_index + 1;
-
-    // Calculate the total supply being removed for verification
+    // Tombstone every removed slot, keeping count of the supply it held
     let mut total_supply_removed = 0u64;
-    for index in start_index..=end_index {
-        let item_position = jellybean_machine.get_loaded_item_position(index as usize);
-        // Skip mint field (32 bytes) and read supply_loaded field (4 bytes)
-        let supply_loaded_position = item_position + 32;
-        let supply_loaded = u32::from_le_bytes(*array_ref![data, supply_loaded_position, 4]);
+    let mut total_redeemed_removed = 0u64;
+    for index in indices.iter() {
+        let item_position = jellybean_machine.get_loaded_item_position(*index as usize);
+        let supply_loaded = u32::from_le_bytes(*array_ref![
+            data,
+            item_position + LOADED_ITEM_SUPPLY_LOADED_OFFSET,
+            4
+        ]);
         total_supply_removed = total_supply_removed
             .checked_add(supply_loaded as u64)
             .ok_or(JellybeanError::NumericalOverflowError)?;
+
+        let supply_redeemed = u32::from_le_bytes(*array_ref![
+            data,
+            item_position + LOADED_ITEM_SUPPLY_REDEMED_OFFSET,
+            4
+        ]);
+        total_redeemed_removed = total_redeemed_removed
+            .checked_add(supply_redeemed as u64)
+            .ok_or(JellybeanError::NumericalOverflowError)?;
+
+        data[item_position..item_position + LOADED_ITEM_SIZE].fill(0);
     }
 
     // Update the jellybean machine counters (reverse of add_item logic)
-    jellybean_machine.items_loaded = jellybean_machine
-        .items_loaded
-        .checked_sub(items_to_remove)
-        .ok_or(JellybeanError::NumericalOverflowError)?;
-
     jellybean_machine.supply_loaded = jellybean_machine
         .supply_loaded
         .checked_sub(total_supply_removed)
         .ok_or(JellybeanError::NumericalOverflowError)?;
 
-    // Move remaining items to fill the gap if we're not removing from the end
-    if end_index < (items_loaded - 1) {
-        let items_after_removal = items_loaded - end_index - 1;
+    // Keep the remaining supply used by draws in sync, in case the sale is reopened
+    jellybean_machine.supply_redeemed = jellybean_machine
+        .supply_redeemed
+        .checked_sub(total_redeemed_removed)
+        .ok_or(JellybeanError::NumericalOverflowError)?;
+
+    // Disabled and limited items go with their item
+    jellybean_machine
+        .disabled_items
+        .retain(|index| indices.binary_search(index).is_err());
+    jellybean_machine
+        .buyer_limits
+        .retain(|buyer_limit| indices.binary_search(&buyer_limit.item_index).is_err());
+
+    // Items after the last one with unclaimed prizes can be shifted down
+    let compact_from = (0..items_loaded)
+        .rev()
+        .find(|index| {
+            let item_position = jellybean_machine.get_loaded_item_position(*index as usize);
+            let supply_redeemed = u32::from_le_bytes(*array_ref![
+                data,
+                item_position + LOADED_ITEM_SUPPLY_REDEMED_OFFSET,
+                4
+            ]);
+            let supply_claimed = u32::from_le_bytes(*array_ref![
+                data,
+                item_position + LOADED_ITEM_SUPPLY_CLAIMED_OFFSET,
+                4
+            ]);
+            supply_redeemed != supply_claimed
+        })
+        .map_or(0, |index| index + 1);
 
-        if items_after_removal > 0 {
-            let source_start = jellybean_machine.get_loaded_item_position((end_index + 1) as usize);
-            let dest_start = jellybean_machine.get_loaded_item_position(start_index as usize);
-            let copy_size = (items_after_removal as usize) * LOADED_ITEM_SIZE;
+    let mut next_index = compact_from;
+    for index in compact_from..items_loaded {
+        let item_position = jellybean_machine.get_loaded_item_position(index as usize);
+        if is_tombstone(&data[item_position..item_position + LOADED_ITEM_SIZE]) {
+            continue;
+        }
 
-            data.copy_within(source_start..source_start + copy_size, dest_start);
+        if index != next_index {
+            let destination = jellybean_machine.get_loaded_item_position(next_index as usize);
+            data.copy_within(item_position..item_position + LOADED_ITEM_SIZE, destination);
+
+            for disabled_index in jellybean_machine.disabled_items.iter_mut() {
+                if *disabled_index == index {
+                    *disabled_index = next_index;
+                }
+            }
+            for buyer_limit in jellybean_machine.buyer_limits.iter_mut() {
+                if buyer_limit.item_index == index {
+                    buyer_limit.item_index = next_index;
+                }
+            }
         }
+        next_index += 1;
     }
+    jellybean_machine.items_loaded = next_index;
 
     drop(data);
 
@@ -85,12 +324,16 @@ pub fn remove_multiple_items_span<'info>(
     **authority.try_borrow_mut_lamports()? += excess_lamports;
 
     msg!(
-        "Items removed: span from {} to {}, new items_loaded={}, new supply_loaded={}",
-        start_index,
-        end_index,
+        "Items removed: count={}, new items_loaded={}, new supply_loaded={}",
+        indices.len(),
         jellybean_machine.items_loaded,
         jellybean_machine.supply_loaded
     );
 
     Ok(())
 }
+
+/// Removed items whose slot had to be kept are zeroed out, including the mint.
+pub fn is_tombstone(item_data: &[u8]) -> bool {
+    item_data.iter().all(|byte| *byte == 0)
+}
@@ -0,0 +1,189 @@
+use anchor_lang::prelude::*;
+use mpl_core::{
+    instructions::{
+        AddCollectionPluginV1CpiBuilder, UpdateCollectionPluginV1CpiBuilder, UpdateV1CpiBuilder,
+    },
+    types::{Plugin, PluginAuthority, UpdateAuthority, UpdateDelegate},
+    Asset, Collection, UpdateDelegatePlugin,
+};
+
+use crate::{JellybeanError, JellybeanMachine};
+
+/// Whether the authority pda can update the asset, directly or through its collection, as
+/// the update authority or an update delegate.
+pub fn is_machine_update_authority(
+    asset: &Asset,
+    collection: Option<&AccountInfo<'_>>,
+    authority_pda: &AccountInfo<'_>,
+) -> Result<bool> {
+    let is_update_delegate = |plugin: &Option<UpdateDelegatePlugin>| {
+        plugin.as_ref().is_some_and(|plugin| {
+            plugin.base.authority.address == Some(authority_pda.key())
+                || plugin
+                    .update_delegate
+                    .additional_delegates
+                    .contains(&authority_pda.key())
+        })
+    };
+
+    if is_update_delegate(&asset.plugin_list.update_delegate) {
+        return Ok(true);
+    }
+
+    let update_authority = match asset.base.update_authority {
+        UpdateAuthority::Address(address) => Some(address),
+        UpdateAuthority::Collection(_) => match collection {
+            Some(collection) => {
+                let collection = Box::<Collection>::try_from(collection)?;
+                if is_update_delegate(&collection.plugin_list.update_delegate) {
+                    return Ok(true);
+                }
+                Some(collection.base.update_authority)
+            }
+            None => None,
+        },
+        UpdateAuthority::None => None,
+    };
+
+    Ok(update_authority == Some(authority_pda.key()))
+}
+
+/// Assets loaded in a machine writing provenance or in delayed reveal mode must be updatable
+/// by the authority pda. The seller grants it by making the pda the update authority or an
+/// update delegate of the asset or its collection before loading it.
+pub fn assert_machine_can_update_core_asset(
+    jellybean_machine: &JellybeanMachine,
+    asset: &AccountInfo<'_>,
+    collection: Option<&AccountInfo<'_>>,
+    authority_pda: &AccountInfo<'_>,
+) -> Result<()> {
+    if jellybean_machine.provenance_config.is_none() && jellybean_machine.reveal_config.is_none() {
+        return Ok(());
+    }
+
+    let asset_data = Box::<Asset>::try_from(asset)?;
+    require!(
+        is_machine_update_authority(&asset_data, collection, authority_pda)?,
+        JellybeanError::MachineCannotUpdateAsset
+    );
+
+    Ok(())
+}
+
+/// Makes the authority pda an update delegate of a master edition collection before its
+/// update authority is handed back, so prints still showing the placeholder can be revealed.
+pub fn delegate_collection_update<'info>(
+    collection: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    authority_pda: &AccountInfo<'info>,
+    mpl_core_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    auth_seeds: &[&[u8]],
+) -> Result<()> {
+    let plugin_list = Box::<Collection>::try_from(collection)?.plugin_list;
+
+    match plugin_list.update_delegate {
+        Some(plugin) => {
+            let mut additional_delegates = plugin.update_delegate.additional_delegates;
+            if plugin.base.authority.address == Some(authority_pda.key())
+                || additional_delegates.contains(&authority_pda.key())
+            {
+                return Ok(());
+            }
+            additional_delegates.push(authority_pda.key());
+
+            UpdateCollectionPluginV1CpiBuilder::new(mpl_core_program)
+                .collection(collection)
+                .payer(payer)
+                .authority(Some(authority_pda))
+                .plugin(Plugin::UpdateDelegate(UpdateDelegate {
+                    additional_delegates,
+                }))
+                .system_program(system_program)
+                .invoke_signed(&[auth_seeds])?;
+        }
+        None => {
+            AddCollectionPluginV1CpiBuilder::new(mpl_core_program)
+                .collection(collection)
+                .payer(payer)
+                .authority(Some(authority_pda))
+                .plugin(Plugin::UpdateDelegate(UpdateDelegate {
+                    additional_delegates: vec![],
+                }))
+                .init_authority(PluginAuthority::Address {
+                    address: authority_pda.key(),
+                })
+                .system_program(system_program)
+                .invoke_signed(&[auth_seeds])?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Swaps the uri of a delivered asset for the placeholder until it is revealed. Loading
+/// requires the authority pda to be able to update the asset, so this is only skipped if
+/// the seller revoked that afterwards.
+#[allow(clippy::too_many_arguments)]
+pub fn hide_core_asset<'info>(
+    asset: &AccountInfo<'info>,
+    collection: Option<&AccountInfo<'info>>,
+    payer: &AccountInfo<'info>,
+    authority_pda: &AccountInfo<'info>,
+    mpl_core_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    auth_seeds: &[&[u8]],
+    placeholder_uri: &str,
+) -> Result<()> {
+    let asset_data = Box::<Asset>::try_from(asset)?;
+    if !is_machine_update_authority(&asset_data, collection, authority_pda)? {
+        msg!("Skipping reveal, the asset update authority is not the machine");
+        return Ok(());
+    }
+
+    UpdateV1CpiBuilder::new(mpl_core_program)
+        .asset(asset)
+        .collection(collection)
+        .payer(payer)
+        .authority(Some(authority_pda))
+        .system_program(system_program)
+        .new_uri(placeholder_uri.to_string())
+        .invoke_signed(&[auth_seeds])?;
+
+    Ok(())
+}
+
+/// Replaces the placeholder uri of an unrevealed asset with its final uri.
+#[allow(clippy::too_many_arguments)]
+pub fn reveal_core_asset<'info>(
+    asset: &AccountInfo<'info>,
+    collection: Option<&AccountInfo<'info>>,
+    payer: &AccountInfo<'info>,
+    authority_pda: &AccountInfo<'info>,
+    mpl_core_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    auth_seeds: &[&[u8]],
+    placeholder_uri: &str,
+    uri: String,
+) -> Result<()> {
+    let asset_data = Box::<Asset>::try_from(asset)?;
+    require!(
+        asset_data.base.uri == placeholder_uri,
+        JellybeanError::AlreadyRevealed
+    );
+    require!(
+        is_machine_update_authority(&asset_data, collection, authority_pda)?,
+        JellybeanError::InvalidAsset
+    );
+
+    UpdateV1CpiBuilder::new(mpl_core_program)
+        .asset(asset)
+        .collection(collection)
+        .payer(payer)
+        .authority(Some(authority_pda))
+        .system_program(system_program)
+        .new_uri(uri)
+        .invoke_signed(&[auth_seeds])?;
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+pub const MAX_BUNDLE_COMPONENTS: usize = 8;
+
+pub const BUNDLE_COMPONENT_SIZE: usize = 1 // kind
+    + 32 // address
+    + 8  // amount
+    + 1; // delivered
+
+/// Assets escrowed by the authority pda and awarded together by a single draw.
+/// The bundle is loaded as one item whose mint is the bundle address.
+#[account]
+#[derive(Debug)]
+pub struct Bundle {
+    /// Version of the account.
+    pub version: u8,
+    /// Pubkey of the JellybeanMachine account.
+    pub jellybean_machine: Pubkey,
+    /// Id chosen by the authority, used in the bundle seeds.
+    pub id: u32,
+    /// Whether the bundle is loaded in the machine. Components are locked while loaded.
+    pub loaded: bool,
+    /// Escrowed components, delivered one by one when claimed.
+    pub components: Vec<BundleComponent>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone)]
+pub struct BundleComponent {
+    pub kind: BundleComponentKind,
+    /// Core asset, or mint of the tokens.
+    pub address: Pubkey,
+    /// Amount of tokens, 1 for Core assets.
+    pub amount: u64,
+    /// Whether the component has been delivered to the buyer.
+    pub delivered: bool,
+}
+
+#[derive(Copy, AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum BundleComponentKind {
+    CoreAsset,
+    Token,
+}
+
+impl Bundle {
+    pub const CURRENT_VERSION: u8 = 1;
+    pub const SEED_PREFIX: &'static str = "bundle";
+
+    pub const SIZE: usize = 8 // discriminator
+        + 1  // version
+        + 32 // jellybean_machine
+        + 4  // id
+        + 1  // loaded
+        + 4 + MAX_BUNDLE_COMPONENTS * BUNDLE_COMPONENT_SIZE; // components
+
+    pub fn is_delivered(&self) -> bool {
+        self.components.iter().all(|component| component.delivered)
+    }
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::MAX_GUARD_PHASES;
+
+/// Draws made by a buyer from a machine, in total and in each guard phase. Unlike unclaimed
+/// prizes, it is kept until the machine is withdrawn or cancelled, so per-wallet limits hold
+/// after prizes are claimed.
+#[account]
+#[derive(Debug)]
+pub struct BuyerRecord {
+    /// Version of the account.
+    pub version: u8,
+    /// Pubkey of the JellybeanMachine account.
+    pub jellybean_machine: Pubkey,
+    /// Buyer the draws are counted for.
+    pub buyer: Pubkey,
+    /// Lifetime draws of the buyer.
+    pub draws: u32,
+    /// Draws in each guard phase, by phase index.
+    pub phase_draws: [u16; MAX_GUARD_PHASES],
+}
+
+impl BuyerRecord {
+    pub const CURRENT_VERSION: u8 = 1;
+    pub const SEED_PREFIX: &'static str = "buyer_record";
+
+    pub const SIZE: usize = 8 // discriminator
+        + 1  // version
+        + 32 // jellybean_machine
+        + 32 // buyer
+        + 4  // draws
+        + 2 * MAX_GUARD_PHASES; // phase_draws
+
+    pub fn find_program_address(jellybean_machine: &Pubkey, buyer: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[
+                Self::SEED_PREFIX.as_bytes(),
+                jellybean_machine.as_ref(),
+                buyer.as_ref(),
+            ],
+            &crate::ID,
+        )
+    }
+}
@@ -0,0 +1,161 @@
+use anchor_lang::prelude::*;
+
+pub const MAX_GUARD_PHASES: usize = 8;
+
+pub const GUARD_PHASE_SIZE: usize = 8 // start_at
+    + 8  // price
+    + 33 // allowlist_root
+    + 1 + DRAW_GATE_SIZE // gate
+    + 3; // max_draws_per_wallet
+
+pub const DRAW_GATE_SIZE: usize = 1 + 32 + 8 // kind
+    + 1; // action
+
+/// Phases of a sale, e.g. an allowlist presale followed by a public sale. The active phase
+/// is the last one that has started, and its rules are applied by draw.
+#[account]
+#[derive(Debug)]
+pub struct GuardPhases {
+    /// Version of the account.
+    pub version: u8,
+    /// Pubkey of the JellybeanMachine account.
+    pub jellybean_machine: Pubkey,
+    /// Phases ordered by start time, up to 8.
+    pub phases: Vec<GuardPhase>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct GuardPhase {
+    /// Unix timestamp at which the phase starts, and the previous one ends.
+    pub start_at: i64,
+    /// Lamports paid by the payer for each draw, split between the machine fee accounts.
+    pub price: u64,
+    /// Root of the Merkle tree of allowed buyers. Buyers prove their membership once with
+    /// prove_allowlist.
+    pub allowlist_root: Option<[u8; 32]>,
+    /// Token or Core asset the buyer must hold.
+    pub gate: Option<DrawGate>,
+    /// Maximum number of draws a single buyer can make in the phase.
+    pub max_draws_per_wallet: Option<u16>,
+}
+
+/// Holding requirement of a guard phase, proven by accounts passed to draw.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct DrawGate {
+    pub kind: DrawGateKind,
+    /// What happens to the tokens or asset held when the buyer draws.
+    pub action: DrawGateAction,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum DrawGateKind {
+    /// The buyer holds at least `amount` tokens of the mint in their associated token account.
+    Token { mint: Pubkey, amount: u64 },
+    /// The buyer owns a Core asset in the collection.
+    CoreCollection { collection: Pubkey },
+}
+
+#[derive(Copy, AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum DrawGateAction {
+    Hold,     // Only checked
+    Burn,     // Tokens or asset burned by the draw
+    MarkUsed, // Core asset can't be used for another draw of the machine
+}
+
+impl GuardPhases {
+    pub const CURRENT_VERSION: u8 = 1;
+    pub const SEED_PREFIX: &'static str = "guard_phases";
+
+    pub const SIZE: usize = 8 // discriminator
+        + 1  // version
+        + 32 // jellybean_machine
+        + 4 + MAX_GUARD_PHASES * GUARD_PHASE_SIZE; // phases
+
+    pub fn find_address(jellybean_machine: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[Self::SEED_PREFIX.as_bytes(), jellybean_machine.as_ref()],
+            &crate::ID,
+        )
+        .0
+    }
+
+    /// Index of the last phase that has started, if any.
+    pub fn get_active_phase(&self, now: i64) -> Option<(usize, &GuardPhase)> {
+        self.phases
+            .iter()
+            .enumerate()
+            .rev()
+            .find(|(_, phase)| phase.start_at <= now)
+    }
+}
+
+/// Created by prove_allowlist once a buyer has proven they are in an allowlist, so the
+/// proof doesn't have to be sent with every draw.
+#[account]
+#[derive(Debug)]
+pub struct AllowlistProof {
+    /// Pubkey of the JellybeanMachine account.
+    pub jellybean_machine: Pubkey,
+    /// Root of the allowlist Merkle tree.
+    pub merkle_root: [u8; 32],
+    /// Buyer in the allowlist.
+    pub buyer: Pubkey,
+}
+
+impl AllowlistProof {
+    pub const SEED_PREFIX: &'static str = "allowlist_proof";
+
+    pub const SIZE: usize = 8 // discriminator
+        + 32 // jellybean_machine
+        + 32 // merkle_root
+        + 32; // buyer
+
+    pub fn find_address(
+        jellybean_machine: &Pubkey,
+        merkle_root: &[u8; 32],
+        buyer: &Pubkey,
+    ) -> Pubkey {
+        Pubkey::find_program_address(
+            &[
+                Self::SEED_PREFIX.as_bytes(),
+                jellybean_machine.as_ref(),
+                merkle_root.as_ref(),
+                buyer.as_ref(),
+            ],
+            &crate::ID,
+        )
+        .0
+    }
+}
+
+/// Marks a Core asset as used by a gate with the MarkUsed action.
+#[account]
+#[derive(Debug)]
+pub struct UsedGateAsset {
+    /// Pubkey of the JellybeanMachine account.
+    pub jellybean_machine: Pubkey,
+    /// Core asset used to pass the gate.
+    pub asset: Pubkey,
+    /// Buyer the asset was used by, gets the rent back when the account is closed.
+    pub buyer: Pubkey,
+}
+
+impl UsedGateAsset {
+    pub const SEED_PREFIX: &'static str = "used_gate_asset";
+
+    pub const SIZE: usize = 8 // discriminator
+        + 32 // jellybean_machine
+        + 32 // asset
+        + 32; // buyer
+
+    pub fn find_program_address(jellybean_machine: &Pubkey, asset: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[
+                Self::SEED_PREFIX.as_bytes(),
+                jellybean_machine.as_ref(),
+                asset.as_ref(),
+            ],
+            &crate::ID,
+        )
+    }
+}
@@ -3,9 +3,15 @@ use anchor_lang::prelude::{
     *,
 };
 
+use crate::UnclaimedPrizes;
+use arrayref::array_ref;
+
 pub const MAX_URI_LENGTH: usize = 196;
 pub const MAX_FEE_ACCOUNTS: usize = 6;
-pub const MAX_ITEMS: u8 = 255;
+pub const MAX_ITEMS: u16 = 10_000;
+pub const MAX_DISABLED_ITEMS: usize = 16;
+pub const MAX_PLACEHOLDER_URI_LENGTH: usize = 100;
+pub const MAX_BUYER_LIMITS: usize = 16;
 
 const BASE_JELLYBEAN_MACHINE_SIZE: usize = 8 // discriminator
     + 1                                       // version
@@ -13,13 +19,28 @@ const BASE_JELLYBEAN_MACHINE_SIZE: usize = 8 // discriminator
     + 32                                      // mint authority
     + 4                                       // fee account vec size
     + 41                                      // print fee config
-    + 1                                       // items loaded
+    + 2                                       // items loaded
     + 8                                       // supply loaded
     + 8                                       // supply redeemed
     + 8                                       // supply settled
     + 1 // state
     + MAX_URI_LENGTH // uri
-    + 320; // padding
+    + 34 // provenance config
+    + 4 + 2 * MAX_DISABLED_ITEMS // disabled items
+    + 1 + 4 + MAX_PLACEHOLDER_URI_LENGTH // reveal config
+    + 4 + BUYER_LIMIT_SIZE * MAX_BUYER_LIMITS // buyer limits
+    + 3 // max wins per item
+    + 9 // go live at
+    + 9 // end at
+    + 1 // has guard phases
+    + 3 // max draws per wallet
+    + 9 // bot tax
+    + 8 // unclaimed price
+    + 9 // claim deadline
+    + 1 // public draw
+    + 2 // open bundles
+    + 2 // open token accounts
+    + 20; // padding
 
 /// Jellybean machine state and config data.
 #[account]
@@ -35,8 +56,8 @@ pub struct JellybeanMachine {
     pub fee_accounts: Vec<FeeAccount>,
     /// Print fee config
     pub print_fee_config: Option<PrintFeeConfig>,
-    /// Total unique items loaded. Up to 255 items.
-    pub items_loaded: u8,
+    /// Total unique items loaded. Up to 10,000 items.
+    pub items_loaded: u16,
     /// Total supply_loaded of all items added.
     pub supply_loaded: u64,
     /// Number of times items have been redeemed.
@@ -45,14 +66,50 @@ pub struct JellybeanMachine {
     pub state: JellybeanState,
     /// Uri of off-chain metadata, max length 196
     pub uri: String,
+    /// Provenance attributes written to assets as they are claimed
+    pub provenance_config: Option<ProvenanceConfig>,
+    /// Indices of items temporarily excluded from draws, up to 16
+    pub disabled_items: Vec<u16>,
+    /// Delayed reveal, claimed assets get a placeholder uri until revealed
+    pub reveal_config: Option<RevealConfig>,
+    /// Maximum number of units of an item a single buyer can win, up to 16 items
+    pub buyer_limits: Vec<BuyerLimit>,
+    /// Maximum number of units of any item a single buyer can win, unless the item has its
+    /// own buyer limit
+    pub max_wins_per_item: Option<u16>,
+    /// Unix timestamp at which draws open, the sale goes live on its own if not started
+    pub go_live_at: Option<i64>,
+    /// Unix timestamp after which draws are closed and anyone can end the sale
+    pub end_at: Option<i64>,
+    /// Whether draws must satisfy the active phase of the guard phases account
+    pub has_guard_phases: bool,
+    /// Maximum number of draws a single buyer can make over the lifetime of the machine
+    pub max_draws_per_wallet: Option<u16>,
+    /// Lamports charged to the payer of a public draw that breaks a machine rule, instead of failing.
+    /// The tax accrues on the machine account and is paid to the authority with its rent
+    pub bot_tax: Option<u64>,
+    /// Draw prices paid for the prizes not claimed yet, escrowed by the authority when the sale
+    /// is cancelled so each buyer gets back what they paid
+    pub unclaimed_price: u64,
+    /// Unix timestamp after which the authority can reclaim prizes that haven't been claimed.
+    /// Must be after end_at when both are set, and no draw is accepted once it has passed
+    pub claim_deadline: Option<i64>,
+    /// Whether buyers can draw without the mint authority co-signing, only through paid
+    /// guard phases
+    pub public_draw: bool,
+    /// Number of bundle accounts holding escrowed components, must be zero to withdraw
+    pub open_bundles: u16,
+    /// Number of authority pda token accounts opened by bundle deposits, must be zero to
+    /// withdraw
+    pub open_token_accounts: u16,
     /// Padding for future use
-    pub padding: [u8; 320],
+    pub padding: [u8; 20],
     // hidden data section to avoid deserialisation:
     // - (LOADED_ITEM_SIZE * items_loaded) - grows as items are loaded
 }
 
 impl JellybeanMachine {
-    pub const CURRENT_VERSION: u8 = 0;
+    pub const CURRENT_VERSION: u8 = 1;
 
     pub fn get_base_size(&self) -> usize {
         Self::get_base_size_with_fee_accounts(self.fee_accounts.len())
@@ -77,12 +134,141 @@ impl JellybeanMachine {
             + (LOADED_ITEM_SIZE * item_count as usize)
     }
 
+    /// Items can be restocked while a sale is live, or after it has ended so
+    /// the machine can be reopened.
     pub fn can_add_items(&self) -> bool {
-        self.state == JellybeanState::None
+        matches!(
+            self.state,
+            JellybeanState::None
+                | JellybeanState::SaleLive
+                | JellybeanState::Paused
+                | JellybeanState::SaleEnded
+        )
+    }
+
+    /// A sale can be started from the initial state, or reopened after it has
+    /// ended as long as there is supply left to draw.
+    pub fn can_start_sale(&self) -> bool {
+        match self.state {
+            JellybeanState::None => true,
+            JellybeanState::SaleEnded => self.supply_redeemed < self.supply_loaded,
+            JellybeanState::SaleLive | JellybeanState::Paused | JellybeanState::Cancelled => false,
+        }
     }
 
+    /// Prizes can still be claimed while the sale is paused.
+    pub fn can_claim_items(&self) -> bool {
+        matches!(
+            self.state,
+            JellybeanState::SaleLive | JellybeanState::Paused | JellybeanState::SaleEnded
+        )
+    }
+
+    /// Items of a cancelled sale can be removed once their prizes have been refunded.
     pub fn can_remove_items(&self) -> bool {
-        self.state == JellybeanState::None || self.state == JellybeanState::SaleEnded
+        matches!(
+            self.state,
+            JellybeanState::None | JellybeanState::SaleEnded | JellybeanState::Cancelled
+        )
+    }
+
+    /// A sale that has ended or been cancelled can no longer be ended.
+    pub fn is_closed(&self) -> bool {
+        matches!(
+            self.state,
+            JellybeanState::SaleEnded | JellybeanState::Cancelled
+        )
+    }
+
+    /// A scheduled machine goes live at go_live_at without anyone sending start_sale.
+    pub fn is_scheduled_to_go_live(&self, now: i64) -> bool {
+        self.state == JellybeanState::None
+            && self.go_live_at.is_some_and(|go_live_at| now >= go_live_at)
+    }
+
+    pub fn is_before_go_live(&self, now: i64) -> bool {
+        self.go_live_at.is_some_and(|go_live_at| now < go_live_at)
+    }
+
+    pub fn is_past_end(&self, now: i64) -> bool {
+        self.end_at.is_some_and(|end_at| now >= end_at)
+    }
+
+    pub fn is_past_claim_deadline(&self, now: i64) -> bool {
+        self.claim_deadline
+            .is_some_and(|claim_deadline| now >= claim_deadline)
+    }
+
+    pub fn is_item_disabled(&self, index: u16) -> bool {
+        self.disabled_items.contains(&index)
+    }
+
+    /// Buyer limit of the item, or the machine-wide limit if it has none of its own.
+    pub fn get_buyer_limit(&self, index: u16) -> Option<u16> {
+        self.buyer_limits
+            .iter()
+            .find(|buyer_limit| buyer_limit.item_index == index)
+            .map(|buyer_limit| buyer_limit.max_per_buyer)
+            .or(self.max_wins_per_item)
+    }
+
+    /// Items a buyer cannot draw: the disabled ones and those the buyer has already won
+    /// as many times as their buyer limit allows. Wins are matched to the items by mint.
+    pub fn get_excluded_items(
+        &self,
+        account_data: &[u8],
+        unclaimed_prizes: &UnclaimedPrizes,
+    ) -> Result<Vec<u16>> {
+        let mut excluded_items = self.disabled_items.clone();
+        if unclaimed_prizes.wins.is_empty() {
+            return Ok(excluded_items);
+        }
+
+        for index in 0..self.items_loaded {
+            let item_position = self.get_loaded_item_position(index as usize);
+            let mint = Pubkey::new_from_array(*array_ref![account_data, item_position, 32]);
+            let reached_limit = unclaimed_prizes.get_wins(&mint).is_some_and(|count| {
+                self.get_buyer_limit(index)
+                    .is_some_and(|max_per_buyer| count >= max_per_buyer)
+            });
+            if reached_limit && !excluded_items.contains(&index) {
+                excluded_items.push(index);
+            }
+        }
+        Ok(excluded_items)
+    }
+
+    /// Supply a buyer can draw from: the remaining supply without the disabled items and
+    /// those excluded for the buyer.
+    pub fn get_drawable_supply(&self, account_data: &[u8], excluded_items: &[u16]) -> Result<u64> {
+        // Disabled items stay loaded but their remaining supply can't be drawn
+        let supply_disabled = self.get_remaining_supply_of(account_data, &self.disabled_items)?;
+        let supply_excluded = self.get_remaining_supply_of(account_data, excluded_items)?;
+
+        let supply_remaining = self.supply_loaded.saturating_sub(self.supply_redeemed);
+
+        // are there items to be minted?
+        if supply_remaining.saturating_sub(supply_disabled) == 0 {
+            return err!(crate::JellybeanError::JellybeanMachineEmpty);
+        }
+
+        // Re-select among the items the buyer hasn't reached the limit of
+        let supply_drawable = supply_remaining.saturating_sub(supply_excluded);
+        if supply_drawable == 0 {
+            return err!(crate::JellybeanError::BuyerLimitReached);
+        }
+
+        Ok(supply_drawable)
+    }
+
+    /// Remaining supply of the given items.
+    pub fn get_remaining_supply_of(&self, account_data: &[u8], indices: &[u16]) -> Result<u64> {
+        let mut remaining_supply = 0u64;
+        for index in indices.iter() {
+            let item = self.get_loaded_item_at_index(account_data, *index as usize)?;
+            remaining_supply += item.supply_loaded.saturating_sub(item.supply_redeemed) as u64;
+        }
+        Ok(remaining_supply)
     }
 
     pub fn get_loaded_item_at_index(
@@ -90,6 +276,11 @@ impl JellybeanMachine {
         account_data: &[u8],
         index: usize,
     ) -> Result<LoadedItem> {
+        require!(
+            index < self.items_loaded as usize,
+            crate::JellybeanError::IndexGreaterThanLength
+        );
+
         let item_position = self.get_loaded_item_position(index);
         let item_data = &mut &account_data[item_position..item_position + LOADED_ITEM_SIZE];
         Ok(LoadedItem::deserialize(item_data)?)
@@ -106,13 +297,28 @@ pub struct FeeAccount {
     pub basis_points: u16,
 }
 
+pub const BUYER_LIMIT_SIZE: usize = 2 + 2;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct BuyerLimit {
+    /// Index of the limited item
+    pub item_index: u16,
+    /// Units of the item a single buyer can win
+    pub max_per_buyer: u16,
+}
+
 pub const LOADED_ITEM_SIZE: usize = 32 + // mint
     4 + // supply_loaded
     4 + // supply_redeemed
     4 + // supply_claimed
-    8; // escrow_amount
+    8 + // escrow_amount
+    4 + // existing_prints
+    1; // kind
 
+pub const LOADED_ITEM_SUPPLY_LOADED_OFFSET: usize = 32;
 pub const LOADED_ITEM_SUPPLY_REDEMED_OFFSET: usize = 32 + 4;
+pub const LOADED_ITEM_SUPPLY_CLAIMED_OFFSET: usize = 32 + 4 + 4;
+pub const LOADED_ITEM_ESCROW_AMOUNT_OFFSET: usize = 32 + 4 + 4 + 4;
 
 /// Config line struct for storing asset (NFT) data pre-mint.
 #[derive(BorshSerialize, BorshDeserialize, Debug)]
@@ -120,13 +326,36 @@ pub struct LoadedItem {
     /// Mint account of the asset.
     pub mint: Pubkey,
     /// Total supply when loaded. Edition count for printable NFTs, number of token prizes for fungible assets.
+    /// Includes any prints minted before a master edition was loaded.
     pub supply_loaded: u32,
-    /// Number of times this item has been redeemed.
+    /// Number of times this item has been redeemed. Starts at the number of existing prints for master editions.
     pub supply_redeemed: u32,
     /// Number of redeemed items that have been claimed.
     pub supply_claimed: u32,
     /// Escrow amount for the item (for edition prints)
     pub escrow_amount: u64,
+    /// Prints of a master edition minted before it was loaded, zero for other items.
+    pub existing_prints: u32,
+    /// What was loaded, decides how the item is drawn and claimed.
+    pub kind: ItemKind,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ItemKind {
+    CoreAsset,     // Single Core asset held by the machine
+    MasterEdition, // Master edition collection, prizes are printed editions
+    Bundle,        // Bundle of components delivered together
+}
+
+impl LoadedItem {
+    /// Funds escrowed from the buyer to cover the fees of printing an edition with the given
+    /// name and uri lengths.
+    pub fn print_escrow_amount(name_len: usize, uri_len: usize) -> Result<u64> {
+        let rent = Rent::get()?;
+
+        // AssetV1 + Edition plugin base size + name + uri size
+        Ok(rent.minimum_balance(108_usize + name_len + uri_len) + 1_500_000) // Metaplex fee
+    }
 }
 
 /// Common arguments for settings-related operations (initialize and update_settings)
@@ -135,6 +364,15 @@ pub struct SettingsArgs {
     pub uri: String,
     pub fee_accounts: Vec<FeeAccount>,
     pub print_fee_config: Option<PrintFeeConfig>,
+    pub provenance_config: Option<ProvenanceConfig>,
+    pub reveal_config: Option<RevealConfig>,
+    pub max_wins_per_item: Option<u16>,
+    pub go_live_at: Option<i64>,
+    pub end_at: Option<i64>,
+    pub max_draws_per_wallet: Option<u16>,
+    pub bot_tax: Option<u64>,
+    pub claim_deadline: Option<i64>,
+    pub public_draw: bool,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
@@ -143,9 +381,35 @@ pub struct PrintFeeConfig {
     pub amount: u64,
 }
 
+/// Provenance recorded in an Attributes plugin on each claimed asset: the machine, the draw
+/// slot, the tier and, for prints, the edition number. The payer of the claim covers the
+/// extra rent.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ProvenanceConfig {
+    /// Authority of the Attributes plugin added to the asset.
+    pub authority: ProvenanceAuthority,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum ProvenanceAuthority {
+    None, // Attributes can no longer be updated
+    UpdateAuthority,
+    Address { address: Pubkey },
+}
+
+/// Mystery-box mode: claimed assets and prints are given the placeholder uri, and the
+/// authority reveals their final metadata later.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct RevealConfig {
+    /// Uri shared by every unrevealed asset, max length 100
+    pub placeholder_uri: String,
+}
+
 #[derive(Copy, AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
 pub enum JellybeanState {
     None,      // Initial state
-    SaleLive,  // Sale started, can now mint items. Cannot no longer update details or add items.
-    SaleEnded, // Sale ended, can now settle items
+    SaleLive,  // Sale started, can now mint items. Cannot no longer update details.
+    SaleEnded, // Sale ended, can now settle items. Can be reopened after restocking.
+    Paused,    // Sale paused, draws are blocked but prizes can be claimed. Can be resumed.
+    Cancelled, // Sale called off, unclaimed prizes are refunded instead of claimed. Final.
 }
@@ -1,5 +1,13 @@
+pub mod bundle;
+pub mod buyer_record;
+pub mod guard_phases;
 pub mod jellybean_machine;
+pub mod print_template;
 pub mod unclaimed_prizes;
 
+pub use bundle::*;
+pub use buyer_record::*;
+pub use guard_phases::*;
 pub use jellybean_machine::*;
+pub use print_template::*;
 pub use unclaimed_prizes::*;
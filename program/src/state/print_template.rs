@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::MAX_URI_LENGTH;
+
+pub const MAX_NAME_TEMPLATE_LENGTH: usize = 64;
+
+/// Name and uri templates used when printing editions of a loaded master edition.
+/// `{name}` and `{uri}` expand to the master edition's name and uri, `{edition}` to the
+/// edition number of the print.
+#[account]
+#[derive(Debug)]
+pub struct PrintTemplate {
+    /// Version of the account.
+    pub version: u8,
+    /// Pubkey of the JellybeanMachine account.
+    pub jellybean_machine: Pubkey,
+    /// Master edition collection the template applies to.
+    pub collection: Pubkey,
+    /// Template for the name of each print, e.g. "{name} #{edition}".
+    pub name: String,
+    /// Template for the uri of each print, e.g. "{uri}/{edition}.json".
+    pub uri: String,
+}
+
+impl PrintTemplate {
+    pub const CURRENT_VERSION: u8 = 1;
+    pub const SEED_PREFIX: &'static str = "print_template";
+
+    pub const SIZE: usize = 8 // discriminator
+        + 1  // version
+        + 32 // jellybean_machine
+        + 32 // collection
+        + 4 + MAX_NAME_TEMPLATE_LENGTH // name
+        + 4 + MAX_URI_LENGTH; // uri
+
+    pub fn find_address(jellybean_machine: &Pubkey, collection: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[
+                Self::SEED_PREFIX.as_bytes(),
+                jellybean_machine.as_ref(),
+                collection.as_ref(),
+            ],
+            &crate::ID,
+        )
+        .0
+    }
+
+    /// Expands the name and uri templates for the given edition number.
+    pub fn expand(&self, name: &str, uri: &str, edition_number: u32) -> (String, String) {
+        (
+            expand_template(&self.name, name, uri, edition_number),
+            expand_template(&self.uri, name, uri, edition_number),
+        )
+    }
+}
+
+fn expand_template(template: &str, name: &str, uri: &str, edition_number: u32) -> String {
+    template
+        .replace("{edition}", &edition_number.to_string())
+        .replace("{name}", name)
+        .replace("{uri}", uri)
+}
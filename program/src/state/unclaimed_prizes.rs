@@ -14,33 +14,69 @@ pub struct UnclaimedPrizes {
     pub buyer: Pubkey,
     /// Indices of prizes drawn by the buyer
     pub prizes: Vec<Prize>,
+    /// Units won of each item with a buyer limit, kept after the prizes are claimed until
+    /// the sale is cancelled or the machine withdrawn
+    pub wins: Vec<ItemWins>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy)]
 pub struct Prize {
-    pub item_index: u8,
+    pub item_index: u16,
     pub edition_number: u32,
+    pub draw_slot: u64,
+    /// Price paid through the machine's guard phases, zero when a wrapping program charged
+    pub price: u64,
 }
 
-pub const PRIZE_SIZE: usize = 5;
+pub const PRIZE_SIZE: usize = 2 + 4 + 8 + 8;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy)]
+pub struct ItemWins {
+    /// Mint of the item, its index changes when earlier items are removed
+    pub mint: Pubkey,
+    pub count: u16,
+}
+
+pub const ITEM_WINS_SIZE: usize = 32 + 2;
 
 impl UnclaimedPrizes {
-    pub const CURRENT_VERSION: u8 = 0;
+    pub const CURRENT_VERSION: u8 = 1;
     pub const SEED_PREFIX: &'static str = "unclaimed_prizes";
 
-    // Base size without the Vec
+    // Base size without the Vecs
     pub const BASE_SIZE: usize = 8 // discriminator
         + 1  // version
         + 32 // jellybean_machine
         + 32 // buyer
-        + 4; // Vec length prefix
+        + 4  // prizes length prefix
+        + 4; // wins length prefix
+
+    /// Calculate the space needed for a given number of prizes and item wins
+    pub fn space(prize_count: usize, wins_count: usize) -> usize {
+        Self::BASE_SIZE + (prize_count * PRIZE_SIZE) + (wins_count * ITEM_WINS_SIZE)
+    }
 
-    /// Calculate the space needed for a given number of prize indices
-    pub fn space(prize_count: usize) -> usize {
-        Self::BASE_SIZE + (prize_count * PRIZE_SIZE)
+    /// Space needed for the current prizes and item wins.
+    pub fn current_space(&self) -> usize {
+        Self::space(self.prizes.len(), self.wins.len())
+    }
+
+    pub fn record_win(&mut self, mint: Pubkey) {
+        match self.wins.iter_mut().find(|wins| wins.mint == mint) {
+            Some(wins) => wins.count = wins.count.saturating_add(1),
+            None => self.wins.push(ItemWins { mint, count: 1 }),
+        }
+    }
+
+    /// Units of the item with the given mint the buyer has won.
+    pub fn get_wins(&self, mint: &Pubkey) -> Option<u16> {
+        self.wins
+            .iter()
+            .find(|wins| wins.mint == *mint)
+            .map(|wins| wins.count)
     }
 
-    pub fn claim_item(&mut self, item_index: u8) -> Result<Prize> {
+    pub fn claim_item(&mut self, item_index: u16) -> Result<Prize> {
         let position = self
             .prizes
             .iter()
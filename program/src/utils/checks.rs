@@ -1,5 +1,9 @@
-use crate::{FeeAccount, JellybeanError, SettingsArgs, MAX_FEE_ACCOUNTS, MAX_URI_LENGTH};
+use crate::{
+    FeeAccount, GuardPhase, JellybeanError, SettingsArgs, MAX_FEE_ACCOUNTS,
+    MAX_PLACEHOLDER_URI_LENGTH, MAX_URI_LENGTH,
+};
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
 use anchor_lang::solana_program::program_pack::{IsInitialized, Pack};
 use anchor_lang::solana_program::{account_info::AccountInfo, pubkey::Pubkey};
 use anchor_spl::associated_token::get_associated_token_address;
@@ -78,9 +82,49 @@ pub fn validate_fee_accounts(fee_accounts: &[FeeAccount]) -> Result<()> {
     Ok(())
 }
 
-/// Validates settings arguments (URI length and fee accounts)
-pub fn validate_settings_args(args: &SettingsArgs) -> Result<()> {
+/// Public draws don't need the mint authority, so they must be paid through guard phases.
+pub fn validate_public_draw_phases(phases: &[GuardPhase]) -> Result<()> {
+    require!(
+        !phases.is_empty() && phases.iter().all(|phase| phase.price > 0),
+        JellybeanError::PublicDrawWithoutPrice
+    );
+    Ok(())
+}
+
+/// Validates settings arguments (URI lengths and fee accounts) against the machine's guard
+/// phases
+pub fn validate_settings_args(args: &SettingsArgs, phases: &[GuardPhase]) -> Result<()> {
     validate_uri_length(&args.uri)?;
     validate_fee_accounts(&args.fee_accounts)?;
+    if let Some(reveal_config) = &args.reveal_config {
+        if reveal_config.placeholder_uri.len() > MAX_PLACEHOLDER_URI_LENGTH {
+            return err!(JellybeanError::UriTooLong);
+        }
+    }
+    if let (Some(go_live_at), Some(end_at)) = (args.go_live_at, args.end_at) {
+        require!(go_live_at < end_at, JellybeanError::InvalidSchedule);
+    }
+    // Prizes can only be reclaimed once the sale is over. Without end_at the claim deadline
+    // also ends the sale, as no draw is accepted after it
+    if let (Some(claim_deadline), Some(end_at)) = (args.claim_deadline, args.end_at) {
+        require!(end_at < claim_deadline, JellybeanError::InvalidSchedule);
+    }
+    if args.public_draw {
+        validate_public_draw_phases(phases)?;
+    }
     Ok(())
 }
+
+/// Verifies that the leaf, keccak256 of the address, is in the Merkle tree. Each pair of
+/// nodes is hashed in sorted order.
+pub fn verify_merkle_proof(proof: &[[u8; 32]], root: &[u8; 32], address: &Pubkey) -> bool {
+    let mut computed_hash = keccak::hashv(&[address.as_ref()]).0;
+    for proof_element in proof.iter() {
+        computed_hash = if computed_hash <= *proof_element {
+            keccak::hashv(&[&computed_hash, proof_element]).0
+        } else {
+            keccak::hashv(&[proof_element, &computed_hash]).0
+        };
+    }
+    computed_hash == *root
+}
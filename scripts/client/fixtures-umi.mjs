@@ -0,0 +1,262 @@
+#!/usr/bin/env zx
+import crypto from 'node:crypto';
+import 'zx/globals';
+import { workingDirectory } from '../utils.mjs';
+
+// Writes the accounts of a version 0 jellybean machine, as the previous program
+// left them, so the migrations can be tested against legacy data. The validator
+// loads them from the fixtures directory on start.
+//
+// The machine is live with two custodied Core assets. The first has been drawn
+// by the buyer and is waiting to be claimed, the second can still be drawn.
+// Keys are derived from fixed seeds, tests recreate them with the same seeds.
+
+const PROGRAM_ID = 'J3LLYcm8V5hJRzCKENRPW3yGdQ6xU8Nie8jr3mU88eqq';
+const MPL_CORE_PROGRAM_ID = 'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d';
+const BASE58_ALPHABET =
+  '123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz';
+const SEEDS = {
+  authority: 1,
+  buyer: 2,
+  firstAsset: 3,
+  secondAsset: 4,
+  jellybeanMachine: 5,
+};
+
+const outputDir = path.join(
+  workingDirectory,
+  'clients',
+  'umi',
+  'test',
+  'fixtures'
+);
+await fs.mkdirp(outputDir);
+
+const authority = keypairFromSeed(SEEDS.authority);
+const buyer = keypairFromSeed(SEEDS.buyer);
+const firstAsset = keypairFromSeed(SEEDS.firstAsset);
+const secondAsset = keypairFromSeed(SEEDS.secondAsset);
+const jellybeanMachine = keypairFromSeed(SEEDS.jellybeanMachine);
+const authorityPda = findProgramAddress(
+  [Buffer.from('authority'), jellybeanMachine],
+  PROGRAM_ID
+);
+const unclaimedPrizes = findProgramAddress(
+  [Buffer.from('unclaimed_prizes'), jellybeanMachine, buyer],
+  PROGRAM_ID
+);
+
+// Version 0 jellybean machine, a 660 byte base and one fee account followed by
+// 52 byte loaded items.
+const machineBase = Buffer.concat([
+  discriminator('JellybeanMachine'),
+  u8(0), // version
+  authority, // authority
+  authority, // mint_authority
+  u32(1), // fee_accounts
+  authority,
+  u16(10000),
+  u8(0), // print_fee_config
+  u8(2), // items_loaded
+  u64(2), // supply_loaded
+  u64(1), // supply_redeemed
+  u8(1), // state: SaleLive
+  string('https://example.com/jellybean-machine.json'), // uri
+]);
+const machineData = Buffer.concat([
+  machineBase,
+  Buffer.alloc(660 + 34 - machineBase.length),
+  loadedItemV0(firstAsset, { supplyRedeemed: 1 }),
+  loadedItemV0(secondAsset, { supplyRedeemed: 0 }),
+]);
+
+// Version 0 unclaimed prizes, with u8 item indices and no item wins.
+const unclaimedPrizesData = Buffer.concat([
+  discriminator('UnclaimedPrizes'),
+  u8(0), // version
+  jellybeanMachine,
+  buyer,
+  u32(1), // prizes
+  u8(0), // item_index
+  u32(1), // edition_number
+]);
+
+writeAccount('jellybean-machine-v0', jellybeanMachine, PROGRAM_ID, machineData);
+writeAccount(
+  'unclaimed-prizes-v0',
+  unclaimedPrizes,
+  PROGRAM_ID,
+  unclaimedPrizesData
+);
+writeAccount(
+  'core-asset-1',
+  firstAsset,
+  MPL_CORE_PROGRAM_ID,
+  coreAsset(authorityPda, authority, 'Legacy Asset #1')
+);
+writeAccount(
+  'core-asset-2',
+  secondAsset,
+  MPL_CORE_PROGRAM_ID,
+  coreAsset(authorityPda, authority, 'Legacy Asset #2')
+);
+
+echo(chalk.green(`Wrote version 0 fixtures to ${outputDir}`));
+
+function loadedItemV0(mint, { supplyRedeemed }) {
+  return Buffer.concat([
+    mint,
+    u32(1), // supply_loaded
+    u32(supplyRedeemed),
+    u32(0), // supply_claimed
+    u64(0), // escrow_amount
+  ]);
+}
+
+// Core AssetV1 without plugins, owned by the authority pda like the assets
+// version 0 loaded.
+function coreAsset(owner, updateAuthority, name) {
+  return Buffer.concat([
+    u8(1), // key: AssetV1
+    owner,
+    u8(1), // update_authority: Address
+    updateAuthority,
+    string(name),
+    string('https://example.com/my-asset.json'),
+    u8(0), // seq
+  ]);
+}
+
+function writeAccount(name, address, owner, data) {
+  const account = {
+    pubkey: encodeBase58(address),
+    account: {
+      lamports: (128 + data.length) * 3480 * 2,
+      data: [data.toString('base64'), 'base64'],
+      owner,
+      executable: false,
+      rentEpoch: 0,
+      space: data.length,
+    },
+  };
+  fs.writeFileSync(
+    path.join(outputDir, `${name}.json`),
+    `${JSON.stringify(account, null, 2)}\n`
+  );
+}
+
+function keypairFromSeed(seed) {
+  const pkcs8Prefix = Buffer.from('302e020100300506032b657004220420', 'hex');
+  const privateKey = crypto.createPrivateKey({
+    key: Buffer.concat([pkcs8Prefix, Buffer.alloc(32, seed)]),
+    format: 'der',
+    type: 'pkcs8',
+  });
+  const { x } = crypto.createPublicKey(privateKey).export({ format: 'jwk' });
+  return Buffer.from(x, 'base64url');
+}
+
+function discriminator(account) {
+  return sha256(Buffer.from(`account:${account}`)).subarray(0, 8);
+}
+
+function findProgramAddress(seeds, programId) {
+  for (let bump = 255; bump >= 0; bump--) {
+    const address = sha256(
+      Buffer.concat([
+        ...seeds,
+        Buffer.from([bump]),
+        decodeBase58(programId),
+        Buffer.from('ProgramDerivedAddress'),
+      ])
+    );
+    if (!isOnCurve(address)) return address;
+  }
+  throw new Error('Unable to find a program address');
+}
+
+// Whether the bytes decompress to an ed25519 point, program addresses must not.
+function isOnCurve(bytes) {
+  const p = 2n ** 255n - 19n;
+  const d = mod(-121665n * inverse(121666n, p), p);
+  const y =
+    BigInt(`0x${Buffer.from(bytes).reverse().toString('hex')}`) &
+    ((1n << 255n) - 1n);
+  if (y >= p) return false;
+  const y2 = mod(y * y, p);
+  const x2 = mod((y2 - 1n) * inverse(d * y2 + 1n, p), p);
+  return x2 === 0n || pow(x2, (p - 1n) / 2n, p) === 1n;
+}
+
+function mod(a, m) {
+  return ((a % m) + m) % m;
+}
+
+function pow(base, exponent, m) {
+  let result = 1n;
+  base = mod(base, m);
+  while (exponent > 0n) {
+    if (exponent & 1n) result = mod(result * base, m);
+    base = mod(base * base, m);
+    exponent >>= 1n;
+  }
+  return result;
+}
+
+function inverse(a, m) {
+  return pow(a, m - 2n, m);
+}
+
+function sha256(data) {
+  return crypto.createHash('sha256').update(data).digest();
+}
+
+function encodeBase58(bytes) {
+  let value = BigInt(`0x${Buffer.from(bytes).toString('hex') || '0'}`);
+  let encoded = '';
+  while (value > 0n) {
+    encoded = BASE58_ALPHABET[Number(value % 58n)] + encoded;
+    value /= 58n;
+  }
+  for (const byte of bytes) {
+    if (byte !== 0) break;
+    encoded = `1${encoded}`;
+  }
+  return encoded;
+}
+
+function decodeBase58(encoded) {
+  let value = 0n;
+  for (const char of encoded) {
+    value = value * 58n + BigInt(BASE58_ALPHABET.indexOf(char));
+  }
+  const bytes = Buffer.from(value.toString(16).padStart(64, '0'), 'hex');
+  return bytes.subarray(bytes.length - 32);
+}
+
+function u8(value) {
+  return Buffer.from([value]);
+}
+
+function u16(value) {
+  const buffer = Buffer.alloc(2);
+  buffer.writeUInt16LE(value);
+  return buffer;
+}
+
+function u32(value) {
+  const buffer = Buffer.alloc(4);
+  buffer.writeUInt32LE(value);
+  return buffer;
+}
+
+function u64(value) {
+  const buffer = Buffer.alloc(8);
+  buffer.writeBigUInt64LE(BigInt(value));
+  return buffer;
+}
+
+function string(value) {
+  const bytes = Buffer.from(value, 'utf8');
+  return Buffer.concat([u32(bytes.length), bytes]);
+}
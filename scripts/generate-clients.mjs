@@ -1,5 +1,8 @@
 #!/usr/bin/env zx
-import { rootNodeFromAnchor } from '@codama/nodes-from-anchor';
+import {
+  accountNodeFromAnchorV00,
+  rootNodeFromAnchor,
+} from '@codama/nodes-from-anchor';
 import { renderVisitor as renderJavaScriptVisitor } from '@codama/renderers-js';
 import { renderVisitor as renderUmiVisitor } from '@codama/renderers-js-umi';
 import { renderVisitor as renderRustVisitor } from '@codama/renderers-rust';
@@ -20,9 +23,131 @@ codama.update(
   })
 );
 
+// Add accounts only passed as remaining accounts, left out of the Anchor IDL.
+codama.update(
+  c.bottomUpTransformerVisitor([
+    {
+      select: '[programNode]mallowJellybean',
+      transform: (node) => {
+        c.assertIsNode(node, 'programNode');
+        return c.programNode({
+          ...node,
+          accounts: [
+            ...node.accounts,
+            accountNodeFromAnchorV00({
+              name: 'BuyerRecord',
+              docs: [
+                'Draws made by a buyer from a machine, in total and in each guard phase. Unlike unclaimed',
+                'prizes, it is kept until the machine is withdrawn or cancelled, so per-wallet limits hold',
+                'after prizes are claimed.',
+              ],
+              type: {
+                kind: 'struct',
+                fields: [
+                  {
+                    name: 'version',
+                    docs: ['Version of the account.'],
+                    type: 'u8',
+                  },
+                  {
+                    name: 'jellybeanMachine',
+                    docs: ['Pubkey of the JellybeanMachine account.'],
+                    type: 'publicKey',
+                  },
+                  {
+                    name: 'buyer',
+                    docs: ['Buyer the draws are counted for.'],
+                    type: 'publicKey',
+                  },
+                  {
+                    name: 'draws',
+                    docs: ['Lifetime draws of the buyer.'],
+                    type: 'u32',
+                  },
+                  {
+                    name: 'phaseDraws',
+                    docs: ['Draws in each guard phase, by phase index.'],
+                    type: { array: ['u16', 8] },
+                  },
+                ],
+              },
+            }),
+            accountNodeFromAnchorV00({
+              name: 'UsedGateAsset',
+              docs: [
+                'Marks a Core asset as used by a gate with the MarkUsed action.',
+              ],
+              type: {
+                kind: 'struct',
+                fields: [
+                  {
+                    name: 'jellybeanMachine',
+                    docs: ['Pubkey of the JellybeanMachine account.'],
+                    type: 'publicKey',
+                  },
+                  {
+                    name: 'asset',
+                    docs: ['Core asset used to pass the gate.'],
+                    type: 'publicKey',
+                  },
+                  {
+                    name: 'buyer',
+                    docs: [
+                      'Buyer the asset was used by, gets the rent back when the account is closed.',
+                    ],
+                    type: 'publicKey',
+                  },
+                ],
+              },
+            }),
+          ],
+        });
+      },
+    },
+  ])
+);
+
 // Update accounts.
 codama.update(
   c.updateAccountsVisitor({
+    allowlistProof: {
+      seeds: [
+        c.constantPdaSeedNodeFromString('utf8', 'allowlist_proof'),
+        c.variablePdaSeedNode('jellybeanMachine', c.publicKeyTypeNode()),
+        c.variablePdaSeedNode(
+          'merkleRoot',
+          c.fixedSizeTypeNode(c.bytesTypeNode(), 32)
+        ),
+        c.variablePdaSeedNode('buyer', c.publicKeyTypeNode()),
+      ],
+    },
+    bundle: {
+      seeds: [
+        c.constantPdaSeedNodeFromString('utf8', 'bundle'),
+        c.variablePdaSeedNode('jellybeanMachine', c.publicKeyTypeNode()),
+        c.variablePdaSeedNode('id', c.numberTypeNode('u32')),
+      ],
+    },
+    buyerRecord: {
+      seeds: [
+        c.constantPdaSeedNodeFromString('utf8', 'buyer_record'),
+        c.variablePdaSeedNode('jellybeanMachine', c.publicKeyTypeNode()),
+        c.variablePdaSeedNode('buyer', c.publicKeyTypeNode()),
+      ],
+    },
+    guardPhases: {
+      seeds: [
+        c.constantPdaSeedNodeFromString('utf8', 'guard_phases'),
+        c.variablePdaSeedNode('jellybeanMachine', c.publicKeyTypeNode()),
+      ],
+    },
+    printTemplate: {
+      seeds: [
+        c.constantPdaSeedNodeFromString('utf8', 'print_template'),
+        c.variablePdaSeedNode('jellybeanMachine', c.publicKeyTypeNode()),
+        c.variablePdaSeedNode('collection', c.publicKeyTypeNode()),
+      ],
+    },
     unclaimedPrizes: {
       seeds: [
         c.constantPdaSeedNodeFromString('utf8', 'unclaimed_prizes'),
@@ -30,6 +155,13 @@ codama.update(
         c.variablePdaSeedNode('buyer', c.publicKeyTypeNode()),
       ],
     },
+    usedGateAsset: {
+      seeds: [
+        c.constantPdaSeedNodeFromString('utf8', 'used_gate_asset'),
+        c.variablePdaSeedNode('jellybeanMachine', c.publicKeyTypeNode()),
+        c.variablePdaSeedNode('asset', c.publicKeyTypeNode()),
+      ],
+    },
   })
 );
 
@@ -55,11 +187,30 @@ codama.update(
       defaultValue: c.pdaValueNode('unclaimedPrizes'),
       ignoreIfOptional: true,
     },
+    {
+      account: 'guardPhases',
+      defaultValue: c.pdaValueNode('guardPhases'),
+      ignoreIfOptional: true,
+    },
+    {
+      account: 'allowlistProof',
+      defaultValue: c.pdaValueNode('allowlistProof'),
+      ignoreIfOptional: true,
+    },
     {
       account: 'authorityPda',
       defaultValue: c.resolverValueNode('resolveAuthorityPda'),
       ignoreIfOptional: true,
     },
+    {
+      account: 'printTemplate',
+      defaultValue: c.resolverValueNode('resolvePrintTemplatePda', {
+        dependsOn: [
+          c.accountValueNode('jellybeanMachine'),
+          c.accountValueNode('collection'),
+        ],
+      }),
+    },
     {
       account: 'eventAuthority',
       defaultValue: c.resolverValueNode('resolveEventAuthorityPda'),
@@ -85,6 +236,9 @@ codama.update(
   c.updateInstructionsVisitor({
     addCoreItem: {
       arguments: {
+        supply: {
+          defaultValue: c.noneValueNode(),
+        },
         unused: {
           type: c.optionTypeNode(c.booleanTypeNode()),
           defaultValue: c.booleanValueNode(false),
@@ -92,6 +246,31 @@ codama.update(
         },
       },
     },
+    unloadAll: {
+      arguments: {
+        unused: {
+          type: c.optionTypeNode(c.booleanTypeNode()),
+          defaultValue: c.booleanValueNode(false),
+          docs: 'Forcing UnloadAllInstructionExtraArgs to be rendered to fix a bug where resolvedArgs is using an undefined type',
+        },
+      },
+    },
+    addBundleComponent: {
+      accounts: {
+        bundle: {
+          defaultValue: c.pdaValueNode('bundle'),
+        },
+      },
+    },
+    addBundleItem: {
+      arguments: {
+        unused: {
+          type: c.optionTypeNode(c.booleanTypeNode()),
+          defaultValue: c.booleanValueNode(false),
+          docs: 'Forcing AddBundleItemInstructionExtraArgs to be rendered to fix a bug where resolvedArgs is using an undefined type',
+        },
+      },
+    },
     draw: {
       accounts: {
         buyer: {
@@ -113,6 +292,31 @@ codama.update(
         },
       },
     },
+    proveAllowlist: {
+      accounts: {
+        buyer: {
+          defaultValue: c.identityValueNode(),
+        },
+      },
+    },
+    withdraw: {
+      arguments: {
+        unused: {
+          type: c.optionTypeNode(c.booleanTypeNode()),
+          defaultValue: c.booleanValueNode(false),
+          docs: 'Forcing WithdrawInstructionExtraArgs to be rendered to fix a bug where resolvedArgs is using an undefined type',
+        },
+      },
+    },
+    cancelSale: {
+      arguments: {
+        unused: {
+          type: c.optionTypeNode(c.booleanTypeNode()),
+          defaultValue: c.booleanValueNode(false),
+          docs: 'Forcing CancelSaleInstructionExtraArgs to be rendered to fix a bug where resolvedArgs is using an undefined type',
+        },
+      },
+    },
   })
 );
 
@@ -120,6 +324,15 @@ codama.update(
   c.setStructDefaultValuesVisitor({
     settingsArgs: {
       printFeeConfig: c.noneValueNode(),
+      provenanceConfig: c.noneValueNode(),
+      revealConfig: c.noneValueNode(),
+      goLiveAt: c.noneValueNode(),
+      endAt: c.noneValueNode(),
+      maxDrawsPerWallet: c.noneValueNode(),
+      botTax: c.noneValueNode(),
+      claimDeadline: c.noneValueNode(),
+      publicDraw: c.booleanValueNode(false),
+      maxWinsPerItem: c.noneValueNode(),
     },
   })
 );
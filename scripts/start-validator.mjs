@@ -58,6 +58,19 @@ accounts.forEach(({ account, deployPath }) => {
   args.push(/* Load account */ '--account', account, deployPath);
 });
 
+// Load test fixtures, accounts left by previous versions of the programs.
+const fixturesDir = path.join(
+  __dirname,
+  '..',
+  'clients',
+  'umi',
+  'test',
+  'fixtures'
+);
+if (fs.existsSync(fixturesDir)) {
+  args.push(/* Load accounts */ '--account-dir', fixturesDir);
+}
+
 // Start the validator in detached mode.
 const cliLogs = path.join(os.tmpdir(), 'validator-cli.log');
 fs.writeFileSync(cliLogs, '', () => {});